@@ -2,7 +2,7 @@ extern crate proc_macro;
 use proc_macro::TokenStream;
 use proc_macro_hack::proc_macro_hack;
 use quote::{quote, ToTokens};
-use style_shared::{Color, DynamicStyles, Style, Styles};
+use style_shared::{Color, DynamicStyles, Style, Styles, Stylesheet};
 
 #[proc_macro_hack]
 pub fn styles(s: TokenStream) -> TokenStream {
@@ -23,6 +23,15 @@ pub fn static_styles(s: TokenStream) -> TokenStream {
     quote!(#styles).into()
 }
 
+#[proc_macro_hack]
+pub fn stylesheet(s: TokenStream) -> TokenStream {
+    let stylesheet: Stylesheet = match syn::parse(s) {
+        Ok(s) => s,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    stylesheet.to_token_stream().into()
+}
+
 #[proc_macro_hack]
 pub fn property(s: TokenStream) -> TokenStream {
     let style: Style = match syn::parse(s) {
@@ -0,0 +1,1034 @@
+//! Interpolation between values, for transitions and animations.
+//!
+//! https://www.w3.org/TR/css-values-4/#interpolation
+use crate::*;
+use std::mem;
+
+/// A value that can be interpolated with another value of the same type.
+pub trait Animate: Sized + Clone {
+    /// The value `progress` of the way from `self` to `other`, or `None` if the two values can't
+    /// be interpolated (like `auto` and a length). Progress outside `0..=1` extrapolates.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self>;
+
+    /// Like `animate`, but values that can't be interpolated switch from `self` to `other`
+    /// halfway through.
+    fn interpolate(&self, other: &Self, progress: f64) -> Self {
+        self.animate(other, progress)
+            .unwrap_or_else(|| discrete(self, other, progress))
+    }
+}
+
+/// How values that can't be interpolated are animated: `from` until halfway, then `to`.
+pub fn discrete<T: Clone>(from: &T, to: &T, progress: f64) -> T {
+    if progress < 0.5 {
+        from.clone()
+    } else {
+        to.clone()
+    }
+}
+
+fn lerp(from: f64, to: f64, progress: f64) -> f64 {
+    from + (to - from) * progress
+}
+
+impl Animate for f64 {
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        Some(lerp(*self, *other, progress))
+    }
+}
+
+impl Animate for Percentage {
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        Some(Percentage(lerp(self.0, other.0, progress)))
+    }
+}
+
+impl Length {
+    /// The number, without the unit.
+    fn value(self) -> f64 {
+        match self {
+            Length::Em(v)
+            | Length::Ex(v)
+            | Length::In(v)
+            | Length::Cm(v)
+            | Length::Mm(v)
+            | Length::Pt(v)
+            | Length::Pc(v)
+            | Length::Px(v) => v,
+            Length::Zero => 0.0,
+        }
+    }
+
+    /// The same unit with a different number.
+    fn with_value(self, value: f64) -> Length {
+        match self {
+            Length::Em(_) => Length::Em(value),
+            Length::Ex(_) => Length::Ex(value),
+            Length::In(_) => Length::In(value),
+            Length::Cm(_) => Length::Cm(value),
+            Length::Mm(_) => Length::Mm(value),
+            Length::Pt(_) => Length::Pt(value),
+            Length::Pc(_) => Length::Pc(value),
+            Length::Px(_) => Length::Px(value),
+            Length::Zero => Length::Zero,
+        }
+    }
+
+    pub(crate) fn is_font_relative(self) -> bool {
+        matches!(self, Length::Em(_) | Length::Ex(_))
+    }
+}
+
+impl Animate for Length {
+    /// Lengths with the same unit keep it, and absolute lengths with different units become
+    /// pixels. Font-relative lengths can only be mixed with other units inside `calc()`.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        let (from, to) = (*self, *other);
+        Some(match (from, to) {
+            (Length::Zero, Length::Zero) => Length::Zero,
+            (Length::Zero, to) => to.with_value(to.value() * progress),
+            (from, Length::Zero) => from.with_value(from.value() * (1.0 - progress)),
+            _ if mem::discriminant(&from) == mem::discriminant(&to) => {
+                from.with_value(lerp(from.value(), to.value(), progress))
+            }
+            _ if !from.is_font_relative() && !to.is_font_relative() => {
+                Length::Px(lerp(from.to_px(0.0), to.to_px(0.0), progress))
+            }
+            _ => return None,
+        })
+    }
+}
+
+impl Animate for LengthPercentage {
+    /// A length and a percentage can only be mixed inside `calc()`.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        match (self, other) {
+            (LengthPercentage::Length(from), LengthPercentage::Length(to)) => {
+                from.animate(to, progress).map(LengthPercentage::Length)
+            }
+            (LengthPercentage::Percentage(from), LengthPercentage::Percentage(to)) => {
+                from.animate(to, progress).map(LengthPercentage::Percentage)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Animate for Calc {
+    /// Values that can't be combined any other way give a `calc()` sum with one term per unit,
+    /// like `calc(5px + 25%)` halfway from `10px` to `50%`.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        if let (Calc::Normal(from), Calc::Normal(to)) = (self, other) {
+            if let Some(value) = from.animate(to, progress) {
+                return Some(Calc::Normal(value));
+            }
+        }
+        let mut terms = Vec::new();
+        add_terms(&mut terms, self, 1.0 - progress)?;
+        add_terms(&mut terms, other, progress)?;
+        Some(calc_from_terms(terms))
+    }
+}
+
+/// Add the terms of `calc`, multiplied by `factor`, to `terms`, where there is at most one term
+/// for each unit. Gives `None` if `calc` isn't a simple sum.
+fn add_terms(terms: &mut Vec<LengthPercentage>, calc: &Calc, factor: f64) -> Option<()> {
+    let mut add = |term: &LengthPercentage, factor: f64| {
+        let (key, value) = match term {
+            LengthPercentage::Length(Length::Zero) => return,
+            LengthPercentage::Length(v) => (Some(mem::discriminant(v)), v.value() * factor),
+            LengthPercentage::Percentage(v) => (None, v.0 * factor),
+        };
+        let existing = terms.iter_mut().find(|term| match term {
+            LengthPercentage::Length(v) => Some(mem::discriminant(v)) == key,
+            LengthPercentage::Percentage(_) => key.is_none(),
+        });
+        match (existing, term) {
+            (Some(LengthPercentage::Length(v)), _) => *v = v.with_value(v.value() + value),
+            (Some(LengthPercentage::Percentage(v)), _) => v.0 += value,
+            (None, LengthPercentage::Length(v)) => {
+                terms.push(LengthPercentage::Length(v.with_value(value)))
+            }
+            (None, LengthPercentage::Percentage(_)) => {
+                terms.push(LengthPercentage::Percentage(Percentage(value)))
+            }
+        }
+    };
+    let term = |product: &CalcProduct| match (&product.first, product.rest.is_empty()) {
+        (CalcValue::LengthPercentage(v), true) => Some(*v),
+        _ => None,
+    };
+    match calc {
+        Calc::Normal(v) => add(v, factor),
+        Calc::Calculated(sum) => {
+            add(&term(&sum.first)?, factor);
+            for op in sum.rest.iter() {
+                match op {
+                    SumOp::Add(v) => add(&term(v)?, factor),
+                    SumOp::Sub(v) => add(&term(v)?, -factor),
+                }
+            }
+        }
+    }
+    Some(())
+}
+
+fn calc_from_terms(terms: Vec<LengthPercentage>) -> Calc {
+    let product = |value: LengthPercentage| CalcProduct {
+        first: CalcValue::LengthPercentage(value),
+        rest: Vec::new(),
+    };
+    let mut terms = terms.into_iter();
+    let first = match terms.next() {
+        Some(first) => first,
+        None => return Calc::Normal(LengthPercentage::Length(Length::Zero)),
+    };
+    let rest: Vec<SumOp> = terms
+        .map(|term| match term {
+            LengthPercentage::Length(v) if v.value() < 0.0 => {
+                SumOp::Sub(product(LengthPercentage::Length(v.with_value(-v.value()))))
+            }
+            LengthPercentage::Percentage(v) if v.0 < 0.0 => {
+                SumOp::Sub(product(LengthPercentage::Percentage(Percentage(-v.0))))
+            }
+            term => SumOp::Add(product(term)),
+        })
+        .collect();
+    if rest.is_empty() {
+        return Calc::Normal(first);
+    }
+    Calc::Calculated(CalcSum {
+        first: product(first),
+        rest,
+    })
+}
+
+impl Animate for Color {
+    /// Colors are interpolated in sRGB, the default for the legacy color syntaxes. Use
+    /// `Color::interpolate_in` for other color spaces.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        Some(self.interpolate_in(*other, progress, ColorSpace::Srgb))
+    }
+}
+
+impl Animate for DynamicColor {
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        match (self, other) {
+            (DynamicColor::Literal(from), DynamicColor::Literal(to)) => {
+                from.animate(to, progress).map(DynamicColor::Literal)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Animate for FontWeight {
+    /// `normal` is 400 and `bold` is 700. `lighter` and `bolder` depend on the parent's weight,
+    /// so they can't be interpolated here.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        let number = |weight: &FontWeight| match weight {
+            FontWeight::Normal => Some(400.0),
+            FontWeight::Bold => Some(700.0),
+            FontWeight::Number(v) => Some(*v),
+            FontWeight::Lighter | FontWeight::Bolder => None,
+        };
+        let weight = lerp(number(self)?, number(other)?, progress);
+        Some(FontWeight::Number(weight.clamp(1.0, 1000.0)))
+    }
+}
+
+impl Animate for FontStretch {
+    /// Keywords are interpolated as their percentages.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        let width = lerp(self.percentage().0, other.percentage().0, progress);
+        Some(FontStretch::Percentage(Percentage(width.max(0.0))))
+    }
+}
+
+impl Animate for LineWidth {
+    /// `thin`, `medium` and `thick` are `1px`, `3px` and `5px`.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        let length = |width: &LineWidth| match width {
+            LineWidth::Length(v) => *v,
+            LineWidth::Thin => Length::Px(1.0),
+            LineWidth::Medium => Length::Px(3.0),
+            LineWidth::Thick => Length::Px(5.0),
+        };
+        length(self)
+            .animate(&length(other), progress)
+            .map(LineWidth::Length)
+    }
+}
+
+impl Animate for AutoLengthPercentage {
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        match (self, other) {
+            (
+                AutoLengthPercentage::LengthPercentage(from),
+                AutoLengthPercentage::LengthPercentage(to),
+            ) => from
+                .animate(to, progress)
+                .map(AutoLengthPercentage::LengthPercentage),
+            _ if self == other => Some(self.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl Animate for WidthHeight {
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        match (self, other) {
+            (WidthHeight::LengthPercentage(from), WidthHeight::LengthPercentage(to)) => from
+                .animate(to, progress)
+                .map(WidthHeight::LengthPercentage),
+            (WidthHeight::FitContent(from), WidthHeight::FitContent(to)) => {
+                from.animate(to, progress).map(WidthHeight::FitContent)
+            }
+            _ if self == other => Some(self.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl Animate for MaxWidthHeight {
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        match (self, other) {
+            (MaxWidthHeight::LengthPercentage(from), MaxWidthHeight::LengthPercentage(to)) => from
+                .animate(to, progress)
+                .map(MaxWidthHeight::LengthPercentage),
+            _ if self == other => Some(self.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl Animate for Width21 {
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        match (self, other) {
+            (Width21::LengthPercentage(from), Width21::LengthPercentage(to)) => {
+                from.animate(to, progress).map(Width21::LengthPercentage)
+            }
+            _ if self == other => Some(self.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl Animate for FlexBasis {
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        match (self, other) {
+            (FlexBasis::Width(from), FlexBasis::Width(to)) => {
+                from.animate(to, progress).map(FlexBasis::Width)
+            }
+            _ if self == other => Some(self.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl Animate for FontSize {
+    /// Only explicit sizes are interpolated, since the keywords depend on the renderer.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        match (self, other) {
+            (FontSize::LengthPercentage(from), FontSize::LengthPercentage(to)) => {
+                from.animate(to, progress).map(FontSize::LengthPercentage)
+            }
+            _ if self == other => Some(self.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Animate + PartialEq> Animate for Rect<T> {
+    /// Each side is interpolated separately, and the result uses the shortest form.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        let (from, to) = (self.sides(), other.sides());
+        let side = |idx: usize| {
+            if from[idx] == to[idx] {
+                Some(from[idx].clone())
+            } else {
+                from[idx].animate(to[idx], progress)
+            }
+        };
+        let (top, right, bottom, left) = (side(0)?, side(1)?, side(2)?, side(3)?);
+        Some(if right != left {
+            Rect::TopRightBottomLeft(top, right, bottom, left)
+        } else if top != bottom {
+            Rect::TopHorizontalBottom(top, right, bottom)
+        } else if top != right {
+            Rect::VerticalHorizontal(top, right)
+        } else {
+            Rect::All(top)
+        })
+    }
+}
+
+impl<T> Rect<T> {
+    /// The top, right, bottom and left values.
+    pub(crate) fn sides(&self) -> [&T; 4] {
+        match self {
+            Rect::All(v) => [v, v, v, v],
+            Rect::VerticalHorizontal(v, h) => [v, h, v, h],
+            Rect::TopHorizontalBottom(t, h, b) => [t, h, b, h],
+            Rect::TopRightBottomLeft(t, r, b, l) => [t, r, b, l],
+        }
+    }
+}
+
+impl<T: Animate> Animate for SingleOrDouble<T> {
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        let parts = |v: &Self| match v {
+            SingleOrDouble::Single(v) => (v.clone(), v.clone()),
+            SingleOrDouble::Double { horiz, vert } => (horiz.clone(), vert.clone()),
+        };
+        if let (SingleOrDouble::Single(from), SingleOrDouble::Single(to)) = (self, other) {
+            return from.animate(to, progress).map(SingleOrDouble::Single);
+        }
+        let (from, to) = (parts(self), parts(other));
+        Some(SingleOrDouble::Double {
+            horiz: from.0.animate(&to.0, progress)?,
+            vert: from.1.animate(&to.1, progress)?,
+        })
+    }
+}
+
+impl Animate for BorderRadius {
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        let horizontal = self.horizontal.animate(&other.horizontal, progress)?;
+        if self.vertical.is_none() && other.vertical.is_none() {
+            return Some(BorderRadius {
+                horizontal,
+                vertical: None,
+            });
+        }
+        let vertical = |v: &Self| v.vertical.clone().unwrap_or_else(|| v.horizontal.clone());
+        Some(BorderRadius {
+            horizontal,
+            vertical: Some(vertical(self).animate(&vertical(other), progress)?),
+        })
+    }
+}
+
+impl Animate for Angle {
+    /// Angles with the same unit keep it, and others become degrees.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        Some(match (*self, *other) {
+            (Angle::Zero, Angle::Zero) => Angle::Zero,
+            (Angle::Deg(from), Angle::Deg(to)) => Angle::Deg(lerp(from, to, progress)),
+            (Angle::Grad(from), Angle::Grad(to)) => Angle::Grad(lerp(from, to, progress)),
+            (Angle::Rad(from), Angle::Rad(to)) => Angle::Rad(lerp(from, to, progress)),
+            (Angle::Turn(from), Angle::Turn(to)) => Angle::Turn(lerp(from, to, progress)),
+            (from, to) => Angle::Deg(lerp(from.to_degrees(), to.to_degrees(), progress)),
+        })
+    }
+}
+
+impl Animate for TransformList {
+    /// There is no reference box here, so see `TransformList::interpolate_unresolved` for which
+    /// lists can be interpolated.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        self.interpolate_unresolved(other, progress)
+    }
+}
+
+fn zero_calc() -> Calc {
+    Calc::Normal(LengthPercentage::Length(Length::Zero))
+}
+
+impl Animate for Translate {
+    /// `none` and missing offsets are 0.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        let offsets = |v: &Translate| match v {
+            Translate::None => (zero_calc(), None, None),
+            Translate::Offset { x, y, z } => (x.clone(), y.clone(), *z),
+        };
+        if let (Translate::None, Translate::None) = (self, other) {
+            return Some(Translate::None);
+        }
+        let (from, to) = (offsets(self), offsets(other));
+        let has_z = from.2.is_some() || to.2.is_some();
+        let has_y = has_z || from.1.is_some() || to.1.is_some();
+        let y = if has_y {
+            let (from, to) = (
+                from.1.unwrap_or_else(zero_calc),
+                to.1.unwrap_or_else(zero_calc),
+            );
+            Some(from.animate(&to, progress)?)
+        } else {
+            None
+        };
+        let z = if has_z {
+            let (from, to) = (from.2.unwrap_or(Length::Zero), to.2.unwrap_or(Length::Zero));
+            Some(from.animate(&to, progress)?)
+        } else {
+            None
+        };
+        Some(Translate::Offset {
+            x: from.0.animate(&to.0, progress)?,
+            y,
+            z,
+        })
+    }
+}
+
+impl Animate for Rotate {
+    /// Rotations around the same axis interpolate the angle, where `none` is no rotation.
+    /// Rotations around different axes need the matrices, so can't be interpolated here.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        let axis = |v: &Rotate| {
+            let [x, y, z] = match v {
+                Rotate::None => return None,
+                Rotate::Angle(_) | Rotate::Axis(RotateAxis::Z, _) => [0.0, 0.0, 1.0],
+                Rotate::Axis(RotateAxis::X, _) => [1.0, 0.0, 0.0],
+                Rotate::Axis(RotateAxis::Y, _) => [0.0, 1.0, 0.0],
+                Rotate::Axis(RotateAxis::Vector(x, y, z), _) => [*x, *y, *z],
+            };
+            let len = (x * x + y * y + z * z).sqrt();
+            Some([x / len, y / len, z / len])
+        };
+        let angle = |v: &Rotate| match v {
+            Rotate::None => Angle::Zero,
+            Rotate::Angle(angle) | Rotate::Axis(_, angle) => *angle,
+        };
+        if let (Some(from), Some(to)) = (axis(self), axis(other)) {
+            if from != to {
+                return None;
+            }
+        }
+        let angle = angle(self).animate(&angle(other), progress)?;
+        // Use the form of whichever value isn't `none`.
+        Some(match (self, other) {
+            (Rotate::None, Rotate::None) => Rotate::None,
+            (Rotate::Angle(_), _) | (Rotate::None, Rotate::Angle(_)) => Rotate::Angle(angle),
+            (Rotate::Axis(axis, _), _) | (Rotate::None, Rotate::Axis(axis, _)) => {
+                Rotate::Axis(*axis, angle)
+            }
+        })
+    }
+}
+
+impl Animate for Scale {
+    /// `none` is a scale of 1. A missing `y` is the same as `x`, and a missing `z` is 1.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        let factors = |v: &Scale| match *v {
+            Scale::None => ([1.0; 3], false, false),
+            Scale::Factors { x, y, z } => (
+                [x, y.unwrap_or(x), z.unwrap_or(1.0)],
+                y.is_some() || z.is_some(),
+                z.is_some(),
+            ),
+        };
+        if let (Scale::None, Scale::None) = (self, other) {
+            return Some(Scale::None);
+        }
+        let ((from, from_y, from_z), (to, to_y, to_z)) = (factors(self), factors(other));
+        let [x, y, z] = [0, 1, 2].map(|idx| lerp(from[idx], to[idx], progress));
+        let has_z = from_z || to_z;
+        Some(Scale::Factors {
+            x,
+            y: (has_z || from_y || to_y).then_some(y),
+            z: has_z.then_some(z),
+        })
+    }
+}
+
+impl ShadowLength {
+    /// The horizontal and vertical offsets, blur and spread, where missing values are 0.
+    fn lengths(&self) -> [Length; 4] {
+        match *self {
+            ShadowLength::Offsets {
+                horizontal,
+                vertical,
+            } => [horizontal, vertical, Length::Zero, Length::Zero],
+            ShadowLength::OffsetsBlur {
+                horizontal,
+                vertical,
+                blur,
+            } => [horizontal, vertical, blur, Length::Zero],
+            ShadowLength::OffsetsBlurSpread {
+                horizontal,
+                vertical,
+                blur,
+                spread,
+            } => [horizontal, vertical, blur, spread],
+        }
+    }
+
+    /// How many of the lengths are written out.
+    fn len(&self) -> usize {
+        match self {
+            ShadowLength::Offsets { .. } => 2,
+            ShadowLength::OffsetsBlur { .. } => 3,
+            ShadowLength::OffsetsBlurSpread { .. } => 4,
+        }
+    }
+}
+
+impl Animate for ShadowLength {
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        let (from, to) = (self.lengths(), other.lengths());
+        let mut out = [Length::Zero; 4];
+        for (idx, length) in out.iter_mut().enumerate() {
+            *length = from[idx].animate(&to[idx], progress)?;
+        }
+        let [horizontal, vertical, blur, spread] = out;
+        Some(match self.len().max(other.len()) {
+            2 => ShadowLength::Offsets {
+                horizontal,
+                vertical,
+            },
+            3 => ShadowLength::OffsetsBlur {
+                horizontal,
+                vertical,
+                blur,
+            },
+            _ => ShadowLength::OffsetsBlurSpread {
+                horizontal,
+                vertical,
+                blur,
+                spread,
+            },
+        })
+    }
+}
+
+impl Animate for Shadow {
+    /// Shadows can only be interpolated if both or neither are `inset`. A missing color is
+    /// `currentcolor`, which can only be interpolated with another `currentcolor`.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        if self.inset != other.inset {
+            return None;
+        }
+        let color = match (&self.color, &other.color) {
+            (Some(from), Some(to)) => Some(from.animate(to, progress)?),
+            (None, None) => None,
+            _ => return None,
+        };
+        Some(Shadow {
+            color,
+            length: self.length.animate(&other.length, progress)?,
+            inset: self.inset,
+        })
+    }
+}
+
+/// Interpolate two lists of shadows, where `None` is no shadows.
+///
+/// The shorter list is padded with transparent shadows with no offset, blur or spread. The padding
+/// has no color if the shadow it pads doesn't, so the two can still be interpolated.
+fn animate_shadows(
+    from: Option<&NonemptyCommaList<Shadow>>,
+    to: Option<&NonemptyCommaList<Shadow>>,
+    progress: f64,
+) -> Option<Option<NonemptyCommaList<Shadow>>> {
+    let shadows = |list: Option<&NonemptyCommaList<Shadow>>| match list {
+        None => Vec::new(),
+        Some(list) => {
+            let mut shadows = vec![list.first.clone()];
+            shadows.extend(list.rest.iter().cloned());
+            shadows
+        }
+    };
+    let (from, to) = (shadows(from), shadows(to));
+    let padding = |shadow: &Shadow| Shadow {
+        color: shadow.color.map(|_| Color::HexRGBA(0, 0, 0, 0)),
+        length: ShadowLength::Offsets {
+            horizontal: Length::Zero,
+            vertical: Length::Zero,
+        },
+        inset: shadow.inset,
+    };
+    let mut out = Vec::new();
+    for idx in 0..from.len().max(to.len()) {
+        let shadow = match (from.get(idx), to.get(idx)) {
+            (Some(from), Some(to)) => from.animate(to, progress)?,
+            (Some(from), None) => from.animate(&padding(from), progress)?,
+            (None, Some(to)) => padding(to).animate(to, progress)?,
+            (None, None) => unreachable!(),
+        };
+        out.push(shadow);
+    }
+    let mut out = out.into_iter();
+    Some(out.next().map(|first| NonemptyCommaList {
+        first,
+        rest: out.collect(),
+    }))
+}
+
+impl Animate for BoxShadow {
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        fn list(v: &BoxShadow) -> Option<&NonemptyCommaList<Shadow>> {
+            match v {
+                BoxShadow::None => None,
+                BoxShadow::Shadows(list) => Some(list),
+            }
+        }
+        Some(match animate_shadows(list(self), list(other), progress)? {
+            Some(list) => BoxShadow::Shadows(list),
+            None => BoxShadow::None,
+        })
+    }
+}
+
+impl Animate for TextShadow {
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        fn list(v: &TextShadow) -> Option<&NonemptyCommaList<Shadow>> {
+            match v {
+                TextShadow::None => None,
+                TextShadow::Shadows(list) => Some(list),
+            }
+        }
+        Some(match animate_shadows(list(self), list(other), progress)? {
+            Some(list) => TextShadow::Shadows(list),
+            None => TextShadow::None,
+        })
+    }
+}
+
+impl Animate for Spacing {
+    /// `normal` is 0.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        let length = |v: &Spacing| match v {
+            Spacing::Normal => Length::Zero,
+            Spacing::Length(v) => *v,
+        };
+        if let (Spacing::Normal, Spacing::Normal) = (self, other) {
+            return Some(Spacing::Normal);
+        }
+        length(self)
+            .animate(&length(other), progress)
+            .map(Spacing::Length)
+    }
+}
+
+impl Animate for LineHeight {
+    /// Numbers can only be interpolated with numbers, and lengths with lengths.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        match (self, other) {
+            (LineHeight::Number(from), LineHeight::Number(to)) => {
+                Some(LineHeight::Number(lerp(*from, *to, progress).max(0.0)))
+            }
+            (LineHeight::LengthPercentage(from), LineHeight::LengthPercentage(to)) => {
+                from.animate(to, progress).map(LineHeight::LengthPercentage)
+            }
+            _ if self == other => Some(self.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Interpolate positions as offsets from the start edge, so `right 10px` is
+/// `calc(100% - 10px)`.
+fn animate_position<S: Clone + PartialEq>(
+    from: &PositionComponent<S>,
+    to: &PositionComponent<S>,
+    progress: f64,
+    is_end: impl Fn(&S) -> bool,
+) -> Option<PositionComponent<S>> {
+    if from == to {
+        return Some(from.clone());
+    }
+    let offset = |component: &PositionComponent<S>| {
+        Some(match component {
+            PositionComponent::Center => {
+                Calc::Normal(LengthPercentage::Percentage(Percentage(50.0)))
+            }
+            PositionComponent::Offset(v) => v.clone(),
+            PositionComponent::Side(side, offset) if !is_end(side) => {
+                offset.clone().unwrap_or_else(zero_calc)
+            }
+            PositionComponent::Side(_, offset) => {
+                let mut terms = vec![LengthPercentage::Percentage(Percentage(100.0))];
+                if let Some(offset) = offset {
+                    add_terms(&mut terms, offset, -1.0)?;
+                }
+                calc_from_terms(terms)
+            }
+        })
+    };
+    offset(from)?
+        .animate(&offset(to)?, progress)
+        .map(PositionComponent::Offset)
+}
+
+impl Animate for PositionComponent<HorizontalSide> {
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        animate_position(self, other, progress, |side| *side == HorizontalSide::Right)
+    }
+}
+
+impl Animate for PositionComponent<VerticalSide> {
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        animate_position(self, other, progress, |side| *side == VerticalSide::Bottom)
+    }
+}
+
+impl Animate for PositionValue {
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        Some(PositionValue {
+            x: self.x.animate(&other.x, progress)?,
+            y: self.y.animate(&other.y, progress)?,
+        })
+    }
+}
+
+impl Animate for Style {
+    /// Two declarations of the same property, for the properties that have values that can be
+    /// interpolated.
+    fn animate(&self, other: &Self, progress: f64) -> Option<Self> {
+        macro_rules! animate {
+            ($($variant:ident),* $(,)?) => {
+                match (self, other) {
+                    $(
+                        (Style::$variant(from), Style::$variant(to)) => {
+                            from.animate(to, progress).map(Style::$variant)
+                        }
+                    )*
+                    _ => None,
+                }
+            };
+        }
+        animate!(
+            BackgroundColor,
+            BackgroundPosition,
+            BackgroundPositionX,
+            BackgroundPositionY,
+            BorderBottomColor,
+            BorderBottomLeftRadius,
+            BorderBottomRightRadius,
+            BorderBottomWidth,
+            BorderColor,
+            BorderLeftColor,
+            BorderLeftWidth,
+            BorderRadius,
+            BorderRightColor,
+            BorderRightWidth,
+            BorderTopColor,
+            BorderTopLeftRadius,
+            BorderTopRightRadius,
+            BorderTopWidth,
+            BorderWidth,
+            Bottom,
+            BoxShadow,
+            Color,
+            FlexBasis,
+            FlexGrow,
+            FlexShrink,
+            FontSize,
+            FontStretch,
+            FontWeight,
+            Height,
+            Left,
+            LetterSpacing,
+            LineHeight,
+            Margin,
+            MarginBottom,
+            MarginLeft,
+            MarginRight,
+            MarginTop,
+            MaskPosition,
+            MaxHeight,
+            MaxWidth,
+            MinHeight,
+            MinWidth,
+            ObjectPosition,
+            Padding,
+            PaddingBottom,
+            PaddingLeft,
+            PaddingRight,
+            PaddingTop,
+            PerspectiveOrigin,
+            Right,
+            Rotate,
+            Scale,
+            TextShadow,
+            TextUnderlineOffset,
+            Top,
+            Transform,
+            Translate,
+            Width,
+            WordSpacing,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn style(css: &str) -> Style {
+        css.parse().unwrap()
+    }
+
+    fn interpolate(from: &str, to: &str, progress: f64) -> String {
+        style(from).interpolate(&style(to), progress).to_string()
+    }
+
+    #[test]
+    fn lengths() {
+        assert_eq!(
+            interpolate("width: 10px", "width: 20px", 0.25),
+            "width:12.5px"
+        );
+        assert_eq!(interpolate("top: 0", "top: 2em", 0.5), "top:1em");
+        assert_eq!(interpolate("left: 1in", "left: 10px", 0.5), "left:53px");
+        assert_eq!(
+            interpolate("width: 10px", "width: 50%", 0.5),
+            "width:calc(5px + 25%)"
+        );
+        assert_eq!(
+            interpolate("padding-top: 1em", "padding-top: calc(10% - 2em)", 0.5),
+            "padding-top:calc(-0.5em + 5%)"
+        );
+        assert_eq!(
+            interpolate("margin: 0 auto", "margin: 10px auto 20px", 0.5),
+            "margin:5px auto 10px"
+        );
+        assert_eq!(
+            interpolate("border-top-width: thin", "border-top-width: 5px", 0.5),
+            "border-top-width:3px"
+        );
+    }
+
+    #[test]
+    fn discrete_values() {
+        assert_eq!(interpolate("width: auto", "width: 10px", 0.4), "width:auto");
+        assert_eq!(interpolate("width: auto", "width: 10px", 0.5), "width:10px");
+        assert_eq!(
+            interpolate("display: block", "display: flex", 0.6),
+            "display:flex"
+        );
+        assert_eq!(
+            interpolate("font-weight: bold", "font-weight: lighter", 0.2),
+            "font-weight:bold"
+        );
+    }
+
+    #[test]
+    fn other_values() {
+        assert_eq!(
+            interpolate("color: #000", "color: #fff", 0.5),
+            "color:#808080"
+        );
+        assert_eq!(
+            interpolate("font-weight: normal", "font-weight: bold", 0.5),
+            "font-weight:550"
+        );
+        assert_eq!(
+            interpolate(
+                "box-shadow: 2px 2px red",
+                "box-shadow: 4px 4px 4px blue, 1px 1px 1px 1px black inset",
+                0.5
+            ),
+            "box-shadow:3px 3px 2px #800080,0.5px 0.5px 0.5px 0.5px #00000080 inset"
+        );
+        assert_eq!(
+            interpolate("box-shadow: none", "box-shadow: 2px 2px red", 0.5),
+            "box-shadow:1px 1px #ff000080"
+        );
+        assert_eq!(
+            interpolate("box-shadow: 1px 1px", "box-shadow: none", 0.4),
+            "box-shadow:0.6px 0.6px"
+        );
+        assert_eq!(
+            interpolate("flex-grow: 1", "flex-grow: 3", 0.5),
+            "flex-grow:2"
+        );
+        assert_eq!(
+            interpolate("font-stretch: condensed", "font-stretch: 125%", 0.5),
+            "font-stretch:100%"
+        );
+        assert_eq!(
+            interpolate(
+                "border-radius: 10px",
+                "border-radius: 20px 30px / 40px",
+                0.5
+            ),
+            "border-radius:15px 20px / 25px"
+        );
+        assert_eq!(
+            interpolate("letter-spacing: normal", "letter-spacing: 10px", 0.4),
+            "letter-spacing:4px"
+        );
+        assert_eq!(
+            interpolate("word-spacing: 1em", "word-spacing: 2em", 0.5),
+            "word-spacing:1.5em"
+        );
+        assert_eq!(
+            interpolate("text-shadow: none", "text-shadow: 2px 2px red", 0.5),
+            "text-shadow:1px 1px #ff000080"
+        );
+        assert_eq!(
+            interpolate("line-height: 1", "line-height: 2", 0.4),
+            "line-height:1.4"
+        );
+        assert_eq!(
+            interpolate("line-height: 10px", "line-height: 20px", 0.4),
+            "line-height:14px"
+        );
+        assert_eq!(
+            interpolate("line-height: 1", "line-height: 20px", 0.4),
+            "line-height:1"
+        );
+        assert_eq!(
+            interpolate(
+                "background-position: left top",
+                "background-position: right bottom",
+                0.4
+            ),
+            "background-position:40% 40%"
+        );
+        assert_eq!(
+            interpolate(
+                "object-position: center",
+                "object-position: right 10px top",
+                0.5
+            ),
+            "object-position:calc(75% - 5px) 25%"
+        );
+    }
+
+    #[test]
+    fn transforms() {
+        for (from, to, progress, output) in [
+            (
+                "transform: rotate(0deg)",
+                "transform: rotate(90deg)",
+                0.4,
+                "transform:rotate(36deg)",
+            ),
+            (
+                "transform: translate(10px) scale(2)",
+                "transform: none",
+                0.5,
+                "transform:translate(5px, 0px) scale(1.5, 1.5)",
+            ),
+            (
+                "transform: translateX(50%) rotate(0)",
+                "transform: translateY(1em) rotate(0.5turn)",
+                0.5,
+                "transform:translate(25%, 0.5em) rotate(90deg)",
+            ),
+            // the matrices would need the size of the box
+            (
+                "transform: translate(50%)",
+                "transform: rotate(10deg)",
+                0.4,
+                "transform:translate(50%)",
+            ),
+            (
+                "translate: none",
+                "translate: 10px 20%",
+                0.5,
+                "translate:5px 10%",
+            ),
+            ("rotate: 10deg", "rotate: none", 0.5, "rotate:5deg"),
+            ("rotate: x 0deg", "rotate: x 1turn", 0.25, "rotate:x 90deg"),
+            ("rotate: x 10deg", "rotate: y 20deg", 0.4, "rotate:x 10deg"),
+            ("scale: none", "scale: 2", 0.5, "scale:1.5"),
+            ("scale: 1 2", "scale: 3", 0.5, "scale:2 2.5"),
+        ] {
+            assert_eq!(interpolate(from, to, progress), output, "{} {}", from, to);
+        }
+    }
+}
@@ -0,0 +1,580 @@
+//! Values of the transition and animation properties, from
+//! https://www.w3.org/TR/css-transitions-1/, https://www.w3.org/TR/css-animations-1/ and
+//! https://www.w3.org/TR/css-easing-2/.
+use crate::{Percentage, Time};
+use std::fmt;
+
+/// https://www.w3.org/TR/css-easing-2/#easing-functions
+#[derive(Debug, Clone, PartialEq)]
+pub enum EasingFunction {
+    /// The `linear` keyword.
+    Linear,
+    Ease,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    /// `cubic-bezier(x1, y1, x2, y2)`. The x values are between 0 and 1.
+    CubicBezier(f64, f64, f64, f64),
+    StepStart,
+    StepEnd,
+    /// `steps(count, position)`. The count is at least 1, or 2 for `jump-none`.
+    Steps(u32, StepPosition),
+    /// `linear(...)`, with at least two stops.
+    LinearStops(Vec<LinearStop>),
+}
+
+impl EasingFunction {
+    /// Whether `name` is the name of an easing function, either a keyword or a function.
+    pub(crate) fn is_name(name: &str) -> bool {
+        [
+            "linear",
+            "ease",
+            "ease-in",
+            "ease-out",
+            "ease-in-out",
+            "cubic-bezier",
+            "step-start",
+            "step-end",
+            "steps",
+        ]
+        .iter()
+        .any(|n| n.eq_ignore_ascii_case(name))
+    }
+
+    /// The output progress for input progress `x`.
+    ///
+    /// Inputs outside `0..=1` are extrapolated. For `steps()`, the input is taken to be moving
+    /// forwards (the "before flag" of the spec is never set).
+    ///
+    /// https://www.w3.org/TR/css-easing-2/#easing-functions
+    pub fn evaluate(&self, x: f64) -> f64 {
+        match self {
+            EasingFunction::Linear => x,
+            EasingFunction::Ease => cubic_bezier(0.25, 0.1, 0.25, 1.0, x),
+            EasingFunction::EaseIn => cubic_bezier(0.42, 0.0, 1.0, 1.0, x),
+            EasingFunction::EaseOut => cubic_bezier(0.0, 0.0, 0.58, 1.0, x),
+            EasingFunction::EaseInOut => cubic_bezier(0.42, 0.0, 0.58, 1.0, x),
+            EasingFunction::CubicBezier(x1, y1, x2, y2) => cubic_bezier(*x1, *y1, *x2, *y2, x),
+            EasingFunction::StepStart => steps(1, StepPosition::JumpStart, x),
+            EasingFunction::StepEnd => steps(1, StepPosition::JumpEnd, x),
+            EasingFunction::Steps(count, position) => steps(*count, *position, x),
+            EasingFunction::LinearStops(stops) => linear(stops, x),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-easing-2/#cubic-bezier-algo
+fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64, x: f64) -> f64 {
+    // The curve is only defined for `0..=1`, outside that we continue along the tangent at the
+    // nearest end.
+    if x < 0.0 {
+        let gradient = if x1 > 0.0 {
+            y1 / x1
+        } else if y1 == 0.0 && x2 > 0.0 {
+            y2 / x2
+        } else {
+            0.0
+        };
+        return gradient * x;
+    }
+    if x > 1.0 {
+        let gradient = if x2 < 1.0 {
+            (y2 - 1.0) / (x2 - 1.0)
+        } else if y2 == 1.0 && x1 < 1.0 {
+            (y1 - 1.0) / (x1 - 1.0)
+        } else {
+            0.0
+        };
+        return 1.0 + gradient * (x - 1.0);
+    }
+
+    // Each coordinate is `3(1-t)²t p1 + 3(1-t)t² p2 + t³`.
+    let curve = |p1: f64, p2: f64, t: f64| {
+        let u = 1.0 - t;
+        3.0 * u * u * t * p1 + 3.0 * u * t * t * p2 + t * t * t
+    };
+    let slope = |p1: f64, p2: f64, t: f64| {
+        let u = 1.0 - t;
+        3.0 * u * u * p1 + 6.0 * u * t * (p2 - p1) + 3.0 * t * t * (1.0 - p2)
+    };
+
+    // Find `t` for `x`, first with newton's method, falling back to bisection (which always
+    // works because x is increasing in t when x1 and x2 are in `0..=1`).
+    const EPSILON: f64 = 1e-9;
+    let mut t = x;
+    for _ in 0..8 {
+        let error = curve(x1, x2, t) - x;
+        if error.abs() < EPSILON {
+            return curve(y1, y2, t);
+        }
+        let d = slope(x1, x2, t);
+        if d.abs() < EPSILON {
+            break;
+        }
+        t -= error / d;
+    }
+    let (mut lo, mut hi) = (0.0, 1.0);
+    t = x;
+    for _ in 0..64 {
+        let value = curve(x1, x2, t);
+        if (value - x).abs() < EPSILON {
+            break;
+        }
+        if value < x {
+            lo = t;
+        } else {
+            hi = t;
+        }
+        t = (lo + hi) / 2.0;
+    }
+    curve(y1, y2, t)
+}
+
+/// https://www.w3.org/TR/css-easing-2/#step-easing-algo
+fn steps(count: u32, position: StepPosition, x: f64) -> f64 {
+    let count = f64::from(count);
+    let mut step = (x * count).floor();
+    if matches!(position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+        step += 1.0;
+    }
+    if x >= 0.0 && step < 0.0 {
+        step = 0.0;
+    }
+    let jumps = match position {
+        StepPosition::JumpBoth => count + 1.0,
+        StepPosition::JumpNone => count - 1.0,
+        StepPosition::JumpStart | StepPosition::JumpEnd => count,
+    };
+    if x <= 1.0 && step > jumps {
+        step = jumps;
+    }
+    step / jumps
+}
+
+/// https://www.w3.org/TR/css-easing-2/#linear-easing-function-output
+fn linear(stops: &[LinearStop], x: f64) -> f64 {
+    let points = linear_points(stops);
+    let (first, last) = (points[0], points[points.len() - 1]);
+    // Pick the two points to interpolate between, using the first or last pair to extrapolate.
+    let (a, b) = if x < first.0 {
+        (first, points[1])
+    } else if x >= last.0 {
+        if x == last.0 {
+            return last.1;
+        }
+        (points[points.len() - 2], last)
+    } else {
+        let idx = points.iter().rposition(|point| point.0 <= x).unwrap();
+        (points[idx], points[idx + 1])
+    };
+    if a.0 == b.0 {
+        return b.1;
+    }
+    a.1 + (b.1 - a.1) * (x - a.0) / (b.0 - a.0)
+}
+
+/// The `(input, output)` control points of `linear()`, with every input filled in and never
+/// decreasing.
+///
+/// https://www.w3.org/TR/css-easing-2/#linear-canonicalization
+fn linear_points(stops: &[LinearStop]) -> Vec<(f64, f64)> {
+    let mut points: Vec<(Option<f64>, f64)> = Vec::new();
+    for stop in stops {
+        points.push((stop.start.map(|p| p.0 / 100.0), stop.output));
+        if let Some(end) = stop.end {
+            points.push((Some(end.0 / 100.0), stop.output));
+        }
+    }
+    if points[0].0.is_none() {
+        points[0].0 = Some(0.0);
+    }
+    let mut largest = f64::NEG_INFINITY;
+    for point in points.iter_mut() {
+        if let Some(input) = &mut point.0 {
+            *input = input.max(largest);
+            largest = *input;
+        }
+    }
+    let last = points.len() - 1;
+    if points[last].0.is_none() {
+        points[last].0 = Some(largest.max(1.0));
+    }
+    // Spread runs of points without an input evenly between their neighbours.
+    let mut idx = 1;
+    while idx < points.len() {
+        if points[idx].0.is_some() {
+            idx += 1;
+            continue;
+        }
+        let start = idx - 1;
+        let end = (idx..points.len())
+            .find(|&idx| points[idx].0.is_some())
+            .unwrap();
+        let (from, to) = (points[start].0.unwrap(), points[end].0.unwrap());
+        for (n, point) in points[start + 1..end].iter_mut().enumerate() {
+            point.0 = Some(from + (to - from) * (n + 1) as f64 / (end - start) as f64);
+        }
+        idx = end;
+    }
+    points
+        .into_iter()
+        .map(|(input, output)| (input.unwrap(), output))
+        .collect()
+}
+
+impl fmt::Display for EasingFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EasingFunction::Linear => write!(f, "linear"),
+            EasingFunction::Ease => write!(f, "ease"),
+            EasingFunction::EaseIn => write!(f, "ease-in"),
+            EasingFunction::EaseOut => write!(f, "ease-out"),
+            EasingFunction::EaseInOut => write!(f, "ease-in-out"),
+            EasingFunction::CubicBezier(x1, y1, x2, y2) => {
+                write!(f, "cubic-bezier({}, {}, {}, {})", x1, y1, x2, y2)
+            }
+            EasingFunction::StepStart => write!(f, "step-start"),
+            EasingFunction::StepEnd => write!(f, "step-end"),
+            EasingFunction::Steps(count, StepPosition::JumpEnd) => write!(f, "steps({})", count),
+            EasingFunction::Steps(count, position) => write!(f, "steps({}, {})", count, position),
+            EasingFunction::LinearStops(stops) => {
+                write!(f, "linear(")?;
+                for (idx, stop) in stops.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ", ")?;
+                    }
+                    stop.fmt(f)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// Where the jumps of `steps()` happen. `start` and `end` are parsed as `jump-start` and
+/// `jump-end`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StepPosition {
+    JumpStart,
+    JumpEnd,
+    JumpNone,
+    JumpBoth,
+}
+
+impl fmt::Display for StepPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StepPosition::JumpStart => write!(f, "jump-start"),
+            StepPosition::JumpEnd => write!(f, "jump-end"),
+            StepPosition::JumpNone => write!(f, "jump-none"),
+            StepPosition::JumpBoth => write!(f, "jump-both"),
+        }
+    }
+}
+
+/// A stop of `linear()`: an output value, with up to two input progress values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearStop {
+    pub output: f64,
+    pub start: Option<Percentage>,
+    /// Only present if there is a `start`.
+    pub end: Option<Percentage>,
+}
+
+impl fmt::Display for LinearStop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.output)?;
+        if let Some(start) = &self.start {
+            write!(f, " {}", start)?;
+        }
+        if let Some(end) = &self.end {
+            write!(f, " {}", end)?;
+        }
+        Ok(())
+    }
+}
+
+/// One of the values of `transition-property`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransitionProperty {
+    None,
+    All,
+    /// The name of a property.
+    Property(String),
+}
+
+impl fmt::Display for TransitionProperty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TransitionProperty::None => write!(f, "none"),
+            TransitionProperty::All => write!(f, "all"),
+            TransitionProperty::Property(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-transitions-2/#transition-behavior-property
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransitionBehavior {
+    Normal,
+    AllowDiscrete,
+}
+
+impl fmt::Display for TransitionBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TransitionBehavior::Normal => write!(f, "normal"),
+            TransitionBehavior::AllowDiscrete => write!(f, "allow-discrete"),
+        }
+    }
+}
+
+/// One comma-separated item of the `transition` shorthand. Missing parts take their initial
+/// values.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SingleTransition {
+    pub property: Option<TransitionProperty>,
+    pub duration: Option<Time>,
+    pub timing_function: Option<EasingFunction>,
+    /// Only present if there is a `duration`.
+    pub delay: Option<Time>,
+    pub behavior: Option<TransitionBehavior>,
+}
+
+impl fmt::Display for SingleTransition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts: Vec<String> = Vec::new();
+        if let Some(v) = &self.property {
+            parts.push(v.to_string());
+        }
+        if let Some(v) = &self.duration {
+            parts.push(v.to_string());
+        }
+        if let Some(v) = &self.timing_function {
+            parts.push(v.to_string());
+        }
+        if let Some(v) = &self.delay {
+            parts.push(v.to_string());
+        }
+        if let Some(v) = &self.behavior {
+            parts.push(v.to_string());
+        }
+        if parts.is_empty() {
+            // all the parts were missing, so write out the initial value of one of them
+            return write!(f, "all");
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+/// One of the values of `animation-name`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnimationName {
+    None,
+    /// The name of a `@keyframes` rule.
+    Name(String),
+}
+
+impl fmt::Display for AnimationName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnimationName::None => write!(f, "none"),
+            AnimationName::Name(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimationIterationCount {
+    Infinite,
+    /// A non-negative number of iterations.
+    Count(f64),
+}
+
+impl fmt::Display for AnimationIterationCount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnimationIterationCount::Infinite => write!(f, "infinite"),
+            AnimationIterationCount::Count(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimationDirection {
+    Normal,
+    Reverse,
+    Alternate,
+    AlternateReverse,
+}
+
+impl fmt::Display for AnimationDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnimationDirection::Normal => write!(f, "normal"),
+            AnimationDirection::Reverse => write!(f, "reverse"),
+            AnimationDirection::Alternate => write!(f, "alternate"),
+            AnimationDirection::AlternateReverse => write!(f, "alternate-reverse"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimationFillMode {
+    None,
+    Forwards,
+    Backwards,
+    Both,
+}
+
+impl fmt::Display for AnimationFillMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnimationFillMode::None => write!(f, "none"),
+            AnimationFillMode::Forwards => write!(f, "forwards"),
+            AnimationFillMode::Backwards => write!(f, "backwards"),
+            AnimationFillMode::Both => write!(f, "both"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimationPlayState {
+    Running,
+    Paused,
+}
+
+impl fmt::Display for AnimationPlayState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnimationPlayState::Running => write!(f, "running"),
+            AnimationPlayState::Paused => write!(f, "paused"),
+        }
+    }
+}
+
+/// One comma-separated item of the `animation` shorthand. Missing parts take their initial
+/// values.
+///
+/// When parsing, a keyword that could be the name is only used as the name if it isn't valid for
+/// any of the other parts, so `none` is the fill mode unless one has already been given.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SingleAnimation {
+    pub duration: Option<Time>,
+    pub timing_function: Option<EasingFunction>,
+    /// Only present if there is a `duration`.
+    pub delay: Option<Time>,
+    pub iteration_count: Option<AnimationIterationCount>,
+    pub direction: Option<AnimationDirection>,
+    pub fill_mode: Option<AnimationFillMode>,
+    pub play_state: Option<AnimationPlayState>,
+    pub name: Option<AnimationName>,
+}
+
+impl fmt::Display for SingleAnimation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts: Vec<String> = Vec::new();
+        if let Some(v) = &self.duration {
+            parts.push(v.to_string());
+        }
+        if let Some(v) = &self.timing_function {
+            parts.push(v.to_string());
+        }
+        if let Some(v) = &self.delay {
+            parts.push(v.to_string());
+        }
+        if let Some(v) = &self.iteration_count {
+            parts.push(v.to_string());
+        }
+        if let Some(v) = &self.direction {
+            parts.push(v.to_string());
+        }
+        if let Some(v) = &self.fill_mode {
+            parts.push(v.to_string());
+        }
+        if let Some(v) = &self.play_state {
+            parts.push(v.to_string());
+        }
+        if let Some(v) = &self.name {
+            parts.push(v.to_string());
+        }
+        if parts.is_empty() {
+            return write!(f, "none");
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            EasingFunction::Steps(3, StepPosition::JumpEnd).to_string(),
+            "steps(3)"
+        );
+        assert_eq!(
+            EasingFunction::LinearStops(vec![
+                LinearStop {
+                    output: 0.0,
+                    start: None,
+                    end: None,
+                },
+                LinearStop {
+                    output: 0.5,
+                    start: Some(Percentage(20.0)),
+                    end: Some(Percentage(40.0)),
+                },
+            ])
+            .to_string(),
+            "linear(0, 0.5 20% 40%)"
+        );
+        let transition = SingleTransition {
+            property: Some(TransitionProperty::Property("opacity".into())),
+            duration: Some(Time::Ms(200.0)),
+            delay: Some(Time::S(-1.0)),
+            ..SingleTransition::default()
+        };
+        assert_eq!(transition.to_string(), "opacity 200ms -1s");
+        assert_eq!(SingleAnimation::default().to_string(), "none");
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn evaluate() {
+        let ease = EasingFunction::Ease;
+        assert_close(ease.evaluate(0.0), 0.0);
+        assert_close(ease.evaluate(0.5), 0.802_403_4);
+        assert_close(ease.evaluate(1.0), 1.0);
+        let bezier = EasingFunction::CubicBezier(0.0, 0.0, 1.0, 1.0);
+        assert_close(bezier.evaluate(0.3), 0.3);
+        // overshooting curves extrapolate along the end tangents
+        let back = EasingFunction::CubicBezier(0.5, -1.0, 0.5, 2.0);
+        assert_close(back.evaluate(-0.5), 1.0);
+        assert_close(back.evaluate(1.5), 0.0);
+
+        let steps = |count, position| EasingFunction::Steps(count, position);
+        assert_close(steps(4, StepPosition::JumpEnd).evaluate(0.3), 0.25);
+        assert_close(steps(4, StepPosition::JumpStart).evaluate(0.3), 0.5);
+        assert_close(steps(3, StepPosition::JumpNone).evaluate(0.5), 0.5);
+        assert_close(steps(3, StepPosition::JumpNone).evaluate(1.0), 1.0);
+        assert_close(steps(3, StepPosition::JumpBoth).evaluate(0.0), 0.25);
+        assert_close(EasingFunction::StepEnd.evaluate(1.0), 1.0);
+
+        let linear: EasingFunction = "linear(0, 0.25 75%, 1)".parse().unwrap();
+        assert_close(linear.evaluate(0.375), 0.125);
+        assert_close(linear.evaluate(0.875), 0.625);
+        assert_close(linear.evaluate(2.0), 4.0);
+        // stops without inputs are spread out, and inputs never go backwards
+        let linear: EasingFunction = "linear(0, 0.5, 0.5 20% 40%, 0.8 10%, 1)".parse().unwrap();
+        assert_close(linear.evaluate(0.1), 0.5);
+        assert_close(linear.evaluate(0.3), 0.5);
+        assert_close(linear.evaluate(0.4), 0.8);
+        assert_close(linear.evaluate(0.7), 0.9);
+    }
+}
@@ -0,0 +1,317 @@
+//! Values of the `border-radius` and `border-image` properties, from
+//! https://www.w3.org/TR/css-backgrounds-3/.
+use crate::{
+    Calc, Image, Length, LengthPercentage, Percentage, Rect, ReferenceBox, SingleOrDouble,
+};
+use std::fmt;
+
+/// The value of the `border-radius` shorthand.
+///
+/// The corners are stored in a [`Rect`], which expands 1 to 4 values in the same way, but in
+/// the order top-left, top-right, bottom-right, bottom-left.
+///
+/// https://www.w3.org/TR/css-backgrounds-3/#border-radius
+#[derive(Debug, Clone, PartialEq)]
+pub struct BorderRadius {
+    pub horizontal: Rect<Calc>,
+    /// The vertical radii, written after a `/`. `None` is the same as `horizontal`.
+    pub vertical: Option<Rect<Calc>>,
+}
+
+impl BorderRadius {
+    /// The values of the top-left, top-right, bottom-right and bottom-left radius properties.
+    pub fn corners(&self) -> [SingleOrDouble<Calc>; 4] {
+        let horizontal = self.horizontal.sides();
+        let vertical = self.vertical.as_ref().map(Rect::sides);
+        let corner = |idx: usize| match vertical {
+            Some(vertical) if vertical[idx] != horizontal[idx] => SingleOrDouble::Double {
+                horiz: horizontal[idx].clone(),
+                vert: vertical[idx].clone(),
+            },
+            _ => SingleOrDouble::Single(horizontal[idx].clone()),
+        };
+        [corner(0), corner(1), corner(2), corner(3)]
+    }
+
+    /// The horizontal and vertical radius of each corner in css pixels, in the order top-left,
+    /// top-right, bottom-right, bottom-left.
+    ///
+    /// Horizontal percentages are of the width of `reference` and vertical percentages of its
+    /// height. If the radii on any side add up to more than the length of that side, all radii
+    /// are scaled down until they fit.
+    pub fn resolve(&self, reference: &ReferenceBox) -> [[f64; 2]; 4] {
+        let mut radii = self
+            .corners()
+            .map(|corner| corner_radius(&corner, reference));
+        let [top_left, top_right, bottom_right, bottom_left] = radii;
+        let scale = [
+            (reference.width, top_left[0] + top_right[0]),
+            (reference.height, top_right[1] + bottom_right[1]),
+            (reference.width, bottom_right[0] + bottom_left[0]),
+            (reference.height, bottom_left[1] + top_left[1]),
+        ]
+        .iter()
+        .filter(|(_, sum)| *sum > 0.0)
+        .map(|(length, sum)| length / sum)
+        .fold(1.0, f64::min);
+        for radius in radii.iter_mut() {
+            for v in radius.iter_mut() {
+                *v = (*v * scale).max(0.0);
+            }
+        }
+        radii
+    }
+}
+
+/// The horizontal and vertical radius of one corner in css pixels, before any scaling.
+fn corner_radius(corner: &SingleOrDouble<Calc>, reference: &ReferenceBox) -> [f64; 2] {
+    let (horizontal, vertical) = match corner {
+        SingleOrDouble::Single(v) => (v, v),
+        SingleOrDouble::Double { horiz, vert } => (horiz, vert),
+    };
+    [
+        horizontal.to_px(reference.width, reference.font_size),
+        vertical.to_px(reference.height, reference.font_size),
+    ]
+}
+
+impl fmt::Display for BorderRadius {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.horizontal)?;
+        if let Some(vertical) = &self.vertical {
+            write!(f, " / {}", vertical)?;
+        }
+        Ok(())
+    }
+}
+
+/// The value of the `border-image` shorthand. Parts that are `None` are reset to their initial
+/// values.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BorderImage {
+    pub source: Option<BorderImageSource>,
+    pub slice: Option<BorderImageSlice>,
+    /// Only given after the slice, following a `/`.
+    pub width: Option<Rect<BorderImageWidth>>,
+    /// Only given after the slice and width, following a second `/`.
+    pub outset: Option<Rect<NumberOrLength>>,
+    pub repeat: Option<BorderImageRepeat>,
+}
+
+impl BorderImage {
+    pub fn source(&self) -> BorderImageSource {
+        self.source.clone().unwrap_or(BorderImageSource::None)
+    }
+
+    pub fn slice(&self) -> BorderImageSlice {
+        self.slice.unwrap_or(BorderImageSlice {
+            offsets: Rect::All(NumberOrPercentage::Percentage(Percentage(100.0))),
+            fill: false,
+        })
+    }
+
+    pub fn width(&self) -> Rect<BorderImageWidth> {
+        self.width
+            .clone()
+            .unwrap_or(Rect::All(BorderImageWidth::Number(1.0)))
+    }
+
+    pub fn outset(&self) -> Rect<NumberOrLength> {
+        self.outset
+            .unwrap_or(Rect::All(NumberOrLength::Number(0.0)))
+    }
+
+    pub fn repeat(&self) -> BorderImageRepeat {
+        self.repeat.unwrap_or(BorderImageRepeat {
+            horizontal: BorderImageRepeatMode::Stretch,
+            vertical: None,
+        })
+    }
+}
+
+impl fmt::Display for BorderImage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(source) = &self.source {
+            parts.push(source.to_string());
+        }
+        if self.slice.is_some() || self.width.is_some() || self.outset.is_some() {
+            // the width and outset can only be written after a slice
+            let slice = self.slice();
+            parts.push(match (&self.width, &self.outset) {
+                (Some(width), Some(outset)) => format!("{} / {} / {}", slice, width, outset),
+                (Some(width), None) => format!("{} / {}", slice, width),
+                (None, Some(outset)) => format!("{} / / {}", slice, outset),
+                (None, None) => slice.to_string(),
+            });
+        }
+        if let Some(repeat) = &self.repeat {
+            parts.push(repeat.to_string());
+        }
+        if parts.is_empty() {
+            f.write_str("none")
+        } else {
+            f.write_str(&parts.join(" "))
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-backgrounds-3/#border-image-source
+#[derive(Debug, Clone, PartialEq)]
+pub enum BorderImageSource {
+    None,
+    Image(Image),
+}
+
+impl fmt::Display for BorderImageSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BorderImageSource::None => f.write_str("none"),
+            BorderImageSource::Image(image) => image.fmt(f),
+        }
+    }
+}
+
+/// How far in from each edge of the image to cut it into 9 regions.
+///
+/// https://www.w3.org/TR/css-backgrounds-3/#border-image-slice
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BorderImageSlice {
+    /// Not negative. Numbers are in pixels of the image (or coordinates for vector images).
+    pub offsets: Rect<NumberOrPercentage>,
+    /// Whether the middle region is drawn as well as the border.
+    pub fill: bool,
+}
+
+impl fmt::Display for BorderImageSlice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.offsets)?;
+        if self.fill {
+            f.write_str(" fill")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumberOrPercentage {
+    Number(f64),
+    Percentage(Percentage),
+}
+
+impl fmt::Display for NumberOrPercentage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NumberOrPercentage::Number(v) => v.fmt(f),
+            NumberOrPercentage::Percentage(v) => v.fmt(f),
+        }
+    }
+}
+
+/// The width of one side of the border image. Not negative.
+///
+/// https://www.w3.org/TR/css-backgrounds-3/#border-image-width
+#[derive(Debug, Clone, PartialEq)]
+pub enum BorderImageWidth {
+    /// A multiple of the `border-width`.
+    Number(f64),
+    /// Percentages are of the size of the border image area.
+    LengthPercentage(LengthPercentage),
+    /// The size of the matching slice of the image.
+    Auto,
+}
+
+impl fmt::Display for BorderImageWidth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BorderImageWidth::Number(v) => v.fmt(f),
+            BorderImageWidth::LengthPercentage(v) => v.fmt(f),
+            BorderImageWidth::Auto => f.write_str("auto"),
+        }
+    }
+}
+
+/// A length, or a multiple of the `border-width`. Used for `border-image-outset`, where it
+/// isn't negative.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumberOrLength {
+    Number(f64),
+    Length(Length),
+}
+
+impl fmt::Display for NumberOrLength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NumberOrLength::Number(v) => v.fmt(f),
+            NumberOrLength::Length(v) => v.fmt(f),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-backgrounds-3/#border-image-repeat
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BorderImageRepeat {
+    pub horizontal: BorderImageRepeatMode,
+    /// `None` is the same as `horizontal`.
+    pub vertical: Option<BorderImageRepeatMode>,
+}
+
+impl fmt::Display for BorderImageRepeat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.horizontal)?;
+        if let Some(vertical) = self.vertical {
+            write!(f, " {}", vertical)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BorderImageRepeatMode {
+    Stretch,
+    Repeat,
+    Round,
+    Space,
+}
+
+impl fmt::Display for BorderImageRepeatMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            BorderImageRepeatMode::Stretch => "stretch",
+            BorderImageRepeatMode::Repeat => "repeat",
+            BorderImageRepeatMode::Round => "round",
+            BorderImageRepeatMode::Space => "space",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_radius() {
+        let reference = ReferenceBox {
+            width: 200.0,
+            height: 100.0,
+            font_size: 10.0,
+        };
+        for (input, radii) in [
+            ("10px", [[10.0, 10.0]; 4]),
+            (
+                "10% 1em / 20%",
+                [[20.0, 20.0], [10.0, 20.0], [20.0, 20.0], [10.0, 20.0]],
+            ),
+            (
+                "1px 2px 3px 4px / 5px 6px",
+                [[1.0, 5.0], [2.0, 6.0], [3.0, 5.0], [4.0, 6.0]],
+            ),
+            ("50%", [[100.0, 50.0]; 4]),
+            // the left and right sides are 100px high, but the radii add up to 200px
+            ("50% / 100%", [[50.0, 50.0]; 4]),
+        ] {
+            let radius: BorderRadius = input.parse().unwrap();
+            assert_eq!(radius.resolve(&reference), radii, "input: {}", input);
+        }
+    }
+}
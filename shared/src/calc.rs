@@ -27,6 +27,42 @@ impl fmt::Display for Calc {
     }
 }
 
+impl Calc {
+    /// The length in css pixels, where percentages are of `basis`.
+    ///
+    /// Multiplication is between the pixel values, since `calc` doesn't have unitless numbers yet.
+    pub fn to_px(&self, basis: f64, font_size: f64) -> f64 {
+        match self {
+            Calc::Calculated(sum) => sum.to_px(basis, font_size),
+            Calc::Normal(v) => v.to_px(basis, font_size),
+        }
+    }
+
+    /// Whether there are no percentages or font-relative lengths, so `to_px` doesn't depend on
+    /// the basis or the font size.
+    pub fn is_absolute(&self) -> bool {
+        let value = |v: &CalcValue| match v {
+            CalcValue::LengthPercentage(LengthPercentage::Length(v)) => !v.is_font_relative(),
+            CalcValue::LengthPercentage(LengthPercentage::Percentage(_)) => false,
+        };
+        let product = |p: &CalcProduct| {
+            value(&p.first)
+                && p.rest
+                    .iter()
+                    .all(|op| matches!(op, ProductOp::Mul(v) if value(v)))
+        };
+        match self {
+            Calc::Normal(v) => value(&CalcValue::LengthPercentage(*v)),
+            Calc::Calculated(sum) => {
+                product(&sum.first)
+                    && sum.rest.iter().all(|op| match op {
+                        SumOp::Add(v) | SumOp::Sub(v) => product(v),
+                    })
+            }
+        }
+    }
+}
+
 impl Parse for Calc {
     fn parse(s: ParseStream) -> syn::Result<Self> {
         custom_keyword!(calc);
@@ -77,6 +113,17 @@ impl fmt::Display for CalcSum {
     }
 }
 
+impl CalcSum {
+    fn to_px(&self, basis: f64, font_size: f64) -> f64 {
+        self.rest
+            .iter()
+            .fold(self.first.to_px(basis, font_size), |acc, op| match op {
+                SumOp::Add(v) => acc + v.to_px(basis, font_size),
+                SumOp::Sub(v) => acc - v.to_px(basis, font_size),
+            })
+    }
+}
+
 impl Parse for CalcSum {
     fn parse(s: ParseStream) -> syn::Result<Self> {
         let first: CalcProduct = s.parse()?;
@@ -162,6 +209,16 @@ impl fmt::Display for CalcProduct {
     }
 }
 
+impl CalcProduct {
+    fn to_px(&self, basis: f64, font_size: f64) -> f64 {
+        self.rest
+            .iter()
+            .fold(self.first.to_px(basis, font_size), |acc, op| match op {
+                ProductOp::Mul(v) => acc * v.to_px(basis, font_size),
+            })
+    }
+}
+
 impl Parse for CalcProduct {
     fn parse(s: ParseStream) -> syn::Result<Self> {
         let first: CalcValue = s.parse()?;
@@ -239,6 +296,14 @@ pub enum CalcValue {
     // todo more variants
 }
 
+impl CalcValue {
+    fn to_px(&self, basis: f64, font_size: f64) -> f64 {
+        match self {
+            CalcValue::LengthPercentage(v) => v.to_px(basis, font_size),
+        }
+    }
+}
+
 impl Parse for CalcValue {
     fn parse(s: ParseStream) -> syn::Result<Self> {
         Ok(CalcValue::LengthPercentage(s.parse()?))
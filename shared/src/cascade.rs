@@ -0,0 +1,265 @@
+//! Find the winning declaration for each property from a set of matching rules.
+//!
+//! https://www.w3.org/TR/css-cascade-4/#cascading
+use crate::*;
+use std::collections::{hash_map::Entry, HashMap};
+
+/// Where a declaration came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Origin {
+    /// The default styles of the renderer.
+    UserAgent,
+    /// Styles set by the user, e.g. for accessibility.
+    User,
+    /// Stylesheets written by the author of the document.
+    Author,
+    /// Styles attached directly to an element, like the html `style` attribute. These are author
+    /// styles that win over any author rule, whatever its specificity.
+    Inline,
+}
+
+impl Origin {
+    /// The precedence of declarations from this origin: higher wins.
+    ///
+    /// `!important` reverses the order of the origins, and important declarations win over all
+    /// normal ones.
+    fn precedence(self, important: bool) -> u8 {
+        match (important, self) {
+            (false, Origin::UserAgent) => 0,
+            (false, Origin::User) => 1,
+            (false, Origin::Author) => 2,
+            (false, Origin::Inline) => 3,
+            (true, Origin::Author) => 4,
+            (true, Origin::Inline) => 5,
+            (true, Origin::User) => 6,
+            (true, Origin::UserAgent) => 7,
+        }
+    }
+}
+
+/// A declaration that has been added to a `Cascade`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Declaration {
+    /// The declaration, including the `!important` flag if it has one.
+    pub style: Style,
+    pub origin: Origin,
+    /// The specificity of the selector that the declaration's rule matched with.
+    pub specificity: Specificity,
+    /// The position of the declaration in the cascade: later declarations win ties.
+    pub order: usize,
+}
+
+impl Declaration {
+    /// Declarations with a higher key win.
+    fn key(&self) -> (u8, Specificity, usize) {
+        (
+            self.origin.precedence(self.style.is_important()),
+            self.specificity,
+            self.order,
+        )
+    }
+}
+
+/// Collects declarations from different origins, and resolves which one wins for each property.
+///
+/// Shorthands that [`Style::longhands`] can expand are added as their longhands, so they compete
+/// with longhands declared on their own. Other shorthands (like `margin`) are compared by name,
+/// and [`Cascade::resolve`] orders its output so that they are still applied correctly.
+#[derive(Debug, Clone, Default)]
+pub struct Cascade {
+    declarations: Vec<Declaration>,
+}
+
+impl Cascade {
+    pub fn new() -> Self {
+        Cascade {
+            declarations: Vec::new(),
+        }
+    }
+
+    /// Add the declarations of a rule that matched with `specificity`.
+    ///
+    /// Rules should be added in the order they appear in their stylesheets: when everything else
+    /// is equal, the declaration added last wins.
+    pub fn add(&mut self, origin: Origin, specificity: Specificity, styles: &Styles) {
+        for style in styles.iter().filter(|style| style.name().is_some()) {
+            let longhands = style.longhands().unwrap_or_else(|| vec![style.clone()]);
+            for style in longhands {
+                let order = self.declarations.len();
+                self.declarations.push(Declaration {
+                    style,
+                    origin,
+                    specificity,
+                    order,
+                });
+            }
+        }
+    }
+
+    /// Add styles that are attached directly to the element.
+    pub fn add_inline(&mut self, styles: &Styles) {
+        self.add(Origin::Inline, Specificity::default(), styles)
+    }
+
+    /// Add the rules of `sheet` that match `element`.
+    pub fn add_stylesheet<E: Element>(
+        &mut self,
+        origin: Origin,
+        sheet: &Stylesheet,
+        element: &E,
+        namespaces: &Namespaces,
+    ) {
+        for (rule, specificity) in sheet.matching_rules(element, namespaces) {
+            self.add(origin, specificity, &rule.styles);
+        }
+    }
+
+    /// All the declarations that have been added, in order, with shorthands expanded.
+    pub fn declarations(&self) -> &[Declaration] {
+        &self.declarations
+    }
+
+    /// The declaration that wins for the property `name`, if there is one. `name` should be a
+    /// longhand if the shorthand can be expanded.
+    pub fn winner(&self, name: &str) -> Option<&Declaration> {
+        self.declarations
+            .iter()
+            .filter(|decl| decl.style.name() == Some(name))
+            .max_by_key(|decl| decl.key())
+    }
+
+    /// The winning declared value of each property, without the `!important` flag.
+    ///
+    /// The values are in order of precedence, lowest first. Later values override earlier ones
+    /// as usual, so a shorthand that wasn't expanded overrides exactly the longhands it beat.
+    pub fn resolve(&self) -> Styles {
+        let mut winners: HashMap<&str, &Declaration> = HashMap::new();
+        for decl in self.declarations.iter() {
+            // Declarations without a name are never added.
+            let name = decl.style.name().unwrap();
+            match winners.entry(name) {
+                Entry::Vacant(entry) => {
+                    entry.insert(decl);
+                }
+                Entry::Occupied(mut entry) => {
+                    if decl.key() > entry.get().key() {
+                        entry.insert(decl);
+                    }
+                }
+            }
+        }
+        let mut winners = winners.into_values().collect::<Vec<_>>();
+        winners.sort_by_key(|decl| decl.key());
+        winners
+            .into_iter()
+            .map(|decl| decl.style.without_importance().clone())
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn styles(css: &str) -> Styles {
+        css.parse().unwrap()
+    }
+
+    #[test]
+    fn origins() {
+        let mut cascade = Cascade::new();
+        let none = Specificity::default();
+        cascade.add(Origin::Author, none, &styles("display: block; color: red"));
+        cascade.add(
+            Origin::UserAgent,
+            none,
+            &styles("display: inline; color: blue !important"),
+        );
+        cascade.add(Origin::User, none, &styles("display: flex; height: 1px"));
+        assert_eq!(
+            cascade.resolve(),
+            styles("height: 1px; display: block; color: blue")
+        );
+
+        // important author beats normal inline, important inline beats important author, and
+        // important user beats both
+        let mut cascade = Cascade::new();
+        cascade.add(
+            Origin::Author,
+            none,
+            &styles("color: red !important; height: 1px !important"),
+        );
+        cascade.add_inline(&styles(
+            "color: blue; height: 2px !important; width: 1px !important",
+        ));
+        cascade.add(Origin::User, none, &styles("width: 2px !important"));
+        assert_eq!(
+            cascade.resolve(),
+            styles("color: red; height: 2px; width: 2px")
+        );
+        assert_eq!(cascade.winner("width").unwrap().origin, Origin::User);
+        assert!(cascade.winner("margin").is_none());
+    }
+
+    #[test]
+    fn specificity_and_order() {
+        let mut cascade = Cascade::new();
+        cascade.add(
+            Origin::Author,
+            Specificity::new(0, 1, 0),
+            &styles("color: red; height: 1px"),
+        );
+        cascade.add(
+            Origin::Author,
+            Specificity::new(0, 0, 5),
+            &styles("color: blue; height: 2px"),
+        );
+        cascade.add(
+            Origin::Author,
+            Specificity::new(0, 1, 0),
+            &styles("height: 3px; dummy"),
+        );
+        assert_eq!(cascade.resolve(), styles("color: red; height: 3px"));
+        // inline styles win over any selector
+        cascade.add_inline(&styles("color: green"));
+        cascade.add(
+            Origin::Author,
+            Specificity::new(9, 0, 0),
+            &styles("color: black"),
+        );
+        assert_eq!(
+            cascade.winner("color").unwrap().style,
+            Style::Color(DynamicColor::Literal(Color::Green))
+        );
+    }
+
+    #[test]
+    fn shorthands() {
+        let none = Specificity::default();
+        // `margin` is declared later, so it overrides `margin-left`
+        let mut cascade = Cascade::new();
+        cascade.add(Origin::Author, none, &styles("margin-left: 5px; margin: 0"));
+        assert_eq!(cascade.resolve(), styles("margin-left: 5px; margin: 0"));
+
+        // but not if `margin-left` has a higher specificity
+        let mut cascade = Cascade::new();
+        cascade.add(
+            Origin::Author,
+            Specificity::new(0, 1, 0),
+            &styles("margin-left: 5px"),
+        );
+        cascade.add(Origin::Author, none, &styles("margin: 0"));
+        assert_eq!(cascade.resolve(), styles("margin: 0; margin-left: 5px"));
+
+        // shorthands that can be expanded compete with their longhands directly
+        let mut cascade = Cascade::new();
+        cascade.add(Origin::Author, none, &styles("flex-grow: 2; flex: none"));
+        cascade.add(Origin::User, none, &styles("flex-shrink: 3 !important"));
+        assert_eq!(
+            cascade.resolve(),
+            styles("flex-grow: 0; flex-basis: auto; flex-shrink: 3")
+        );
+        assert!(cascade.winner("flex").is_none());
+    }
+}
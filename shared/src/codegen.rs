@@ -34,18 +34,347 @@ impl ToTokens for DynamicStyle {
     }
 }
 
+impl ToTokens for Styles {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let rules = self.rules.iter().filter(|style| !style.is_dummy());
+        tokens.extend(path!(Styles {
+            rules: vec![#(#rules),*],
+        }))
+    }
+}
+
+impl ToTokens for Stylesheet {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let rules = &self.rules;
+        tokens.extend(path!(Stylesheet {
+            rules: vec![#(#rules),*],
+        }))
+    }
+}
+
+impl ToTokens for Rule {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Rule::Qualified(v) => path!(Rule::Qualified(#v)),
+            Rule::At(v) => path!(Rule::At(#v)),
+        })
+    }
+}
+
+impl ToTokens for QualifiedRule {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let selectors = &self.selectors;
+        let styles = &self.styles;
+        tokens.extend(path!(QualifiedRule {
+            selectors: #selectors,
+            styles: #styles,
+        }))
+    }
+}
+
+impl ToTokens for AtRule {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = &self.name;
+        let prelude = &self.prelude;
+        let block = match &self.block {
+            Some(block) => quote!(Some(#block)),
+            None => quote!(None),
+        };
+        tokens.extend(path!(AtRule {
+            name: String::from(#name),
+            prelude: String::from(#prelude),
+            block: #block,
+        }))
+    }
+}
+
+impl ToTokens for AtRuleBlock {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            AtRuleBlock::Rules(rules) => path!(AtRuleBlock::Rules(vec![#(#rules),*])),
+            AtRuleBlock::Keyframes(keyframes) => {
+                path!(AtRuleBlock::Keyframes(vec![#(#keyframes),*]))
+            }
+            AtRuleBlock::Raw(raw) => path!(AtRuleBlock::Raw(String::from(#raw))),
+        })
+    }
+}
+
+impl ToTokens for Keyframe {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let selectors = &self.selectors;
+        let styles = &self.styles;
+        tokens.extend(path!(Keyframe {
+            selectors: vec![#(#selectors),*],
+            styles: #styles,
+        }))
+    }
+}
+
+impl ToTokens for SelectorList {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let selectors = &self.0;
+        tokens.extend(path!(SelectorList(vec![#(#selectors),*])))
+    }
+}
+
+impl ToTokens for Selector {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let first = &self.first;
+        let rest = self
+            .rest
+            .iter()
+            .map(|(combinator, compound)| quote!((#combinator, #compound)));
+        tokens.extend(path!(Selector {
+            first: #first,
+            rest: vec![#(#rest),*],
+        }))
+    }
+}
+
+impl ToTokens for RelativeSelector {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let combinator = &self.combinator;
+        let selector = &self.selector;
+        tokens.extend(path!(RelativeSelector {
+            combinator: #combinator,
+            selector: #selector,
+        }))
+    }
+}
+
+impl ToTokens for Combinator {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Combinator::Descendant => path!(Combinator::Descendant),
+            Combinator::Child => path!(Combinator::Child),
+            Combinator::NextSibling => path!(Combinator::NextSibling),
+            Combinator::SubsequentSibling => path!(Combinator::SubsequentSibling),
+        })
+    }
+}
+
+impl ToTokens for CompoundSelector {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let type_selector = match &self.type_selector {
+            Some(type_selector) => quote!(Some(#type_selector)),
+            None => quote!(None),
+        };
+        let subclasses = &self.subclasses;
+        let pseudo_element = match &self.pseudo_element {
+            Some(pseudo_element) => quote!(Some(#pseudo_element)),
+            None => quote!(None),
+        };
+        tokens.extend(path!(CompoundSelector {
+            type_selector: #type_selector,
+            subclasses: vec![#(#subclasses),*],
+            pseudo_element: #pseudo_element,
+        }))
+    }
+}
+
+impl ToTokens for TypeSelector {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let namespace = match &self.namespace {
+            Some(namespace) => quote!(Some(#namespace)),
+            None => quote!(None),
+        };
+        let name = &self.name;
+        tokens.extend(path!(TypeSelector {
+            namespace: #namespace,
+            name: #name,
+        }))
+    }
+}
+
+impl ToTokens for TypeName {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TypeName::Universal => path!(TypeName::Universal),
+            TypeName::Name(name) => path!(TypeName::Name(String::from(#name))),
+        })
+    }
+}
+
+impl ToTokens for NamespacePrefix {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            NamespacePrefix::Any => path!(NamespacePrefix::Any),
+            NamespacePrefix::None => path!(NamespacePrefix::None),
+            NamespacePrefix::Named(prefix) => path!(NamespacePrefix::Named(String::from(#prefix))),
+        })
+    }
+}
+
+impl ToTokens for SubclassSelector {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            SubclassSelector::Id(id) => path!(SubclassSelector::Id(String::from(#id))),
+            SubclassSelector::Class(class) => path!(SubclassSelector::Class(String::from(#class))),
+            SubclassSelector::Attribute(v) => path!(SubclassSelector::Attribute(#v)),
+            SubclassSelector::PseudoClass(v) => path!(SubclassSelector::PseudoClass(#v)),
+        })
+    }
+}
+
+impl ToTokens for AttributeSelector {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let namespace = match &self.namespace {
+            Some(namespace) => quote!(Some(#namespace)),
+            None => quote!(None),
+        };
+        let name = &self.name;
+        let matcher = match &self.matcher {
+            Some(matcher) => quote!(Some(#matcher)),
+            None => quote!(None),
+        };
+        tokens.extend(path!(AttributeSelector {
+            namespace: #namespace,
+            name: String::from(#name),
+            matcher: #matcher,
+        }))
+    }
+}
+
+impl ToTokens for AttributeMatcher {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let operator = &self.operator;
+        let value = &self.value;
+        let case = match &self.case {
+            Some(case) => quote!(Some(#case)),
+            None => quote!(None),
+        };
+        tokens.extend(path!(AttributeMatcher {
+            operator: #operator,
+            value: String::from(#value),
+            case: #case,
+        }))
+    }
+}
+
+impl ToTokens for AttributeOperator {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            AttributeOperator::Equals => path!(AttributeOperator::Equals),
+            AttributeOperator::Includes => path!(AttributeOperator::Includes),
+            AttributeOperator::DashMatch => path!(AttributeOperator::DashMatch),
+            AttributeOperator::Prefix => path!(AttributeOperator::Prefix),
+            AttributeOperator::Suffix => path!(AttributeOperator::Suffix),
+            AttributeOperator::Substring => path!(AttributeOperator::Substring),
+        })
+    }
+}
+
+impl ToTokens for CaseSensitivity {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            CaseSensitivity::Insensitive => path!(CaseSensitivity::Insensitive),
+            CaseSensitivity::Sensitive => path!(CaseSensitivity::Sensitive),
+        })
+    }
+}
+
+impl ToTokens for PseudoClass {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        fn of(of: &Option<SelectorList>) -> TokenStream {
+            match of {
+                Some(of) => quote!(Some(#of)),
+                None => quote!(None),
+            }
+        }
+        tokens.extend(match self {
+            PseudoClass::Is(v) => path!(PseudoClass::Is(#v)),
+            PseudoClass::Where(v) => path!(PseudoClass::Where(#v)),
+            PseudoClass::Not(v) => path!(PseudoClass::Not(#v)),
+            PseudoClass::Has(v) => path!(PseudoClass::Has(vec![#(#v),*])),
+            PseudoClass::NthChild(nth, v) => {
+                let v = of(v);
+                path!(PseudoClass::NthChild(#nth, #v))
+            }
+            PseudoClass::NthLastChild(nth, v) => {
+                let v = of(v);
+                path!(PseudoClass::NthLastChild(#nth, #v))
+            }
+            PseudoClass::NthOfType(nth) => path!(PseudoClass::NthOfType(#nth)),
+            PseudoClass::NthLastOfType(nth) => path!(PseudoClass::NthLastOfType(#nth)),
+            PseudoClass::FirstChild => path!(PseudoClass::FirstChild),
+            PseudoClass::LastChild => path!(PseudoClass::LastChild),
+            PseudoClass::OnlyChild => path!(PseudoClass::OnlyChild),
+            PseudoClass::FirstOfType => path!(PseudoClass::FirstOfType),
+            PseudoClass::LastOfType => path!(PseudoClass::LastOfType),
+            PseudoClass::OnlyOfType => path!(PseudoClass::OnlyOfType),
+            PseudoClass::Root => path!(PseudoClass::Root),
+            PseudoClass::Empty => path!(PseudoClass::Empty),
+            PseudoClass::Named(name) => path!(PseudoClass::Named(String::from(#name))),
+            PseudoClass::Function(name, args) => path!(PseudoClass::Function(
+                String::from(#name),
+                String::from(#args)
+            )),
+        })
+    }
+}
+
+impl ToTokens for Nth {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Nth { a, b } = self;
+        tokens.extend(path!(Nth { a: #a, b: #b }))
+    }
+}
+
+impl ToTokens for PseudoElementSelector {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let element = &self.element;
+        let pseudo_classes = &self.pseudo_classes;
+        tokens.extend(path!(PseudoElementSelector {
+            element: #element,
+            pseudo_classes: vec![#(#pseudo_classes),*],
+        }))
+    }
+}
+
+impl ToTokens for PseudoElement {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            PseudoElement::Before => path!(PseudoElement::Before),
+            PseudoElement::After => path!(PseudoElement::After),
+            PseudoElement::FirstLine => path!(PseudoElement::FirstLine),
+            PseudoElement::FirstLetter => path!(PseudoElement::FirstLetter),
+            PseudoElement::Marker => path!(PseudoElement::Marker),
+            PseudoElement::Placeholder => path!(PseudoElement::Placeholder),
+            PseudoElement::Selection => path!(PseudoElement::Selection),
+            PseudoElement::Backdrop => path!(PseudoElement::Backdrop),
+            PseudoElement::Named(name) => path!(PseudoElement::Named(String::from(#name))),
+            PseudoElement::Function(name, args) => path!(PseudoElement::Function(
+                String::from(#name),
+                String::from(#args)
+            )),
+        })
+    }
+}
+
 impl ToTokens for Style {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let path = quote!(::style::Style::);
         tokens.extend(match self {
             Style::Dummy => quote!(#path Dummy),
             Style::Unchecked(v) => quote!(#path Unchecked(String::from(#v))),
+            Style::Important(v) => quote!(#path Important(Box::new(#v))),
 
             Style::AlignContent(v) => quote!(#path AlignContent(#v)),
             Style::AlignItems(v) => quote!(#path AlignItems(#v)),
             Style::AlignSelf(v) => quote!(#path AlignSelf(#v)),
             // all
-            // background
+            Style::Animation(v) => quote!(#path Animation(#v)),
+            Style::AnimationDelay(v) => quote!(#path AnimationDelay(#v)),
+            Style::AnimationDirection(v) => quote!(#path AnimationDirection(#v)),
+            Style::AnimationDuration(v) => quote!(#path AnimationDuration(#v)),
+            Style::AnimationFillMode(v) => quote!(#path AnimationFillMode(#v)),
+            Style::AnimationIterationCount(v) => quote!(#path AnimationIterationCount(#v)),
+            Style::AnimationName(v) => quote!(#path AnimationName(#v)),
+            Style::AnimationPlayState(v) => quote!(#path AnimationPlayState(#v)),
+            Style::AnimationTimingFunction(v) => quote!(#path AnimationTimingFunction(#v)),
+            Style::BackfaceVisibility(v) => quote!(#path BackfaceVisibility(#v)),
+            Style::Background(v) => quote!(#path Background(#v)),
             Style::BackgroundAttachment(v) => quote!(#path BackgroundAttachment(#v)),
             Style::BackgroundBlendMode(v) => quote!(#path BackgroundBlendMode(#v)),
             Style::BackgroundClip(v) => quote!(#path BackgroundClip(#v)),
@@ -53,6 +382,8 @@ impl ToTokens for Style {
             Style::BackgroundImage(v) => quote!(#path BackgroundImage(#v)),
             Style::BackgroundOrigin(v) => quote!(#path BackgroundOrigin(#v)),
             Style::BackgroundPosition(v) => quote!(#path BackgroundPosition(#v)),
+            Style::BackgroundPositionX(v) => quote!(#path BackgroundPositionX(#v)),
+            Style::BackgroundPositionY(v) => quote!(#path BackgroundPositionY(#v)),
             Style::BackgroundRepeat(v) => quote!(#path BackgroundRepeat(#v)),
             Style::BackgroundSize(v) => quote!(#path BackgroundSize(#v)),
             Style::Border(v) => quote!(#path Border(#v)),
@@ -64,12 +395,12 @@ impl ToTokens for Style {
             Style::BorderBottomWidth(v) => quote!(#path BorderBottomWidth(#v)),
             Style::BorderCollapse(v) => quote!(#path BorderCollapse(#v)),
             Style::BorderColor(v) => quote!(#path BorderColor(#v)),
-            // border-image
-            // border-image-outset
-            // border-image-repeat
-            // border-image-slice
-            // border-image-source
-            // border-image-width
+            Style::BorderImage(v) => quote!(#path BorderImage(#v)),
+            Style::BorderImageOutset(v) => quote!(#path BorderImageOutset(#v)),
+            Style::BorderImageRepeat(v) => quote!(#path BorderImageRepeat(#v)),
+            Style::BorderImageSlice(v) => quote!(#path BorderImageSlice(#v)),
+            Style::BorderImageSource(v) => quote!(#path BorderImageSource(#v)),
+            Style::BorderImageWidth(v) => quote!(#path BorderImageWidth(#v)),
             Style::BorderLeft(v) => quote!(#path BorderLeft(#v)),
             Style::BorderLeftColor(v) => quote!(#path BorderLeftColor(#v)),
             Style::BorderLeftStyle(v) => quote!(#path BorderLeftStyle(#v)),
@@ -111,57 +442,64 @@ impl ToTokens for Style {
             // cue-after
             // cue-before
             Style::Cursor(v) => quote!(#path Cursor(#v)),
-            // direction
+            Style::Direction(v) => quote!(#path Direction(#v)),
             Style::Display(v) => quote!(#path Display(#v)),
             // elevation
             // empty-cells
-            // flex
+            Style::Flex(v) => quote!(#path Flex(#v)),
             Style::FlexBasis(v) => quote!(#path FlexBasis(#v)),
             Style::FlexDirection(v) => quote!(#path FlexDirection(#v)),
-            // flex-flow
+            Style::FlexFlow(v) => quote!(#path FlexFlow(#v)),
             Style::FlexGrow(v) => quote!(#path FlexGrow(#v)),
             Style::FlexShrink(v) => quote!(#path FlexShrink(#v)),
             Style::FlexWrap(v) => quote!(#path FlexWrap(#v)),
             Style::Float(v) => quote!(#path Float(#v)),
-            // font
+            Style::Font(v) => quote!(#path Font(#v)),
             Style::FontFamily(v) => quote!(#path FontFamily(#v)),
-            // font-feature-settings
-            // font-kerning
+            Style::FontFeatureSettings(v) => quote!(#path FontFeatureSettings(#v)),
+            Style::FontKerning(v) => quote!(#path FontKerning(#v)),
             Style::FontSize(v) => quote!(#path FontSize(#v)),
-            // font-size-adjust
-            // font-stretch
+            Style::FontSizeAdjust(v) => quote!(#path FontSizeAdjust(#v)),
+            Style::FontStretch(v) => quote!(#path FontStretch(#v)),
             Style::FontStyle(v) => quote!(#path FontStyle(#v)),
-            // font-synthesis
-            // font-variant
-            // font-variant-caps
-            // font-variant-east-asian
-            // font-variant-ligatures
-            // font-variant-numeric
-            // font-variant-position
+            Style::FontSynthesis(v) => quote!(#path FontSynthesis(#v)),
+            Style::FontVariant(v) => quote!(#path FontVariant(#v)),
+            Style::FontVariantAlternates(v) => quote!(#path FontVariantAlternates(#v)),
+            Style::FontVariantCaps(v) => quote!(#path FontVariantCaps(#v)),
+            Style::FontVariantEastAsian(v) => quote!(#path FontVariantEastAsian(#v)),
+            Style::FontVariantEmoji(v) => quote!(#path FontVariantEmoji(#v)),
+            Style::FontVariantLigatures(v) => quote!(#path FontVariantLigatures(#v)),
+            Style::FontVariantNumeric(v) => quote!(#path FontVariantNumeric(#v)),
+            Style::FontVariantPosition(v) => quote!(#path FontVariantPosition(#v)),
+            Style::FontVariationSettings(v) => quote!(#path FontVariationSettings(#v)),
             Style::FontWeight(v) => quote!(#path FontWeight(#v)),
             // glyph-orientation-vertical
-            // grid
-            // grid-area
-            // grid-auto-columns
-            // grid-auto-flow
-            // grid-auto-rows
-            // grid-column
-            // grid-column-end
-            // grid-column-start
-            // grid-row
-            // grid-row-end
-            // grid-row-start
-            // grid-template
-            // grid-template-areas
-            // grid-template-columns
-            // grid-template-rows
+            Style::Grid(v) => quote!(#path Grid(#v)),
+            Style::GridArea(v) => quote!(#path GridArea(#v)),
+            Style::GridAutoColumns(v) => quote!(#path GridAutoColumns(#v)),
+            Style::GridAutoFlow(v) => quote!(#path GridAutoFlow(#v)),
+            Style::GridAutoRows(v) => quote!(#path GridAutoRows(#v)),
+            Style::GridColumn(v) => quote!(#path GridColumn(#v)),
+            Style::GridColumnEnd(v) => quote!(#path GridColumnEnd(#v)),
+            Style::GridColumnStart(v) => quote!(#path GridColumnStart(#v)),
+            Style::GridRow(v) => quote!(#path GridRow(#v)),
+            Style::GridRowEnd(v) => quote!(#path GridRowEnd(#v)),
+            Style::GridRowStart(v) => quote!(#path GridRowStart(#v)),
+            Style::GridTemplate(v) => quote!(#path GridTemplate(#v)),
+            Style::GridTemplateAreas(v) => quote!(#path GridTemplateAreas(#v)),
+            Style::GridTemplateColumns(v) => quote!(#path GridTemplateColumns(#v)),
+            Style::GridTemplateRows(v) => quote!(#path GridTemplateRows(#v)),
+            Style::HangingPunctuation(v) => quote!(#path HangingPunctuation(#v)),
             Style::Height(v) => quote!(#path Height(#v)),
+            Style::HyphenateCharacter(v) => quote!(#path HyphenateCharacter(#v)),
+            Style::Hyphens(v) => quote!(#path Hyphens(#v)),
             // image-orientation
             // image-rendering
             // isolation
             Style::JustifyContent(v) => quote!(#path JustifyContent(#v)),
             Style::Left(v) => quote!(#path Left(#v)),
-            // letter-spacing
+            Style::LetterSpacing(v) => quote!(#path LetterSpacing(#v)),
+            Style::LineBreak(v) => quote!(#path LineBreak(#v)),
             Style::LineHeight(v) => quote!(#path LineHeight(#v)),
             // list-style
             // list-style-image
@@ -185,7 +523,7 @@ impl ToTokens for Style {
             // mask-image
             // mask-mode
             // mask-origin
-            // mask-position
+            Style::MaskPosition(v) => quote!(#path MaskPosition(#v)),
             // mask-repeat
             // mask-size
             // mask-type
@@ -195,7 +533,7 @@ impl ToTokens for Style {
             Style::MinWidth(v) => quote!(#path MinWidth(#v)),
             // mix-blend-mode
             Style::ObjectFit(v) => quote!(#path ObjectFit(#v)),
-            // object-position
+            Style::ObjectPosition(v) => quote!(#path ObjectPosition(#v)),
             // opacity
             // order
             // orphans
@@ -205,6 +543,7 @@ impl ToTokens for Style {
             // outline-style
             // outline-width
             Style::Overflow(v) => quote!(#path Overflow(#v)),
+            Style::OverflowWrap(v) => quote!(#path OverflowWrap(#v)),
             Style::OverflowX(v) => quote!(#path OverflowX(#v)),
             Style::OverflowY(v) => quote!(#path OverflowY(#v)),
             Style::Padding(v) => quote!(#path Padding(#v)),
@@ -218,6 +557,8 @@ impl ToTokens for Style {
             // pause
             // pause-after
             // pause-before
+            Style::Perspective(v) => quote!(#path Perspective(#v)),
+            Style::PerspectiveOrigin(v) => quote!(#path PerspectiveOrigin(#v)),
             // pitch
             // pitch-range
             // play-during
@@ -226,6 +567,8 @@ impl ToTokens for Style {
             Style::Resize(v) => quote!(#path Resize(#v)),
             // richness
             Style::Right(v) => quote!(#path Right(#v)),
+            Style::Rotate(v) => quote!(#path Rotate(#v)),
+            Style::Scale(v) => quote!(#path Scale(#v)),
             // scroll-margin
             // scroll-margin-block
             // scroll-margin-block-end
@@ -260,39 +603,55 @@ impl ToTokens for Style {
             // speak-punctuation
             // speech-rate
             // stress
+            Style::TabSize(v) => quote!(#path TabSize(#v)),
             // table-layout
             Style::TextAlign(v) => quote!(#path TextAlign(#v)),
-            // text-combine-upright
-            // text-decoration
-            // text-decoration-color
-            // text-decoration-line
-            // text-decoration-style
+            Style::TextCombineUpright(v) => quote!(#path TextCombineUpright(#v)),
+            Style::TextDecoration(v) => quote!(#path TextDecoration(#v)),
+            Style::TextDecorationColor(v) => quote!(#path TextDecorationColor(#v)),
+            Style::TextDecorationLine(v) => quote!(#path TextDecorationLine(#v)),
+            Style::TextDecorationStyle(v) => quote!(#path TextDecorationStyle(#v)),
+            Style::TextDecorationThickness(v) => quote!(#path TextDecorationThickness(#v)),
             // text-emphasis
             // text-emphasis-color
             // text-emphasis-position
             // text-emphasis-style
-            // text-indent
-            // text-orientation
+            Style::TextIndent(v) => quote!(#path TextIndent(#v)),
+            Style::TextOrientation(v) => quote!(#path TextOrientation(#v)),
             // text-overflow
-            // text-shadow
-            // text-transform
-            // text-underline-position
+            Style::TextShadow(v) => quote!(#path TextShadow(#v)),
+            Style::TextTransform(v) => quote!(#path TextTransform(#v)),
+            Style::TextUnderlineOffset(v) => quote!(#path TextUnderlineOffset(#v)),
+            Style::TextUnderlinePosition(v) => quote!(#path TextUnderlinePosition(#v)),
+            Style::TextWrap(v) => quote!(#path TextWrap(#v)),
+            Style::TextWrapMode(v) => quote!(#path TextWrapMode(#v)),
+            Style::TextWrapStyle(v) => quote!(#path TextWrapStyle(#v)),
             Style::Top(v) => quote!(#path Top(#v)),
-            // transform
+            Style::Transform(v) => quote!(#path Transform(#v)),
             // transform-box
-            // transform-origin
-            // unicode-bidi
+            Style::TransformOrigin(v) => quote!(#path TransformOrigin(#v)),
+            Style::TransformStyle(v) => quote!(#path TransformStyle(#v)),
+            Style::Transition(v) => quote!(#path Transition(#v)),
+            Style::TransitionBehavior(v) => quote!(#path TransitionBehavior(#v)),
+            Style::TransitionDelay(v) => quote!(#path TransitionDelay(#v)),
+            Style::TransitionDuration(v) => quote!(#path TransitionDuration(#v)),
+            Style::TransitionProperty(v) => quote!(#path TransitionProperty(#v)),
+            Style::TransitionTimingFunction(v) => quote!(#path TransitionTimingFunction(#v)),
+            Style::Translate(v) => quote!(#path Translate(#v)),
+            Style::UnicodeBidi(v) => quote!(#path UnicodeBidi(#v)),
             // vertical-align
             // visibility
             // voice-family
             // volume
             // white-space
             Style::WhiteSpace(v) => quote!(#path WhiteSpace(#v)),
+            Style::WhiteSpaceCollapse(v) => quote!(#path WhiteSpaceCollapse(#v)),
             Style::Widows(v) => quote!(#path Widows(#v)),
             Style::Width(v) => quote!(#path Width(#v)),
             // will-change
-            // word-spacing
-            // writing-mode
+            Style::WordBreak(v) => quote!(#path WordBreak(#v)),
+            Style::WordSpacing(v) => quote!(#path WordSpacing(#v)),
+            Style::WritingMode(v) => quote!(#path WritingMode(#v)),
             // z-index
         });
     }
@@ -377,7 +736,9 @@ impl ToTokens for FlexDirection {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         tokens.extend(match self {
             FlexDirection::Row => path!(FlexDirection::Row),
+            FlexDirection::RowReverse => path!(FlexDirection::RowReverse),
             FlexDirection::Column => path!(FlexDirection::Column),
+            FlexDirection::ColumnReverse => path!(FlexDirection::ColumnReverse),
         });
     }
 }
@@ -387,6 +748,37 @@ impl ToTokens for FlexWrap {
         tokens.extend(match self {
             FlexWrap::Wrap => path!(FlexWrap::Wrap),
             FlexWrap::Nowrap => path!(FlexWrap::Nowrap),
+            FlexWrap::WrapReverse => path!(FlexWrap::WrapReverse),
+        });
+    }
+}
+
+impl ToTokens for FlexFlow {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let direction = option_tokens(&self.direction);
+        let wrap = option_tokens(&self.wrap);
+        tokens.extend(path!(FlexFlow {
+            direction: #direction,
+            wrap: #wrap,
+        }));
+    }
+}
+
+impl ToTokens for Flex {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Flex::None => path!(Flex::None),
+            Flex::Auto => path!(Flex::Auto),
+            Flex::Initial => path!(Flex::Initial),
+            Flex::Values {
+                grow,
+                shrink,
+                basis,
+            } => path!(Flex::Values {
+                grow: #grow,
+                shrink: #shrink,
+                basis: #basis,
+            }),
         });
     }
 }
@@ -479,20 +871,49 @@ impl ToTokens for BackgroundImage {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         tokens.extend(match self {
             BackgroundImage::None => path!(BackgroundImage::None),
-            BackgroundImage::Url(url) => path!(BackgroundImage::Url(#url)),
+            BackgroundImage::Image(v) => path!(BackgroundImage::Image(#v)),
         })
     }
 }
 
-impl ToTokens for BackgroundPosition {
+impl ToTokens for Background {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        tokens.extend(match self {
-            BackgroundPosition::Top => path!(BackgroundPosition::Top),
-            BackgroundPosition::Bottom => path!(BackgroundPosition::Bottom),
-            BackgroundPosition::Left => path!(BackgroundPosition::Left),
-            BackgroundPosition::Right => path!(BackgroundPosition::Right),
-            BackgroundPosition::Center => path!(BackgroundPosition::Center),
-        })
+        let Background { layers, color } = self;
+        let color = option_tokens(color);
+        tokens.extend(path!(Background {
+            layers: #layers,
+            color: #color,
+        }))
+    }
+}
+
+impl ToTokens for BackgroundLayer {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let BackgroundLayer {
+            image,
+            position,
+            size,
+            repeat,
+            attachment,
+            origin,
+            clip,
+        } = self;
+        let image = option_tokens(image);
+        let position = option_tokens(position);
+        let size = option_tokens(size);
+        let repeat = option_tokens(repeat);
+        let attachment = option_tokens(attachment);
+        let origin = option_tokens(origin);
+        let clip = option_tokens(clip);
+        tokens.extend(path!(BackgroundLayer {
+            image: #image,
+            position: #position,
+            size: #size,
+            repeat: #repeat,
+            attachment: #attachment,
+            origin: #origin,
+            clip: #clip,
+        }))
     }
 }
 
@@ -581,6 +1002,14 @@ impl ToTokens for Font {
             Font::Cursive => path!(Font::Cursive),
             Font::Fantasy => path!(Font::Fantasy),
             Font::Monospace => path!(Font::Monospace),
+            Font::SystemUi => path!(Font::SystemUi),
+            Font::Emoji => path!(Font::Emoji),
+            Font::Math => path!(Font::Math),
+            Font::Fangsong => path!(Font::Fangsong),
+            Font::UiSerif => path!(Font::UiSerif),
+            Font::UiSansSerif => path!(Font::UiSansSerif),
+            Font::UiMonospace => path!(Font::UiMonospace),
+            Font::UiRounded => path!(Font::UiRounded),
         })
     }
 }
@@ -773,12 +1202,6 @@ impl ToTokens for LineWidth {
     }
 }
 
-impl ToTokens for LineHeight {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        self.0.to_tokens(tokens)
-    }
-}
-
 impl ToTokens for ListStyleType {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         tokens.extend(match self {
@@ -824,18 +1247,6 @@ impl ToTokens for Resize {
     }
 }
 
-impl ToTokens for WhiteSpace {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        tokens.extend(match self {
-            WhiteSpace::Normal => path!(WhiteSpace::Normal),
-            WhiteSpace::Pre => path!(WhiteSpace::Pre),
-            WhiteSpace::Nowrap => path!(WhiteSpace::Nowrap),
-            WhiteSpace::PreWrap => path!(WhiteSpace::PreWrap),
-            WhiteSpace::PreLine => path!(WhiteSpace::PreLine),
-        })
-    }
-}
-
 impl ToTokens for WidthHeight {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         tokens.extend(match self {
@@ -1116,34 +1527,1726 @@ impl ToTokens for Color {
     }
 }
 
-// Generic containers
+// Grid
 
-impl<T> ToTokens for NonemptyCommaList<T>
-where
-    T: ToTokens,
-{
+fn option_tokens<T: ToTokens>(v: &Option<T>) -> TokenStream {
+    match v {
+        Some(v) => quote!(Some(#v)),
+        None => quote!(None),
+    }
+}
+
+fn option_string(v: &Option<String>) -> TokenStream {
+    match v {
+        Some(v) => quote!(Some(String::from(#v))),
+        None => quote!(None),
+    }
+}
+
+impl ToTokens for Grid {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let first = &self.first;
-        let rest = &self.rest;
-        tokens.extend(path! {
-            NonemptyCommaList {
-                first: #first,
-                rest: vec![#(#rest),*],
+        tokens.extend(match self {
+            Grid::Template(v) => path!(Grid::Template(#v)),
+            Grid::AutoColumns {
+                rows,
+                dense,
+                auto_columns,
+            } => {
+                let auto_columns = option_tokens(auto_columns);
+                path!(Grid::AutoColumns {
+                    rows: #rows,
+                    dense: #dense,
+                    auto_columns: #auto_columns,
+                })
+            }
+            Grid::AutoRows {
+                dense,
+                auto_rows,
+                columns,
+            } => {
+                let auto_rows = option_tokens(auto_rows);
+                path!(Grid::AutoRows {
+                    dense: #dense,
+                    auto_rows: #auto_rows,
+                    columns: #columns,
+                })
             }
         })
     }
 }
 
-impl<T> ToTokens for SingleOrDouble<T>
-where
-    T: ToTokens,
-{
+impl ToTokens for GridArea {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let row_start = &self.row_start;
+        let column_start = option_tokens(&self.column_start);
+        let row_end = option_tokens(&self.row_end);
+        let column_end = option_tokens(&self.column_end);
+        tokens.extend(path!(GridArea {
+            row_start: #row_start,
+            column_start: #column_start,
+            row_end: #row_end,
+            column_end: #column_end,
+        }))
+    }
+}
+
+impl ToTokens for GridAreas {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        // The areas were checked when they were parsed, so `new` won't fail.
+        let rows = self.rows().iter().map(|row| {
+            row.iter()
+                .map(|cell| cell.as_deref().unwrap_or("."))
+                .collect::<Vec<_>>()
+                .join(" ")
+        });
+        tokens.extend(path!(GridAreas::new(vec![#(#rows),*]).unwrap()))
+    }
+}
+
+impl ToTokens for GridAutoFlow {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let GridAutoFlow { column, dense } = self;
+        tokens.extend(path!(GridAutoFlow {
+            column: #column,
+            dense: #dense,
+        }))
+    }
+}
+
+impl ToTokens for GridAutoTracks {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let sizes = &self.0;
+        tokens.extend(path!(GridAutoTracks(vec![#(#sizes),*])))
+    }
+}
+
+impl ToTokens for GridLine {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         tokens.extend(match self {
-            SingleOrDouble::Single(t) => path!(SingleOrDouble::Single(#t)),
-            SingleOrDouble::Double { vert, horiz } => path!(SingleOrDouble::Double {
-                vert: #vert,
-                horiz: #horiz,
+            GridLine::Auto => path!(GridLine::Auto),
+            GridLine::Name(name) => path!(GridLine::Name(String::from(#name))),
+            GridLine::Line(n, name) => {
+                let name = option_string(name);
+                path!(GridLine::Line(#n, #name))
+            }
+            GridLine::Span(n, name) => {
+                let name = option_string(name);
+                path!(GridLine::Span(#n, #name))
+            }
+        })
+    }
+}
+
+impl ToTokens for GridPlacement {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let start = &self.start;
+        let end = option_tokens(&self.end);
+        tokens.extend(path!(GridPlacement {
+            start: #start,
+            end: #end,
+        }))
+    }
+}
+
+impl ToTokens for GridTemplate {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            GridTemplate::None => path!(GridTemplate::None),
+            GridTemplate::RowsColumns(rows, columns) => {
+                path!(GridTemplate::RowsColumns(#rows, #columns))
+            }
+            GridTemplate::Areas {
+                areas,
+                rows,
+                columns,
+            } => {
+                let columns = option_tokens(columns);
+                path!(GridTemplate::Areas {
+                    areas: #areas,
+                    rows: vec![#(#rows),*],
+                    columns: #columns,
+                })
+            }
+        })
+    }
+}
+
+impl ToTokens for GridTemplateAreas {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            GridTemplateAreas::None => path!(GridTemplateAreas::None),
+            GridTemplateAreas::Areas(v) => path!(GridTemplateAreas::Areas(#v)),
+        })
+    }
+}
+
+impl ToTokens for GridTemplateRow {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let names_before = option_tokens(&self.names_before);
+        let size = option_tokens(&self.size);
+        let names_after = option_tokens(&self.names_after);
+        tokens.extend(path!(GridTemplateRow {
+            names_before: #names_before,
+            size: #size,
+            names_after: #names_after,
+        }))
+    }
+}
+
+impl ToTokens for GridTemplateTracks {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            GridTemplateTracks::None => path!(GridTemplateTracks::None),
+            GridTemplateTracks::TrackList(v) => path!(GridTemplateTracks::TrackList(#v)),
+            GridTemplateTracks::Subgrid(v) => path!(GridTemplateTracks::Subgrid(vec![#(#v),*])),
+        })
+    }
+}
+
+impl ToTokens for SubgridLineNames {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            SubgridLineNames::Names(v) => path!(SubgridLineNames::Names(#v)),
+            SubgridLineNames::Repeat(count, names) => {
+                path!(SubgridLineNames::Repeat(#count, vec![#(#names),*]))
+            }
+        })
+    }
+}
+
+impl ToTokens for LineNames {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let names = &self.0;
+        tokens.extend(path!(LineNames(vec![#(String::from(#names)),*])))
+    }
+}
+
+impl ToTokens for TrackList {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let items = &self.0;
+        tokens.extend(path!(TrackList(vec![#(#items),*])))
+    }
+}
+
+impl ToTokens for TrackListItem {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TrackListItem::LineNames(v) => path!(TrackListItem::LineNames(#v)),
+            TrackListItem::Size(v) => path!(TrackListItem::Size(#v)),
+            TrackListItem::Repeat(v) => path!(TrackListItem::Repeat(#v)),
+        })
+    }
+}
+
+impl ToTokens for TrackRepeat {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let count = &self.count;
+        let tracks = &self.tracks;
+        tokens.extend(path!(TrackRepeat {
+            count: #count,
+            tracks: #tracks,
+        }))
+    }
+}
+
+impl ToTokens for RepeatCount {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            RepeatCount::Count(v) => path!(RepeatCount::Count(#v)),
+            RepeatCount::AutoFill => path!(RepeatCount::AutoFill),
+            RepeatCount::AutoFit => path!(RepeatCount::AutoFit),
+        })
+    }
+}
+
+impl ToTokens for TrackSize {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TrackSize::Breadth(v) => path!(TrackSize::Breadth(#v)),
+            TrackSize::MinMax(min, max) => path!(TrackSize::MinMax(#min, #max)),
+            TrackSize::FitContent(v) => path!(TrackSize::FitContent(#v)),
+        })
+    }
+}
+
+impl ToTokens for TrackBreadth {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TrackBreadth::LengthPercentage(v) => path!(TrackBreadth::LengthPercentage(#v)),
+            TrackBreadth::Flex(v) => path!(TrackBreadth::Flex(#v)),
+            TrackBreadth::MinContent => path!(TrackBreadth::MinContent),
+            TrackBreadth::MaxContent => path!(TrackBreadth::MaxContent),
+            TrackBreadth::Auto => path!(TrackBreadth::Auto),
+        })
+    }
+}
+
+// Border
+
+impl ToTokens for BorderRadius {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let BorderRadius {
+            horizontal,
+            vertical,
+        } = self;
+        let vertical = option_tokens(vertical);
+        tokens.extend(path!(BorderRadius {
+            horizontal: #horizontal,
+            vertical: #vertical,
+        }))
+    }
+}
+
+impl ToTokens for BorderImage {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let BorderImage {
+            source,
+            slice,
+            width,
+            outset,
+            repeat,
+        } = self;
+        let source = option_tokens(source);
+        let slice = option_tokens(slice);
+        let width = option_tokens(width);
+        let outset = option_tokens(outset);
+        let repeat = option_tokens(repeat);
+        tokens.extend(path!(BorderImage {
+            source: #source,
+            slice: #slice,
+            width: #width,
+            outset: #outset,
+            repeat: #repeat,
+        }))
+    }
+}
+
+impl ToTokens for BorderImageSource {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            BorderImageSource::None => path!(BorderImageSource::None),
+            BorderImageSource::Image(v) => path!(BorderImageSource::Image(#v)),
+        })
+    }
+}
+
+impl ToTokens for BorderImageSlice {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let BorderImageSlice { offsets, fill } = self;
+        tokens.extend(path!(BorderImageSlice {
+            offsets: #offsets,
+            fill: #fill,
+        }))
+    }
+}
+
+impl ToTokens for NumberOrPercentage {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            NumberOrPercentage::Number(v) => path!(NumberOrPercentage::Number(#v)),
+            NumberOrPercentage::Percentage(v) => path!(NumberOrPercentage::Percentage(#v)),
+        })
+    }
+}
+
+impl ToTokens for BorderImageWidth {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            BorderImageWidth::Number(v) => path!(BorderImageWidth::Number(#v)),
+            BorderImageWidth::LengthPercentage(v) => {
+                path!(BorderImageWidth::LengthPercentage(#v))
+            }
+            BorderImageWidth::Auto => path!(BorderImageWidth::Auto),
+        })
+    }
+}
+
+impl ToTokens for NumberOrLength {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            NumberOrLength::Number(v) => path!(NumberOrLength::Number(#v)),
+            NumberOrLength::Length(v) => path!(NumberOrLength::Length(#v)),
+        })
+    }
+}
+
+impl ToTokens for BorderImageRepeat {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let BorderImageRepeat {
+            horizontal,
+            vertical,
+        } = self;
+        let vertical = option_tokens(vertical);
+        tokens.extend(path!(BorderImageRepeat {
+            horizontal: #horizontal,
+            vertical: #vertical,
+        }))
+    }
+}
+
+impl ToTokens for BorderImageRepeatMode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            BorderImageRepeatMode::Stretch => path!(BorderImageRepeatMode::Stretch),
+            BorderImageRepeatMode::Repeat => path!(BorderImageRepeatMode::Repeat),
+            BorderImageRepeatMode::Round => path!(BorderImageRepeatMode::Round),
+            BorderImageRepeatMode::Space => path!(BorderImageRepeatMode::Space),
+        })
+    }
+}
+
+// Text
+
+impl ToTokens for Spacing {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Spacing::Normal => path!(Spacing::Normal),
+            Spacing::Length(v) => path!(Spacing::Length(#v)),
+        })
+    }
+}
+
+impl ToTokens for TextIndent {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let TextIndent {
+            length,
+            hanging,
+            each_line,
+        } = self;
+        tokens.extend(path!(TextIndent {
+            length: #length,
+            hanging: #hanging,
+            each_line: #each_line,
+        }))
+    }
+}
+
+impl ToTokens for TextTransform {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let TextTransform {
+            case,
+            full_width,
+            full_size_kana,
+        } = self;
+        let case = option_tokens(case);
+        tokens.extend(path!(TextTransform {
+            case: #case,
+            full_width: #full_width,
+            full_size_kana: #full_size_kana,
+        }))
+    }
+}
+
+impl ToTokens for TextTransformCase {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TextTransformCase::Capitalize => path!(TextTransformCase::Capitalize),
+            TextTransformCase::Uppercase => path!(TextTransformCase::Uppercase),
+            TextTransformCase::Lowercase => path!(TextTransformCase::Lowercase),
+        })
+    }
+}
+
+impl ToTokens for TextDecoration {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let TextDecoration {
+            line,
+            thickness,
+            style,
+            color,
+        } = self;
+        let line = option_tokens(line);
+        let thickness = option_tokens(thickness);
+        let style = option_tokens(style);
+        let color = option_tokens(color);
+        tokens.extend(path!(TextDecoration {
+            line: #line,
+            thickness: #thickness,
+            style: #style,
+            color: #color,
+        }))
+    }
+}
+
+impl ToTokens for TextDecorationLine {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let TextDecorationLine {
+            underline,
+            overline,
+            line_through,
+            blink,
+        } = self;
+        tokens.extend(path!(TextDecorationLine {
+            underline: #underline,
+            overline: #overline,
+            line_through: #line_through,
+            blink: #blink,
+        }))
+    }
+}
+
+impl ToTokens for TextDecorationStyle {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TextDecorationStyle::Solid => path!(TextDecorationStyle::Solid),
+            TextDecorationStyle::Double => path!(TextDecorationStyle::Double),
+            TextDecorationStyle::Dotted => path!(TextDecorationStyle::Dotted),
+            TextDecorationStyle::Dashed => path!(TextDecorationStyle::Dashed),
+            TextDecorationStyle::Wavy => path!(TextDecorationStyle::Wavy),
+        })
+    }
+}
+
+impl ToTokens for TextDecorationColor {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TextDecorationColor::CurrentColor => path!(TextDecorationColor::CurrentColor),
+            TextDecorationColor::Color(v) => path!(TextDecorationColor::Color(#v)),
+        })
+    }
+}
+
+impl ToTokens for TextDecorationThickness {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TextDecorationThickness::Auto => path!(TextDecorationThickness::Auto),
+            TextDecorationThickness::FromFont => path!(TextDecorationThickness::FromFont),
+            TextDecorationThickness::LengthPercentage(v) => {
+                path!(TextDecorationThickness::LengthPercentage(#v))
+            }
+        })
+    }
+}
+
+impl ToTokens for TextUnderlinePosition {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TextUnderlinePosition::Auto => path!(TextUnderlinePosition::Auto),
+            TextUnderlinePosition::FromFont => path!(TextUnderlinePosition::FromFont),
+            TextUnderlinePosition::Position { under, side } => {
+                let side = option_tokens(side);
+                path!(TextUnderlinePosition::Position {
+                    under: #under,
+                    side: #side,
+                })
+            }
+        })
+    }
+}
+
+impl ToTokens for TextShadow {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TextShadow::None => path!(TextShadow::None),
+            TextShadow::Shadows(list) => path!(TextShadow::Shadows(#list)),
+        })
+    }
+}
+
+impl ToTokens for LineHeight {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            LineHeight::Normal => path!(LineHeight::Normal),
+            LineHeight::Number(v) => path!(LineHeight::Number(#v)),
+            LineHeight::LengthPercentage(v) => path!(LineHeight::LengthPercentage(#v)),
+        })
+    }
+}
+
+impl ToTokens for WhiteSpace {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            WhiteSpace::Normal => path!(WhiteSpace::Normal),
+            WhiteSpace::Pre => path!(WhiteSpace::Pre),
+            WhiteSpace::Nowrap => path!(WhiteSpace::Nowrap),
+            WhiteSpace::PreWrap => path!(WhiteSpace::PreWrap),
+            WhiteSpace::BreakSpaces => path!(WhiteSpace::BreakSpaces),
+            WhiteSpace::PreLine => path!(WhiteSpace::PreLine),
+            WhiteSpace::Longhands {
+                collapse,
+                wrap_mode,
+            } => {
+                let collapse = option_tokens(collapse);
+                let wrap_mode = option_tokens(wrap_mode);
+                path!(WhiteSpace::Longhands {
+                    collapse: #collapse,
+                    wrap_mode: #wrap_mode,
+                })
+            }
+        })
+    }
+}
+
+impl ToTokens for WordBreak {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            WordBreak::Normal => path!(WordBreak::Normal),
+            WordBreak::BreakAll => path!(WordBreak::BreakAll),
+            WordBreak::KeepAll => path!(WordBreak::KeepAll),
+            WordBreak::BreakWord => path!(WordBreak::BreakWord),
+        })
+    }
+}
+
+impl ToTokens for OverflowWrap {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            OverflowWrap::Normal => path!(OverflowWrap::Normal),
+            OverflowWrap::BreakWord => path!(OverflowWrap::BreakWord),
+            OverflowWrap::Anywhere => path!(OverflowWrap::Anywhere),
+        })
+    }
+}
+
+impl ToTokens for LineBreak {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            LineBreak::Auto => path!(LineBreak::Auto),
+            LineBreak::Loose => path!(LineBreak::Loose),
+            LineBreak::Normal => path!(LineBreak::Normal),
+            LineBreak::Strict => path!(LineBreak::Strict),
+            LineBreak::Anywhere => path!(LineBreak::Anywhere),
+        })
+    }
+}
+
+impl ToTokens for Hyphens {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Hyphens::None => path!(Hyphens::None),
+            Hyphens::Manual => path!(Hyphens::Manual),
+            Hyphens::Auto => path!(Hyphens::Auto),
+        })
+    }
+}
+
+impl ToTokens for HyphenateCharacter {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            HyphenateCharacter::Auto => path!(HyphenateCharacter::Auto),
+            HyphenateCharacter::String(v) => path!(HyphenateCharacter::String(String::from(#v))),
+        })
+    }
+}
+
+impl ToTokens for TextWrap {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let TextWrap { mode, style } = self;
+        let mode = option_tokens(mode);
+        let style = option_tokens(style);
+        tokens.extend(path!(TextWrap {
+            mode: #mode,
+            style: #style,
+        }))
+    }
+}
+
+impl ToTokens for TextWrapMode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TextWrapMode::Wrap => path!(TextWrapMode::Wrap),
+            TextWrapMode::Nowrap => path!(TextWrapMode::Nowrap),
+        })
+    }
+}
+
+impl ToTokens for TextWrapStyle {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TextWrapStyle::Auto => path!(TextWrapStyle::Auto),
+            TextWrapStyle::Balance => path!(TextWrapStyle::Balance),
+            TextWrapStyle::Stable => path!(TextWrapStyle::Stable),
+            TextWrapStyle::Pretty => path!(TextWrapStyle::Pretty),
+        })
+    }
+}
+
+impl ToTokens for WhiteSpaceCollapse {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            WhiteSpaceCollapse::Collapse => path!(WhiteSpaceCollapse::Collapse),
+            WhiteSpaceCollapse::Discard => path!(WhiteSpaceCollapse::Discard),
+            WhiteSpaceCollapse::Preserve => path!(WhiteSpaceCollapse::Preserve),
+            WhiteSpaceCollapse::PreserveBreaks => path!(WhiteSpaceCollapse::PreserveBreaks),
+            WhiteSpaceCollapse::PreserveSpaces => path!(WhiteSpaceCollapse::PreserveSpaces),
+            WhiteSpaceCollapse::BreakSpaces => path!(WhiteSpaceCollapse::BreakSpaces),
+        })
+    }
+}
+
+impl ToTokens for HangingPunctuation {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let HangingPunctuation { first, end, last } = self;
+        let end = option_tokens(end);
+        tokens.extend(path!(HangingPunctuation {
+            first: #first,
+            end: #end,
+            last: #last,
+        }))
+    }
+}
+
+impl ToTokens for HangingPunctuationEnd {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            HangingPunctuationEnd::ForceEnd => path!(HangingPunctuationEnd::ForceEnd),
+            HangingPunctuationEnd::AllowEnd => path!(HangingPunctuationEnd::AllowEnd),
+        })
+    }
+}
+
+// Writing modes
+
+impl ToTokens for Direction {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Direction::Ltr => path!(Direction::Ltr),
+            Direction::Rtl => path!(Direction::Rtl),
+        })
+    }
+}
+
+impl ToTokens for UnicodeBidi {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            UnicodeBidi::Normal => path!(UnicodeBidi::Normal),
+            UnicodeBidi::Embed => path!(UnicodeBidi::Embed),
+            UnicodeBidi::Isolate => path!(UnicodeBidi::Isolate),
+            UnicodeBidi::BidiOverride => path!(UnicodeBidi::BidiOverride),
+            UnicodeBidi::IsolateOverride => path!(UnicodeBidi::IsolateOverride),
+            UnicodeBidi::Plaintext => path!(UnicodeBidi::Plaintext),
+        })
+    }
+}
+
+impl ToTokens for WritingMode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            WritingMode::HorizontalTb => path!(WritingMode::HorizontalTb),
+            WritingMode::VerticalRl => path!(WritingMode::VerticalRl),
+            WritingMode::VerticalLr => path!(WritingMode::VerticalLr),
+            WritingMode::SidewaysRl => path!(WritingMode::SidewaysRl),
+            WritingMode::SidewaysLr => path!(WritingMode::SidewaysLr),
+        })
+    }
+}
+
+impl ToTokens for TextOrientation {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TextOrientation::Mixed => path!(TextOrientation::Mixed),
+            TextOrientation::Upright => path!(TextOrientation::Upright),
+            TextOrientation::Sideways => path!(TextOrientation::Sideways),
+        })
+    }
+}
+
+impl ToTokens for TextCombineUpright {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TextCombineUpright::None => path!(TextCombineUpright::None),
+            TextCombineUpright::All => path!(TextCombineUpright::All),
+            TextCombineUpright::Digits(v) => {
+                let v = option_tokens(v);
+                path!(TextCombineUpright::Digits(#v))
+            }
+        })
+    }
+}
+
+// Font
+
+impl ToTokens for FontShorthand {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            FontShorthand::System(v) => path!(FontShorthand::System(#v)),
+            FontShorthand::Values {
+                style,
+                small_caps,
+                weight,
+                stretch,
+                size,
+                line_height,
+                family,
+            } => {
+                let style = option_tokens(style);
+                let weight = option_tokens(weight);
+                let stretch = option_tokens(stretch);
+                let line_height = option_tokens(line_height);
+                path!(FontShorthand::Values {
+                    style: #style,
+                    small_caps: #small_caps,
+                    weight: #weight,
+                    stretch: #stretch,
+                    size: #size,
+                    line_height: #line_height,
+                    family: #family,
+                })
+            }
+        })
+    }
+}
+
+impl ToTokens for SystemFont {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            SystemFont::Caption => path!(SystemFont::Caption),
+            SystemFont::Icon => path!(SystemFont::Icon),
+            SystemFont::Menu => path!(SystemFont::Menu),
+            SystemFont::MessageBox => path!(SystemFont::MessageBox),
+            SystemFont::SmallCaption => path!(SystemFont::SmallCaption),
+            SystemFont::StatusBar => path!(SystemFont::StatusBar),
+        })
+    }
+}
+
+impl ToTokens for FontStretch {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            FontStretch::Normal => path!(FontStretch::Normal),
+            FontStretch::UltraCondensed => path!(FontStretch::UltraCondensed),
+            FontStretch::ExtraCondensed => path!(FontStretch::ExtraCondensed),
+            FontStretch::Condensed => path!(FontStretch::Condensed),
+            FontStretch::SemiCondensed => path!(FontStretch::SemiCondensed),
+            FontStretch::SemiExpanded => path!(FontStretch::SemiExpanded),
+            FontStretch::Expanded => path!(FontStretch::Expanded),
+            FontStretch::ExtraExpanded => path!(FontStretch::ExtraExpanded),
+            FontStretch::UltraExpanded => path!(FontStretch::UltraExpanded),
+            FontStretch::Percentage(v) => path!(FontStretch::Percentage(#v)),
+        })
+    }
+}
+
+impl ToTokens for FontKerning {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            FontKerning::Auto => path!(FontKerning::Auto),
+            FontKerning::Normal => path!(FontKerning::Normal),
+            FontKerning::None => path!(FontKerning::None),
+        })
+    }
+}
+
+impl ToTokens for FontSizeAdjust {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            FontSizeAdjust::None => path!(FontSizeAdjust::None),
+            FontSizeAdjust::Adjust { metric, value } => {
+                let value = option_tokens(value);
+                path!(FontSizeAdjust::Adjust {
+                    metric: #metric,
+                    value: #value,
+                })
+            }
+        })
+    }
+}
+
+impl ToTokens for FontMetric {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            FontMetric::ExHeight => path!(FontMetric::ExHeight),
+            FontMetric::CapHeight => path!(FontMetric::CapHeight),
+            FontMetric::ChWidth => path!(FontMetric::ChWidth),
+            FontMetric::IcWidth => path!(FontMetric::IcWidth),
+            FontMetric::IcHeight => path!(FontMetric::IcHeight),
+        })
+    }
+}
+
+impl ToTokens for FontSynthesis {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let FontSynthesis {
+            weight,
+            style,
+            small_caps,
+            position,
+        } = self;
+        tokens.extend(path!(FontSynthesis {
+            weight: #weight,
+            style: #style,
+            small_caps: #small_caps,
+            position: #position,
+        }))
+    }
+}
+
+impl ToTokens for FontVariant {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let FontVariant {
+            ligatures,
+            caps,
+            alternates,
+            numeric,
+            east_asian,
+            position,
+            emoji,
+        } = self;
+        tokens.extend(path!(FontVariant {
+            ligatures: #ligatures,
+            caps: #caps,
+            alternates: #alternates,
+            numeric: #numeric,
+            east_asian: #east_asian,
+            position: #position,
+            emoji: #emoji,
+        }))
+    }
+}
+
+impl ToTokens for FontVariantLigatures {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            FontVariantLigatures::Normal => path!(FontVariantLigatures::Normal),
+            FontVariantLigatures::None => path!(FontVariantLigatures::None),
+            FontVariantLigatures::Values {
+                common,
+                discretionary,
+                historical,
+                contextual,
+            } => {
+                let common = option_tokens(common);
+                let discretionary = option_tokens(discretionary);
+                let historical = option_tokens(historical);
+                let contextual = option_tokens(contextual);
+                path!(FontVariantLigatures::Values {
+                    common: #common,
+                    discretionary: #discretionary,
+                    historical: #historical,
+                    contextual: #contextual,
+                })
+            }
+        })
+    }
+}
+
+impl ToTokens for FontVariantCaps {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            FontVariantCaps::Normal => path!(FontVariantCaps::Normal),
+            FontVariantCaps::SmallCaps => path!(FontVariantCaps::SmallCaps),
+            FontVariantCaps::AllSmallCaps => path!(FontVariantCaps::AllSmallCaps),
+            FontVariantCaps::PetiteCaps => path!(FontVariantCaps::PetiteCaps),
+            FontVariantCaps::AllPetiteCaps => path!(FontVariantCaps::AllPetiteCaps),
+            FontVariantCaps::Unicase => path!(FontVariantCaps::Unicase),
+            FontVariantCaps::TitlingCaps => path!(FontVariantCaps::TitlingCaps),
+        })
+    }
+}
+
+impl ToTokens for FontVariantAlternates {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let stylistic = option_string(&self.stylistic);
+        let historical_forms = self.historical_forms;
+        let styleset = &self.styleset;
+        let character_variant = &self.character_variant;
+        let swash = option_string(&self.swash);
+        let ornaments = option_string(&self.ornaments);
+        let annotation = option_string(&self.annotation);
+        tokens.extend(path!(FontVariantAlternates {
+            stylistic: #stylistic,
+            historical_forms: #historical_forms,
+            styleset: vec![#(String::from(#styleset)),*],
+            character_variant: vec![#(String::from(#character_variant)),*],
+            swash: #swash,
+            ornaments: #ornaments,
+            annotation: #annotation,
+        }))
+    }
+}
+
+impl ToTokens for FontVariantNumeric {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let figure = option_tokens(&self.figure);
+        let spacing = option_tokens(&self.spacing);
+        let fraction = option_tokens(&self.fraction);
+        let ordinal = self.ordinal;
+        let slashed_zero = self.slashed_zero;
+        tokens.extend(path!(FontVariantNumeric {
+            figure: #figure,
+            spacing: #spacing,
+            fraction: #fraction,
+            ordinal: #ordinal,
+            slashed_zero: #slashed_zero,
+        }))
+    }
+}
+
+impl ToTokens for NumericFigure {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            NumericFigure::Lining => path!(NumericFigure::Lining),
+            NumericFigure::Oldstyle => path!(NumericFigure::Oldstyle),
+        })
+    }
+}
+
+impl ToTokens for NumericSpacing {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            NumericSpacing::Proportional => path!(NumericSpacing::Proportional),
+            NumericSpacing::Tabular => path!(NumericSpacing::Tabular),
+        })
+    }
+}
+
+impl ToTokens for NumericFraction {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            NumericFraction::Diagonal => path!(NumericFraction::Diagonal),
+            NumericFraction::Stacked => path!(NumericFraction::Stacked),
+        })
+    }
+}
+
+impl ToTokens for FontVariantEastAsian {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let variant = option_tokens(&self.variant);
+        let width = option_tokens(&self.width);
+        let ruby = self.ruby;
+        tokens.extend(path!(FontVariantEastAsian {
+            variant: #variant,
+            width: #width,
+            ruby: #ruby,
+        }))
+    }
+}
+
+impl ToTokens for EastAsianVariant {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            EastAsianVariant::Jis78 => path!(EastAsianVariant::Jis78),
+            EastAsianVariant::Jis83 => path!(EastAsianVariant::Jis83),
+            EastAsianVariant::Jis90 => path!(EastAsianVariant::Jis90),
+            EastAsianVariant::Jis04 => path!(EastAsianVariant::Jis04),
+            EastAsianVariant::Simplified => path!(EastAsianVariant::Simplified),
+            EastAsianVariant::Traditional => path!(EastAsianVariant::Traditional),
+        })
+    }
+}
+
+impl ToTokens for EastAsianWidth {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            EastAsianWidth::Full => path!(EastAsianWidth::Full),
+            EastAsianWidth::Proportional => path!(EastAsianWidth::Proportional),
+        })
+    }
+}
+
+impl ToTokens for FontVariantPosition {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            FontVariantPosition::Normal => path!(FontVariantPosition::Normal),
+            FontVariantPosition::Sub => path!(FontVariantPosition::Sub),
+            FontVariantPosition::Super => path!(FontVariantPosition::Super),
+        })
+    }
+}
+
+impl ToTokens for FontVariantEmoji {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            FontVariantEmoji::Normal => path!(FontVariantEmoji::Normal),
+            FontVariantEmoji::Text => path!(FontVariantEmoji::Text),
+            FontVariantEmoji::Emoji => path!(FontVariantEmoji::Emoji),
+            FontVariantEmoji::Unicode => path!(FontVariantEmoji::Unicode),
+        })
+    }
+}
+
+impl ToTokens for FontFeatureSettings {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            FontFeatureSettings::Normal => path!(FontFeatureSettings::Normal),
+            FontFeatureSettings::Features(v) => path!(FontFeatureSettings::Features(#v)),
+        })
+    }
+}
+
+impl ToTokens for FeatureTagValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let FeatureTagValue { tag, value } = self;
+        tokens.extend(path!(FeatureTagValue {
+            tag: String::from(#tag),
+            value: #value,
+        }))
+    }
+}
+
+impl ToTokens for FontVariationSettings {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            FontVariationSettings::Normal => path!(FontVariationSettings::Normal),
+            FontVariationSettings::Axes(v) => path!(FontVariationSettings::Axes(#v)),
+        })
+    }
+}
+
+impl ToTokens for VariationAxis {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let VariationAxis { tag, value } = self;
+        tokens.extend(path!(VariationAxis {
+            tag: String::from(#tag),
+            value: #value,
+        }))
+    }
+}
+
+// Image
+
+impl ToTokens for Url {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Url { url, modifiers } = self;
+        tokens.extend(path!(Url {
+            url: String::from(#url),
+            modifiers: vec![#(#modifiers),*],
+        }))
+    }
+}
+
+impl ToTokens for UrlModifier {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            UrlModifier::CrossOrigin(v) => path!(UrlModifier::CrossOrigin(#v)),
+            UrlModifier::Integrity(v) => path!(UrlModifier::Integrity(String::from(#v))),
+            UrlModifier::ReferrerPolicy(v) => path!(UrlModifier::ReferrerPolicy(#v)),
+        })
+    }
+}
+
+impl ToTokens for CrossOrigin {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            CrossOrigin::Anonymous => path!(CrossOrigin::Anonymous),
+            CrossOrigin::UseCredentials => path!(CrossOrigin::UseCredentials),
+        })
+    }
+}
+
+impl ToTokens for ReferrerPolicy {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            ReferrerPolicy::NoReferrer => path!(ReferrerPolicy::NoReferrer),
+            ReferrerPolicy::NoReferrerWhenDowngrade => {
+                path!(ReferrerPolicy::NoReferrerWhenDowngrade)
+            }
+            ReferrerPolicy::SameOrigin => path!(ReferrerPolicy::SameOrigin),
+            ReferrerPolicy::Origin => path!(ReferrerPolicy::Origin),
+            ReferrerPolicy::StrictOrigin => path!(ReferrerPolicy::StrictOrigin),
+            ReferrerPolicy::OriginWhenCrossOrigin => path!(ReferrerPolicy::OriginWhenCrossOrigin),
+            ReferrerPolicy::StrictOriginWhenCrossOrigin => {
+                path!(ReferrerPolicy::StrictOriginWhenCrossOrigin)
+            }
+            ReferrerPolicy::UnsafeUrl => path!(ReferrerPolicy::UnsafeUrl),
+        })
+    }
+}
+
+impl ToTokens for Image {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Image::Url(v) => path!(Image::Url(#v)),
+            Image::Gradient(v) => path!(Image::Gradient(Box::new(#v))),
+            Image::ImageSet(v) => path!(Image::ImageSet(#v)),
+        })
+    }
+}
+
+impl ToTokens for Gradient {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Gradient::Linear(v) => path!(Gradient::Linear(#v)),
+            Gradient::Radial(v) => path!(Gradient::Radial(#v)),
+            Gradient::Conic(v) => path!(Gradient::Conic(#v)),
+        })
+    }
+}
+
+impl ToTokens for LinearGradient {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let LinearGradient {
+            repeating,
+            direction,
+            stops,
+        } = self;
+        let direction = option_tokens(direction);
+        tokens.extend(path!(LinearGradient {
+            repeating: #repeating,
+            direction: #direction,
+            stops: #stops,
+        }))
+    }
+}
+
+impl ToTokens for LineDirection {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            LineDirection::Angle(v) => path!(LineDirection::Angle(#v)),
+            LineDirection::To {
+                horizontal,
+                vertical,
+            } => {
+                let horizontal = option_tokens(horizontal);
+                let vertical = option_tokens(vertical);
+                path!(LineDirection::To {
+                    horizontal: #horizontal,
+                    vertical: #vertical,
+                })
+            }
+        })
+    }
+}
+
+impl ToTokens for HorizontalSide {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            HorizontalSide::Left => path!(HorizontalSide::Left),
+            HorizontalSide::Right => path!(HorizontalSide::Right),
+        })
+    }
+}
+
+impl ToTokens for VerticalSide {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            VerticalSide::Top => path!(VerticalSide::Top),
+            VerticalSide::Bottom => path!(VerticalSide::Bottom),
+        })
+    }
+}
+
+impl ToTokens for RadialGradient {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let RadialGradient {
+            repeating,
+            shape,
+            size,
+            position,
+            stops,
+        } = self;
+        let shape = option_tokens(shape);
+        let size = option_tokens(size);
+        let position = option_tokens(position);
+        tokens.extend(path!(RadialGradient {
+            repeating: #repeating,
+            shape: #shape,
+            size: #size,
+            position: #position,
+            stops: #stops,
+        }))
+    }
+}
+
+impl ToTokens for EndingShape {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            EndingShape::Circle => path!(EndingShape::Circle),
+            EndingShape::Ellipse => path!(EndingShape::Ellipse),
+        })
+    }
+}
+
+impl ToTokens for RadialSize {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            RadialSize::ClosestSide => path!(RadialSize::ClosestSide),
+            RadialSize::ClosestCorner => path!(RadialSize::ClosestCorner),
+            RadialSize::FarthestSide => path!(RadialSize::FarthestSide),
+            RadialSize::FarthestCorner => path!(RadialSize::FarthestCorner),
+            RadialSize::Length(v) => path!(RadialSize::Length(#v)),
+            RadialSize::Ellipse(x, y) => path!(RadialSize::Ellipse(#x, #y)),
+        })
+    }
+}
+
+impl ToTokens for ConicGradient {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ConicGradient {
+            repeating,
+            from,
+            position,
+            stops,
+        } = self;
+        let from = option_tokens(from);
+        let position = option_tokens(position);
+        tokens.extend(path!(ConicGradient {
+            repeating: #repeating,
+            from: #from,
+            position: #position,
+            stops: #stops,
+        }))
+    }
+}
+
+impl ToTokens for AnglePercentage {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            AnglePercentage::Angle(v) => path!(AnglePercentage::Angle(#v)),
+            AnglePercentage::Percentage(v) => path!(AnglePercentage::Percentage(#v)),
+        })
+    }
+}
+
+impl<P: ToTokens> ToTokens for ColorStopList<P> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let items = self.0.iter();
+        tokens.extend(path!(ColorStopList(vec![#(#items),*])))
+    }
+}
+
+impl<P: ToTokens> ToTokens for GradientItem<P> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            GradientItem::Stop { color, start, end } => {
+                let start = option_tokens(start);
+                let end = option_tokens(end);
+                path!(GradientItem::Stop {
+                    color: #color,
+                    start: #start,
+                    end: #end,
+                })
+            }
+            GradientItem::Hint(v) => path!(GradientItem::Hint(#v)),
+        })
+    }
+}
+
+impl ToTokens for ImageSet {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let options = &self.0;
+        tokens.extend(path!(ImageSet(#options)))
+    }
+}
+
+impl ToTokens for ImageSetOption {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ImageSetOption {
+            image,
+            resolution,
+            mime_type,
+        } = self;
+        let resolution = option_tokens(resolution);
+        let mime_type = option_string(mime_type);
+        tokens.extend(path!(ImageSetOption {
+            image: Box::new(#image),
+            resolution: #resolution,
+            mime_type: #mime_type,
+        }))
+    }
+}
+
+impl ToTokens for Resolution {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Resolution::X(v) => path!(Resolution::X(#v)),
+            Resolution::Dppx(v) => path!(Resolution::Dppx(#v)),
+            Resolution::Dpi(v) => path!(Resolution::Dpi(#v)),
+            Resolution::Dpcm(v) => path!(Resolution::Dpcm(#v)),
+        })
+    }
+}
+
+// Position
+
+impl ToTokens for PositionValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let PositionValue { x, y } = self;
+        tokens.extend(path!(PositionValue { x: #x, y: #y }))
+    }
+}
+
+impl<S: ToTokens> ToTokens for PositionComponent<S> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            PositionComponent::Center => path!(PositionComponent::Center),
+            PositionComponent::Offset(v) => path!(PositionComponent::Offset(#v)),
+            PositionComponent::Side(side, offset) => {
+                let offset = option_tokens(offset);
+                path!(PositionComponent::Side(#side, #offset))
+            }
+        })
+    }
+}
+
+// Transform
+
+impl ToTokens for Angle {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Angle::Deg(v) => path!(Angle::Deg(#v)),
+            Angle::Grad(v) => path!(Angle::Grad(#v)),
+            Angle::Rad(v) => path!(Angle::Rad(#v)),
+            Angle::Turn(v) => path!(Angle::Turn(#v)),
+            Angle::Zero => path!(Angle::Zero),
+        })
+    }
+}
+
+impl ToTokens for TransformList {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let functions = self.0.iter();
+        tokens.extend(path!(TransformList(vec![#(#functions),*])))
+    }
+}
+
+impl ToTokens for TransformFunction {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TransformFunction::Matrix(v) => path!(TransformFunction::Matrix([#(#v),*])),
+            TransformFunction::Matrix3d(v) => path!(TransformFunction::Matrix3d([#(#v),*])),
+            TransformFunction::Translate(x, y) => {
+                let y = option_tokens(y);
+                path!(TransformFunction::Translate(#x, #y))
+            }
+            TransformFunction::TranslateX(v) => path!(TransformFunction::TranslateX(#v)),
+            TransformFunction::TranslateY(v) => path!(TransformFunction::TranslateY(#v)),
+            TransformFunction::TranslateZ(v) => path!(TransformFunction::TranslateZ(#v)),
+            TransformFunction::Translate3d(x, y, z) => {
+                path!(TransformFunction::Translate3d(#x, #y, #z))
+            }
+            TransformFunction::Scale(x, y) => {
+                let y = option_tokens(y);
+                path!(TransformFunction::Scale(#x, #y))
+            }
+            TransformFunction::ScaleX(v) => path!(TransformFunction::ScaleX(#v)),
+            TransformFunction::ScaleY(v) => path!(TransformFunction::ScaleY(#v)),
+            TransformFunction::ScaleZ(v) => path!(TransformFunction::ScaleZ(#v)),
+            TransformFunction::Scale3d(x, y, z) => path!(TransformFunction::Scale3d(#x, #y, #z)),
+            TransformFunction::Rotate(v) => path!(TransformFunction::Rotate(#v)),
+            TransformFunction::RotateX(v) => path!(TransformFunction::RotateX(#v)),
+            TransformFunction::RotateY(v) => path!(TransformFunction::RotateY(#v)),
+            TransformFunction::RotateZ(v) => path!(TransformFunction::RotateZ(#v)),
+            TransformFunction::Rotate3d(x, y, z, angle) => {
+                path!(TransformFunction::Rotate3d(#x, #y, #z, #angle))
+            }
+            TransformFunction::Skew(x, y) => {
+                let y = option_tokens(y);
+                path!(TransformFunction::Skew(#x, #y))
+            }
+            TransformFunction::SkewX(v) => path!(TransformFunction::SkewX(#v)),
+            TransformFunction::SkewY(v) => path!(TransformFunction::SkewY(#v)),
+            TransformFunction::Perspective(v) => {
+                let v = option_tokens(v);
+                path!(TransformFunction::Perspective(#v))
+            }
+        })
+    }
+}
+
+impl ToTokens for TransformOrigin {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let x = &self.x;
+        let y = &self.y;
+        let z = option_tokens(&self.z);
+        tokens.extend(path!(TransformOrigin {
+            x: #x,
+            y: #y,
+            z: #z,
+        }))
+    }
+}
+
+impl ToTokens for OriginX {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            OriginX::Left => path!(OriginX::Left),
+            OriginX::Center => path!(OriginX::Center),
+            OriginX::Right => path!(OriginX::Right),
+            OriginX::Offset(v) => path!(OriginX::Offset(#v)),
+        })
+    }
+}
+
+impl ToTokens for OriginY {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            OriginY::Top => path!(OriginY::Top),
+            OriginY::Center => path!(OriginY::Center),
+            OriginY::Bottom => path!(OriginY::Bottom),
+            OriginY::Offset(v) => path!(OriginY::Offset(#v)),
+        })
+    }
+}
+
+impl ToTokens for TransformStyle {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TransformStyle::Flat => path!(TransformStyle::Flat),
+            TransformStyle::Preserve3d => path!(TransformStyle::Preserve3d),
+        })
+    }
+}
+
+impl ToTokens for Perspective {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Perspective::None => path!(Perspective::None),
+            Perspective::Length(v) => path!(Perspective::Length(#v)),
+        })
+    }
+}
+
+impl ToTokens for BackfaceVisibility {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            BackfaceVisibility::Visible => path!(BackfaceVisibility::Visible),
+            BackfaceVisibility::Hidden => path!(BackfaceVisibility::Hidden),
+        })
+    }
+}
+
+impl ToTokens for Translate {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Translate::None => path!(Translate::None),
+            Translate::Offset { x, y, z } => {
+                let y = option_tokens(y);
+                let z = option_tokens(z);
+                path!(Translate::Offset {
+                    x: #x,
+                    y: #y,
+                    z: #z,
+                })
+            }
+        })
+    }
+}
+
+impl ToTokens for Rotate {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Rotate::None => path!(Rotate::None),
+            Rotate::Angle(v) => path!(Rotate::Angle(#v)),
+            Rotate::Axis(axis, angle) => path!(Rotate::Axis(#axis, #angle)),
+        })
+    }
+}
+
+impl ToTokens for RotateAxis {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            RotateAxis::X => path!(RotateAxis::X),
+            RotateAxis::Y => path!(RotateAxis::Y),
+            RotateAxis::Z => path!(RotateAxis::Z),
+            RotateAxis::Vector(x, y, z) => path!(RotateAxis::Vector(#x, #y, #z)),
+        })
+    }
+}
+
+impl ToTokens for Scale {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Scale::None => path!(Scale::None),
+            Scale::Factors { x, y, z } => {
+                let y = option_tokens(y);
+                let z = option_tokens(z);
+                path!(Scale::Factors {
+                    x: #x,
+                    y: #y,
+                    z: #z,
+                })
+            }
+        })
+    }
+}
+
+// Animation
+
+impl ToTokens for Time {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Time::S(v) => path!(Time::S(#v)),
+            Time::Ms(v) => path!(Time::Ms(#v)),
+        })
+    }
+}
+
+impl ToTokens for EasingFunction {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            EasingFunction::Linear => path!(EasingFunction::Linear),
+            EasingFunction::Ease => path!(EasingFunction::Ease),
+            EasingFunction::EaseIn => path!(EasingFunction::EaseIn),
+            EasingFunction::EaseOut => path!(EasingFunction::EaseOut),
+            EasingFunction::EaseInOut => path!(EasingFunction::EaseInOut),
+            EasingFunction::CubicBezier(x1, y1, x2, y2) => {
+                path!(EasingFunction::CubicBezier(#x1, #y1, #x2, #y2))
+            }
+            EasingFunction::StepStart => path!(EasingFunction::StepStart),
+            EasingFunction::StepEnd => path!(EasingFunction::StepEnd),
+            EasingFunction::Steps(count, position) => {
+                path!(EasingFunction::Steps(#count, #position))
+            }
+            EasingFunction::LinearStops(stops) => {
+                path!(EasingFunction::LinearStops(vec![#(#stops),*]))
+            }
+        })
+    }
+}
+
+impl ToTokens for StepPosition {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            StepPosition::JumpStart => path!(StepPosition::JumpStart),
+            StepPosition::JumpEnd => path!(StepPosition::JumpEnd),
+            StepPosition::JumpNone => path!(StepPosition::JumpNone),
+            StepPosition::JumpBoth => path!(StepPosition::JumpBoth),
+        })
+    }
+}
+
+impl ToTokens for LinearStop {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let output = self.output;
+        let start = option_tokens(&self.start);
+        let end = option_tokens(&self.end);
+        tokens.extend(path!(LinearStop {
+            output: #output,
+            start: #start,
+            end: #end,
+        }))
+    }
+}
+
+impl ToTokens for TransitionProperty {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TransitionProperty::None => path!(TransitionProperty::None),
+            TransitionProperty::All => path!(TransitionProperty::All),
+            TransitionProperty::Property(name) => {
+                path!(TransitionProperty::Property(String::from(#name)))
+            }
+        })
+    }
+}
+
+impl ToTokens for TransitionBehavior {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TransitionBehavior::Normal => path!(TransitionBehavior::Normal),
+            TransitionBehavior::AllowDiscrete => path!(TransitionBehavior::AllowDiscrete),
+        })
+    }
+}
+
+impl ToTokens for SingleTransition {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let property = option_tokens(&self.property);
+        let duration = option_tokens(&self.duration);
+        let timing_function = option_tokens(&self.timing_function);
+        let delay = option_tokens(&self.delay);
+        let behavior = option_tokens(&self.behavior);
+        tokens.extend(path!(SingleTransition {
+            property: #property,
+            duration: #duration,
+            timing_function: #timing_function,
+            delay: #delay,
+            behavior: #behavior,
+        }))
+    }
+}
+
+impl ToTokens for AnimationName {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            AnimationName::None => path!(AnimationName::None),
+            AnimationName::Name(name) => path!(AnimationName::Name(String::from(#name))),
+        })
+    }
+}
+
+impl ToTokens for AnimationIterationCount {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            AnimationIterationCount::Infinite => path!(AnimationIterationCount::Infinite),
+            AnimationIterationCount::Count(v) => path!(AnimationIterationCount::Count(#v)),
+        })
+    }
+}
+
+impl ToTokens for AnimationDirection {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            AnimationDirection::Normal => path!(AnimationDirection::Normal),
+            AnimationDirection::Reverse => path!(AnimationDirection::Reverse),
+            AnimationDirection::Alternate => path!(AnimationDirection::Alternate),
+            AnimationDirection::AlternateReverse => path!(AnimationDirection::AlternateReverse),
+        })
+    }
+}
+
+impl ToTokens for AnimationFillMode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            AnimationFillMode::None => path!(AnimationFillMode::None),
+            AnimationFillMode::Forwards => path!(AnimationFillMode::Forwards),
+            AnimationFillMode::Backwards => path!(AnimationFillMode::Backwards),
+            AnimationFillMode::Both => path!(AnimationFillMode::Both),
+        })
+    }
+}
+
+impl ToTokens for AnimationPlayState {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            AnimationPlayState::Running => path!(AnimationPlayState::Running),
+            AnimationPlayState::Paused => path!(AnimationPlayState::Paused),
+        })
+    }
+}
+
+impl ToTokens for SingleAnimation {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let duration = option_tokens(&self.duration);
+        let timing_function = option_tokens(&self.timing_function);
+        let delay = option_tokens(&self.delay);
+        let iteration_count = option_tokens(&self.iteration_count);
+        let direction = option_tokens(&self.direction);
+        let fill_mode = option_tokens(&self.fill_mode);
+        let play_state = option_tokens(&self.play_state);
+        let name = option_tokens(&self.name);
+        tokens.extend(path!(SingleAnimation {
+            duration: #duration,
+            timing_function: #timing_function,
+            delay: #delay,
+            iteration_count: #iteration_count,
+            direction: #direction,
+            fill_mode: #fill_mode,
+            play_state: #play_state,
+            name: #name,
+        }))
+    }
+}
+
+// Generic containers
+
+impl<T> ToTokens for NonemptyCommaList<T>
+where
+    T: ToTokens,
+{
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let first = &self.first;
+        let rest = &self.rest;
+        tokens.extend(path! {
+            NonemptyCommaList {
+                first: #first,
+                rest: vec![#(#rest),*],
+            }
+        })
+    }
+}
+
+impl<T> ToTokens for SingleOrDouble<T>
+where
+    T: ToTokens,
+{
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            SingleOrDouble::Single(t) => path!(SingleOrDouble::Single(#t)),
+            SingleOrDouble::Double { horiz, vert } => path!(SingleOrDouble::Double {
+                horiz: #horiz,
+                vert: #vert,
             }),
         })
     }
@@ -796,10 +796,171 @@ impl fmt::Display for Color {
     }
 }
 
+/// A color space to interpolate colors in.
+///
+/// https://www.w3.org/TR/css-color-4/#interpolation-space
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// The default for the legacy color syntaxes, which are the only ones we support.
+    Srgb,
+    SrgbLinear,
+    /// Perceptually uniform, so gradients and transitions don't go muddy in the middle.
+    Oklab,
+    /// Hues are interpolated the shorter way round the color wheel.
+    Hsl,
+}
+
+impl Color {
+    /// The red, green, blue and alpha components, between 0 and 1.
+    pub fn to_rgba(self) -> [f64; 4] {
+        match self {
+            Color::HexRGBA(r, g, b, a) => [
+                f64::from(r) / 255.0,
+                f64::from(g) / 255.0,
+                f64::from(b) / 255.0,
+                f64::from(a) / 255.0,
+            ],
+            Color::HSL(h, s, l) => Color::HSLA(h, s, l, 1.0).to_rgba(),
+            Color::HSLA(h, s, l, a) => {
+                let (r, g, b) = hsl_to_rgb(h, s * 0.01, l * 0.01);
+                [r, g, b, a]
+            }
+            color => match color.to_rgb() {
+                Color::HexRGB(r, g, b) => Color::HexRGBA(r, g, b, 255).to_rgba(),
+                _ => unreachable!(),
+            },
+        }
+    }
+
+    /// The closest hex color to the components, which are clamped to `0..=1`.
+    pub fn from_rgba(rgba: [f64; 4]) -> Color {
+        let [r, g, b, a] = rgba.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+        if a == 255 {
+            Color::HexRGB(r, g, b)
+        } else {
+            Color::HexRGBA(r, g, b, a)
+        }
+    }
+
+    /// The color `progress` of the way from `self` to `other`, interpolated in `space` with
+    /// premultiplied alpha.
+    ///
+    /// https://www.w3.org/TR/css-color-4/#interpolation
+    pub fn interpolate_in(self, other: Color, progress: f64, space: ColorSpace) -> Color {
+        let (from, to) = (self.to_rgba(), other.to_rgba());
+        let alpha = (from[3] + (to[3] - from[3]) * progress).clamp(0.0, 1.0);
+        let lerp = |a: f64, b: f64| a + (b - a) * progress;
+        // Premultiply, interpolate, then divide by the interpolated alpha.
+        let mix = |a: [f64; 3], b: [f64; 3]| {
+            let mut out = [0.0; 3];
+            for (idx, c) in out.iter_mut().enumerate() {
+                let value = lerp(a[idx] * from[3], b[idx] * to[3]);
+                *c = if alpha == 0.0 { 0.0 } else { value / alpha };
+            }
+            out
+        };
+        let rgb = |c: [f64; 4]| [c[0], c[1], c[2]];
+        let [r, g, b] = match space {
+            ColorSpace::Srgb => mix(rgb(from), rgb(to)),
+            ColorSpace::SrgbLinear => {
+                let linear = |c: [f64; 4]| rgb(c).map(srgb_to_linear);
+                mix(linear(from), linear(to)).map(linear_to_srgb)
+            }
+            ColorSpace::Oklab => {
+                let oklab = |c: [f64; 4]| linear_to_oklab(rgb(c).map(srgb_to_linear));
+                oklab_to_linear(mix(oklab(from), oklab(to))).map(linear_to_srgb)
+            }
+            ColorSpace::Hsl => {
+                let [mut h1, s1, l1] = rgb_to_hsl(rgb(from));
+                let [mut h2, s2, l2] = rgb_to_hsl(rgb(to));
+                // A gray has no hue, so it takes the hue of the other color.
+                if s1 == 0.0 {
+                    h1 = h2;
+                } else if s2 == 0.0 {
+                    h2 = h1;
+                }
+                if h2 - h1 > 180.0 {
+                    h1 += 360.0;
+                } else if h1 - h2 > 180.0 {
+                    h2 += 360.0;
+                }
+                let hue = lerp(h1, h2).rem_euclid(360.0);
+                let [_, s, l] = mix([0.0, s1, l1], [0.0, s2, l2]);
+                let (s, l) = (s.clamp(0.0, 1.0), l.clamp(0.0, 1.0));
+                return if alpha == 1.0 {
+                    Color::HSL(hue, s * 100.0, l * 100.0)
+                } else {
+                    Color::HSLA(hue, s * 100.0, l * 100.0, alpha)
+                };
+            }
+        };
+        Color::from_rgba([r, g, b, alpha])
+    }
+}
+
+fn srgb_to_linear(c: f64) -> f64 {
+    if c.abs() <= 0.04045 {
+        c / 12.92
+    } else {
+        c.signum() * ((c.abs() + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f64) -> f64 {
+    if c.abs() <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        c.signum() * (1.055 * c.abs().powf(1.0 / 2.4) - 0.055)
+    }
+}
+
+/// https://bottosson.github.io/posts/oklab/
+fn linear_to_oklab([r, g, b]: [f64; 3]) -> [f64; 3] {
+    let l = (0.412_221_470_8 * r + 0.536_332_536_3 * g + 0.051_445_992_9 * b).cbrt();
+    let m = (0.211_903_498_2 * r + 0.680_699_545_1 * g + 0.107_396_956_6 * b).cbrt();
+    let s = (0.088_302_461_9 * r + 0.281_718_837_6 * g + 0.629_978_700_5 * b).cbrt();
+    [
+        0.210_454_255_3 * l + 0.793_617_785 * m - 0.004_072_046_8 * s,
+        1.977_998_495_1 * l - 2.428_592_205 * m + 0.450_593_709_9 * s,
+        0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766 * s,
+    ]
+}
+
+fn oklab_to_linear([l, a, b]: [f64; 3]) -> [f64; 3] {
+    let l_ = (l + 0.396_337_777_4 * a + 0.215_803_757_3 * b).powi(3);
+    let m_ = (l - 0.105_561_345_8 * a - 0.063_854_172_8 * b).powi(3);
+    let s_ = (l - 0.089_484_177_5 * a - 1.291_485_548 * b).powi(3);
+    [
+        4.076_741_662_1 * l_ - 3.307_711_591_3 * m_ + 0.230_969_929_2 * s_,
+        -1.268_438_004_6 * l_ + 2.609_757_401_1 * m_ - 0.341_319_396_5 * s_,
+        -0.004_196_086_3 * l_ - 0.703_418_614_7 * m_ + 1.707_614_701 * s_,
+    ]
+}
+
+/// The hue in degrees, and the saturation and lightness between 0 and 1.
+fn rgb_to_hsl([r, g, b]: [f64; 3]) -> [f64; 3] {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let d = max - min;
+    if d == 0.0 {
+        return [0.0, 0.0, l];
+    }
+    let s = d / (1.0 - (2.0 * l - 1.0).abs());
+    let h = if max == r {
+        ((g - b) / d).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+    [h * 60.0, s, l]
+}
+
 fn hsl_to_rgb(h: f64, s: f64, l: f64) -> (f64, f64, f64) {
-    debug_assert!(h >= 0.0 && h < 360.0);
-    debug_assert!(s >= 0.0 && s <= 1.0);
-    debug_assert!(l >= 0.0 && l <= 1.0);
+    debug_assert!((0.0..360.0).contains(&h));
+    debug_assert!((0.0..=1.0).contains(&s));
+    debug_assert!((0.0..=1.0).contains(&l));
     let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
     let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
     let m = l - c * 0.5;
@@ -852,3 +1013,36 @@ fn test_color_convert() {
     let color = Color::HSL(0.0, 100.0, 50.0);
     assert_eq!(color.to_rgb(), Color::HexRGB(255, 0, 0));
 }
+
+#[test]
+fn test_color_interpolate() {
+    let red = Color::Red;
+    let blue = Color::Blue;
+    assert_eq!(
+        red.interpolate_in(blue, 0.5, ColorSpace::Srgb),
+        Color::HexRGB(128, 0, 128)
+    );
+    assert_eq!(
+        red.interpolate_in(blue, 0.5, ColorSpace::SrgbLinear),
+        Color::HexRGB(188, 0, 188)
+    );
+    assert_eq!(
+        red.interpolate_in(blue, 0.5, ColorSpace::Oklab),
+        Color::HexRGB(140, 83, 162)
+    );
+    // the short way round from red (0deg) to blue (240deg) is through magenta
+    assert_eq!(
+        red.interpolate_in(blue, 0.5, ColorSpace::Hsl),
+        Color::HSL(300.0, 100.0, 50.0)
+    );
+    // premultiplied, so a transparent color doesn't darken the other one
+    let transparent = Color::HexRGBA(0, 0, 0, 0);
+    assert_eq!(
+        transparent.interpolate_in(Color::White, 0.5, ColorSpace::Srgb),
+        Color::HexRGBA(255, 255, 255, 128)
+    );
+    assert_eq!(
+        Color::from_rgba(Color::Salmon.to_rgba()),
+        Color::HexRGB(250, 128, 114)
+    );
+}
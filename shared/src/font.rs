@@ -0,0 +1,781 @@
+//! Values of the font properties that were added after `font-family`, `font-size`, `font-style`
+//! and `font-weight`, from https://www.w3.org/TR/css-fonts-4/.
+use crate::{
+    FontFamily, FontSize, FontStyle, FontWeight, LineHeight, NonemptyCommaList, Percentage,
+};
+use std::fmt;
+
+/// The value of the `font` shorthand.
+///
+/// https://www.w3.org/TR/css-fonts-4/#font-prop
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum FontShorthand {
+    /// Use the font of a part of the operating system's user interface.
+    System(SystemFont),
+    /// Parts that are `None` (or `false`) were either missing or `normal`, and are reset to their
+    /// initial values.
+    Values {
+        style: Option<FontStyle>,
+        /// Only `normal` and `small-caps` can be given for `font-variant` in the shorthand.
+        small_caps: bool,
+        weight: Option<FontWeight>,
+        /// Only the keywords can be given for `font-stretch` in the shorthand.
+        stretch: Option<FontStretch>,
+        size: FontSize,
+        line_height: Option<LineHeight>,
+        family: FontFamily,
+    },
+}
+
+impl fmt::Display for FontShorthand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FontShorthand::System(v) => fmt::Display::fmt(v, f),
+            FontShorthand::Values {
+                style,
+                small_caps,
+                weight,
+                stretch,
+                size,
+                line_height,
+                family,
+            } => {
+                if let Some(style) = style {
+                    write!(f, "{} ", style)?;
+                }
+                if *small_caps {
+                    f.write_str("small-caps ")?;
+                }
+                if let Some(weight) = weight {
+                    write!(f, "{} ", weight)?;
+                }
+                if let Some(stretch) = stretch {
+                    write!(f, "{} ", stretch)?;
+                }
+                write!(f, "{}", size)?;
+                if let Some(line_height) = line_height {
+                    write!(f, "/{}", line_height)?;
+                }
+                write!(f, " {}", family)
+            }
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-fonts-4/#system-family-name-value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SystemFont {
+    Caption,
+    Icon,
+    Menu,
+    MessageBox,
+    SmallCaption,
+    StatusBar,
+}
+
+impl fmt::Display for SystemFont {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SystemFont::Caption => "caption",
+            SystemFont::Icon => "icon",
+            SystemFont::Menu => "menu",
+            SystemFont::MessageBox => "message-box",
+            SystemFont::SmallCaption => "small-caption",
+            SystemFont::StatusBar => "status-bar",
+        })
+    }
+}
+
+/// https://www.w3.org/TR/css-fonts-4/#font-stretch-prop
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FontStretch {
+    Normal,
+    UltraCondensed,
+    ExtraCondensed,
+    Condensed,
+    SemiCondensed,
+    SemiExpanded,
+    Expanded,
+    ExtraExpanded,
+    UltraExpanded,
+    /// Not negative.
+    Percentage(Percentage),
+}
+
+impl FontStretch {
+    /// The width as a percentage of the normal width, so `condensed` is `75%`.
+    pub fn percentage(&self) -> Percentage {
+        Percentage(match self {
+            FontStretch::Normal => 100.0,
+            FontStretch::UltraCondensed => 50.0,
+            FontStretch::ExtraCondensed => 62.5,
+            FontStretch::Condensed => 75.0,
+            FontStretch::SemiCondensed => 87.5,
+            FontStretch::SemiExpanded => 112.5,
+            FontStretch::Expanded => 125.0,
+            FontStretch::ExtraExpanded => 150.0,
+            FontStretch::UltraExpanded => 200.0,
+            FontStretch::Percentage(v) => return *v,
+        })
+    }
+}
+
+impl fmt::Display for FontStretch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FontStretch::Normal => f.write_str("normal"),
+            FontStretch::UltraCondensed => f.write_str("ultra-condensed"),
+            FontStretch::ExtraCondensed => f.write_str("extra-condensed"),
+            FontStretch::Condensed => f.write_str("condensed"),
+            FontStretch::SemiCondensed => f.write_str("semi-condensed"),
+            FontStretch::SemiExpanded => f.write_str("semi-expanded"),
+            FontStretch::Expanded => f.write_str("expanded"),
+            FontStretch::ExtraExpanded => f.write_str("extra-expanded"),
+            FontStretch::UltraExpanded => f.write_str("ultra-expanded"),
+            FontStretch::Percentage(v) => fmt::Display::fmt(v, f),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-fonts-4/#font-kerning-prop
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FontKerning {
+    Auto,
+    Normal,
+    None,
+}
+
+impl fmt::Display for FontKerning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            FontKerning::Auto => "auto",
+            FontKerning::Normal => "normal",
+            FontKerning::None => "none",
+        })
+    }
+}
+
+/// https://www.w3.org/TR/css-fonts-5/#font-size-adjust-prop
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FontSizeAdjust {
+    None,
+    Adjust {
+        metric: FontMetric,
+        /// The value of the metric divided by the font size. `None` means `from-font`.
+        value: Option<f64>,
+    },
+}
+
+impl fmt::Display for FontSizeAdjust {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FontSizeAdjust::None => f.write_str("none"),
+            FontSizeAdjust::Adjust { metric, value } => {
+                // `ex-height` is the default, so it is left out.
+                if *metric != FontMetric::ExHeight {
+                    write!(f, "{} ", metric)?;
+                }
+                match value {
+                    Some(value) => write!(f, "{}", value),
+                    None => f.write_str("from-font"),
+                }
+            }
+        }
+    }
+}
+
+/// The font metric that `font-size-adjust` keeps the same between fonts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FontMetric {
+    ExHeight,
+    CapHeight,
+    ChWidth,
+    IcWidth,
+    IcHeight,
+}
+
+impl fmt::Display for FontMetric {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            FontMetric::ExHeight => "ex-height",
+            FontMetric::CapHeight => "cap-height",
+            FontMetric::ChWidth => "ch-width",
+            FontMetric::IcWidth => "ic-width",
+            FontMetric::IcHeight => "ic-height",
+        })
+    }
+}
+
+/// Which typographic features the browser may synthesize if the font doesn't have them.
+///
+/// https://www.w3.org/TR/css-fonts-4/#font-synthesis
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontSynthesis {
+    pub weight: bool,
+    pub style: bool,
+    pub small_caps: bool,
+    pub position: bool,
+}
+
+impl FontSynthesis {
+    /// Allow the feature for the keyword `word`. Returns `false` if `word` isn't a keyword of
+    /// `font-synthesis`, or if the feature was already allowed.
+    pub(crate) fn add_keyword(&mut self, word: &str) -> bool {
+        let field = match word.to_ascii_lowercase().as_str() {
+            "weight" => &mut self.weight,
+            "style" => &mut self.style,
+            "small-caps" => &mut self.small_caps,
+            "position" => &mut self.position,
+            _ => return false,
+        };
+        set_flag(field)
+    }
+}
+
+impl fmt::Display for FontSynthesis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let words: Vec<&str> = [
+            (self.weight, "weight"),
+            (self.style, "style"),
+            (self.small_caps, "small-caps"),
+            (self.position, "position"),
+        ]
+        .iter()
+        .filter_map(|&(on, word)| on.then_some(word))
+        .collect();
+        if words.is_empty() {
+            f.write_str("none")
+        } else {
+            f.write_str(&words.join(" "))
+        }
+    }
+}
+
+/// The value of the `font-variant` shorthand. Each field is the value of one of its longhands.
+///
+/// The shorthand can only express `font-variant-ligatures: none` when all the other longhands
+/// are `normal`.
+///
+/// https://www.w3.org/TR/css-fonts-4/#font-variant-prop
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FontVariant {
+    pub ligatures: FontVariantLigatures,
+    pub caps: FontVariantCaps,
+    pub alternates: FontVariantAlternates,
+    pub numeric: FontVariantNumeric,
+    pub east_asian: FontVariantEastAsian,
+    pub position: FontVariantPosition,
+    pub emoji: FontVariantEmoji,
+}
+
+impl fmt::Display for FontVariant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = Vec::new();
+        if self.ligatures != FontVariantLigatures::Normal {
+            parts.push(self.ligatures.to_string());
+        }
+        if self.caps != FontVariantCaps::Normal {
+            parts.push(self.caps.to_string());
+        }
+        if self.alternates != FontVariantAlternates::default() {
+            parts.push(self.alternates.to_string());
+        }
+        if self.numeric != FontVariantNumeric::default() {
+            parts.push(self.numeric.to_string());
+        }
+        if self.east_asian != FontVariantEastAsian::default() {
+            parts.push(self.east_asian.to_string());
+        }
+        if self.position != FontVariantPosition::Normal {
+            parts.push(self.position.to_string());
+        }
+        if self.emoji != FontVariantEmoji::Normal {
+            parts.push(self.emoji.to_string());
+        }
+        if parts.is_empty() {
+            f.write_str("normal")
+        } else {
+            f.write_str(&parts.join(" "))
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-fonts-4/#font-variant-ligatures-prop
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FontVariantLigatures {
+    #[default]
+    Normal,
+    None,
+    /// For each kind of ligature, `Some(true)` enables it (e.g. `common-ligatures`), `Some(false)`
+    /// disables it (e.g. `no-common-ligatures`) and `None` leaves it to the font. At least one is
+    /// `Some`.
+    Values {
+        common: Option<bool>,
+        discretionary: Option<bool>,
+        historical: Option<bool>,
+        contextual: Option<bool>,
+    },
+}
+
+impl FontVariantLigatures {
+    /// Set the kind of ligature for the keyword `word`. Returns `false` if `word` isn't one of
+    /// the keywords, or if that kind of ligature was already set.
+    pub(crate) fn add_keyword(&mut self, word: &str) -> bool {
+        let mut values = match *self {
+            FontVariantLigatures::Normal => [None; 4],
+            FontVariantLigatures::None => return false,
+            FontVariantLigatures::Values {
+                common,
+                discretionary,
+                historical,
+                contextual,
+            } => [common, discretionary, historical, contextual],
+        };
+        let (idx, value) = match word.to_ascii_lowercase().as_str() {
+            "common-ligatures" => (0, true),
+            "no-common-ligatures" => (0, false),
+            "discretionary-ligatures" => (1, true),
+            "no-discretionary-ligatures" => (1, false),
+            "historical-ligatures" => (2, true),
+            "no-historical-ligatures" => (2, false),
+            "contextual" => (3, true),
+            "no-contextual" => (3, false),
+            _ => return false,
+        };
+        if !set_once(&mut values[idx], value) {
+            return false;
+        }
+        let [common, discretionary, historical, contextual] = values;
+        *self = FontVariantLigatures::Values {
+            common,
+            discretionary,
+            historical,
+            contextual,
+        };
+        true
+    }
+}
+
+impl fmt::Display for FontVariantLigatures {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FontVariantLigatures::Normal => f.write_str("normal"),
+            FontVariantLigatures::None => f.write_str("none"),
+            FontVariantLigatures::Values {
+                common,
+                discretionary,
+                historical,
+                contextual,
+            } => {
+                let words: Vec<String> = [
+                    (common, "common-ligatures"),
+                    (discretionary, "discretionary-ligatures"),
+                    (historical, "historical-ligatures"),
+                    (contextual, "contextual"),
+                ]
+                .iter()
+                .filter_map(|&(value, word)| match value {
+                    Some(true) => Some(word.to_string()),
+                    Some(false) => Some(format!("no-{}", word)),
+                    None => None,
+                })
+                .collect();
+                f.write_str(&words.join(" "))
+            }
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-fonts-4/#font-variant-caps-prop
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FontVariantCaps {
+    #[default]
+    Normal,
+    SmallCaps,
+    AllSmallCaps,
+    PetiteCaps,
+    AllPetiteCaps,
+    Unicase,
+    TitlingCaps,
+}
+
+impl fmt::Display for FontVariantCaps {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            FontVariantCaps::Normal => "normal",
+            FontVariantCaps::SmallCaps => "small-caps",
+            FontVariantCaps::AllSmallCaps => "all-small-caps",
+            FontVariantCaps::PetiteCaps => "petite-caps",
+            FontVariantCaps::AllPetiteCaps => "all-petite-caps",
+            FontVariantCaps::Unicase => "unicase",
+            FontVariantCaps::TitlingCaps => "titling-caps",
+        })
+    }
+}
+
+/// The alternate glyphs to use. The functions take the names of values defined in
+/// `@font-feature-values` rules. Everything missing or empty is `normal`.
+///
+/// https://www.w3.org/TR/css-fonts-4/#font-variant-alternates-prop
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FontVariantAlternates {
+    pub stylistic: Option<String>,
+    pub historical_forms: bool,
+    pub styleset: Vec<String>,
+    pub character_variant: Vec<String>,
+    pub swash: Option<String>,
+    pub ornaments: Option<String>,
+    pub annotation: Option<String>,
+}
+
+impl fmt::Display for FontVariantAlternates {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(name) = &self.stylistic {
+            parts.push(format!("stylistic({})", name));
+        }
+        if self.historical_forms {
+            parts.push("historical-forms".to_string());
+        }
+        if !self.styleset.is_empty() {
+            parts.push(format!("styleset({})", self.styleset.join(", ")));
+        }
+        if !self.character_variant.is_empty() {
+            parts.push(format!(
+                "character-variant({})",
+                self.character_variant.join(", ")
+            ));
+        }
+        if let Some(name) = &self.swash {
+            parts.push(format!("swash({})", name));
+        }
+        if let Some(name) = &self.ornaments {
+            parts.push(format!("ornaments({})", name));
+        }
+        if let Some(name) = &self.annotation {
+            parts.push(format!("annotation({})", name));
+        }
+        if parts.is_empty() {
+            f.write_str("normal")
+        } else {
+            f.write_str(&parts.join(" "))
+        }
+    }
+}
+
+/// Everything missing is `normal`.
+///
+/// https://www.w3.org/TR/css-fonts-4/#font-variant-numeric-prop
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FontVariantNumeric {
+    pub figure: Option<NumericFigure>,
+    pub spacing: Option<NumericSpacing>,
+    pub fraction: Option<NumericFraction>,
+    pub ordinal: bool,
+    pub slashed_zero: bool,
+}
+
+impl FontVariantNumeric {
+    /// Set the feature for the keyword `word`. Returns `false` if `word` isn't one of the
+    /// keywords, or if that feature was already set.
+    pub(crate) fn add_keyword(&mut self, word: &str) -> bool {
+        match word.to_ascii_lowercase().as_str() {
+            "lining-nums" => set_once(&mut self.figure, NumericFigure::Lining),
+            "oldstyle-nums" => set_once(&mut self.figure, NumericFigure::Oldstyle),
+            "proportional-nums" => set_once(&mut self.spacing, NumericSpacing::Proportional),
+            "tabular-nums" => set_once(&mut self.spacing, NumericSpacing::Tabular),
+            "diagonal-fractions" => set_once(&mut self.fraction, NumericFraction::Diagonal),
+            "stacked-fractions" => set_once(&mut self.fraction, NumericFraction::Stacked),
+            "ordinal" => set_flag(&mut self.ordinal),
+            "slashed-zero" => set_flag(&mut self.slashed_zero),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for FontVariantNumeric {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut words = Vec::new();
+        if let Some(figure) = self.figure {
+            words.push(match figure {
+                NumericFigure::Lining => "lining-nums",
+                NumericFigure::Oldstyle => "oldstyle-nums",
+            });
+        }
+        if let Some(spacing) = self.spacing {
+            words.push(match spacing {
+                NumericSpacing::Proportional => "proportional-nums",
+                NumericSpacing::Tabular => "tabular-nums",
+            });
+        }
+        if let Some(fraction) = self.fraction {
+            words.push(match fraction {
+                NumericFraction::Diagonal => "diagonal-fractions",
+                NumericFraction::Stacked => "stacked-fractions",
+            });
+        }
+        if self.ordinal {
+            words.push("ordinal");
+        }
+        if self.slashed_zero {
+            words.push("slashed-zero");
+        }
+        if words.is_empty() {
+            f.write_str("normal")
+        } else {
+            f.write_str(&words.join(" "))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumericFigure {
+    Lining,
+    Oldstyle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumericSpacing {
+    Proportional,
+    Tabular,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumericFraction {
+    Diagonal,
+    Stacked,
+}
+
+/// Everything missing is `normal`.
+///
+/// https://www.w3.org/TR/css-fonts-4/#font-variant-east-asian-prop
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FontVariantEastAsian {
+    pub variant: Option<EastAsianVariant>,
+    pub width: Option<EastAsianWidth>,
+    pub ruby: bool,
+}
+
+impl FontVariantEastAsian {
+    /// Set the feature for the keyword `word`. Returns `false` if `word` isn't one of the
+    /// keywords, or if that feature was already set.
+    pub(crate) fn add_keyword(&mut self, word: &str) -> bool {
+        let variant = match word.to_ascii_lowercase().as_str() {
+            "jis78" => EastAsianVariant::Jis78,
+            "jis83" => EastAsianVariant::Jis83,
+            "jis90" => EastAsianVariant::Jis90,
+            "jis04" => EastAsianVariant::Jis04,
+            "simplified" => EastAsianVariant::Simplified,
+            "traditional" => EastAsianVariant::Traditional,
+            "full-width" => return set_once(&mut self.width, EastAsianWidth::Full),
+            "proportional-width" => return set_once(&mut self.width, EastAsianWidth::Proportional),
+            "ruby" => return set_flag(&mut self.ruby),
+            _ => return false,
+        };
+        set_once(&mut self.variant, variant)
+    }
+}
+
+impl fmt::Display for FontVariantEastAsian {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut words = Vec::new();
+        if let Some(variant) = self.variant {
+            words.push(match variant {
+                EastAsianVariant::Jis78 => "jis78",
+                EastAsianVariant::Jis83 => "jis83",
+                EastAsianVariant::Jis90 => "jis90",
+                EastAsianVariant::Jis04 => "jis04",
+                EastAsianVariant::Simplified => "simplified",
+                EastAsianVariant::Traditional => "traditional",
+            });
+        }
+        if let Some(width) = self.width {
+            words.push(match width {
+                EastAsianWidth::Full => "full-width",
+                EastAsianWidth::Proportional => "proportional-width",
+            });
+        }
+        if self.ruby {
+            words.push("ruby");
+        }
+        if words.is_empty() {
+            f.write_str("normal")
+        } else {
+            f.write_str(&words.join(" "))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EastAsianVariant {
+    Jis78,
+    Jis83,
+    Jis90,
+    Jis04,
+    Simplified,
+    Traditional,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EastAsianWidth {
+    Full,
+    Proportional,
+}
+
+/// https://www.w3.org/TR/css-fonts-4/#font-variant-position-prop
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FontVariantPosition {
+    #[default]
+    Normal,
+    Sub,
+    Super,
+}
+
+impl fmt::Display for FontVariantPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            FontVariantPosition::Normal => "normal",
+            FontVariantPosition::Sub => "sub",
+            FontVariantPosition::Super => "super",
+        })
+    }
+}
+
+/// https://www.w3.org/TR/css-fonts-4/#font-variant-emoji-prop
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FontVariantEmoji {
+    #[default]
+    Normal,
+    Text,
+    Emoji,
+    Unicode,
+}
+
+impl fmt::Display for FontVariantEmoji {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            FontVariantEmoji::Normal => "normal",
+            FontVariantEmoji::Text => "text",
+            FontVariantEmoji::Emoji => "emoji",
+            FontVariantEmoji::Unicode => "unicode",
+        })
+    }
+}
+
+/// https://www.w3.org/TR/css-fonts-4/#font-feature-settings-prop
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontFeatureSettings {
+    Normal,
+    Features(NonemptyCommaList<FeatureTagValue>),
+}
+
+impl fmt::Display for FontFeatureSettings {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FontFeatureSettings::Normal => f.write_str("normal"),
+            FontFeatureSettings::Features(v) => fmt::Display::fmt(v, f),
+        }
+    }
+}
+
+/// An OpenType feature and its value, e.g. `"liga" 0`. `on` is `1` and `off` is `0`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeatureTagValue {
+    /// Four ASCII characters, see `is_tag`.
+    pub tag: String,
+    pub value: u32,
+}
+
+impl fmt::Display for FeatureTagValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\"{}\"", self.tag)?;
+        if self.value != 1 {
+            write!(f, " {}", self.value)?;
+        }
+        Ok(())
+    }
+}
+
+/// https://www.w3.org/TR/css-fonts-4/#font-variation-settings-def
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontVariationSettings {
+    Normal,
+    Axes(NonemptyCommaList<VariationAxis>),
+}
+
+impl fmt::Display for FontVariationSettings {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FontVariationSettings::Normal => f.write_str("normal"),
+            FontVariationSettings::Axes(v) => fmt::Display::fmt(v, f),
+        }
+    }
+}
+
+/// A variation axis of a variable font and its value, e.g. `"wght" 400`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariationAxis {
+    /// Four ASCII characters, see `is_tag`.
+    pub tag: String,
+    pub value: f64,
+}
+
+impl fmt::Display for VariationAxis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\"{}\" {}", self.tag, self.value)
+    }
+}
+
+/// Set `field` to `value` unless it is already set, returning whether it was set.
+fn set_once<T>(field: &mut Option<T>, value: T) -> bool {
+    if field.is_some() {
+        return false;
+    }
+    *field = Some(value);
+    true
+}
+
+/// Set `flag` unless it is already set, returning whether it was set.
+fn set_flag(flag: &mut bool) -> bool {
+    !std::mem::replace(flag, true)
+}
+
+/// Whether `tag` is a valid OpenType tag: exactly four characters between U+20 and U+7E.
+pub(crate) fn is_tag(tag: &str) -> bool {
+    tag.len() == 4 && tag.bytes().all(|b| (0x20..=0x7e).contains(&b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keywords() {
+        let mut ligatures = FontVariantLigatures::Normal;
+        assert!(ligatures.add_keyword("no-common-ligatures"));
+        assert!(ligatures.add_keyword("Contextual"));
+        assert!(!ligatures.add_keyword("common-ligatures"));
+        assert!(!ligatures.add_keyword("ruby"));
+        assert_eq!(ligatures.to_string(), "no-common-ligatures contextual");
+        assert!(!FontVariantLigatures::None.add_keyword("contextual"));
+
+        let mut numeric = FontVariantNumeric::default();
+        assert!(numeric.add_keyword("slashed-zero"));
+        assert!(numeric.add_keyword("tabular-nums"));
+        assert!(!numeric.add_keyword("proportional-nums"));
+        assert!(!numeric.add_keyword("slashed-zero"));
+        assert_eq!(numeric.to_string(), "tabular-nums slashed-zero");
+
+        let mut synthesis = FontSynthesis {
+            weight: false,
+            style: false,
+            small_caps: false,
+            position: false,
+        };
+        assert_eq!(synthesis.to_string(), "none");
+        assert!(synthesis.add_keyword("style"));
+        assert!(!synthesis.add_keyword("style"));
+        assert_eq!(synthesis.to_string(), "style");
+    }
+}
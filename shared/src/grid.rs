@@ -0,0 +1,752 @@
+//! Values of the grid layout properties, from https://www.w3.org/TR/css-grid-2/.
+use crate::Calc;
+use std::{collections::BTreeMap, fmt};
+
+/// The value of `grid-template-columns` and `grid-template-rows`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GridTemplateTracks {
+    None,
+    TrackList(TrackList),
+    /// `subgrid`, optionally followed by the names of the lines (like `subgrid [a] [b c]`).
+    Subgrid(Vec<SubgridLineNames>),
+}
+
+impl fmt::Display for GridTemplateTracks {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GridTemplateTracks::None => write!(f, "none"),
+            GridTemplateTracks::TrackList(v) => v.fmt(f),
+            GridTemplateTracks::Subgrid(names) => {
+                write!(f, "subgrid")?;
+                for names in names.iter() {
+                    write!(f, " {}", names)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The line names after `subgrid`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubgridLineNames {
+    Names(LineNames),
+    /// `repeat(<count>, <line-names>+)`. The count can be an integer or `auto-fill`.
+    Repeat(RepeatCount, Vec<LineNames>),
+}
+
+impl fmt::Display for SubgridLineNames {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubgridLineNames::Names(names) => names.fmt(f),
+            SubgridLineNames::Repeat(count, names) => {
+                write!(f, "repeat({},", count)?;
+                for names in names.iter() {
+                    write!(f, " {}", names)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// A list of track sizes and line names, like `[full-start] minmax(1em, 1fr) repeat(3, 10px)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackList(pub Vec<TrackListItem>);
+
+impl TrackList {
+    /// Check the rules that the grammar of track lists can't express:
+    ///
+    ///  - there must be at least one track,
+    ///  - line names must be separated by a track,
+    ///  - there can be at most one `repeat(auto-fill, ..)` or `repeat(auto-fit, ..)`, and if there
+    ///    is one then all the sizes in the list must be fixed (see `TrackSize::is_fixed`).
+    ///
+    /// `repeat()` can't be nested, and the tracks it repeats follow the same rules.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        let mut tracks = 0;
+        let mut auto_repeat = false;
+        let mut fixed = true;
+        let mut after_names = false;
+        for item in self.0.iter() {
+            match item {
+                TrackListItem::LineNames(_) if after_names => {
+                    return Err("line names must be separated by a track size")
+                }
+                TrackListItem::LineNames(_) => (),
+                TrackListItem::Size(size) => {
+                    tracks += 1;
+                    fixed &= size.is_fixed();
+                }
+                TrackListItem::Repeat(repeat) => {
+                    if repeat.tracks.validate().is_err()
+                        || repeat
+                            .tracks
+                            .0
+                            .iter()
+                            .any(|item| matches!(item, TrackListItem::Repeat(_)))
+                    {
+                        return Err("invalid tracks in `repeat()`");
+                    }
+                    if let RepeatCount::Count(0) = repeat.count {
+                        return Err("the number of repetitions must be at least 1");
+                    }
+                    if repeat.count.is_auto() {
+                        if auto_repeat {
+                            return Err("only one `repeat()` can use `auto-fill` or `auto-fit`");
+                        }
+                        auto_repeat = true;
+                    }
+                    tracks += 1;
+                    fixed &= repeat.tracks.0.iter().all(|item| match item {
+                        TrackListItem::Size(size) => size.is_fixed(),
+                        _ => true,
+                    });
+                }
+            }
+            after_names = matches!(item, TrackListItem::LineNames(_));
+        }
+        if tracks == 0 {
+            Err("expected at least one track size")
+        } else if auto_repeat && !fixed {
+            Err("all track sizes must be fixed when using `auto-fill` or `auto-fit`")
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Whether the list contains any `repeat()`.
+    pub fn has_repeat(&self) -> bool {
+        self.0
+            .iter()
+            .any(|item| matches!(item, TrackListItem::Repeat(_)))
+    }
+}
+
+impl fmt::Display for TrackList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_space_list(f, &self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrackListItem {
+    LineNames(LineNames),
+    Size(TrackSize),
+    Repeat(TrackRepeat),
+}
+
+impl fmt::Display for TrackListItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TrackListItem::LineNames(v) => v.fmt(f),
+            TrackListItem::Size(v) => v.fmt(f),
+            TrackListItem::Repeat(v) => v.fmt(f),
+        }
+    }
+}
+
+/// Names for a grid line, like `[main-start sidebar-end]`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LineNames(pub Vec<String>);
+
+impl fmt::Display for LineNames {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[")?;
+        write_space_list(f, &self.0)?;
+        write!(f, "]")
+    }
+}
+
+/// `repeat(<count>, <track-list>)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackRepeat {
+    pub count: RepeatCount,
+    pub tracks: TrackList,
+}
+
+impl fmt::Display for TrackRepeat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "repeat({}, {})", self.count, self.tracks)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RepeatCount {
+    Count(u32),
+    AutoFill,
+    AutoFit,
+}
+
+impl RepeatCount {
+    /// Whether the number of repetitions depends on the size of the container.
+    pub fn is_auto(self) -> bool {
+        matches!(self, RepeatCount::AutoFill | RepeatCount::AutoFit)
+    }
+}
+
+impl fmt::Display for RepeatCount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RepeatCount::Count(v) => write!(f, "{}", v),
+            RepeatCount::AutoFill => write!(f, "auto-fill"),
+            RepeatCount::AutoFit => write!(f, "auto-fit"),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-grid-2/#typedef-track-size
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrackSize {
+    Breadth(TrackBreadth),
+    /// `minmax(min, max)`. The minimum can't be flexible.
+    MinMax(TrackBreadth, TrackBreadth),
+    FitContent(Calc),
+}
+
+impl TrackSize {
+    /// Whether the size has a fixed minimum or maximum (it is a `<fixed-size>`).
+    pub fn is_fixed(&self) -> bool {
+        match self {
+            TrackSize::Breadth(breadth) => breadth.is_fixed(),
+            TrackSize::MinMax(min, max) => min.is_fixed() || max.is_fixed(),
+            TrackSize::FitContent(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for TrackSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TrackSize::Breadth(v) => v.fmt(f),
+            TrackSize::MinMax(min, max) => write!(f, "minmax({}, {})", min, max),
+            TrackSize::FitContent(v) => write!(f, "fit-content({})", v),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-grid-2/#typedef-track-breadth
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrackBreadth {
+    LengthPercentage(Calc),
+    /// A flexible size in `fr` units.
+    Flex(f64),
+    MinContent,
+    MaxContent,
+    Auto,
+}
+
+impl TrackBreadth {
+    /// Whether this is a length or percentage.
+    pub fn is_fixed(&self) -> bool {
+        matches!(self, TrackBreadth::LengthPercentage(_))
+    }
+}
+
+impl fmt::Display for TrackBreadth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TrackBreadth::LengthPercentage(v) => v.fmt(f),
+            TrackBreadth::Flex(v) => write!(f, "{}fr", v),
+            TrackBreadth::MinContent => write!(f, "min-content"),
+            TrackBreadth::MaxContent => write!(f, "max-content"),
+            TrackBreadth::Auto => write!(f, "auto"),
+        }
+    }
+}
+
+/// The value of `grid-template-areas`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GridTemplateAreas {
+    None,
+    Areas(GridAreas),
+}
+
+impl fmt::Display for GridTemplateAreas {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GridTemplateAreas::None => write!(f, "none"),
+            GridTemplateAreas::Areas(v) => v.fmt(f),
+        }
+    }
+}
+
+/// Named grid areas, written as one string per row, like `"head head" "nav main"`.
+///
+/// Each row must have the same number of columns, and each named area must be a rectangle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridAreas {
+    /// The cells of each row. `None` is an unnamed cell (written `.`).
+    rows: Vec<Vec<Option<String>>>,
+}
+
+impl GridAreas {
+    /// Create the areas from the strings for each row.
+    pub fn new<S: AsRef<str>>(rows: impl IntoIterator<Item = S>) -> Result<Self, String> {
+        let rows = rows
+            .into_iter()
+            .map(|row| area_cells(row.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let columns = match rows.first() {
+            Some(row) => row.len(),
+            None => return Err("expected at least one row".into()),
+        };
+        if columns == 0 {
+            return Err("expected at least one cell in each row".into());
+        }
+        if rows.iter().any(|row| row.len() != columns) {
+            return Err("each row must have the same number of cells".into());
+        }
+        let areas = GridAreas { rows };
+        for (name, area) in areas.areas() {
+            let rectangle = (area.row_start..area.row_end).all(|row| {
+                (area.column_start..area.column_end)
+                    .all(|column| areas.rows[row - 1][column - 1].as_deref() == Some(name))
+            });
+            if !rectangle {
+                return Err(format!("the grid area `{}` is not a rectangle", name));
+            }
+        }
+        Ok(areas)
+    }
+
+    /// The cells of each row. `None` is an unnamed cell.
+    pub fn rows(&self) -> &[Vec<Option<String>>] {
+        &self.rows
+    }
+
+    /// The number of columns.
+    pub fn columns(&self) -> usize {
+        self.rows[0].len()
+    }
+
+    /// The lines that each named area lies between.
+    pub fn areas(&self) -> BTreeMap<&str, GridAreaLines> {
+        let mut areas = BTreeMap::new();
+        for (row, cells) in self.rows.iter().enumerate() {
+            for (column, name) in cells.iter().enumerate() {
+                let name = match name {
+                    Some(name) => name.as_str(),
+                    None => continue,
+                };
+                let area = areas.entry(name).or_insert(GridAreaLines {
+                    row_start: row + 1,
+                    row_end: row + 2,
+                    column_start: column + 1,
+                    column_end: column + 2,
+                });
+                area.row_start = area.row_start.min(row + 1);
+                area.row_end = area.row_end.max(row + 2);
+                area.column_start = area.column_start.min(column + 1);
+                area.column_end = area.column_end.max(column + 2);
+            }
+        }
+        areas
+    }
+}
+
+impl fmt::Display for GridAreas {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, row) in self.rows.iter().enumerate() {
+            if idx > 0 {
+                write!(f, " ")?;
+            }
+            write_area_row(f, row)?;
+        }
+        Ok(())
+    }
+}
+
+/// The lines around a named grid area, numbered from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridAreaLines {
+    pub row_start: usize,
+    pub row_end: usize,
+    pub column_start: usize,
+    pub column_end: usize,
+}
+
+/// Split a row of `grid-template-areas` into cells.
+///
+/// https://www.w3.org/TR/css-grid-2/#valdef-grid-template-areas-string
+fn area_cells(row: &str) -> Result<Vec<Option<String>>, String> {
+    let is_name = |ch: char| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' || !ch.is_ascii();
+    let mut cells = Vec::new();
+    let mut chars = row.chars().peekable();
+    while let Some(&ch) = chars.peek() {
+        if ch.is_ascii_whitespace() {
+            chars.next();
+        } else if ch == '.' {
+            while chars.next_if_eq(&'.').is_some() {}
+            cells.push(None);
+        } else if is_name(ch) {
+            let mut name = String::new();
+            while let Some(ch) = chars.next_if(|&ch| is_name(ch)) {
+                name.push(ch);
+            }
+            cells.push(Some(name));
+        } else {
+            return Err(format!("unexpected `{}` in grid area string", ch));
+        }
+    }
+    Ok(cells)
+}
+
+fn write_area_row(f: &mut fmt::Formatter, row: &[Option<String>]) -> fmt::Result {
+    let cells = row
+        .iter()
+        .map(|cell| cell.as_deref().unwrap_or("."))
+        .collect::<Vec<_>>();
+    write!(f, "\"{}\"", cells.join(" "))
+}
+
+/// The value of `grid-auto-columns` and `grid-auto-rows`: one or more track sizes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridAutoTracks(pub Vec<TrackSize>);
+
+impl fmt::Display for GridAutoTracks {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_space_list(f, &self.0)
+    }
+}
+
+/// The value of `grid-auto-flow`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct GridAutoFlow {
+    /// Whether items are placed by filling each column in turn, rather than each row.
+    pub column: bool,
+    pub dense: bool,
+}
+
+impl fmt::Display for GridAutoFlow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.column, self.dense) {
+            (false, false) => write!(f, "row"),
+            (false, true) => write!(f, "dense"),
+            (true, false) => write!(f, "column"),
+            (true, true) => write!(f, "column dense"),
+        }
+    }
+}
+
+/// The value of the `grid-row-start`, `grid-column-end`, etc. properties.
+///
+/// https://www.w3.org/TR/css-grid-2/#typedef-grid-row-start-grid-line
+#[derive(Debug, Clone, PartialEq)]
+pub enum GridLine {
+    Auto,
+    /// A named area or line, like `header`.
+    Name(String),
+    /// The nth line (counting back from the end if negative), optionally only counting lines with
+    /// the given name. The number can't be 0.
+    Line(i32, Option<String>),
+    /// Span a number of tracks (at least 1), optionally only counting lines with the given name.
+    Span(u32, Option<String>),
+}
+
+impl fmt::Display for GridLine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GridLine::Auto => write!(f, "auto"),
+            GridLine::Name(name) => write!(f, "{}", name),
+            GridLine::Line(n, None) => write!(f, "{}", n),
+            GridLine::Line(n, Some(name)) => write!(f, "{} {}", n, name),
+            GridLine::Span(n, None) => write!(f, "span {}", n),
+            GridLine::Span(1, Some(name)) => write!(f, "span {}", name),
+            GridLine::Span(n, Some(name)) => write!(f, "span {} {}", n, name),
+        }
+    }
+}
+
+/// The value of `grid-row` and `grid-column`, like `1 / span 2`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridPlacement {
+    pub start: GridLine,
+    /// If missing, this is the same as `start` if it is a name, and `auto` otherwise.
+    pub end: Option<GridLine>,
+}
+
+impl fmt::Display for GridPlacement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.start.fmt(f)?;
+        if let Some(end) = &self.end {
+            write!(f, " / {}", end)?;
+        }
+        Ok(())
+    }
+}
+
+/// The value of `grid-area`: up to four lines separated by `/`, in the order `row-start`,
+/// `column-start`, `row-end`, `column-end`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridArea {
+    pub row_start: GridLine,
+    pub column_start: Option<GridLine>,
+    pub row_end: Option<GridLine>,
+    pub column_end: Option<GridLine>,
+}
+
+impl fmt::Display for GridArea {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.row_start.fmt(f)?;
+        for line in [&self.column_start, &self.row_end, &self.column_end]
+            .iter()
+            .filter_map(|line| line.as_ref())
+        {
+            write!(f, " / {}", line)?;
+        }
+        Ok(())
+    }
+}
+
+/// The value of the `grid-template` shorthand.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GridTemplate {
+    None,
+    /// `<rows> / <columns>`
+    RowsColumns(GridTemplateTracks, GridTemplateTracks),
+    /// Rows of named areas, each with optional line names and a size, optionally followed by
+    /// `/` and the columns, like `[top] "a a" 10px [bottom] / 1fr 1fr`.
+    Areas {
+        areas: GridAreas,
+        /// The line names and size of each row of `areas`.
+        rows: Vec<GridTemplateRow>,
+        /// The columns, which can't use `repeat()`.
+        columns: Option<TrackList>,
+    },
+}
+
+impl fmt::Display for GridTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GridTemplate::None => write!(f, "none"),
+            GridTemplate::RowsColumns(rows, columns) => write!(f, "{} / {}", rows, columns),
+            GridTemplate::Areas {
+                areas,
+                rows,
+                columns,
+            } => {
+                for (idx, (cells, row)) in areas.rows().iter().zip(rows.iter()).enumerate() {
+                    if idx > 0 {
+                        write!(f, " ")?;
+                    }
+                    if let Some(names) = &row.names_before {
+                        write!(f, "{} ", names)?;
+                    }
+                    write_area_row(f, cells)?;
+                    if let Some(size) = &row.size {
+                        write!(f, " {}", size)?;
+                    }
+                    if let Some(names) = &row.names_after {
+                        write!(f, " {}", names)?;
+                    }
+                }
+                if let Some(columns) = columns {
+                    write!(f, " / {}", columns)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The line names and size of a row in `grid-template`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GridTemplateRow {
+    pub names_before: Option<LineNames>,
+    /// If missing, the row is `auto`.
+    pub size: Option<TrackSize>,
+    pub names_after: Option<LineNames>,
+}
+
+/// The value of the `grid` shorthand.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Grid {
+    Template(GridTemplate),
+    /// `<rows> / auto-flow dense? <auto-columns>?`
+    AutoColumns {
+        rows: GridTemplateTracks,
+        dense: bool,
+        auto_columns: Option<GridAutoTracks>,
+    },
+    /// `auto-flow dense? <auto-rows>? / <columns>`
+    AutoRows {
+        dense: bool,
+        auto_rows: Option<GridAutoTracks>,
+        columns: GridTemplateTracks,
+    },
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn auto_flow(
+            f: &mut fmt::Formatter,
+            dense: bool,
+            tracks: &Option<GridAutoTracks>,
+        ) -> fmt::Result {
+            write!(f, "auto-flow")?;
+            if dense {
+                write!(f, " dense")?;
+            }
+            if let Some(tracks) = tracks {
+                write!(f, " {}", tracks)?;
+            }
+            Ok(())
+        }
+        match self {
+            Grid::Template(v) => v.fmt(f),
+            Grid::AutoColumns {
+                rows,
+                dense,
+                auto_columns,
+            } => {
+                write!(f, "{} / ", rows)?;
+                auto_flow(f, *dense, auto_columns)
+            }
+            Grid::AutoRows {
+                dense,
+                auto_rows,
+                columns,
+            } => {
+                auto_flow(f, *dense, auto_rows)?;
+                write!(f, " / {}", columns)
+            }
+        }
+    }
+}
+
+fn write_space_list<T: fmt::Display>(f: &mut fmt::Formatter, list: &[T]) -> fmt::Result {
+    for (idx, item) in list.iter().enumerate() {
+        if idx > 0 {
+            write!(f, " ")?;
+        }
+        item.fmt(f)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn areas() {
+        let areas = GridAreas::new(["head head", "nav  main", "... foot"]).unwrap();
+        assert_eq!(areas.to_string(), r#""head head" "nav main" ". foot""#);
+        assert_eq!(areas.columns(), 2);
+        assert_eq!(
+            areas.areas()["head"],
+            GridAreaLines {
+                row_start: 1,
+                row_end: 2,
+                column_start: 1,
+                column_end: 3,
+            }
+        );
+        assert_eq!(areas.areas().len(), 4);
+        let areas = GridAreas::new(["a a b", "a a b"]).unwrap();
+        assert_eq!(areas.areas()["a"].row_end, 3);
+        assert_eq!(areas.areas()["b"].column_start, 3);
+
+        for (rows, error) in [
+            (
+                &["a b", "a"][..],
+                "each row must have the same number of cells",
+            ),
+            (&["a b a"], "the grid area `a` is not a rectangle"),
+            (&["a b", "b b"], "the grid area `b` is not a rectangle"),
+            (&["a", "b", "a"], "the grid area `a` is not a rectangle"),
+            (&["a ! b"], "unexpected `!` in grid area string"),
+            (&["  "], "expected at least one cell in each row"),
+            (&[], "expected at least one row"),
+        ] {
+            assert_eq!(GridAreas::new(rows).unwrap_err(), error, "rows: {:?}", rows);
+        }
+    }
+
+    #[test]
+    fn track_list() {
+        use TrackBreadth::*;
+        let px = |v| Calc::Normal(crate::LengthPercentage::Length(crate::Length::Px(v)));
+        let size = |breadth| TrackListItem::Size(TrackSize::Breadth(breadth));
+        let names = |names: &[&str]| {
+            TrackListItem::LineNames(LineNames(names.iter().map(|s| s.to_string()).collect()))
+        };
+        let repeat = |count, tracks| {
+            TrackListItem::Repeat(TrackRepeat {
+                count,
+                tracks: TrackList(tracks),
+            })
+        };
+
+        let list = TrackList(vec![
+            names(&["a", "b"]),
+            size(LengthPercentage(px(10.0))),
+            repeat(RepeatCount::Count(2), vec![size(Flex(1.5)), names(&[])]),
+        ]);
+        assert!(list.validate().is_ok());
+        assert_eq!(list.to_string(), "[a b] 10px repeat(2, 1.5fr [])");
+
+        for (list, error) in [
+            (vec![names(&["a"])], "expected at least one track size"),
+            (
+                vec![names(&["a"]), names(&["b"]), size(Auto)],
+                "line names must be separated by a track size",
+            ),
+            (
+                vec![repeat(RepeatCount::Count(0), vec![size(Auto)])],
+                "the number of repetitions must be at least 1",
+            ),
+            (
+                vec![repeat(RepeatCount::Count(2), vec![])],
+                "invalid tracks in `repeat()`",
+            ),
+            (
+                vec![
+                    repeat(RepeatCount::AutoFill, vec![size(LengthPercentage(px(1.0)))]),
+                    repeat(RepeatCount::AutoFit, vec![size(LengthPercentage(px(1.0)))]),
+                ],
+                "only one `repeat()` can use `auto-fill` or `auto-fit`",
+            ),
+            (
+                vec![
+                    size(Flex(1.0)),
+                    repeat(RepeatCount::AutoFill, vec![size(LengthPercentage(px(1.0)))]),
+                ],
+                "all track sizes must be fixed when using `auto-fill` or `auto-fit`",
+            ),
+        ] {
+            assert_eq!(TrackList(list).validate(), Err(error));
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            GridLine::Span(1, Some("a".into())).to_string(),
+            "span a".to_string()
+        );
+        assert_eq!(
+            GridArea {
+                row_start: GridLine::Line(-1, None),
+                column_start: Some(GridLine::Auto),
+                row_end: Some(GridLine::Span(2, None)),
+                column_end: None,
+            }
+            .to_string(),
+            "-1 / auto / span 2"
+        );
+        for (flow, output) in [
+            (GridAutoFlow::default(), "row"),
+            (
+                GridAutoFlow {
+                    column: true,
+                    dense: true,
+                },
+                "column dense",
+            ),
+        ] {
+            assert_eq!(flow.to_string(), output);
+        }
+    }
+}
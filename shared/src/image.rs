@@ -0,0 +1,412 @@
+//! Images that are generated or referenced in css, from https://www.w3.org/TR/css-images-4/.
+use crate::{
+    Angle, Calc, Color, Length, LengthPercentage, NonemptyCommaList, Percentage, PositionValue, Url,
+};
+use std::fmt;
+
+/// https://www.w3.org/TR/css-images-4/#image-values
+#[derive(Debug, Clone, PartialEq)]
+pub enum Image {
+    Url(Url),
+    /// Boxed because gradients are much larger than the other images.
+    Gradient(Box<Gradient>),
+    ImageSet(ImageSet),
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Image::Url(v) => fmt::Display::fmt(v, f),
+            Image::Gradient(v) => fmt::Display::fmt(v, f),
+            Image::ImageSet(v) => fmt::Display::fmt(v, f),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-images-4/#gradients
+#[derive(Debug, Clone, PartialEq)]
+pub enum Gradient {
+    Linear(LinearGradient),
+    Radial(RadialGradient),
+    Conic(ConicGradient),
+}
+
+impl fmt::Display for Gradient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Gradient::Linear(v) => fmt::Display::fmt(v, f),
+            Gradient::Radial(v) => fmt::Display::fmt(v, f),
+            Gradient::Conic(v) => fmt::Display::fmt(v, f),
+        }
+    }
+}
+
+/// `linear-gradient()` or `repeating-linear-gradient()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearGradient {
+    pub repeating: bool,
+    /// `None` is `to bottom`.
+    pub direction: Option<LineDirection>,
+    pub stops: ColorStopList<Calc>,
+}
+
+impl fmt::Display for LinearGradient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.repeating {
+            f.write_str("repeating-")?;
+        }
+        f.write_str("linear-gradient(")?;
+        if let Some(direction) = &self.direction {
+            write!(f, "{}, ", direction)?;
+        }
+        write!(f, "{})", self.stops)
+    }
+}
+
+/// The direction of a linear gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineDirection {
+    Angle(Angle),
+    /// `to` a side or corner. At least one of the sides is `Some`.
+    To {
+        horizontal: Option<HorizontalSide>,
+        vertical: Option<VerticalSide>,
+    },
+}
+
+impl fmt::Display for LineDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LineDirection::Angle(v) => fmt::Display::fmt(v, f),
+            LineDirection::To {
+                horizontal,
+                vertical,
+            } => {
+                f.write_str("to")?;
+                if let Some(horizontal) = horizontal {
+                    write!(f, " {}", horizontal)?;
+                }
+                if let Some(vertical) = vertical {
+                    write!(f, " {}", vertical)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HorizontalSide {
+    Left,
+    Right,
+}
+
+impl fmt::Display for HorizontalSide {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            HorizontalSide::Left => "left",
+            HorizontalSide::Right => "right",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VerticalSide {
+    Top,
+    Bottom,
+}
+
+impl fmt::Display for VerticalSide {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            VerticalSide::Top => "top",
+            VerticalSide::Bottom => "bottom",
+        })
+    }
+}
+
+/// `radial-gradient()` or `repeating-radial-gradient()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadialGradient {
+    pub repeating: bool,
+    /// `None` is a circle if the size is a single length, and an ellipse otherwise.
+    pub shape: Option<EndingShape>,
+    /// `None` is `farthest-corner`.
+    pub size: Option<RadialSize>,
+    /// `None` is the center of the box.
+    pub position: Option<PositionValue>,
+    pub stops: ColorStopList<Calc>,
+}
+
+impl fmt::Display for RadialGradient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.repeating {
+            f.write_str("repeating-")?;
+        }
+        f.write_str("radial-gradient(")?;
+        let mut parts = Vec::new();
+        if let Some(shape) = self.shape {
+            parts.push(shape.to_string());
+        }
+        if let Some(size) = &self.size {
+            parts.push(size.to_string());
+        }
+        if let Some(position) = &self.position {
+            parts.push(format!("at {}", position));
+        }
+        if !parts.is_empty() {
+            write!(f, "{}, ", parts.join(" "))?;
+        }
+        write!(f, "{})", self.stops)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EndingShape {
+    Circle,
+    Ellipse,
+}
+
+impl fmt::Display for EndingShape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            EndingShape::Circle => "circle",
+            EndingShape::Ellipse => "ellipse",
+        })
+    }
+}
+
+/// The size of the ending shape of a radial gradient.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RadialSize {
+    ClosestSide,
+    ClosestCorner,
+    FarthestSide,
+    FarthestCorner,
+    /// The radius of a circle. Not negative.
+    Length(Length),
+    /// The horizontal and vertical radii of an ellipse. Not negative.
+    Ellipse(Calc, Calc),
+}
+
+impl fmt::Display for RadialSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RadialSize::ClosestSide => f.write_str("closest-side"),
+            RadialSize::ClosestCorner => f.write_str("closest-corner"),
+            RadialSize::FarthestSide => f.write_str("farthest-side"),
+            RadialSize::FarthestCorner => f.write_str("farthest-corner"),
+            RadialSize::Length(v) => fmt::Display::fmt(v, f),
+            RadialSize::Ellipse(x, y) => write!(f, "{} {}", x, y),
+        }
+    }
+}
+
+impl RadialSize {
+    /// The size from one or two lengths, checking that they agree with the `shape`.
+    pub(crate) fn from_lengths(
+        shape: Option<EndingShape>,
+        first: Calc,
+        second: Option<Calc>,
+    ) -> Result<RadialSize, &'static str> {
+        let negative = |value: &Calc| match value {
+            Calc::Normal(LengthPercentage::Length(length)) => length.is_negative(),
+            Calc::Normal(LengthPercentage::Percentage(percentage)) => percentage.0 < 0.0,
+            Calc::Calculated(_) => false,
+        };
+        if negative(&first) || second.as_ref().is_some_and(negative) {
+            return Err("the size of a radial gradient can't be negative");
+        }
+        match (shape, first, second) {
+            (Some(EndingShape::Circle), _, Some(_)) => Err("a circle must have a single radius"),
+            (Some(EndingShape::Ellipse), _, None) => Err("an ellipse must have two radii"),
+            (_, x, Some(y)) => Ok(RadialSize::Ellipse(x, y)),
+            (_, Calc::Normal(LengthPercentage::Length(length)), None) => {
+                Ok(RadialSize::Length(length))
+            }
+            (_, _, None) => Err("the radius of a circle must be a length"),
+        }
+    }
+}
+
+/// `conic-gradient()` or `repeating-conic-gradient()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConicGradient {
+    pub repeating: bool,
+    /// The angle the gradient starts at, where `None` is `0deg` (straight up).
+    pub from: Option<Angle>,
+    /// `None` is the center of the box.
+    pub position: Option<PositionValue>,
+    pub stops: ColorStopList<AnglePercentage>,
+}
+
+impl fmt::Display for ConicGradient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.repeating {
+            f.write_str("repeating-")?;
+        }
+        f.write_str("conic-gradient(")?;
+        let mut parts = Vec::new();
+        if let Some(from) = &self.from {
+            parts.push(format!("from {}", from));
+        }
+        if let Some(position) = &self.position {
+            parts.push(format!("at {}", position));
+        }
+        if !parts.is_empty() {
+            write!(f, "{}, ", parts.join(" "))?;
+        }
+        write!(f, "{})", self.stops)
+    }
+}
+
+/// An angle, or a percentage of a full turn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnglePercentage {
+    Angle(Angle),
+    Percentage(Percentage),
+}
+
+impl fmt::Display for AnglePercentage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnglePercentage::Angle(v) => fmt::Display::fmt(v, f),
+            AnglePercentage::Percentage(v) => fmt::Display::fmt(v, f),
+        }
+    }
+}
+
+/// The color stops and hints of a gradient, where `P` is the type of the positions.
+///
+/// There are at least two stops. The first and last items are stops, and there is never more
+/// than one hint in a row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorStopList<P>(pub Vec<GradientItem<P>>);
+
+impl<P> ColorStopList<P> {
+    /// Whether `items` are a valid list of color stops and hints, as described on the type.
+    pub(crate) fn is_valid(items: &[GradientItem<P>]) -> bool {
+        let stops = items
+            .iter()
+            .filter(|item| matches!(item, GradientItem::Stop { .. }))
+            .count();
+        stops >= 2
+            && matches!(items.first(), Some(GradientItem::Stop { .. }))
+            && matches!(items.last(), Some(GradientItem::Stop { .. }))
+            && !items
+                .windows(2)
+                .any(|pair| matches!(pair, [GradientItem::Hint(_), GradientItem::Hint(_)]))
+    }
+}
+
+impl<P: fmt::Display> fmt::Display for ColorStopList<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, item) in self.0.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(", ")?;
+            }
+            fmt::Display::fmt(item, f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A color stop, or a hint for where the color is halfway between the stops either side.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GradientItem<P> {
+    /// A color that starts at `start` and, if there is an `end`, stays the same until `end`.
+    Stop {
+        color: Color,
+        start: Option<P>,
+        end: Option<P>,
+    },
+    Hint(P),
+}
+
+impl<P: fmt::Display> fmt::Display for GradientItem<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GradientItem::Stop { color, start, end } => {
+                write!(f, "{}", color)?;
+                if let Some(start) = start {
+                    write!(f, " {}", start)?;
+                }
+                if let Some(end) = end {
+                    write!(f, " {}", end)?;
+                }
+                Ok(())
+            }
+            GradientItem::Hint(v) => fmt::Display::fmt(v, f),
+        }
+    }
+}
+
+/// `image-set()`, which lets the browser choose the most appropriate image.
+///
+/// https://www.w3.org/TR/css-images-4/#image-set-notation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageSet(pub NonemptyCommaList<ImageSetOption>);
+
+impl fmt::Display for ImageSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "image-set({}", self.0.first)?;
+        for option in self.0.rest.iter() {
+            write!(f, ", {}", option)?;
+        }
+        f.write_str(")")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageSetOption {
+    /// A plain string is stored as a `url()` without modifiers. This is never an `image-set()`.
+    pub image: Box<Image>,
+    /// `None` is `1x`.
+    pub resolution: Option<Resolution>,
+    /// The MIME type from `type()`, so the browser can skip images it doesn't support.
+    pub mime_type: Option<String>,
+}
+
+impl fmt::Display for ImageSetOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.image)?;
+        if let Some(resolution) = &self.resolution {
+            write!(f, " {}", resolution)?;
+        }
+        if let Some(mime_type) = &self.mime_type {
+            write!(f, " type(\"{}\")", mime_type)?;
+        }
+        Ok(())
+    }
+}
+
+/// https://www.w3.org/TR/css-values-4/#resolution
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Resolution {
+    /// Dots per pixel, written with the `x` unit.
+    X(f64),
+    Dppx(f64),
+    Dpi(f64),
+    Dpcm(f64),
+}
+
+impl Resolution {
+    pub fn to_dppx(self) -> f64 {
+        match self {
+            Resolution::X(v) | Resolution::Dppx(v) => v,
+            Resolution::Dpi(v) => v / 96.0,
+            Resolution::Dpcm(v) => v * 2.54 / 96.0,
+        }
+    }
+}
+
+impl fmt::Display for Resolution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Resolution::X(v) => write!(f, "{}x", v),
+            Resolution::Dppx(v) => write!(f, "{}dppx", v),
+            Resolution::Dpi(v) => write!(f, "{}dpi", v),
+            Resolution::Dpcm(v) => write!(f, "{}dpcm", v),
+        }
+    }
+}
@@ -0,0 +1,300 @@
+//! Running css animations: the progress of an animation at a point in time, and the styles of its
+//! keyframes at that progress.
+//!
+//! https://www.w3.org/TR/css-animations-1/ and https://www.w3.org/TR/web-animations-1/#timing-model
+use crate::*;
+
+impl SingleAnimation {
+    /// The progress through the keyframes, `time` seconds after the animation was applied, or
+    /// `None` if the animation doesn't have an effect at that time.
+    ///
+    /// The progress is `0` at the first keyframe and `1` at the last, and takes account of the
+    /// delay, iteration count, direction and fill mode. Missing parts take their initial values.
+    pub fn progress(&self, time: f64) -> Option<f64> {
+        let duration = self.duration.map(Time::to_seconds).unwrap_or(0.0).max(0.0);
+        let delay = self.delay.map(Time::to_seconds).unwrap_or(0.0);
+        let iterations = match self.iteration_count {
+            Some(AnimationIterationCount::Count(count)) => count,
+            Some(AnimationIterationCount::Infinite) => f64::INFINITY,
+            None => 1.0,
+        };
+        let fill_mode = self.fill_mode.unwrap_or(AnimationFillMode::None);
+        let active_duration = if duration == 0.0 {
+            0.0
+        } else {
+            duration * iterations
+        };
+
+        let local_time = time - delay;
+        let (progress, iteration) = if local_time < 0.0 {
+            if !matches!(
+                fill_mode,
+                AnimationFillMode::Backwards | AnimationFillMode::Both
+            ) {
+                return None;
+            }
+            (0.0, 0.0)
+        } else if local_time >= active_duration {
+            if !matches!(
+                fill_mode,
+                AnimationFillMode::Forwards | AnimationFillMode::Both
+            ) {
+                return None;
+            }
+            if iterations.is_infinite() {
+                // Only possible when the duration is 0.
+                (1.0, 0.0)
+            } else if iterations > 0.0 && iterations.fract() == 0.0 {
+                // Finishing on an iteration boundary holds the end of the last iteration, rather
+                // than jumping back to the start of the next one.
+                (1.0, iterations - 1.0)
+            } else {
+                (iterations.fract(), iterations.floor())
+            }
+        } else {
+            let overall = local_time / duration;
+            (overall.fract(), overall.floor())
+        };
+
+        let reversed = match self.direction.unwrap_or(AnimationDirection::Normal) {
+            AnimationDirection::Normal => false,
+            AnimationDirection::Reverse => true,
+            AnimationDirection::Alternate => iteration % 2.0 == 1.0,
+            AnimationDirection::AlternateReverse => iteration % 2.0 == 0.0,
+        };
+        Some(if reversed { 1.0 - progress } else { progress })
+    }
+
+    /// The styles that the animation applies `time` seconds after it was applied, or `None` if it
+    /// doesn't have an effect at that time.
+    ///
+    /// `underlying` is used for properties that are missing from the first or last keyframe. See
+    /// `sample_keyframes` for how the styles are interpolated.
+    pub fn sample(&self, keyframes: &[Keyframe], underlying: &Styles, time: f64) -> Option<Styles> {
+        let progress = self.progress(time)?;
+        let easing = self.timing_function.clone().unwrap_or(EasingFunction::Ease);
+        Some(sample_keyframes(keyframes, underlying, progress, &easing))
+    }
+}
+
+/// The styles of `keyframes` at `progress`, where `0` is the first keyframe and `1` is the last.
+///
+/// Each property is interpolated between the keyframes either side of `progress` that contain
+/// it, using the `animation-timing-function` of the earlier keyframe, or `easing` if it doesn't
+/// have one. When the `0%` or `100%` keyframe is missing a property, its value is taken from
+/// `underlying`.
+///
+/// Values are interpolated with `Animate`, so values that can't be interpolated switch from one
+/// keyframe's value to the next halfway between them. `!important` declarations and the
+/// `animation-*` properties are ignored.
+pub fn sample_keyframes(
+    keyframes: &[Keyframe],
+    underlying: &Styles,
+    progress: f64,
+    easing: &EasingFunction,
+) -> Styles {
+    let mut frames: Vec<(f64, &Keyframe)> = keyframes
+        .iter()
+        .flat_map(|keyframe| {
+            keyframe
+                .selectors
+                .iter()
+                .map(move |selector| (selector.0 / 100.0, keyframe))
+        })
+        .collect();
+    // Stable, so later keyframes with the same offset still come later.
+    frames.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut names: Vec<&str> = Vec::new();
+    for (_, keyframe) in frames.iter() {
+        for name in keyframe.styles.iter().filter_map(animatable_name) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+
+    let mut out = Styles::new();
+    for name in names {
+        // The offset, value and easing of each keyframe with the property. Later keyframes at
+        // the same offset replace earlier ones.
+        let mut points: Vec<(f64, &Style, &EasingFunction)> = Vec::new();
+        for (offset, keyframe) in frames.iter() {
+            let style = match find(&keyframe.styles, name) {
+                Some(style) => style,
+                None => continue,
+            };
+            let point = (*offset, style, keyframe_easing(keyframe).unwrap_or(easing));
+            match points.last_mut() {
+                Some(last) if last.0 == *offset => *last = point,
+                _ => points.push(point),
+            }
+        }
+        if let Some(style) = find(underlying, name) {
+            if points[0].0 > 0.0 {
+                points.insert(0, (0.0, style, easing));
+            }
+            if points[points.len() - 1].0 < 1.0 {
+                points.push((1.0, style, easing));
+            }
+        }
+
+        if points.len() == 1 {
+            out.add(points[0].1.clone());
+            continue;
+        }
+        // Use the first or last pair when `progress` is outside the keyframes.
+        let idx = points
+            .iter()
+            .rposition(|point| point.0 <= progress)
+            .unwrap_or(0)
+            .min(points.len() - 2);
+        let (from, to) = (points[idx], points[idx + 1]);
+        let local = if from.0 == to.0 {
+            1.0
+        } else {
+            (progress - from.0) / (to.0 - from.0)
+        };
+        out.add(from.1.interpolate(to.1, from.2.evaluate(local)));
+    }
+    out
+}
+
+/// The name of `style` if it can be animated by keyframes.
+fn animatable_name(style: &Style) -> Option<&str> {
+    if style.is_important() {
+        return None;
+    }
+    style.name().filter(|name| !name.starts_with("animation"))
+}
+
+/// The last normal declaration of the property `name` in `styles`.
+fn find<'a>(styles: &'a Styles, name: &str) -> Option<&'a Style> {
+    styles
+        .iter()
+        .rev()
+        .find(|style| animatable_name(style) == Some(name))
+}
+
+/// The easing set by the `animation-timing-function` of a keyframe, if it has one.
+fn keyframe_easing(keyframe: &Keyframe) -> Option<&EasingFunction> {
+    keyframe.styles.iter().rev().find_map(|style| match style {
+        Style::AnimationTimingFunction(list) => Some(&list.first),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn animation(css: &str) -> SingleAnimation {
+        css.parse().unwrap()
+    }
+
+    fn keyframes(css: &str) -> Vec<Keyframe> {
+        let sheet: Stylesheet = css.parse().unwrap();
+        sheet.keyframes("a").unwrap().to_vec()
+    }
+
+    #[test]
+    fn progress() {
+        let a = animation("2s 1s 3 alternate");
+        assert_eq!(a.progress(0.5), None);
+        assert_eq!(a.progress(1.5), Some(0.25));
+        assert_eq!(a.progress(3.5), Some(0.75));
+        assert_eq!(a.progress(5.5), Some(0.25));
+        assert_eq!(a.progress(7.5), None);
+
+        let a = animation("2s 1s 1.5 reverse both");
+        assert_eq!(a.progress(0.0), Some(1.0));
+        assert_eq!(a.progress(10.0), Some(0.5));
+        // ending on an iteration boundary holds the last frame
+        let a = animation("1s 2 alternate forwards");
+        assert_eq!(a.progress(5.0), Some(0.0));
+        let a = animation("1s 2 forwards");
+        assert_eq!(a.progress(5.0), Some(1.0));
+        assert_eq!(animation("0s forwards").progress(0.0), Some(1.0));
+        assert_eq!(animation("1s infinite").progress(1000.25), Some(0.25));
+    }
+
+    #[test]
+    fn sample() {
+        let frames = keyframes(
+            "@keyframes a {
+                from { display: inline; height: 0 }
+                50% { display: block; animation-timing-function: steps(2, start) }
+                to { display: flex; height: 10px }
+            }",
+        );
+        let styles = |css: &str| css.parse::<Styles>().unwrap();
+        let underlying = styles("display: flex; color: red");
+        let linear = EasingFunction::Linear;
+        assert_eq!(
+            sample_keyframes(&frames, &underlying, 0.2, &linear),
+            styles("display: inline; height: 2px")
+        );
+        assert_eq!(
+            sample_keyframes(&frames, &underlying, 0.3, &linear),
+            styles("display: block; height: 3px")
+        );
+        // `steps(2, start)` jumps straight to halfway from 50%
+        assert_eq!(
+            sample_keyframes(&frames, &underlying, 0.55, &linear),
+            styles("display: flex; height: 5.5px")
+        );
+
+        // properties missing from the first and last keyframe come from the underlying styles
+        let frames = keyframes("@keyframes a { 40% { display: block } }");
+        assert_eq!(
+            sample_keyframes(&frames, &underlying, 0.1, &linear),
+            styles("display: flex")
+        );
+        assert_eq!(
+            sample_keyframes(&frames, &underlying, 0.6, &linear),
+            styles("display: block")
+        );
+        assert_eq!(
+            sample_keyframes(&frames, &Styles::new(), 1.0, &linear),
+            styles("display: block")
+        );
+
+        let a = animation("1s linear a");
+        assert_eq!(a.sample(&frames, &underlying, 2.0), None);
+        assert_eq!(
+            a.sample(&frames, &underlying, 0.75),
+            Some(styles("display: flex"))
+        );
+
+        // a NaN offset can't be parsed, but `Keyframe` can be built directly
+        let mut frames = keyframes("@keyframes a { from { height: 0 } to { height: 10px } }");
+        frames.push(Keyframe {
+            selectors: vec![Percentage(f64::NAN)],
+            styles: styles("height: 5px"),
+        });
+        assert_eq!(
+            sample_keyframes(&frames, &underlying, 0.2, &linear),
+            styles("height: 2px")
+        );
+    }
+
+    #[test]
+    fn sample_transform() {
+        let styles = |css: &str| css.parse::<Styles>().unwrap();
+        let linear = EasingFunction::Linear;
+        let frames = keyframes(
+            "@keyframes a { from { transform: rotate(0) } to { transform: rotate(360deg) } }",
+        );
+        assert_eq!(
+            sample_keyframes(&frames, &Styles::new(), 0.25, &linear),
+            styles("transform: rotate(90deg)")
+        );
+        let frames = keyframes(
+            "@keyframes a { from { transform: translateX(0) } to { transform: translateX(100%) } }",
+        );
+        assert_eq!(
+            sample_keyframes(&frames, &Styles::new(), 0.25, &linear),
+            styles("transform: translate(25%, 0)")
+        );
+    }
+}
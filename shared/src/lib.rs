@@ -1,11 +1,28 @@
 //! A module to type styles.
+#![cfg_attr(test, allow(clippy::useless_vec))]
 // TODO most stuff here is on the stack, but there are a few heap-allocs here and there. It would
 // be good if we could just to allocate them in the bump arena when using bumpalo.
+mod animate;
+mod animation;
+mod border;
 mod calc;
+mod cascade;
 mod codegen;
 mod color;
+mod font;
+mod grid;
+mod image;
+mod keyframes;
+mod matching;
+mod matrix;
+mod position;
+mod selector;
 pub mod string;
+mod stylesheet;
 mod syn_parse;
+mod text;
+mod transform;
+mod writing;
 
 use std::{
     fmt,
@@ -13,8 +30,25 @@ use std::{
 };
 
 pub use crate::{
+    animate::{discrete, Animate},
+    animation::*,
+    border::*,
     calc::*,
-    color::{Color, DynamicColor},
+    cascade::{Cascade, Declaration, Origin},
+    color::{Color, ColorSpace, DynamicColor},
+    font::*,
+    grid::*,
+    image::*,
+    keyframes::sample_keyframes,
+    matching::{Element, Namespaces},
+    matrix::{DecomposedMatrix, TransformMatrix},
+    position::*,
+    selector::*,
+    string::parse::ParseError,
+    stylesheet::*,
+    text::*,
+    transform::*,
+    writing::*,
 };
 
 pub struct DynamicStyles {
@@ -41,7 +75,7 @@ impl fmt::Display for DynamicStyles {
 }
 
 // TODO make container generic over heap (e.g. support bumpalo)
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Styles {
     pub rules: Vec<Style>,
 }
@@ -56,7 +90,19 @@ impl Styles {
     }
 
     pub fn merge(&mut self, other: Styles) {
-        self.rules.extend(other.rules.into_iter())
+        self.rules.extend(other.rules)
+    }
+
+    /// The styles with every shorthand that can be expanded replaced by its longhands.
+    pub fn expand_shorthands(&self) -> Styles {
+        let mut out = Styles::new();
+        for style in self.rules.iter() {
+            match style.longhands() {
+                Some(longhands) => out.rules.extend(longhands),
+                None => out.add(style.clone()),
+            }
+        }
+        out
     }
 }
 
@@ -109,6 +155,7 @@ impl fmt::Display for Styles {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum DynamicStyle {
     /// A literal style.
     Literal(Style),
@@ -154,6 +201,8 @@ pub enum Style {
     Dummy,
     /// For when you want to use some unimplemented css. This is not type checked!
     Unchecked(String),
+    /// A declaration with the `!important` flag.
+    Important(Box<Style>),
 
     // *From w3 spec:*
     /// align-content
@@ -163,7 +212,28 @@ pub enum Style {
     /// align-self
     AlignSelf(AlignSelf),
     // all - todo when doing global values
-    // background
+    /// animation
+    Animation(NonemptyCommaList<SingleAnimation>),
+    /// animation-delay
+    AnimationDelay(NonemptyCommaList<Time>),
+    /// animation-direction
+    AnimationDirection(NonemptyCommaList<AnimationDirection>),
+    /// animation-duration
+    AnimationDuration(NonemptyCommaList<Time>),
+    /// animation-fill-mode
+    AnimationFillMode(NonemptyCommaList<AnimationFillMode>),
+    /// animation-iteration-count
+    AnimationIterationCount(NonemptyCommaList<AnimationIterationCount>),
+    /// animation-name
+    AnimationName(NonemptyCommaList<AnimationName>),
+    /// animation-play-state
+    AnimationPlayState(NonemptyCommaList<AnimationPlayState>),
+    /// animation-timing-function
+    AnimationTimingFunction(NonemptyCommaList<EasingFunction>),
+    /// backface-visibility
+    BackfaceVisibility(BackfaceVisibility),
+    /// background
+    Background(Background),
     /// background-attachment
     BackgroundAttachment(BackgroundAttachment),
     /// background-blend-mode
@@ -177,7 +247,11 @@ pub enum Style {
     /// background-origin
     BackgroundOrigin(BackgroundBox),
     /// background-position
-    BackgroundPosition(BackgroundPosition),
+    BackgroundPosition(PositionValue),
+    /// background-position-x
+    BackgroundPositionX(PositionComponent<HorizontalSide>),
+    /// background-position-y
+    BackgroundPositionY(PositionComponent<VerticalSide>),
     /// background-repeat
     BackgroundRepeat(NonemptyCommaList<BackgroundRepeat>),
     /// background-size
@@ -189,9 +263,9 @@ pub enum Style {
     /// border-bottom-color
     BorderBottomColor(Color),
     /// border-bottom-left-radius
-    BorderBottomLeftRadius(SingleOrDouble<LengthPercentage>),
+    BorderBottomLeftRadius(SingleOrDouble<Calc>),
     /// border-bottom-right-radius
-    BorderBottomRightRadius(SingleOrDouble<LengthPercentage>),
+    BorderBottomRightRadius(SingleOrDouble<Calc>),
     /// border-bottom-style
     BorderBottomStyle(LineStyle),
     /// border-bottom-width
@@ -200,12 +274,18 @@ pub enum Style {
     BorderCollapse(BorderCollapse),
     /// border-color
     BorderColor(Rect<Color>),
-    // border-image
-    // border-image-outset
-    // border-image-repeat
-    // border-image-slice
-    // border-image-source
-    // border-image-width
+    /// border-image
+    BorderImage(BorderImage),
+    /// border-image-outset
+    BorderImageOutset(Rect<NumberOrLength>),
+    /// border-image-repeat
+    BorderImageRepeat(BorderImageRepeat),
+    /// border-image-slice
+    BorderImageSlice(BorderImageSlice),
+    /// border-image-source
+    BorderImageSource(BorderImageSource),
+    /// border-image-width
+    BorderImageWidth(Rect<BorderImageWidth>),
     /// border-left
     BorderLeft(Border),
     /// border-left-color
@@ -232,9 +312,9 @@ pub enum Style {
     /// border-top-color
     BorderTopColor(Color),
     /// border-top-left-radius
-    BorderTopLeftRadius(SingleOrDouble<LengthPercentage>),
+    BorderTopLeftRadius(SingleOrDouble<Calc>),
     /// border-top-right-radius
-    BorderTopRightRadius(SingleOrDouble<LengthPercentage>),
+    BorderTopRightRadius(SingleOrDouble<Calc>),
     /// border-top-style
     BorderTopStyle(LineStyle),
     /// border-top-width
@@ -271,17 +351,20 @@ pub enum Style {
     // cue-before
     /// cursor
     Cursor(Cursor),
-    // direction
+    /// direction
+    Direction(Direction),
     /// display https://www.w3.org/TR/css-display-3/#typedef-display-outside
     Display(Display),
     // elevation
     // empty-cells
-    // flex
+    /// flex
+    Flex(Flex),
     /// flex-basis
     FlexBasis(FlexBasis),
     /// flex-direction
     FlexDirection(FlexDirection),
-    // flex-flow
+    /// flex-flow
+    FlexFlow(FlexFlow),
     /// flex-grow
     FlexGrow(f64),
     /// flex-shrink
@@ -290,44 +373,83 @@ pub enum Style {
     FlexWrap(FlexWrap),
     /// float
     Float(Float),
-    // font
+    /// font
+    Font(FontShorthand),
     /// font-family
     FontFamily(FontFamily),
-    // font-feature-settings
-    // font-kerning
+    /// font-feature-settings
+    FontFeatureSettings(FontFeatureSettings),
+    /// font-kerning
+    FontKerning(FontKerning),
     /// font-size
     FontSize(FontSize),
-    // font-size-adjust
-    // font-stretch
+    /// font-size-adjust
+    FontSizeAdjust(FontSizeAdjust),
+    /// font-stretch
+    FontStretch(FontStretch),
     /// font-style
     FontStyle(FontStyle),
-    // font-synthesis
-    // font-variant
-    // font-variant-caps
-    // font-variant-east-asian
-    // font-variant-ligatures
-    // font-variant-numeric
-    // font-variant-position
+    /// font-synthesis
+    FontSynthesis(FontSynthesis),
+    /// font-variant
+    FontVariant(FontVariant),
+    /// font-variant-alternates
+    FontVariantAlternates(FontVariantAlternates),
+    /// font-variant-caps
+    FontVariantCaps(FontVariantCaps),
+    /// font-variant-east-asian
+    FontVariantEastAsian(FontVariantEastAsian),
+    /// font-variant-emoji
+    FontVariantEmoji(FontVariantEmoji),
+    /// font-variant-ligatures
+    FontVariantLigatures(FontVariantLigatures),
+    /// font-variant-numeric
+    FontVariantNumeric(FontVariantNumeric),
+    /// font-variant-position
+    FontVariantPosition(FontVariantPosition),
+    /// font-variation-settings
+    FontVariationSettings(FontVariationSettings),
     /// font-weight
     FontWeight(FontWeight),
     // glyph-orientation-vertical
-    // grid
-    // grid-area
-    // grid-auto-columns
-    // grid-auto-flow
-    // grid-auto-rows
-    // grid-column
-    // grid-column-end
-    // grid-column-start
-    // grid-row
-    // grid-row-end
-    // grid-row-start
-    // grid-template
-    // grid-template-areas
-    // grid-template-columns
-    // grid-template-rows
+    /// grid
+    Grid(Grid),
+    /// grid-area
+    GridArea(GridArea),
+    /// grid-auto-columns
+    GridAutoColumns(GridAutoTracks),
+    /// grid-auto-flow
+    GridAutoFlow(GridAutoFlow),
+    /// grid-auto-rows
+    GridAutoRows(GridAutoTracks),
+    /// grid-column
+    GridColumn(GridPlacement),
+    /// grid-column-end
+    GridColumnEnd(GridLine),
+    /// grid-column-start
+    GridColumnStart(GridLine),
+    /// grid-row
+    GridRow(GridPlacement),
+    /// grid-row-end
+    GridRowEnd(GridLine),
+    /// grid-row-start
+    GridRowStart(GridLine),
+    /// grid-template
+    GridTemplate(GridTemplate),
+    /// grid-template-areas
+    GridTemplateAreas(GridTemplateAreas),
+    /// grid-template-columns
+    GridTemplateColumns(GridTemplateTracks),
+    /// grid-template-rows
+    GridTemplateRows(GridTemplateTracks),
+    /// hanging-punctuation
+    HangingPunctuation(HangingPunctuation),
     /// height
     Height(WidthHeight),
+    /// hyphenate-character
+    HyphenateCharacter(HyphenateCharacter),
+    /// hyphens
+    Hyphens(Hyphens),
     // image-orientation
     // image-rendering
     // isolation
@@ -335,7 +457,10 @@ pub enum Style {
     JustifyContent(JustifyContent),
     /// left
     Left(AutoLengthPercentage),
-    // letter-spacing
+    /// letter-spacing
+    LetterSpacing(Spacing),
+    /// line-break
+    LineBreak(LineBreak),
     /// line-height
     LineHeight(LineHeight),
     // list-style
@@ -366,7 +491,8 @@ pub enum Style {
     // mask-image
     // mask-mode
     // mask-origin
-    // mask-position
+    /// mask-position
+    MaskPosition(PositionValue),
     // mask-repeat
     // mask-size
     // mask-type
@@ -381,7 +507,8 @@ pub enum Style {
     // mix-blend-mode
     /// object-fit - https://drafts.csswg.org/css-images-4/#the-object-fit
     ObjectFit(ObjectFit),
-    // object-position
+    /// object-position
+    ObjectPosition(PositionValue),
     // opacity
     // order
     // orphans
@@ -392,6 +519,8 @@ pub enum Style {
     // outline-width
     /// overflow - https://drafts.csswg.org/css-overflow-3/#propdef-overflow
     Overflow(Overflow),
+    /// overflow-wrap
+    OverflowWrap(OverflowWrap),
     /// overflow-x manually added
     OverflowX(OverflowXY),
     /// overflow-y manually added
@@ -412,6 +541,10 @@ pub enum Style {
     // pause
     // pause-after
     // pause-before
+    /// perspective
+    Perspective(Perspective),
+    /// perspective-origin
+    PerspectiveOrigin(PositionValue),
     // pitch
     // pitch-range
     // play-during
@@ -423,6 +556,10 @@ pub enum Style {
     // richness
     /// right
     Right(AutoLengthPercentage),
+    /// rotate
+    Rotate(Rotate),
+    /// scale
+    Scale(Scale),
     // scroll-margin
     // scroll-margin-block
     // scroll-margin-block-end
@@ -457,58 +594,382 @@ pub enum Style {
     // speak-punctuation
     // speech-rate
     // stress
+    /// tab-size
+    TabSize(NumberOrLength),
     // table-layout
     /// text-align
     TextAlign(TextAlign),
-    // text-combine-upright
-    // text-decoration
-    // text-decoration-color
-    // text-decoration-line
-    // text-decoration-style
+    /// text-combine-upright
+    TextCombineUpright(TextCombineUpright),
+    /// text-decoration
+    TextDecoration(TextDecoration),
+    /// text-decoration-color
+    TextDecorationColor(TextDecorationColor),
+    /// text-decoration-line
+    TextDecorationLine(TextDecorationLine),
+    /// text-decoration-style
+    TextDecorationStyle(TextDecorationStyle),
+    /// text-decoration-thickness
+    TextDecorationThickness(TextDecorationThickness),
     // text-emphasis
     // text-emphasis-color
     // text-emphasis-position
     // text-emphasis-style
-    // text-indent
-    // text-orientation
+    /// text-indent
+    TextIndent(TextIndent),
+    /// text-orientation
+    TextOrientation(TextOrientation),
     // text-overflow
-    // text-shadow
-    // text-transform
-    // text-underline-position
+    /// text-shadow
+    TextShadow(TextShadow),
+    /// text-transform
+    TextTransform(TextTransform),
+    /// text-underline-offset
+    TextUnderlineOffset(AutoLengthPercentage),
+    /// text-underline-position
+    TextUnderlinePosition(TextUnderlinePosition),
+    /// text-wrap
+    TextWrap(TextWrap),
+    /// text-wrap-mode
+    TextWrapMode(TextWrapMode),
+    /// text-wrap-style
+    TextWrapStyle(TextWrapStyle),
     /// top
     Top(AutoLengthPercentage),
-    // transform
+    /// transform
+    Transform(TransformList),
     // transform-box
-    // transform-origin
-    // unicode-bidi
+    /// transform-origin
+    TransformOrigin(TransformOrigin),
+    /// transform-style
+    TransformStyle(TransformStyle),
+    /// transition
+    Transition(NonemptyCommaList<SingleTransition>),
+    /// transition-behavior
+    TransitionBehavior(NonemptyCommaList<TransitionBehavior>),
+    /// transition-delay
+    TransitionDelay(NonemptyCommaList<Time>),
+    /// transition-duration
+    TransitionDuration(NonemptyCommaList<Time>),
+    /// transition-property
+    TransitionProperty(NonemptyCommaList<TransitionProperty>),
+    /// transition-timing-function
+    TransitionTimingFunction(NonemptyCommaList<EasingFunction>),
+    /// translate
+    Translate(Translate),
+    /// unicode-bidi
+    UnicodeBidi(UnicodeBidi),
     // vertical-align
     // visibility
     // voice-family
     // volume
     /// white-space
     WhiteSpace(WhiteSpace),
+    /// white-space-collapse
+    WhiteSpaceCollapse(WhiteSpaceCollapse),
     /// widows
     Widows(u32),
     /// width
     Width(WidthHeight),
     // will-change
-    // word-spacing
-    // writing-mode
+    /// word-break
+    WordBreak(WordBreak),
+    /// word-spacing
+    WordSpacing(Spacing),
+    /// writing-mode
+    WritingMode(WritingMode),
     // z-index
 }
 
 impl Style {
     fn is_dummy(&self) -> bool {
+        matches!(self, Style::Dummy)
+    }
+
+    /// Mark the declaration as `!important`.
+    pub fn important(self) -> Style {
         match self {
-            Style::Dummy => true,
-            _ => false,
+            Style::Important(_) | Style::Dummy => self,
+            style => Style::Important(Box::new(style)),
         }
     }
 
+    /// Whether the declaration is `!important`.
+    pub fn is_important(&self) -> bool {
+        matches!(self, Style::Important(_))
+    }
+
+    /// The declaration, without the `!important` flag if it has one.
+    pub fn without_importance(&self) -> &Style {
+        match self {
+            Style::Important(style) => style.without_importance(),
+            style => style,
+        }
+    }
+
+    /// The name of the property, like `align-content`.
+    ///
+    /// For unchecked styles this is the text before the `:`. It is `None` for `dummy`.
+    pub fn name(&self) -> Option<&str> {
+        Some(match self {
+            Style::Dummy => return None,
+            Style::Unchecked(v) => v.split(':').next().unwrap_or_default().trim(),
+            Style::Important(style) => return style.name(),
+
+            Style::AlignContent(_) => "align-content",
+            Style::AlignItems(_) => "align-items",
+            Style::AlignSelf(_) => "align-self",
+            Style::Animation(_) => "animation",
+            Style::AnimationDelay(_) => "animation-delay",
+            Style::AnimationDirection(_) => "animation-direction",
+            Style::AnimationDuration(_) => "animation-duration",
+            Style::AnimationFillMode(_) => "animation-fill-mode",
+            Style::AnimationIterationCount(_) => "animation-iteration-count",
+            Style::AnimationName(_) => "animation-name",
+            Style::AnimationPlayState(_) => "animation-play-state",
+            Style::AnimationTimingFunction(_) => "animation-timing-function",
+            Style::BackfaceVisibility(_) => "backface-visibility",
+            Style::Background(_) => "background",
+            Style::BackgroundAttachment(_) => "background-attachment",
+            Style::BackgroundBlendMode(_) => "background-blend-mode",
+            Style::BackgroundClip(_) => "background-clip",
+            Style::BackgroundColor(_) => "background-color",
+            Style::BackgroundImage(_) => "background-image",
+            Style::BackgroundOrigin(_) => "background-origin",
+            Style::BackgroundPosition(_) => "background-position",
+            Style::BackgroundPositionX(_) => "background-position-x",
+            Style::BackgroundPositionY(_) => "background-position-y",
+            Style::BackgroundRepeat(_) => "background-repeat",
+            Style::BackgroundSize(_) => "background-size",
+            Style::Border(_) => "border",
+            Style::BorderBottom(_) => "border-bottom",
+            Style::BorderBottomColor(_) => "border-bottom-color",
+            Style::BorderBottomLeftRadius(_) => "border-bottom-left-radius",
+            Style::BorderBottomRightRadius(_) => "border-bottom-right-radius",
+            Style::BorderBottomStyle(_) => "border-bottom-style",
+            Style::BorderBottomWidth(_) => "border-bottom-width",
+            Style::BorderCollapse(_) => "border-collapse",
+            Style::BorderColor(_) => "border-color",
+            Style::BorderImage(_) => "border-image",
+            Style::BorderImageOutset(_) => "border-image-outset",
+            Style::BorderImageRepeat(_) => "border-image-repeat",
+            Style::BorderImageSlice(_) => "border-image-slice",
+            Style::BorderImageSource(_) => "border-image-source",
+            Style::BorderImageWidth(_) => "border-image-width",
+            Style::BorderLeft(_) => "border-left",
+            Style::BorderLeftColor(_) => "border-left-color",
+            Style::BorderLeftStyle(_) => "border-left-style",
+            Style::BorderLeftWidth(_) => "border-left-width",
+            Style::BorderRadius(_) => "border-radius",
+            Style::BorderRight(_) => "border-right",
+            Style::BorderRightColor(_) => "border-right-color",
+            Style::BorderRightStyle(_) => "border-right-style",
+            Style::BorderRightWidth(_) => "border-right-width",
+            Style::BorderStyle(_) => "border-style",
+            Style::BorderTop(_) => "border-top",
+            Style::BorderTopColor(_) => "border-top-color",
+            Style::BorderTopLeftRadius(_) => "border-top-left-radius",
+            Style::BorderTopRightRadius(_) => "border-top-right-radius",
+            Style::BorderTopStyle(_) => "border-top-style",
+            Style::BorderTopWidth(_) => "border-top-width",
+            Style::BorderWidth(_) => "border-width",
+            Style::Bottom(_) => "bottom",
+            Style::BoxShadow(_) => "box-shadow",
+            Style::BoxSizing(_) => "box-sizing",
+            Style::Clear(_) => "clear",
+            Style::Color(_) => "color",
+            Style::ColumnCount(_) => "column-count",
+            Style::Cursor(_) => "cursor",
+            Style::Direction(_) => "direction",
+            Style::Display(_) => "display",
+            Style::Flex(_) => "flex",
+            Style::FlexBasis(_) => "flex-basis",
+            Style::FlexDirection(_) => "flex-direction",
+            Style::FlexFlow(_) => "flex-flow",
+            Style::FlexGrow(_) => "flex-grow",
+            Style::FlexShrink(_) => "flex-shrink",
+            Style::FlexWrap(_) => "flex-wrap",
+            Style::Float(_) => "float",
+            Style::Font(_) => "font",
+            Style::FontFamily(_) => "font-family",
+            Style::FontFeatureSettings(_) => "font-feature-settings",
+            Style::FontKerning(_) => "font-kerning",
+            Style::FontSize(_) => "font-size",
+            Style::FontSizeAdjust(_) => "font-size-adjust",
+            Style::FontStretch(_) => "font-stretch",
+            Style::FontStyle(_) => "font-style",
+            Style::FontSynthesis(_) => "font-synthesis",
+            Style::FontVariant(_) => "font-variant",
+            Style::FontVariantAlternates(_) => "font-variant-alternates",
+            Style::FontVariantCaps(_) => "font-variant-caps",
+            Style::FontVariantEastAsian(_) => "font-variant-east-asian",
+            Style::FontVariantEmoji(_) => "font-variant-emoji",
+            Style::FontVariantLigatures(_) => "font-variant-ligatures",
+            Style::FontVariantNumeric(_) => "font-variant-numeric",
+            Style::FontVariantPosition(_) => "font-variant-position",
+            Style::FontVariationSettings(_) => "font-variation-settings",
+            Style::FontWeight(_) => "font-weight",
+            Style::Grid(_) => "grid",
+            Style::GridArea(_) => "grid-area",
+            Style::GridAutoColumns(_) => "grid-auto-columns",
+            Style::GridAutoFlow(_) => "grid-auto-flow",
+            Style::GridAutoRows(_) => "grid-auto-rows",
+            Style::GridColumn(_) => "grid-column",
+            Style::GridColumnEnd(_) => "grid-column-end",
+            Style::GridColumnStart(_) => "grid-column-start",
+            Style::GridRow(_) => "grid-row",
+            Style::GridRowEnd(_) => "grid-row-end",
+            Style::GridRowStart(_) => "grid-row-start",
+            Style::GridTemplate(_) => "grid-template",
+            Style::GridTemplateAreas(_) => "grid-template-areas",
+            Style::GridTemplateColumns(_) => "grid-template-columns",
+            Style::GridTemplateRows(_) => "grid-template-rows",
+            Style::HangingPunctuation(_) => "hanging-punctuation",
+            Style::Height(_) => "height",
+            Style::HyphenateCharacter(_) => "hyphenate-character",
+            Style::Hyphens(_) => "hyphens",
+            Style::JustifyContent(_) => "justify-content",
+            Style::Left(_) => "left",
+            Style::LetterSpacing(_) => "letter-spacing",
+            Style::LineBreak(_) => "line-break",
+            Style::LineHeight(_) => "line-height",
+            Style::ListStyleType(_) => "list-style-type",
+            Style::Margin(_) => "margin",
+            Style::MarginBottom(_) => "margin-bottom",
+            Style::MarginLeft(_) => "margin-left",
+            Style::MarginRight(_) => "margin-right",
+            Style::MarginTop(_) => "margin-top",
+            Style::MaskPosition(_) => "mask-position",
+            Style::MaxHeight(_) => "max-height",
+            Style::MaxWidth(_) => "max-width",
+            Style::MinHeight(_) => "min-height",
+            Style::MinWidth(_) => "min-width",
+            Style::ObjectFit(_) => "object-fit",
+            Style::ObjectPosition(_) => "object-position",
+            Style::Overflow(_) => "overflow",
+            Style::OverflowWrap(_) => "overflow-wrap",
+            Style::OverflowX(_) => "overflow-x",
+            Style::OverflowY(_) => "overflow-y",
+            Style::Padding(_) => "padding",
+            Style::PaddingBottom(_) => "padding-bottom",
+            Style::PaddingLeft(_) => "padding-left",
+            Style::PaddingRight(_) => "padding-right",
+            Style::PaddingTop(_) => "padding-top",
+            Style::Perspective(_) => "perspective",
+            Style::PerspectiveOrigin(_) => "perspective-origin",
+            Style::Position(_) => "position",
+            Style::Resize(_) => "resize",
+            Style::Right(_) => "right",
+            Style::Rotate(_) => "rotate",
+            Style::Scale(_) => "scale",
+            Style::TabSize(_) => "tab-size",
+            Style::TextAlign(_) => "text-align",
+            Style::TextCombineUpright(_) => "text-combine-upright",
+            Style::TextDecoration(_) => "text-decoration",
+            Style::TextDecorationColor(_) => "text-decoration-color",
+            Style::TextDecorationLine(_) => "text-decoration-line",
+            Style::TextDecorationStyle(_) => "text-decoration-style",
+            Style::TextDecorationThickness(_) => "text-decoration-thickness",
+            Style::TextIndent(_) => "text-indent",
+            Style::TextOrientation(_) => "text-orientation",
+            Style::TextShadow(_) => "text-shadow",
+            Style::TextTransform(_) => "text-transform",
+            Style::TextUnderlineOffset(_) => "text-underline-offset",
+            Style::TextUnderlinePosition(_) => "text-underline-position",
+            Style::TextWrap(_) => "text-wrap",
+            Style::TextWrapMode(_) => "text-wrap-mode",
+            Style::TextWrapStyle(_) => "text-wrap-style",
+            Style::Top(_) => "top",
+            Style::Transform(_) => "transform",
+            Style::TransformOrigin(_) => "transform-origin",
+            Style::TransformStyle(_) => "transform-style",
+            Style::Transition(_) => "transition",
+            Style::TransitionBehavior(_) => "transition-behavior",
+            Style::TransitionDelay(_) => "transition-delay",
+            Style::TransitionDuration(_) => "transition-duration",
+            Style::TransitionProperty(_) => "transition-property",
+            Style::TransitionTimingFunction(_) => "transition-timing-function",
+            Style::Translate(_) => "translate",
+            Style::UnicodeBidi(_) => "unicode-bidi",
+            Style::WhiteSpace(_) => "white-space",
+            Style::WhiteSpaceCollapse(_) => "white-space-collapse",
+            Style::Widows(_) => "widows",
+            Style::Width(_) => "width",
+            Style::WordBreak(_) => "word-break",
+            Style::WordSpacing(_) => "word-spacing",
+            Style::WritingMode(_) => "writing-mode",
+        })
+    }
+
+    /// The longhand declarations that a shorthand declaration sets, or `None` if the declaration
+    /// isn't a shorthand that can be expanded. `!important` is kept on each longhand.
+    pub fn longhands(&self) -> Option<Vec<Style>> {
+        Some(match self {
+            Style::Important(style) => style
+                .longhands()?
+                .into_iter()
+                .map(Style::important)
+                .collect(),
+            Style::BorderImage(image) => vec![
+                Style::BorderImageSource(image.source()),
+                Style::BorderImageSlice(image.slice()),
+                Style::BorderImageWidth(image.width()),
+                Style::BorderImageOutset(image.outset()),
+                Style::BorderImageRepeat(image.repeat()),
+            ],
+            Style::BorderRadius(radius) => {
+                let [top_left, top_right, bottom_right, bottom_left] = radius.corners();
+                vec![
+                    Style::BorderTopLeftRadius(top_left),
+                    Style::BorderTopRightRadius(top_right),
+                    Style::BorderBottomRightRadius(bottom_right),
+                    Style::BorderBottomLeftRadius(bottom_left),
+                ]
+            }
+            Style::Flex(flex) => {
+                let (grow, shrink, basis) = flex.longhands();
+                vec![
+                    Style::FlexGrow(grow),
+                    Style::FlexShrink(shrink),
+                    Style::FlexBasis(basis),
+                ]
+            }
+            Style::FlexFlow(flow) => vec![
+                Style::FlexDirection(flow.direction()),
+                Style::FlexWrap(flow.wrap()),
+            ],
+            Style::FontVariant(variant) => vec![
+                Style::FontVariantLigatures(variant.ligatures),
+                Style::FontVariantCaps(variant.caps),
+                Style::FontVariantAlternates(variant.alternates.clone()),
+                Style::FontVariantNumeric(variant.numeric),
+                Style::FontVariantEastAsian(variant.east_asian),
+                Style::FontVariantPosition(variant.position),
+                Style::FontVariantEmoji(variant.emoji),
+            ],
+            Style::TextDecoration(decoration) => vec![
+                Style::TextDecorationLine(decoration.line()),
+                Style::TextDecorationThickness(decoration.thickness()),
+                Style::TextDecorationStyle(decoration.style()),
+                Style::TextDecorationColor(decoration.color()),
+            ],
+            Style::TextWrap(wrap) => vec![
+                Style::TextWrapMode(wrap.mode()),
+                Style::TextWrapStyle(wrap.style()),
+            ],
+            Style::WhiteSpace(white_space) => vec![
+                Style::WhiteSpaceCollapse(white_space.collapse()),
+                Style::TextWrapMode(white_space.wrap_mode()),
+            ],
+            _ => return None,
+        })
+    }
+
     fn is_dynamic(&self) -> bool {
         match self {
             Style::BackgroundColor(value) => value.is_dynamic(),
             Style::Color(value) => value.is_dynamic(),
+            Style::Important(style) => style.is_dynamic(),
             _ => false,
         }
     }
@@ -519,12 +980,23 @@ impl fmt::Display for Style {
         match self {
             Style::Dummy => Ok(()),
             Style::Unchecked(v) => write!(f, "{}", v),
+            Style::Important(v) => write!(f, "{} !important", v),
 
             Style::AlignContent(v) => write!(f, "align-content:{}", v),
             Style::AlignItems(v) => write!(f, "align-items:{}", v),
             Style::AlignSelf(v) => write!(f, "align-self:{}", v),
             // all - deferred
-            // background
+            Style::Animation(v) => write!(f, "animation:{}", v),
+            Style::AnimationDelay(v) => write!(f, "animation-delay:{}", v),
+            Style::AnimationDirection(v) => write!(f, "animation-direction:{}", v),
+            Style::AnimationDuration(v) => write!(f, "animation-duration:{}", v),
+            Style::AnimationFillMode(v) => write!(f, "animation-fill-mode:{}", v),
+            Style::AnimationIterationCount(v) => write!(f, "animation-iteration-count:{}", v),
+            Style::AnimationName(v) => write!(f, "animation-name:{}", v),
+            Style::AnimationPlayState(v) => write!(f, "animation-play-state:{}", v),
+            Style::AnimationTimingFunction(v) => write!(f, "animation-timing-function:{}", v),
+            Style::BackfaceVisibility(v) => write!(f, "backface-visibility:{}", v),
+            Style::Background(v) => write!(f, "background:{}", v),
             Style::BackgroundAttachment(v) => write!(f, "background-attachment:{}", v),
             Style::BackgroundBlendMode(v) => write!(f, "background-blend-mode:{}", v),
             Style::BackgroundClip(v) => write!(f, "background-clip:{}", v),
@@ -532,6 +1004,8 @@ impl fmt::Display for Style {
             Style::BackgroundImage(v) => write!(f, "background-image:{}", v),
             Style::BackgroundOrigin(v) => write!(f, "background-origin:{}", v),
             Style::BackgroundPosition(v) => write!(f, "background-position:{}", v),
+            Style::BackgroundPositionX(v) => write!(f, "background-position-x:{}", v),
+            Style::BackgroundPositionY(v) => write!(f, "background-position-y:{}", v),
             Style::BackgroundRepeat(v) => write!(f, "background-repeat:{}", v),
             Style::BackgroundSize(v) => write!(f, "background-size:{}", v),
             Style::Border(v) => write!(f, "border:{}", v),
@@ -543,12 +1017,12 @@ impl fmt::Display for Style {
             Style::BorderBottomWidth(v) => write!(f, "border-bottom-width:{}", v),
             Style::BorderCollapse(v) => write!(f, "border-collapse:{}", v),
             Style::BorderColor(v) => write!(f, "border-color:{}", v),
-            // border-image
-            // border-image-outset
-            // border-image-repeat
-            // border-image-slice
-            // border-image-source
-            // border-image-width
+            Style::BorderImage(v) => write!(f, "border-image:{}", v),
+            Style::BorderImageOutset(v) => write!(f, "border-image-outset:{}", v),
+            Style::BorderImageRepeat(v) => write!(f, "border-image-repeat:{}", v),
+            Style::BorderImageSlice(v) => write!(f, "border-image-slice:{}", v),
+            Style::BorderImageSource(v) => write!(f, "border-image-source:{}", v),
+            Style::BorderImageWidth(v) => write!(f, "border-image-width:{}", v),
             Style::BorderLeft(v) => write!(f, "border-left:{}", v),
             Style::BorderLeftColor(v) => write!(f, "border-left-color:{}", v),
             Style::BorderLeftStyle(v) => write!(f, "border-left-style:{}", v),
@@ -590,58 +1064,65 @@ impl fmt::Display for Style {
             // cue-after
             // cue-before
             Style::Cursor(v) => write!(f, "cursor:{}", v),
-            // direction
+            Style::Direction(v) => write!(f, "direction:{}", v),
             Style::Display(v) => write!(f, "display:{}", v),
             // elevation
             // empty-cells
-            // flex
+            Style::Flex(v) => write!(f, "flex:{}", v),
             Style::FlexBasis(v) => write!(f, "flex-basis:{}", v),
             Style::FlexDirection(v) => write!(f, "flex-direction:{}", v),
-            // flex-flow
+            Style::FlexFlow(v) => write!(f, "flex-flow:{}", v),
             Style::FlexGrow(v) => write!(f, "flex-grow:{}", v),
             Style::FlexShrink(v) => write!(f, "flex-shrink:{}", v),
             Style::FlexWrap(v) => write!(f, "flex-wrap:{}", v),
             Style::Float(v) => write!(f, "float:{}", v),
-            // font
+            Style::Font(v) => write!(f, "font:{}", v),
             Style::FontFamily(v) => write!(f, "font-family:{}", v),
-            // font-feature-settings
-            // font-kerning
+            Style::FontFeatureSettings(v) => write!(f, "font-feature-settings:{}", v),
+            Style::FontKerning(v) => write!(f, "font-kerning:{}", v),
             Style::FontSize(v) => write!(f, "font-size:{}", v),
-            // font-size-adjust
-            // font-stretch
+            Style::FontSizeAdjust(v) => write!(f, "font-size-adjust:{}", v),
+            Style::FontStretch(v) => write!(f, "font-stretch:{}", v),
             Style::FontStyle(v) => write!(f, "font-style:{}", v),
-            // font-synthesis
-            // font-variant
-            // font-variant-caps
-            // font-variant-east-asian
-            // font-variant-ligatures
-            // font-variant-numeric
-            // font-variant-position
+            Style::FontSynthesis(v) => write!(f, "font-synthesis:{}", v),
+            Style::FontVariant(v) => write!(f, "font-variant:{}", v),
+            Style::FontVariantAlternates(v) => write!(f, "font-variant-alternates:{}", v),
+            Style::FontVariantCaps(v) => write!(f, "font-variant-caps:{}", v),
+            Style::FontVariantEastAsian(v) => write!(f, "font-variant-east-asian:{}", v),
+            Style::FontVariantEmoji(v) => write!(f, "font-variant-emoji:{}", v),
+            Style::FontVariantLigatures(v) => write!(f, "font-variant-ligatures:{}", v),
+            Style::FontVariantNumeric(v) => write!(f, "font-variant-numeric:{}", v),
+            Style::FontVariantPosition(v) => write!(f, "font-variant-position:{}", v),
+            Style::FontVariationSettings(v) => write!(f, "font-variation-settings:{}", v),
             Style::FontWeight(v) => write!(f, "font-weight:{}", v),
             // glyph-orientation-vertical
-            // grid
-            // grid-area
-            // grid-auto-columns
-            // grid-auto-flow
-            // grid-auto-rows
-            // grid-column
-            // grid-column-end
-            // grid-column-start
-            // grid-row
-            // grid-row-end
-            // grid-row-start
-            // grid-template
-            // grid-template-areas
-            // grid-template-columns
-            // grid-template-rows
+            Style::Grid(v) => write!(f, "grid:{}", v),
+            Style::GridArea(v) => write!(f, "grid-area:{}", v),
+            Style::GridAutoColumns(v) => write!(f, "grid-auto-columns:{}", v),
+            Style::GridAutoFlow(v) => write!(f, "grid-auto-flow:{}", v),
+            Style::GridAutoRows(v) => write!(f, "grid-auto-rows:{}", v),
+            Style::GridColumn(v) => write!(f, "grid-column:{}", v),
+            Style::GridColumnEnd(v) => write!(f, "grid-column-end:{}", v),
+            Style::GridColumnStart(v) => write!(f, "grid-column-start:{}", v),
+            Style::GridRow(v) => write!(f, "grid-row:{}", v),
+            Style::GridRowEnd(v) => write!(f, "grid-row-end:{}", v),
+            Style::GridRowStart(v) => write!(f, "grid-row-start:{}", v),
+            Style::GridTemplate(v) => write!(f, "grid-template:{}", v),
+            Style::GridTemplateAreas(v) => write!(f, "grid-template-areas:{}", v),
+            Style::GridTemplateColumns(v) => write!(f, "grid-template-columns:{}", v),
+            Style::GridTemplateRows(v) => write!(f, "grid-template-rows:{}", v),
+            Style::HangingPunctuation(v) => write!(f, "hanging-punctuation:{}", v),
             Style::Height(v) => write!(f, "height:{}", v),
+            Style::HyphenateCharacter(v) => write!(f, "hyphenate-character:{}", v),
+            Style::Hyphens(v) => write!(f, "hyphens:{}", v),
             // image-orientation
             // image-rendering
             // isolation
             Style::JustifyContent(v) => write!(f, "justify-content:{}", v),
             // left
             Style::Left(v) => write!(f, "left:{}", v),
-            // letter-spacing
+            Style::LetterSpacing(v) => write!(f, "letter-spacing:{}", v),
+            Style::LineBreak(v) => write!(f, "line-break:{}", v),
             // line-height
             Style::LineHeight(v) => write!(f, "line-height:{}", v),
             // list-style
@@ -666,7 +1147,7 @@ impl fmt::Display for Style {
             // mask-image
             // mask-mode
             // mask-origin
-            // mask-position
+            Style::MaskPosition(v) => write!(f, "mask-position:{}", v),
             // mask-repeat
             // mask-size
             // mask-type
@@ -676,7 +1157,7 @@ impl fmt::Display for Style {
             Style::MinWidth(v) => write!(f, "min-width:{}", v),
             // mix-blend-mode
             Style::ObjectFit(v) => write!(f, "object-fit:{}", v),
-            // object-position
+            Style::ObjectPosition(v) => write!(f, "object-position:{}", v),
             // opacity
             // order
             // orphans
@@ -686,6 +1167,7 @@ impl fmt::Display for Style {
             // outline-style
             // outline-width
             Style::Overflow(v) => write!(f, "overflow:{}", v),
+            Style::OverflowWrap(v) => write!(f, "overflow-wrap:{}", v),
             Style::OverflowX(v) => write!(f, "overflow-x:{}", v),
             Style::OverflowY(v) => write!(f, "overflow-y:{}", v),
             Style::Padding(v) => write!(f, "padding:{}", v),
@@ -703,6 +1185,8 @@ impl fmt::Display for Style {
             // pause
             // pause-after
             // pause-before
+            Style::Perspective(v) => write!(f, "perspective:{}", v),
+            Style::PerspectiveOrigin(v) => write!(f, "perspective-origin:{}", v),
             // pitch
             // pitch-range
             // play-during
@@ -711,6 +1195,8 @@ impl fmt::Display for Style {
             Style::Resize(v) => write!(f, "resize:{}", v),
             // richness
             Style::Right(v) => write!(f, "right:{}", v),
+            Style::Rotate(v) => write!(f, "rotate:{}", v),
+            Style::Scale(v) => write!(f, "scale:{}", v),
             // scroll-margin
             // scroll-margin-block
             // scroll-margin-block-end
@@ -745,61 +1231,72 @@ impl fmt::Display for Style {
             // speak-punctuation
             // speech-rate
             // stress
+            Style::TabSize(v) => write!(f, "tab-size:{}", v),
             // table-layout
             Style::TextAlign(v) => write!(f, "text-align:{}", v),
-            // text-combine-upright
-            // text-decoration
-            // text-decoration-color
-            // text-decoration-line
-            // text-decoration-style
+            Style::TextCombineUpright(v) => write!(f, "text-combine-upright:{}", v),
+            Style::TextDecoration(v) => write!(f, "text-decoration:{}", v),
+            Style::TextDecorationColor(v) => write!(f, "text-decoration-color:{}", v),
+            Style::TextDecorationLine(v) => write!(f, "text-decoration-line:{}", v),
+            Style::TextDecorationStyle(v) => write!(f, "text-decoration-style:{}", v),
+            Style::TextDecorationThickness(v) => write!(f, "text-decoration-thickness:{}", v),
             // text-emphasis
             // text-emphasis-color
             // text-emphasis-position
             // text-emphasis-style
-            // text-indent
-            // text-orientation
+            Style::TextIndent(v) => write!(f, "text-indent:{}", v),
+            Style::TextOrientation(v) => write!(f, "text-orientation:{}", v),
             // text-overflow
-            // text-shadow
-            // text-transform
-            // text-underline-position
+            Style::TextShadow(v) => write!(f, "text-shadow:{}", v),
+            Style::TextTransform(v) => write!(f, "text-transform:{}", v),
+            Style::TextUnderlineOffset(v) => write!(f, "text-underline-offset:{}", v),
+            Style::TextUnderlinePosition(v) => write!(f, "text-underline-position:{}", v),
+            Style::TextWrap(v) => write!(f, "text-wrap:{}", v),
+            Style::TextWrapMode(v) => write!(f, "text-wrap-mode:{}", v),
+            Style::TextWrapStyle(v) => write!(f, "text-wrap-style:{}", v),
             // top
             Style::Top(v) => write!(f, "top:{}", v),
-            // transform
+            Style::Transform(v) => write!(f, "transform:{}", v),
             // transform-box
-            // transform-origin
-            // unicode-bidi
+            Style::TransformOrigin(v) => write!(f, "transform-origin:{}", v),
+            Style::TransformStyle(v) => write!(f, "transform-style:{}", v),
+            Style::Transition(v) => write!(f, "transition:{}", v),
+            Style::TransitionBehavior(v) => write!(f, "transition-behavior:{}", v),
+            Style::TransitionDelay(v) => write!(f, "transition-delay:{}", v),
+            Style::TransitionDuration(v) => write!(f, "transition-duration:{}", v),
+            Style::TransitionProperty(v) => write!(f, "transition-property:{}", v),
+            Style::TransitionTimingFunction(v) => write!(f, "transition-timing-function:{}", v),
+            Style::Translate(v) => write!(f, "translate:{}", v),
+            Style::UnicodeBidi(v) => write!(f, "unicode-bidi:{}", v),
             // vertical-align
             // visibility
             // voice-family
             // volume
             Style::WhiteSpace(v) => write!(f, "white-space:{}", v),
+            Style::WhiteSpaceCollapse(v) => write!(f, "white-space-collapse:{}", v),
             Style::Widows(v) => write!(f, "widows:{}", v),
             Style::Width(v) => write!(f, "width:{}", v),
             // will-change
-            // word-spacing
-            // writing-mode
+            Style::WordBreak(v) => write!(f, "word-break:{}", v),
+            Style::WordSpacing(v) => write!(f, "word-spacing:{}", v),
+            Style::WritingMode(v) => write!(f, "writing-mode:{}", v),
             // z-index
         }
     }
 }
 
 /// https://www.w3.org/TR/css-flexbox-1/#propdef-justify-content
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum AlignContent {
     FlexStart,
     Center,
     FlexEnd,
     SpaceBetween,
     SpaceAround,
+    #[default]
     Stretch,
 }
 
-impl Default for AlignContent {
-    fn default() -> Self {
-        AlignContent::Stretch
-    }
-}
-
 impl fmt::Display for AlignContent {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -891,6 +1388,164 @@ impl fmt::Display for AlignSelf {
     }
 }
 
+/// https://www.w3.org/TR/css-values-4/#angles
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Angle {
+    Deg(f64),
+    Grad(f64),
+    Rad(f64),
+    Turn(f64),
+    /// A unitless 0, which is allowed for angles in transform functions.
+    Zero,
+}
+
+impl Angle {
+    pub fn to_degrees(self) -> f64 {
+        match self {
+            Angle::Deg(v) => v,
+            Angle::Grad(v) => v * 0.9,
+            Angle::Rad(v) => v.to_degrees(),
+            Angle::Turn(v) => v * 360.0,
+            Angle::Zero => 0.0,
+        }
+    }
+
+    pub fn to_radians(self) -> f64 {
+        match self {
+            Angle::Rad(v) => v,
+            angle => angle.to_degrees().to_radians(),
+        }
+    }
+}
+
+impl fmt::Display for Angle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Angle::Deg(v) => write!(f, "{}deg", v),
+            Angle::Grad(v) => write!(f, "{}grad", v),
+            Angle::Rad(v) => write!(f, "{}rad", v),
+            Angle::Turn(v) => write!(f, "{}turn", v),
+            Angle::Zero => write!(f, "0"),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-values-4/#time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Time {
+    S(f64),
+    Ms(f64),
+}
+
+impl Time {
+    pub fn to_seconds(self) -> f64 {
+        match self {
+            Time::S(v) => v,
+            Time::Ms(v) => v / 1000.0,
+        }
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Time::S(v) => write!(f, "{}s", v),
+            Time::Ms(v) => write!(f, "{}ms", v),
+        }
+    }
+}
+
+/// The value of the `background` shorthand.
+///
+/// https://www.w3.org/TR/css-backgrounds-3/#background
+#[derive(Debug, Clone, PartialEq)]
+pub struct Background {
+    pub layers: NonemptyCommaList<BackgroundLayer>,
+    /// The background color, which is written in the last layer.
+    pub color: Option<Color>,
+}
+
+impl fmt::Display for Background {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let layers = std::iter::once(&self.layers.first).chain(self.layers.rest.iter());
+        let count = self.layers.rest.len() + 1;
+        for (idx, layer) in layers.enumerate() {
+            if idx > 0 {
+                f.write_str(",")?;
+            }
+            match &self.color {
+                Some(color) if idx == count - 1 => {
+                    if layer.is_empty() {
+                        write!(f, "{}", color)?;
+                    } else {
+                        write!(f, "{} {}", layer, color)?;
+                    }
+                }
+                _ => write!(f, "{}", layer)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One layer of the `background` shorthand. Parts that are `None` are reset to their initial
+/// values.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BackgroundLayer {
+    pub image: Option<BackgroundImage>,
+    pub position: Option<PositionValue>,
+    /// Only given after the position.
+    pub size: Option<BackgroundSize>,
+    pub repeat: Option<BackgroundRepeat>,
+    pub attachment: Option<BackgroundAttachment>,
+    /// When there is no `clip`, this sets both `background-origin` and `background-clip`.
+    pub origin: Option<BackgroundBox>,
+    pub clip: Option<BackgroundBox>,
+}
+
+impl BackgroundLayer {
+    fn is_empty(&self) -> bool {
+        self.image.is_none()
+            && self.position.is_none()
+            && self.size.is_none()
+            && self.repeat.is_none()
+            && self.attachment.is_none()
+            && self.origin.is_none()
+            && self.clip.is_none()
+    }
+}
+
+impl fmt::Display for BackgroundLayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("none");
+        }
+        let mut parts = Vec::new();
+        if let Some(image) = &self.image {
+            parts.push(image.to_string());
+        }
+        match (&self.position, &self.size) {
+            (Some(position), Some(size)) => parts.push(format!("{}/{}", position, size)),
+            (None, Some(size)) => parts.push(format!("{}/{}", PositionValue::CENTER, size)),
+            (Some(position), None) => parts.push(position.to_string()),
+            (None, None) => (),
+        }
+        if let Some(repeat) = &self.repeat {
+            parts.push(repeat.to_string());
+        }
+        if let Some(attachment) = &self.attachment {
+            parts.push(attachment.to_string());
+        }
+        if let Some(origin) = &self.origin {
+            parts.push(origin.to_string());
+        }
+        if let Some(clip) = &self.clip {
+            parts.push(clip.to_string());
+        }
+        f.write_str(&parts.join(" "))
+    }
+}
+
 /// https://developer.mozilla.org/en-US/docs/Web/CSS/background-attachment
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum BackgroundAttachment {
@@ -973,37 +1628,14 @@ impl fmt::Display for BackgroundBox {
 #[derive(Debug, Clone, PartialEq)]
 pub enum BackgroundImage {
     None,
-    Url(String),
-    // TODO other variants
+    Image(Image),
 }
 
 impl fmt::Display for BackgroundImage {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             BackgroundImage::None => write!(f, "none"),
-            BackgroundImage::Url(url) => write!(f, "url({})", url),
-        }
-    }
-}
-
-#[derive(Debug, Clone, PartialEq)]
-pub enum BackgroundPosition {
-    Top,
-    Bottom,
-    Left,
-    Right,
-    Center,
-    // TODO other variants
-}
-
-impl fmt::Display for BackgroundPosition {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            BackgroundPosition::Top => write!(f, "top"),
-            BackgroundPosition::Left => write!(f, "left"),
-            BackgroundPosition::Bottom => write!(f, "bottom"),
-            BackgroundPosition::Right => write!(f, "right"),
-            BackgroundPosition::Center => write!(f, "center"),
+            BackgroundImage::Image(image) => image.fmt(f),
         }
     }
 }
@@ -1078,10 +1710,10 @@ impl Border {
     }
 
     fn is_full(&self) -> bool {
-        match (&self.line_width, &self.line_style, &self.color) {
-            (Some(_), Some(_), Some(_)) => true,
-            _ => false,
-        }
+        matches!(
+            (&self.line_width, &self.line_style, &self.color),
+            (Some(_), Some(_), Some(_))
+        )
     }
 
     fn has_line_width(&self) -> bool {
@@ -1135,8 +1767,6 @@ impl fmt::Display for BorderCollapse {
     }
 }
 
-pub type BorderRadius = Calc;
-
 pub type BorderStyle = Rect<LineStyle>;
 
 pub type BorderWidth = Rect<LineWidth>;
@@ -1329,14 +1959,48 @@ impl fmt::Display for FlexBasis {
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum FlexDirection {
     Row,
+    RowReverse,
     Column,
+    ColumnReverse,
 }
 
 impl fmt::Display for FlexDirection {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             FlexDirection::Row => f.write_str("row"),
+            FlexDirection::RowReverse => f.write_str("row-reverse"),
             FlexDirection::Column => f.write_str("column"),
+            FlexDirection::ColumnReverse => f.write_str("column-reverse"),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-flexbox-1/#flex-flow-property
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlexFlow {
+    /// `row` if missing.
+    pub direction: Option<FlexDirection>,
+    /// `nowrap` if missing.
+    pub wrap: Option<FlexWrap>,
+}
+
+impl FlexFlow {
+    pub fn direction(&self) -> FlexDirection {
+        self.direction.unwrap_or(FlexDirection::Row)
+    }
+
+    pub fn wrap(&self) -> FlexWrap {
+        self.wrap.unwrap_or(FlexWrap::Nowrap)
+    }
+}
+
+impl fmt::Display for FlexFlow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.direction, self.wrap) {
+            (Some(direction), Some(wrap)) => write!(f, "{} {}", direction, wrap),
+            (Some(direction), None) => write!(f, "{}", direction),
+            (None, Some(wrap)) => write!(f, "{}", wrap),
+            (None, None) => write!(f, "row"),
         }
     }
 }
@@ -1345,6 +2009,7 @@ impl fmt::Display for FlexDirection {
 pub enum FlexWrap {
     Wrap,
     Nowrap,
+    WrapReverse,
 }
 
 impl fmt::Display for FlexWrap {
@@ -1352,6 +2017,56 @@ impl fmt::Display for FlexWrap {
         match self {
             FlexWrap::Wrap => write!(f, "wrap"),
             FlexWrap::Nowrap => write!(f, "nowrap"),
+            FlexWrap::WrapReverse => write!(f, "wrap-reverse"),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-flexbox-1/#flex-property
+#[derive(Debug, Clone, PartialEq)]
+pub enum Flex {
+    /// `0 0 auto`
+    None,
+    /// `1 1 auto`
+    Auto,
+    /// `0 1 auto`
+    Initial,
+    /// Missing parts are filled in when parsing, so `flex: 1` is `1 1 0`.
+    Values {
+        grow: f64,
+        shrink: f64,
+        basis: FlexBasis,
+    },
+}
+
+impl Flex {
+    /// The values of `flex-grow`, `flex-shrink` and `flex-basis` that this sets.
+    pub fn longhands(&self) -> (f64, f64, FlexBasis) {
+        let auto = FlexBasis::Width(Width21::Auto);
+        match self {
+            Flex::None => (0.0, 0.0, auto),
+            Flex::Auto => (1.0, 1.0, auto),
+            Flex::Initial => (0.0, 1.0, auto),
+            Flex::Values {
+                grow,
+                shrink,
+                basis,
+            } => (*grow, *shrink, basis.clone()),
+        }
+    }
+}
+
+impl fmt::Display for Flex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Flex::None => f.write_str("none"),
+            Flex::Auto => f.write_str("auto"),
+            Flex::Initial => f.write_str("initial"),
+            Flex::Values {
+                grow,
+                shrink,
+                basis,
+            } => write!(f, "{} {} {}", grow, shrink, basis),
         }
     }
 }
@@ -1386,6 +2101,14 @@ pub enum Font {
     Cursive,
     Fantasy,
     Monospace,
+    SystemUi,
+    Emoji,
+    Math,
+    Fangsong,
+    UiSerif,
+    UiSansSerif,
+    UiMonospace,
+    UiRounded,
 }
 
 impl fmt::Display for Font {
@@ -1397,6 +2120,14 @@ impl fmt::Display for Font {
             Font::Cursive => write!(f, "cursive"),
             Font::Fantasy => write!(f, "fantasy"),
             Font::Monospace => write!(f, "monospace"),
+            Font::SystemUi => write!(f, "system-ui"),
+            Font::Emoji => write!(f, "emoji"),
+            Font::Math => write!(f, "math"),
+            Font::Fangsong => write!(f, "fangsong"),
+            Font::UiSerif => write!(f, "ui-serif"),
+            Font::UiSansSerif => write!(f, "ui-sans-serif"),
+            Font::UiMonospace => write!(f, "ui-monospace"),
+            Font::UiRounded => write!(f, "ui-rounded"),
         }
     }
 }
@@ -1526,12 +2257,55 @@ impl fmt::Display for Length {
     }
 }
 
+impl Length {
+    /// The length in css pixels, given the computed `font-size` in pixels.
+    ///
+    /// `ex` is taken to be half an `em`, since we don't know the x-height of the font.
+    pub fn to_px(&self, font_size: f64) -> f64 {
+        match *self {
+            Length::Em(v) => v * font_size,
+            Length::Ex(v) => v * font_size * 0.5,
+            Length::In(v) => v * 96.0,
+            Length::Cm(v) => v * 96.0 / 2.54,
+            Length::Mm(v) => v * 96.0 / 25.4,
+            Length::Pt(v) => v * 96.0 / 72.0,
+            Length::Pc(v) => v * 16.0,
+            Length::Px(v) => v,
+            Length::Zero => 0.0,
+        }
+    }
+
+    pub(crate) fn is_negative(&self) -> bool {
+        match *self {
+            Length::Em(v)
+            | Length::Ex(v)
+            | Length::In(v)
+            | Length::Cm(v)
+            | Length::Mm(v)
+            | Length::Pt(v)
+            | Length::Pc(v)
+            | Length::Px(v) => v < 0.0,
+            Length::Zero => false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LengthPercentage {
     Length(Length),
     Percentage(Percentage),
 }
 
+impl LengthPercentage {
+    /// The length in css pixels, where percentages are of `basis`.
+    pub fn to_px(&self, basis: f64, font_size: f64) -> f64 {
+        match self {
+            LengthPercentage::Length(v) => v.to_px(font_size),
+            LengthPercentage::Percentage(v) => v.0 * basis / 100.0,
+        }
+    }
+}
+
 impl fmt::Display for LengthPercentage {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -1590,14 +2364,25 @@ impl fmt::Display for LineWidth {
     }
 }
 
-// TODO this isn't the full spec for lineheight
-// (https://www.w3.org/TR/CSS2/visudet.html#propdef-line-height)
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct LineHeight(f64);
+/// https://www.w3.org/TR/CSS2/visudet.html#propdef-line-height
+///
+/// None of the values can be negative.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LineHeight {
+    Normal,
+    /// A multiple of the font size.
+    Number(f64),
+    /// Percentages are of the font size.
+    LengthPercentage(Calc),
+}
 
 impl fmt::Display for LineHeight {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.0)
+        match self {
+            LineHeight::Normal => write!(f, "normal"),
+            LineHeight::Number(v) => write!(f, "{}", v),
+            LineHeight::LengthPercentage(v) => write!(f, "{}", v),
+        }
     }
 }
 
@@ -1931,25 +2716,128 @@ impl fmt::Display for TextAlign {
     }
 }
 
+/// https://www.w3.org/TR/css-values-4/#urls
 #[derive(Debug, Clone, PartialEq)]
 pub struct Url {
-    // todo modifiers
-    url: String,
+    pub url: String,
+    /// Only quoted urls can have modifiers.
+    pub modifiers: Vec<UrlModifier>,
 }
 
 impl fmt::Display for Url {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "url(\"{}\")", self.url)
+        write!(f, "url(\"{}\"", self.url)?;
+        for modifier in self.modifiers.iter() {
+            write!(f, " {}", modifier)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// https://www.w3.org/TR/css-values-5/#request-url-modifiers
+#[derive(Debug, Clone, PartialEq)]
+pub enum UrlModifier {
+    CrossOrigin(CrossOrigin),
+    /// The hash of the resource, for subresource integrity.
+    Integrity(String),
+    ReferrerPolicy(ReferrerPolicy),
+}
+
+impl fmt::Display for UrlModifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UrlModifier::CrossOrigin(v) => write!(f, "crossorigin({})", v),
+            UrlModifier::Integrity(v) => write!(f, "integrity(\"{}\")", v),
+            UrlModifier::ReferrerPolicy(v) => write!(f, "referrerpolicy({})", v),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CrossOrigin {
+    Anonymous,
+    UseCredentials,
+}
+
+impl fmt::Display for CrossOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CrossOrigin::Anonymous => write!(f, "anonymous"),
+            CrossOrigin::UseCredentials => write!(f, "use-credentials"),
+        }
+    }
+}
+
+/// https://w3c.github.io/webappsec-referrer-policy/#referrer-policies
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReferrerPolicy {
+    NoReferrer,
+    NoReferrerWhenDowngrade,
+    SameOrigin,
+    Origin,
+    StrictOrigin,
+    OriginWhenCrossOrigin,
+    StrictOriginWhenCrossOrigin,
+    UnsafeUrl,
+}
+
+impl fmt::Display for ReferrerPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReferrerPolicy::NoReferrer => write!(f, "no-referrer"),
+            ReferrerPolicy::NoReferrerWhenDowngrade => write!(f, "no-referrer-when-downgrade"),
+            ReferrerPolicy::SameOrigin => write!(f, "same-origin"),
+            ReferrerPolicy::Origin => write!(f, "origin"),
+            ReferrerPolicy::StrictOrigin => write!(f, "strict-origin"),
+            ReferrerPolicy::OriginWhenCrossOrigin => write!(f, "origin-when-cross-origin"),
+            ReferrerPolicy::StrictOriginWhenCrossOrigin => {
+                write!(f, "strict-origin-when-cross-origin")
+            }
+            ReferrerPolicy::UnsafeUrl => write!(f, "unsafe-url"),
+        }
     }
 }
 
+/// The `white-space` shorthand, which sets `white-space-collapse` and `text-wrap-mode`.
 #[derive(Debug, Clone, PartialEq)]
 pub enum WhiteSpace {
     Normal,
     Pre,
     Nowrap,
     PreWrap,
+    BreakSpaces,
     PreLine,
+    /// The longhand values, where at least one is set and the other is reset to its initial
+    /// value.
+    Longhands {
+        collapse: Option<WhiteSpaceCollapse>,
+        wrap_mode: Option<TextWrapMode>,
+    },
+}
+
+impl WhiteSpace {
+    pub fn collapse(&self) -> WhiteSpaceCollapse {
+        match self {
+            WhiteSpace::Normal | WhiteSpace::Nowrap => WhiteSpaceCollapse::Collapse,
+            WhiteSpace::Pre | WhiteSpace::PreWrap => WhiteSpaceCollapse::Preserve,
+            WhiteSpace::BreakSpaces => WhiteSpaceCollapse::BreakSpaces,
+            WhiteSpace::PreLine => WhiteSpaceCollapse::PreserveBreaks,
+            WhiteSpace::Longhands { collapse, .. } => {
+                collapse.unwrap_or(WhiteSpaceCollapse::Collapse)
+            }
+        }
+    }
+
+    pub fn wrap_mode(&self) -> TextWrapMode {
+        match self {
+            WhiteSpace::Pre | WhiteSpace::Nowrap => TextWrapMode::Nowrap,
+            WhiteSpace::Normal
+            | WhiteSpace::PreWrap
+            | WhiteSpace::BreakSpaces
+            | WhiteSpace::PreLine => TextWrapMode::Wrap,
+            WhiteSpace::Longhands { wrap_mode, .. } => wrap_mode.unwrap_or(TextWrapMode::Wrap),
+        }
+    }
 }
 
 impl fmt::Display for WhiteSpace {
@@ -1959,7 +2847,17 @@ impl fmt::Display for WhiteSpace {
             WhiteSpace::Pre => write!(f, "pre"),
             WhiteSpace::Nowrap => write!(f, "nowrap"),
             WhiteSpace::PreWrap => write!(f, "pre-wrap"),
+            WhiteSpace::BreakSpaces => write!(f, "break-spaces"),
             WhiteSpace::PreLine => write!(f, "pre-line"),
+            WhiteSpace::Longhands {
+                collapse,
+                wrap_mode,
+            } => match (collapse, wrap_mode) {
+                (Some(collapse), Some(wrap_mode)) => write!(f, "{} {}", collapse, wrap_mode),
+                (Some(collapse), None) => collapse.fmt(f),
+                (None, Some(wrap_mode)) => wrap_mode.fmt(f),
+                (None, None) => write!(f, "normal"),
+            },
         }
     }
 }
@@ -2022,7 +2920,7 @@ where
     }
 }
 
-/// Matches one or two variables.
+/// Matches one or two variables. When there are two, the horizontal value comes first.
 #[derive(Debug, Clone, PartialEq)]
 pub enum SingleOrDouble<T> {
     Single(T),
@@ -2036,7 +2934,7 @@ where
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             SingleOrDouble::Single(t) => t.fmt(f),
-            SingleOrDouble::Double { vert, horiz } => write!(f, "{} {}", vert, horiz),
+            SingleOrDouble::Double { horiz, vert } => write!(f, "{} {}", horiz, vert),
         }
     }
 }
@@ -0,0 +1,740 @@
+//! Match selectors against a tree of elements.
+//!
+//! The tree is supplied by implementing `Element` for a handle to a node. Matching works from the
+//! rightmost compound selector to the left, backtracking over the possible ancestors and siblings
+//! for descendant and sibling combinators.
+use crate::*;
+use std::collections::HashMap;
+
+/// A handle to an element in a document tree, used for selector matching.
+///
+/// Handles are compared with `==`, which should be true only if they refer to the same node.
+/// Only elements are visited: text and other kinds of node should be skipped by `prev_sibling`,
+/// `next_sibling` and `first_child`.
+pub trait Element: Clone + PartialEq {
+    fn parent(&self) -> Option<Self>;
+
+    /// The previous sibling element.
+    fn prev_sibling(&self) -> Option<Self>;
+
+    /// The next sibling element.
+    fn next_sibling(&self) -> Option<Self>;
+
+    /// The first child element.
+    fn first_child(&self) -> Option<Self>;
+
+    /// The element name, like `div`. Type selectors match it ignoring ascii case.
+    fn local_name(&self) -> &str;
+
+    /// The namespace url of the element, if it has one.
+    fn namespace(&self) -> Option<&str> {
+        None
+    }
+
+    fn id(&self) -> Option<&str>;
+
+    fn has_class(&self, name: &str) -> bool;
+
+    /// The value of the attribute `name` (with no namespace), if the element has it.
+    fn attribute(&self, name: &str) -> Option<&str>;
+
+    /// The value of the attribute `name` in the namespace with url `namespace`.
+    fn namespaced_attribute(&self, namespace: &str, name: &str) -> Option<&str> {
+        let _ = (namespace, name);
+        None
+    }
+
+    /// Whether the element has no children, for `:empty`. Implementations with text nodes should
+    /// also check that there is no text.
+    fn is_empty(&self) -> bool {
+        self.first_child().is_none()
+    }
+
+    /// Whether the element is in the state described by a pseudo-class that doesn't depend on
+    /// the structure of the tree, like `:hover`, `:checked` or `:lang(en)`.
+    ///
+    /// `pseudo_class` is always `PseudoClass::Named` or `PseudoClass::Function`.
+    fn has_state(&self, pseudo_class: &PseudoClass) -> bool {
+        let _ = pseudo_class;
+        false
+    }
+}
+
+/// The namespaces declared with `@namespace`, for matching selectors with namespace prefixes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Namespaces {
+    /// The default namespace, which unprefixed type selectors are restricted to.
+    pub default: Option<String>,
+    /// Namespace urls by prefix.
+    pub prefixes: HashMap<String, String>,
+}
+
+impl SelectorList {
+    /// Whether any of the selectors match `element`.
+    pub fn matches<E: Element>(&self, element: &E) -> bool {
+        self.matches_with(element, &Namespaces::default())
+    }
+
+    pub fn matches_with<E: Element>(&self, element: &E, namespaces: &Namespaces) -> bool {
+        self.0
+            .iter()
+            .any(|selector| selector.matches_with(element, namespaces))
+    }
+
+    /// The highest specificity of the selectors that match `element`, or `None` if none match.
+    pub fn matching_specificity<E: Element>(
+        &self,
+        element: &E,
+        namespaces: &Namespaces,
+    ) -> Option<Specificity> {
+        self.0
+            .iter()
+            .filter(|selector| selector.matches_with(element, namespaces))
+            .map(Selector::specificity)
+            .max()
+    }
+}
+
+impl Selector {
+    /// Whether the selector matches `element`.
+    ///
+    /// Selectors with a pseudo-element never match, because they select part of an element rather
+    /// than the element itself.
+    pub fn matches<E: Element>(&self, element: &E) -> bool {
+        self.matches_with(element, &Namespaces::default())
+    }
+
+    pub fn matches_with<E: Element>(&self, element: &E, namespaces: &Namespaces) -> bool {
+        let cx = Context {
+            namespaces,
+            anchor: None,
+        };
+        cx.matches_from(self, self.rest.len(), element)
+    }
+}
+
+impl QualifiedRule {
+    /// The specificity that the rule applies to `element` with, or `None` if its selectors don't
+    /// match.
+    pub fn matches<E: Element>(&self, element: &E, namespaces: &Namespaces) -> Option<Specificity> {
+        self.selectors.matching_specificity(element, namespaces)
+    }
+}
+
+impl Stylesheet {
+    /// The top-level style rules that match `element`, in order, with the specificity they match
+    /// with.
+    ///
+    /// At-rules are skipped, including conditional ones like `@media`.
+    pub fn matching_rules<'a, E: Element>(
+        &'a self,
+        element: &'a E,
+        namespaces: &'a Namespaces,
+    ) -> impl Iterator<Item = (&'a QualifiedRule, Specificity)> + 'a {
+        self.rules.iter().filter_map(move |rule| match rule {
+            Rule::Qualified(rule) => rule
+                .matches(element, namespaces)
+                .map(|specificity| (rule, specificity)),
+            Rule::At(_) => None,
+        })
+    }
+}
+
+impl AttributeMatcher {
+    /// Whether `value` (the value of the attribute on an element) matches.
+    ///
+    /// Without a case flag, the comparison is case-sensitive.
+    pub fn matches(&self, value: &str) -> bool {
+        let (value, expected) = if self.case == Some(CaseSensitivity::Insensitive) {
+            (value.to_ascii_lowercase(), self.value.to_ascii_lowercase())
+        } else {
+            (value.to_string(), self.value.clone())
+        };
+        match self.operator {
+            AttributeOperator::Equals => value == expected,
+            AttributeOperator::Includes => {
+                !expected.is_empty()
+                    && !expected.contains(|ch: char| ch.is_ascii_whitespace())
+                    && value.split_ascii_whitespace().any(|word| word == expected)
+            }
+            AttributeOperator::DashMatch => {
+                value == expected
+                    || (value.starts_with(&expected) && value[expected.len()..].starts_with('-'))
+            }
+            AttributeOperator::Prefix => !expected.is_empty() && value.starts_with(&expected),
+            AttributeOperator::Suffix => !expected.is_empty() && value.ends_with(&expected),
+            AttributeOperator::Substring => !expected.is_empty() && value.contains(&expected),
+        }
+    }
+}
+
+impl Nth {
+    /// Whether the (1-based) `index` is `a*n + b` for some `n >= 0`.
+    pub fn matches(&self, index: i32) -> bool {
+        // `a` and `b` can be anything that fits in an `i32`, so widen to avoid overflow
+        let (a, offset) = (i64::from(self.a), i64::from(index) - i64::from(self.b));
+        if a == 0 {
+            offset == 0
+        } else {
+            offset % a == 0 && offset / a >= 0
+        }
+    }
+}
+
+struct Context<'a, E> {
+    namespaces: &'a Namespaces,
+    /// When matching a relative selector inside `:has()`, the element that `:has()` is being
+    /// matched against, and how the leftmost compound selector must be related to it.
+    anchor: Option<(&'a E, Combinator)>,
+}
+
+impl<E: Element> Context<'_, E> {
+    /// Whether the compound selectors of `selector` up to and including the one at `idx` (0 is
+    /// `first`) match with `element` as the subject.
+    fn matches_from(&self, selector: &Selector, idx: usize, element: &E) -> bool {
+        let (combinator, compound) = match idx {
+            0 => (None, &selector.first),
+            idx => {
+                let (combinator, compound) = &selector.rest[idx - 1];
+                (Some(*combinator), compound)
+            }
+        };
+        if !self.compound_matches(compound, element) {
+            return false;
+        }
+        let combinator = match combinator {
+            Some(combinator) => combinator,
+            None => {
+                return match self.anchor {
+                    Some((anchor, combinator)) => related(element, anchor, combinator),
+                    None => true,
+                }
+            }
+        };
+        // Try each element that could match the next compound selector to the left.
+        let mut candidates = Candidates::new(element, combinator);
+        while let Some(candidate) = candidates.next_candidate() {
+            if self.matches_from(selector, idx - 1, &candidate) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn compound_matches(&self, compound: &CompoundSelector, element: &E) -> bool {
+        if compound.pseudo_element.is_some() {
+            return false;
+        }
+        let type_matches = match &compound.type_selector {
+            Some(type_selector) => self.type_matches(type_selector, element),
+            None => self.namespace_matches(None, element),
+        };
+        type_matches
+            && compound
+                .subclasses
+                .iter()
+                .all(|subclass| self.subclass_matches(subclass, element))
+    }
+
+    fn type_matches(&self, type_selector: &TypeSelector, element: &E) -> bool {
+        let name_matches = match &type_selector.name {
+            TypeName::Universal => true,
+            TypeName::Name(name) => element.local_name().eq_ignore_ascii_case(name),
+        };
+        name_matches && self.namespace_matches(type_selector.namespace.as_ref(), element)
+    }
+
+    fn namespace_matches(&self, prefix: Option<&NamespacePrefix>, element: &E) -> bool {
+        match prefix {
+            None => match &self.namespaces.default {
+                Some(default) => element.namespace() == Some(default.as_str()),
+                None => true,
+            },
+            Some(NamespacePrefix::Any) => true,
+            Some(NamespacePrefix::None) => element.namespace().is_none(),
+            Some(NamespacePrefix::Named(prefix)) => match self.namespaces.prefixes.get(prefix) {
+                Some(url) => element.namespace() == Some(url.as_str()),
+                // Undeclared prefixes make the selector invalid, so it never matches.
+                None => false,
+            },
+        }
+    }
+
+    fn subclass_matches(&self, subclass: &SubclassSelector, element: &E) -> bool {
+        match subclass {
+            SubclassSelector::Id(id) => element.id() == Some(id.as_str()),
+            SubclassSelector::Class(class) => element.has_class(class),
+            SubclassSelector::Attribute(attr) => self.attribute_matches(attr, element),
+            SubclassSelector::PseudoClass(pseudo) => self.pseudo_class_matches(pseudo, element),
+        }
+    }
+
+    fn attribute_matches(&self, attr: &AttributeSelector, element: &E) -> bool {
+        let value = match &attr.namespace {
+            None | Some(NamespacePrefix::None) | Some(NamespacePrefix::Any) => {
+                element.attribute(&attr.name)
+            }
+            Some(NamespacePrefix::Named(prefix)) => self
+                .namespaces
+                .prefixes
+                .get(prefix)
+                .and_then(|url| element.namespaced_attribute(url, &attr.name)),
+        };
+        match (value, &attr.matcher) {
+            (Some(value), Some(matcher)) => matcher.matches(value),
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+
+    fn pseudo_class_matches(&self, pseudo: &PseudoClass, element: &E) -> bool {
+        // Selectors inside pseudo-classes are matched on their own, not relative to an anchor.
+        let inner = Context::<E> {
+            namespaces: self.namespaces,
+            anchor: None,
+        };
+        let list_matches = |list: &SelectorList, element: &E| {
+            list.0
+                .iter()
+                .any(|selector| inner.matches_from(selector, selector.rest.len(), element))
+        };
+        match pseudo {
+            PseudoClass::Is(list) | PseudoClass::Where(list) => list_matches(list, element),
+            PseudoClass::Not(list) => !list_matches(list, element),
+            PseudoClass::Has(list) => list
+                .iter()
+                .any(|relative| self.has_matches(relative, element)),
+            PseudoClass::NthChild(nth, of) | PseudoClass::NthLastChild(nth, of) => {
+                if let Some(of) = of {
+                    if !list_matches(of, element) {
+                        return false;
+                    }
+                }
+                let from_end = matches!(pseudo, PseudoClass::NthLastChild(..));
+                let index = sibling_index(element, from_end, |sibling| match of {
+                    Some(of) => list_matches(of, sibling),
+                    None => true,
+                });
+                nth.matches(index)
+            }
+            PseudoClass::NthOfType(nth) | PseudoClass::NthLastOfType(nth) => {
+                let from_end = matches!(pseudo, PseudoClass::NthLastOfType(_));
+                nth.matches(sibling_index(element, from_end, |sibling| {
+                    same_type(element, sibling)
+                }))
+            }
+            PseudoClass::FirstChild => element.prev_sibling().is_none(),
+            PseudoClass::LastChild => element.next_sibling().is_none(),
+            PseudoClass::OnlyChild => {
+                element.prev_sibling().is_none() && element.next_sibling().is_none()
+            }
+            PseudoClass::FirstOfType => {
+                sibling_index(element, false, |s| same_type(element, s)) == 1
+            }
+            PseudoClass::LastOfType => sibling_index(element, true, |s| same_type(element, s)) == 1,
+            PseudoClass::OnlyOfType => {
+                sibling_index(element, false, |s| same_type(element, s)) == 1
+                    && sibling_index(element, true, |s| same_type(element, s)) == 1
+            }
+            PseudoClass::Root => element.parent().is_none(),
+            PseudoClass::Empty => element.is_empty(),
+            PseudoClass::Named(_) | PseudoClass::Function(..) => element.has_state(pseudo),
+        }
+    }
+
+    /// Whether `relative` matches any element relative to `anchor`.
+    fn has_matches(&self, relative: &RelativeSelector, anchor: &E) -> bool {
+        let cx = Context {
+            namespaces: self.namespaces,
+            anchor: Some((anchor, relative.combinator)),
+        };
+        let selector = &relative.selector;
+        let last = selector.rest.len();
+        // The subject of the relative selector must be a descendant of the anchor, or (if the
+        // selector starts with a sibling combinator) a later sibling or one of its descendants.
+        let mut roots = Vec::new();
+        match relative.combinator {
+            Combinator::Descendant | Combinator::Child => roots.extend(anchor.first_child()),
+            Combinator::NextSibling | Combinator::SubsequentSibling => {
+                roots.extend(anchor.next_sibling())
+            }
+        }
+        let mut stack = roots;
+        while let Some(element) = stack.pop() {
+            if cx.matches_from(selector, last, &element) {
+                return true;
+            }
+            stack.extend(element.next_sibling());
+            stack.extend(element.first_child());
+        }
+        false
+    }
+}
+
+/// Whether `element` is related to `anchor` by `combinator`, e.g. for `Child` whether `anchor` is
+/// the parent of `element`.
+fn related<E: Element>(element: &E, anchor: &E, combinator: Combinator) -> bool {
+    let mut candidates = Candidates::new(element, combinator);
+    while let Some(candidate) = candidates.next_candidate() {
+        if candidate == *anchor {
+            return true;
+        }
+    }
+    false
+}
+
+/// The elements that could match the compound selector to the left of `combinator`, when
+/// `element` matches the one to the right.
+struct Candidates<E> {
+    next: Option<E>,
+    combinator: Combinator,
+}
+
+impl<E: Element> Candidates<E> {
+    fn new(element: &E, combinator: Combinator) -> Self {
+        let next = match combinator {
+            Combinator::Descendant | Combinator::Child => element.parent(),
+            Combinator::NextSibling | Combinator::SubsequentSibling => element.prev_sibling(),
+        };
+        Candidates { next, combinator }
+    }
+
+    fn next_candidate(&mut self) -> Option<E> {
+        let current = self.next.take()?;
+        self.next = match self.combinator {
+            Combinator::Descendant => current.parent(),
+            Combinator::SubsequentSibling => current.prev_sibling(),
+            Combinator::Child | Combinator::NextSibling => None,
+        };
+        Some(current)
+    }
+}
+
+/// The 1-based index of `element` among its siblings that pass `filter`, counting from the end if
+/// `from_end` is true.
+fn sibling_index<E: Element>(element: &E, from_end: bool, filter: impl Fn(&E) -> bool) -> i32 {
+    let step = |e: &E| {
+        if from_end {
+            e.next_sibling()
+        } else {
+            e.prev_sibling()
+        }
+    };
+    let mut index = 1;
+    let mut sibling = step(element);
+    while let Some(current) = sibling {
+        if filter(&current) {
+            index += 1;
+        }
+        sibling = step(&current);
+    }
+    index
+}
+
+fn same_type<E: Element>(a: &E, b: &E) -> bool {
+    a.local_name().eq_ignore_ascii_case(b.local_name()) && a.namespace() == b.namespace()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    /// A tree stored as a list of nodes.
+    #[derive(Debug, Default)]
+    struct Tree {
+        nodes: Vec<Node>,
+    }
+
+    #[derive(Debug)]
+    struct Node {
+        name: &'static str,
+        id: Option<&'static str>,
+        classes: Vec<&'static str>,
+        attributes: Vec<(&'static str, &'static str)>,
+        hover: bool,
+        parent: Option<usize>,
+        children: Vec<usize>,
+    }
+
+    impl Tree {
+        /// Add an element. `spec` is like `li#id.class`.
+        fn add(&mut self, parent: Option<usize>, spec: &'static str) -> usize {
+            let mut parts = spec.split('.');
+            let mut name_id = parts.next().unwrap().split('#');
+            let name = name_id.next().unwrap();
+            let idx = self.nodes.len();
+            self.nodes.push(Node {
+                name,
+                id: name_id.next(),
+                classes: parts.collect(),
+                attributes: Vec::new(),
+                hover: false,
+                parent,
+                children: Vec::new(),
+            });
+            if let Some(parent) = parent {
+                self.nodes[parent].children.push(idx);
+            }
+            idx
+        }
+
+        fn el(&self, idx: usize) -> El<'_> {
+            El { tree: self, idx }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct El<'a> {
+        tree: &'a Tree,
+        idx: usize,
+    }
+
+    impl PartialEq for El<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.idx == other.idx
+        }
+    }
+
+    impl<'a> El<'a> {
+        fn node(&self) -> &'a Node {
+            &self.tree.nodes[self.idx]
+        }
+
+        fn sibling(&self, offset: isize) -> Option<Self> {
+            let siblings = &self.tree.nodes[self.node().parent?].children;
+            let pos = siblings.iter().position(|&idx| idx == self.idx)? as isize + offset;
+            let idx = *siblings.get(usize::try_from(pos).ok()?)?;
+            Some(self.tree.el(idx))
+        }
+    }
+
+    impl Element for El<'_> {
+        fn parent(&self) -> Option<Self> {
+            Some(self.tree.el(self.node().parent?))
+        }
+
+        fn prev_sibling(&self) -> Option<Self> {
+            self.sibling(-1)
+        }
+
+        fn next_sibling(&self) -> Option<Self> {
+            self.sibling(1)
+        }
+
+        fn first_child(&self) -> Option<Self> {
+            Some(self.tree.el(*self.node().children.first()?))
+        }
+
+        fn local_name(&self) -> &str {
+            self.node().name
+        }
+
+        fn id(&self) -> Option<&str> {
+            self.node().id
+        }
+
+        fn has_class(&self, name: &str) -> bool {
+            self.node().classes.contains(&name)
+        }
+
+        fn attribute(&self, name: &str) -> Option<&str> {
+            self.node()
+                .attributes
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| *value)
+        }
+
+        fn has_state(&self, pseudo_class: &PseudoClass) -> bool {
+            *pseudo_class == PseudoClass::Named("hover".into()) && self.node().hover
+        }
+    }
+
+    /// ```text
+    /// html
+    ///   body
+    ///     div#main.content
+    ///       p.intro
+    ///       ul
+    ///         li.a
+    ///         li.b
+    ///         li.a
+    ///         li.b
+    ///       p
+    ///     footer
+    ///       a (href="https://example.com/x", lang="en-US")
+    /// ```
+    fn tree() -> Tree {
+        let mut tree = Tree::default();
+        let html = tree.add(None, "html");
+        let body = tree.add(Some(html), "body");
+        let main = tree.add(Some(body), "div#main.content");
+        tree.add(Some(main), "p.intro");
+        let ul = tree.add(Some(main), "ul");
+        for spec in ["li.a", "li.b", "li.a", "li.b"] {
+            tree.add(Some(ul), spec);
+        }
+        tree.add(Some(main), "p");
+        let footer = tree.add(Some(body), "footer");
+        let a = tree.add(Some(footer), "a");
+        tree.nodes[a].attributes = vec![("href", "https://example.com/x"), ("lang", "en-US")];
+        tree.nodes[a].hover = true;
+        tree
+    }
+
+    /// The indices of the elements that `selector` matches.
+    fn select(tree: &Tree, selector: &str) -> Vec<usize> {
+        let selectors: SelectorList = selector.parse().unwrap();
+        (0..tree.nodes.len())
+            .filter(|&idx| selectors.matches(&tree.el(idx)))
+            .collect()
+    }
+
+    #[test]
+    fn simple() {
+        let tree = tree();
+        for (selector, expected) in [
+            ("*", (0..12).collect::<Vec<_>>()),
+            ("LI", vec![5, 6, 7, 8]),
+            ("#main", vec![2]),
+            (".a", vec![5, 7]),
+            ("p.intro, footer", vec![3, 10]),
+            ("div.content#main", vec![2]),
+            ("[href]", vec![11]),
+            ("[href^=https][href$='/x']", vec![11]),
+            ("[href*=EXAMPLE]", vec![]),
+            ("[href*=EXAMPLE i]", vec![11]),
+            ("[lang|=en]", vec![11]),
+            ("[lang~=en]", vec![]),
+            (":hover", vec![11]),
+            (":root", vec![0]),
+            (":empty", vec![3, 5, 6, 7, 8, 9, 11]),
+            ("p::before", vec![]),
+        ] {
+            assert_eq!(select(&tree, selector), expected, "selector: {}", selector);
+        }
+    }
+
+    #[test]
+    fn combinators() {
+        let tree = tree();
+        for (selector, expected) in [
+            ("body p", vec![3, 9]),
+            ("body > p", vec![]),
+            ("div > p", vec![3, 9]),
+            ("p + ul", vec![4]),
+            ("p ~ p", vec![9]),
+            ("li + li", vec![6, 7, 8]),
+            (".a ~ .b", vec![6, 8]),
+            // needs backtracking: the first `div` ancestor found isn't a child of `body`
+            ("body > * li", vec![5, 6, 7, 8]),
+            ("html > * > * > ul > .a + li", vec![6, 8]),
+            ("footer a, div a", vec![11]),
+        ] {
+            assert_eq!(select(&tree, selector), expected, "selector: {}", selector);
+        }
+    }
+
+    #[test]
+    fn structural() {
+        let tree = tree();
+        for (selector, expected) in [
+            ("li:first-child", vec![5]),
+            ("li:last-child", vec![8]),
+            ("li:nth-child(2n)", vec![6, 8]),
+            ("li:nth-child(odd)", vec![5, 7]),
+            ("li:nth-child(-n+2)", vec![5, 6]),
+            ("li:nth-child(n-2147483648)", vec![5, 6, 7, 8]),
+            ("li:nth-last-child(1)", vec![8]),
+            ("li:nth-child(2 of .a)", vec![7]),
+            ("li:nth-last-child(1 of .a)", vec![7]),
+            ("div > :nth-of-type(2)", vec![9]),
+            ("div > :nth-last-of-type(1)", vec![4, 9]),
+            ("div > :first-of-type", vec![3, 4]),
+            ("div > :only-of-type", vec![4]),
+            ("a:only-child", vec![11]),
+            ("li:not(.a, :last-child)", vec![6]),
+            (":is(ul, footer) > :where(.b, a)", vec![6, 8, 11]),
+        ] {
+            assert_eq!(select(&tree, selector), expected, "selector: {}", selector);
+        }
+    }
+
+    #[test]
+    fn has() {
+        let tree = tree();
+        for (selector, expected) in [
+            (":has(li)", vec![0, 1, 2, 4]),
+            (":has(> li)", vec![4]),
+            ("div:has(> ul > .a)", vec![2]),
+            ("li:has(+ .a)", vec![6]),
+            ("li:has(~ .a)", vec![5, 6]),
+            ("p:has(+ ul li.b)", vec![3]),
+            ("body:has(> div p.intro + ul)", vec![1]),
+            (":has(> p, > a)", vec![2, 10]),
+            ("ul:has(> :nth-child(5))", vec![]),
+        ] {
+            assert_eq!(select(&tree, selector), expected, "selector: {}", selector);
+        }
+    }
+
+    #[test]
+    fn nth() {
+        for (a, b, matches) in [
+            (2, 1, vec![1, 3, 5]),
+            (0, 3, vec![3]),
+            (-1, 3, vec![1, 2, 3]),
+            (3, -1, vec![2, 5]),
+            (-2, 0, vec![]),
+            // these overflow an `i32`
+            (1, i32::MIN, vec![1, 2, 3, 4, 5, 6]),
+            (-1, i32::MIN, vec![]),
+            (-1, i32::MAX, vec![1, 2, 3, 4, 5, 6]),
+            (i32::MIN, 1, vec![1]),
+        ] {
+            let nth = Nth::new(a, b);
+            let found = (1..=6).filter(|&i| nth.matches(i)).collect::<Vec<_>>();
+            assert_eq!(found, matches, "{}", nth);
+        }
+    }
+
+    #[test]
+    fn matching_rules() {
+        let tree = tree();
+        let sheet: Stylesheet = "li { color: red } @media print { li { color: blue } } \
+            ul > .a, #main li { display: block }"
+            .parse()
+            .unwrap();
+        let matched = sheet
+            .matching_rules(&tree.el(5), &Namespaces::default())
+            .map(|(_, specificity)| specificity)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            matched,
+            vec![Specificity::new(0, 0, 1), Specificity::new(1, 0, 1)]
+        );
+        assert_eq!(
+            sheet
+                .matching_rules(&tree.el(6), &Namespaces::default())
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn namespaces() {
+        let tree = tree();
+        let mut namespaces = Namespaces::default();
+        let selectors: SelectorList = "svg|a".parse().unwrap();
+        // undeclared prefixes never match
+        assert!(!selectors.matches_with(&tree.el(11), &namespaces));
+        namespaces
+            .prefixes
+            .insert("svg".into(), "http://www.w3.org/2000/svg".into());
+        // the element has no namespace
+        assert!(!selectors.matches_with(&tree.el(11), &namespaces));
+        assert!("|a".parse::<SelectorList>().unwrap().matches(&tree.el(11)));
+        assert!("*|a".parse::<SelectorList>().unwrap().matches(&tree.el(11)));
+    }
+}
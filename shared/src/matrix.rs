@@ -0,0 +1,541 @@
+//! 4x4 transform matrices, with the decomposition and interpolation algorithms from
+//! https://www.w3.org/TR/css-transforms-2/#interpolation-of-3d-matrices.
+
+/// A 4x4 matrix in column-major order, the same order as the arguments of `matrix3d()`.
+///
+/// Points are column vectors, so `a.multiply(&b)` applies `b` first and then `a`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransformMatrix(pub [f64; 16]);
+
+impl Default for TransformMatrix {
+    fn default() -> Self {
+        TransformMatrix::IDENTITY
+    }
+}
+
+impl TransformMatrix {
+    pub const IDENTITY: TransformMatrix = TransformMatrix([
+        1.0, 0.0, 0.0, 0.0, //
+        0.0, 1.0, 0.0, 0.0, //
+        0.0, 0.0, 1.0, 0.0, //
+        0.0, 0.0, 0.0, 1.0,
+    ]);
+
+    /// The matrix of `matrix(a, b, c, d, e, f)`.
+    pub fn from_2d([a, b, c, d, e, f]: [f64; 6]) -> Self {
+        TransformMatrix([
+            a, b, 0.0, 0.0, //
+            c, d, 0.0, 0.0, //
+            0.0, 0.0, 1.0, 0.0, //
+            e, f, 0.0, 1.0,
+        ])
+    }
+
+    pub fn translate(x: f64, y: f64, z: f64) -> Self {
+        let mut m = TransformMatrix::IDENTITY;
+        m.0[12] = x;
+        m.0[13] = y;
+        m.0[14] = z;
+        m
+    }
+
+    pub fn scale(x: f64, y: f64, z: f64) -> Self {
+        let mut m = TransformMatrix::IDENTITY;
+        m.0[0] = x;
+        m.0[5] = y;
+        m.0[10] = z;
+        m
+    }
+
+    /// A clockwise rotation by `angle` radians around the axis `[x, y, z]`, which doesn't need to
+    /// be normalized.
+    ///
+    /// If the axis has no length, this is the identity.
+    pub fn rotate(x: f64, y: f64, z: f64, angle: f64) -> Self {
+        let len = (x * x + y * y + z * z).sqrt();
+        if len == 0.0 {
+            return TransformMatrix::IDENTITY;
+        }
+        let (x, y, z) = (x / len, y / len, z / len);
+        let half = angle / 2.0;
+        let sc = half.sin() * half.cos();
+        let sq = half.sin() * half.sin();
+        TransformMatrix([
+            1.0 - 2.0 * (y * y + z * z) * sq,
+            2.0 * (x * y * sq + z * sc),
+            2.0 * (x * z * sq - y * sc),
+            0.0,
+            2.0 * (x * y * sq - z * sc),
+            1.0 - 2.0 * (x * x + z * z) * sq,
+            2.0 * (y * z * sq + x * sc),
+            0.0,
+            2.0 * (x * z * sq + y * sc),
+            2.0 * (y * z * sq - x * sc),
+            1.0 - 2.0 * (x * x + y * y) * sq,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            1.0,
+        ])
+    }
+
+    /// Skew by `x` radians along the x axis and `y` radians along the y axis.
+    pub fn skew(x: f64, y: f64) -> Self {
+        TransformMatrix::from_2d([1.0, y.tan(), x.tan(), 1.0, 0.0, 0.0])
+    }
+
+    /// The matrix of `perspective(distance)`. Distances less than 1px are clamped to 1px.
+    pub fn perspective(distance: f64) -> Self {
+        let mut m = TransformMatrix::IDENTITY;
+        m.0[11] = -1.0 / distance.max(1.0);
+        m
+    }
+
+    /// The entry in column `col` and row `row`.
+    fn get(&self, col: usize, row: usize) -> f64 {
+        self.0[col * 4 + row]
+    }
+
+    /// The matrix product `self * other`, which applies `other` first.
+    pub fn multiply(&self, other: &TransformMatrix) -> TransformMatrix {
+        let mut out = [0.0; 16];
+        for col in 0..4 {
+            for row in 0..4 {
+                out[col * 4 + row] = (0..4).map(|k| self.get(k, row) * other.get(col, k)).sum();
+            }
+        }
+        TransformMatrix(out)
+    }
+
+    /// Whether this matrix could be written as `matrix(a, b, c, d, e, f)`.
+    pub fn is_2d(&self) -> bool {
+        let m = &self.0;
+        [m[2], m[3], m[6], m[7], m[8], m[9], m[11], m[14]]
+            .iter()
+            .all(|v| *v == 0.0)
+            && m[10] == 1.0
+            && m[15] == 1.0
+    }
+
+    /// The arguments of `matrix()` for this matrix, if it is 2D.
+    pub fn to_2d(&self) -> Option<[f64; 6]> {
+        let m = &self.0;
+        if self.is_2d() {
+            Some([m[0], m[1], m[4], m[5], m[12], m[13]])
+        } else {
+            None
+        }
+    }
+
+    pub fn determinant(&self) -> f64 {
+        let cofactors = self.cofactors();
+        (0..4).map(|i| self.0[i] * cofactors[i * 4]).sum()
+    }
+
+    /// The inverse of this matrix, or `None` if it is singular.
+    pub fn invert(&self) -> Option<TransformMatrix> {
+        let cofactors = self.cofactors();
+        let det: f64 = (0..4).map(|i| self.0[i] * cofactors[i * 4]).sum();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let mut out = cofactors;
+        for v in out.iter_mut() {
+            *v /= det;
+        }
+        Some(TransformMatrix(out))
+    }
+
+    /// The transpose of the matrix of cofactors, so that `self * cofactors = det * I`.
+    fn cofactors(&self) -> [f64; 16] {
+        let m = &self.0;
+        let mut inv = [0.0; 16];
+        inv[0] = m[5] * m[10] * m[15] - m[5] * m[11] * m[14] - m[9] * m[6] * m[15]
+            + m[9] * m[7] * m[14]
+            + m[13] * m[6] * m[11]
+            - m[13] * m[7] * m[10];
+        inv[4] = -m[4] * m[10] * m[15] + m[4] * m[11] * m[14] + m[8] * m[6] * m[15]
+            - m[8] * m[7] * m[14]
+            - m[12] * m[6] * m[11]
+            + m[12] * m[7] * m[10];
+        inv[8] = m[4] * m[9] * m[15] - m[4] * m[11] * m[13] - m[8] * m[5] * m[15]
+            + m[8] * m[7] * m[13]
+            + m[12] * m[5] * m[11]
+            - m[12] * m[7] * m[9];
+        inv[12] = -m[4] * m[9] * m[14] + m[4] * m[10] * m[13] + m[8] * m[5] * m[14]
+            - m[8] * m[6] * m[13]
+            - m[12] * m[5] * m[10]
+            + m[12] * m[6] * m[9];
+        inv[1] = -m[1] * m[10] * m[15] + m[1] * m[11] * m[14] + m[9] * m[2] * m[15]
+            - m[9] * m[3] * m[14]
+            - m[13] * m[2] * m[11]
+            + m[13] * m[3] * m[10];
+        inv[5] = m[0] * m[10] * m[15] - m[0] * m[11] * m[14] - m[8] * m[2] * m[15]
+            + m[8] * m[3] * m[14]
+            + m[12] * m[2] * m[11]
+            - m[12] * m[3] * m[10];
+        inv[9] = -m[0] * m[9] * m[15] + m[0] * m[11] * m[13] + m[8] * m[1] * m[15]
+            - m[8] * m[3] * m[13]
+            - m[12] * m[1] * m[11]
+            + m[12] * m[3] * m[9];
+        inv[13] = m[0] * m[9] * m[14] - m[0] * m[10] * m[13] - m[8] * m[1] * m[14]
+            + m[8] * m[2] * m[13]
+            + m[12] * m[1] * m[10]
+            - m[12] * m[2] * m[9];
+        inv[2] = m[1] * m[6] * m[15] - m[1] * m[7] * m[14] - m[5] * m[2] * m[15]
+            + m[5] * m[3] * m[14]
+            + m[13] * m[2] * m[7]
+            - m[13] * m[3] * m[6];
+        inv[6] = -m[0] * m[6] * m[15] + m[0] * m[7] * m[14] + m[4] * m[2] * m[15]
+            - m[4] * m[3] * m[14]
+            - m[12] * m[2] * m[7]
+            + m[12] * m[3] * m[6];
+        inv[10] = m[0] * m[5] * m[15] - m[0] * m[7] * m[13] - m[4] * m[1] * m[15]
+            + m[4] * m[3] * m[13]
+            + m[12] * m[1] * m[7]
+            - m[12] * m[3] * m[5];
+        inv[14] = -m[0] * m[5] * m[14] + m[0] * m[6] * m[13] + m[4] * m[1] * m[14]
+            - m[4] * m[2] * m[13]
+            - m[12] * m[1] * m[6]
+            + m[12] * m[2] * m[5];
+        inv[3] = -m[1] * m[6] * m[11] + m[1] * m[7] * m[10] + m[5] * m[2] * m[11]
+            - m[5] * m[3] * m[10]
+            - m[9] * m[2] * m[7]
+            + m[9] * m[3] * m[6];
+        inv[7] = m[0] * m[6] * m[11] - m[0] * m[7] * m[10] - m[4] * m[2] * m[11]
+            + m[4] * m[3] * m[10]
+            + m[8] * m[2] * m[7]
+            - m[8] * m[3] * m[6];
+        inv[11] = -m[0] * m[5] * m[11] + m[0] * m[7] * m[9] + m[4] * m[1] * m[11]
+            - m[4] * m[3] * m[9]
+            - m[8] * m[1] * m[7]
+            + m[8] * m[3] * m[5];
+        inv[15] = m[0] * m[5] * m[10] - m[0] * m[6] * m[9] - m[4] * m[1] * m[10]
+            + m[4] * m[2] * m[9]
+            + m[8] * m[1] * m[6]
+            - m[8] * m[2] * m[5];
+        inv
+    }
+
+    /// Transform the point `[x, y, z]`, including the divide by `w` for perspective.
+    pub fn transform_point(&self, point: [f64; 3]) -> [f64; 3] {
+        let [x, y, z] = point;
+        let mut out = [0.0; 4];
+        for (row, v) in out.iter_mut().enumerate() {
+            *v = self.get(0, row) * x
+                + self.get(1, row) * y
+                + self.get(2, row) * z
+                + self.get(3, row);
+        }
+        [out[0] / out[3], out[1] / out[3], out[2] / out[3]]
+    }
+
+    /// Split the matrix into translation, scale, skew, perspective and rotation, or `None` if
+    /// the matrix can't be decomposed.
+    ///
+    /// https://www.w3.org/TR/css-transforms-2/#decomposing-a-3d-matrix
+    pub fn decompose(&self) -> Option<DecomposedMatrix> {
+        if self.0[15] == 0.0 {
+            return None;
+        }
+        // `matrix[i]` is column `i`, as in the spec.
+        let mut matrix = [[0.0; 4]; 4];
+        for (i, column) in matrix.iter_mut().enumerate() {
+            for (j, v) in column.iter_mut().enumerate() {
+                *v = self.get(i, j) / self.0[15];
+            }
+        }
+
+        // The perspective matrix is also used to test the upper 3x3 for singularity.
+        let mut perspective_matrix = TransformMatrix::IDENTITY;
+        for (i, column) in matrix.iter().enumerate() {
+            perspective_matrix.0[i * 4..i * 4 + 3].copy_from_slice(&column[..3]);
+        }
+        if perspective_matrix.determinant() == 0.0 {
+            return None;
+        }
+
+        let perspective = if matrix[0][3] != 0.0 || matrix[1][3] != 0.0 || matrix[2][3] != 0.0 {
+            let rhs = [matrix[0][3], matrix[1][3], matrix[2][3], matrix[3][3]];
+            let inverse = perspective_matrix.invert()?;
+            let mut perspective = [0.0; 4];
+            for (i, v) in perspective.iter_mut().enumerate() {
+                *v = (0..4).map(|j| rhs[j] * inverse.get(i, j)).sum();
+            }
+            perspective
+        } else {
+            [0.0, 0.0, 0.0, 1.0]
+        };
+
+        let translate = [matrix[3][0], matrix[3][1], matrix[3][2]];
+
+        let mut row = [[0.0; 3]; 3];
+        for i in 0..3 {
+            row[i].copy_from_slice(&matrix[i][..3]);
+        }
+        let mut scale = [0.0; 3];
+        let mut skew = [0.0; 3];
+
+        scale[0] = length(row[0]);
+        row[0] = normalize(row[0]);
+
+        skew[0] = dot(row[0], row[1]);
+        row[1] = combine(row[1], row[0], 1.0, -skew[0]);
+        scale[1] = length(row[1]);
+        row[1] = normalize(row[1]);
+        skew[0] /= scale[1];
+
+        skew[1] = dot(row[0], row[2]);
+        row[2] = combine(row[2], row[0], 1.0, -skew[1]);
+        skew[2] = dot(row[1], row[2]);
+        row[2] = combine(row[2], row[1], 1.0, -skew[2]);
+        scale[2] = length(row[2]);
+        row[2] = normalize(row[2]);
+        skew[1] /= scale[2];
+        skew[2] /= scale[2];
+
+        // If the coordinate system is flipped, negate the scale and the rows.
+        if dot(row[0], cross(row[1], row[2])) < 0.0 {
+            for i in 0..3 {
+                scale[i] = -scale[i];
+                row[i] = combine(row[i], row[i], -1.0, 0.0);
+            }
+        }
+
+        let mut quaternion = [
+            0.5 * (1.0 + row[0][0] - row[1][1] - row[2][2]).max(0.0).sqrt(),
+            0.5 * (1.0 - row[0][0] + row[1][1] - row[2][2]).max(0.0).sqrt(),
+            0.5 * (1.0 - row[0][0] - row[1][1] + row[2][2]).max(0.0).sqrt(),
+            0.5 * (1.0 + row[0][0] + row[1][1] + row[2][2]).max(0.0).sqrt(),
+        ];
+        if row[2][1] > row[1][2] {
+            quaternion[0] = -quaternion[0];
+        }
+        if row[0][2] > row[2][0] {
+            quaternion[1] = -quaternion[1];
+        }
+        if row[1][0] > row[0][1] {
+            quaternion[2] = -quaternion[2];
+        }
+
+        Some(DecomposedMatrix {
+            translate,
+            scale,
+            skew,
+            perspective,
+            quaternion,
+        })
+    }
+
+    /// Interpolate between two matrices by decomposing them, where `t` is the progress from `self`
+    /// (at 0) to `other` (at 1).
+    ///
+    /// Returns `None` if either matrix can't be decomposed, in which case the spec says to switch
+    /// from one to the other half way through.
+    pub fn interpolate(&self, other: &TransformMatrix, t: f64) -> Option<TransformMatrix> {
+        let from = self.decompose()?;
+        let to = other.decompose()?;
+        Some(from.interpolate(&to, t).recompose())
+    }
+}
+
+/// The parts of a decomposed `TransformMatrix`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecomposedMatrix {
+    pub translate: [f64; 3],
+    pub scale: [f64; 3],
+    /// The shear factors `xy`, `xz` and `yz`.
+    pub skew: [f64; 3],
+    pub perspective: [f64; 4],
+    /// The rotation as a unit quaternion `[x, y, z, w]`.
+    pub quaternion: [f64; 4],
+}
+
+impl DecomposedMatrix {
+    /// https://www.w3.org/TR/css-transforms-2/#recomposing-to-a-3d-matrix
+    pub fn recompose(&self) -> TransformMatrix {
+        let mut matrix = TransformMatrix::IDENTITY;
+        for i in 0..4 {
+            matrix.0[i * 4 + 3] = self.perspective[i];
+        }
+        for i in 0..4 {
+            for j in 0..3 {
+                matrix.0[12 + i] += self.translate[j] * matrix.get(j, i);
+            }
+        }
+
+        // The spec's rotation matrix is transposed relative to its own decomposition, so this
+        // uses the transpose, like browsers do.
+        let [x, y, z, w] = self.quaternion;
+        let mut rotation = TransformMatrix::IDENTITY;
+        rotation.0[0] = 1.0 - 2.0 * (y * y + z * z);
+        rotation.0[1] = 2.0 * (x * y + z * w);
+        rotation.0[2] = 2.0 * (x * z - y * w);
+        rotation.0[4] = 2.0 * (x * y - z * w);
+        rotation.0[5] = 1.0 - 2.0 * (x * x + z * z);
+        rotation.0[6] = 2.0 * (y * z + x * w);
+        rotation.0[8] = 2.0 * (x * z + y * w);
+        rotation.0[9] = 2.0 * (y * z - x * w);
+        rotation.0[10] = 1.0 - 2.0 * (x * x + y * y);
+        matrix = matrix.multiply(&rotation);
+
+        let [xy, xz, yz] = self.skew;
+        if yz != 0.0 {
+            let mut skew = TransformMatrix::IDENTITY;
+            skew.0[9] = yz;
+            matrix = matrix.multiply(&skew);
+        }
+        if xz != 0.0 {
+            let mut skew = TransformMatrix::IDENTITY;
+            skew.0[8] = xz;
+            matrix = matrix.multiply(&skew);
+        }
+        if xy != 0.0 {
+            let mut skew = TransformMatrix::IDENTITY;
+            skew.0[4] = xy;
+            matrix = matrix.multiply(&skew);
+        }
+
+        for i in 0..3 {
+            for j in 0..4 {
+                matrix.0[i * 4 + j] *= self.scale[i];
+            }
+        }
+        matrix
+    }
+
+    /// https://www.w3.org/TR/css-transforms-2/#interpolation-of-decomposed-3d-matrix-values
+    pub fn interpolate(&self, other: &DecomposedMatrix, t: f64) -> DecomposedMatrix {
+        let lerp = |a: f64, b: f64| a + (b - a) * t;
+        let mut out = *self;
+        for i in 0..3 {
+            out.translate[i] = lerp(self.translate[i], other.translate[i]);
+            out.scale[i] = lerp(self.scale[i], other.scale[i]);
+            out.skew[i] = lerp(self.skew[i], other.skew[i]);
+        }
+        for i in 0..4 {
+            out.perspective[i] = lerp(self.perspective[i], other.perspective[i]);
+        }
+        out.quaternion = slerp(self.quaternion, other.quaternion, t);
+        out
+    }
+}
+
+/// Spherical linear interpolation between two unit quaternions.
+fn slerp(a: [f64; 4], b: [f64; 4], t: f64) -> [f64; 4] {
+    let product: f64 = (0..4).map(|i| a[i] * b[i]).sum::<f64>().clamp(-1.0, 1.0);
+    if product.abs() == 1.0 {
+        return a;
+    }
+    let theta = product.acos();
+    let w = (t * theta).sin() / (1.0 - product * product).sqrt();
+    let mut out = [0.0; 4];
+    for i in 0..4 {
+        out[i] = a[i] * ((t * theta).cos() - product * w) + b[i] * w;
+    }
+    out
+}
+
+fn length(v: [f64; 3]) -> f64 {
+    dot(v, v).sqrt()
+}
+
+fn normalize(v: [f64; 3]) -> [f64; 3] {
+    let len = length(v);
+    [v[0] / len, v[1] / len, v[2] / len]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// `a * a_scale + b * b_scale`
+fn combine(a: [f64; 3], b: [f64; 3], a_scale: f64, b_scale: f64) -> [f64; 3] {
+    [
+        a[0] * a_scale + b[0] * b_scale,
+        a[1] * a_scale + b[1] * b_scale,
+        a[2] * a_scale + b[2] * b_scale,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: &TransformMatrix, b: &TransformMatrix) {
+        for i in 0..16 {
+            assert!(
+                (a.0[i] - b.0[i]).abs() < 1e-9,
+                "matrices differ at {}:\n{:?}\n{:?}",
+                i,
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn multiply_and_invert() {
+        let m = TransformMatrix::translate(10.0, 20.0, 0.0).multiply(&TransformMatrix::rotate(
+            0.0,
+            0.0,
+            1.0,
+            std::f64::consts::FRAC_PI_2,
+        ));
+        let p = m.transform_point([1.0, 0.0, 0.0]);
+        assert!((p[0] - 10.0).abs() < 1e-9 && (p[1] - 21.0).abs() < 1e-9);
+        assert_close(
+            &m.multiply(&m.invert().unwrap()),
+            &TransformMatrix::IDENTITY,
+        );
+        assert!(TransformMatrix::scale(0.0, 1.0, 1.0).invert().is_none());
+        assert!(m.is_2d());
+        assert!(!TransformMatrix::perspective(100.0).is_2d());
+    }
+
+    #[test]
+    fn decompose() {
+        let m = TransformMatrix::translate(5.0, -3.0, 2.0)
+            .multiply(&TransformMatrix::rotate(1.0, 2.0, 3.0, 0.7))
+            .multiply(&TransformMatrix::skew(0.3, 0.0))
+            .multiply(&TransformMatrix::scale(2.0, 0.5, -1.5));
+        assert_close(&m.decompose().unwrap().recompose(), &m);
+
+        // decomposing normalizes the matrix so that the bottom right entry is 1
+        let mut m = TransformMatrix::perspective(200.0).multiply(&m);
+        let w = m.0[15];
+        m.0.iter_mut().for_each(|v| *v /= w);
+        assert_close(&m.decompose().unwrap().recompose(), &m);
+
+        let d = TransformMatrix::scale(2.0, 3.0, 1.0).decompose().unwrap();
+        assert_eq!(d.scale, [2.0, 3.0, 1.0]);
+        assert!(TransformMatrix::scale(0.0, 1.0, 1.0).decompose().is_none());
+    }
+
+    #[test]
+    fn interpolate() {
+        let from = TransformMatrix::rotate(0.0, 0.0, 1.0, 0.0);
+        let to = TransformMatrix::rotate(0.0, 0.0, 1.0, 1.0);
+        let half = from.interpolate(&to, 0.5).unwrap();
+        assert_close(&half, &TransformMatrix::rotate(0.0, 0.0, 1.0, 0.5));
+
+        let from = TransformMatrix::translate(0.0, 0.0, 0.0);
+        let to = TransformMatrix::translate(10.0, 20.0, 0.0)
+            .multiply(&TransformMatrix::scale(3.0, 3.0, 1.0));
+        let half = from.interpolate(&to, 0.5).unwrap();
+        assert_close(
+            &half,
+            &TransformMatrix::translate(5.0, 10.0, 0.0)
+                .multiply(&TransformMatrix::scale(2.0, 2.0, 1.0)),
+        );
+    }
+}
@@ -0,0 +1,230 @@
+//! The `<position>` value type, used by `background-position`, `object-position`,
+//! `mask-position`, `perspective-origin` and gradients.
+//!
+//! https://www.w3.org/TR/css-values-4/#position
+use crate::{Calc, HorizontalSide, OriginValue, ReferenceBox, VerticalSide};
+use std::fmt;
+
+/// A point in a box, written with 1 to 4 values like `center`, `10px 50%` or
+/// `right 10px bottom 20%`.
+///
+/// Called `PositionValue` because `Position` is the value of the `position` property.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionValue {
+    pub x: PositionComponent<HorizontalSide>,
+    pub y: PositionComponent<VerticalSide>,
+}
+
+impl PositionValue {
+    /// The center of the box.
+    pub const CENTER: PositionValue = PositionValue {
+        x: PositionComponent::Center,
+        y: PositionComponent::Center,
+    };
+
+    /// The point in css pixels, relative to the top left of `reference`.
+    ///
+    /// For `background-position` and `object-position`, percentages are of the space left over
+    /// once the image is placed, so `reference` should be the size of the box minus the size of
+    /// the image.
+    pub fn resolve(&self, reference: &ReferenceBox) -> [f64; 2] {
+        [
+            self.x.resolve(reference.width, reference.font_size),
+            self.y.resolve(reference.height, reference.font_size),
+        ]
+    }
+
+    /// Build the position from 1 to 4 keywords and offsets, in the order they were written.
+    pub(crate) fn from_values(values: Vec<OriginValue>) -> Result<Self, &'static str> {
+        use OriginValue::*;
+        match values.len() {
+            1 => {
+                let value = values.into_iter().next().unwrap();
+                Ok(match value {
+                    Top | Bottom => PositionValue {
+                        x: PositionComponent::Center,
+                        y: y_component(value).unwrap(),
+                    },
+                    _ => PositionValue {
+                        x: x_component(value).unwrap(),
+                        y: PositionComponent::Center,
+                    },
+                })
+            }
+            2 => {
+                let mut values = values.into_iter();
+                let (first, second) = (values.next().unwrap(), values.next().unwrap());
+                // keywords can be in either order, but offsets can't be swapped
+                let swapped = matches!(first, Top | Bottom) || matches!(second, Left | Right);
+                let (x, y) = match (swapped, &first, &second) {
+                    (false, _, _) => (x_component(first), y_component(second)),
+                    (true, Offset(_), _) | (true, _, Offset(_)) => (None, None),
+                    (true, _, _) => (x_component(second), y_component(first)),
+                };
+                match (x, y) {
+                    (Some(x), Some(y)) => Ok(PositionValue { x, y }),
+                    _ => Err("expected a horizontal position followed by a vertical position"),
+                }
+            }
+            3 | 4 => {
+                // each keyword is followed by an optional offset from that side
+                let mut groups: Vec<(OriginValue, Option<Calc>)> = Vec::new();
+                for value in values {
+                    match (value, groups.last_mut()) {
+                        (Offset(offset), Some((_, last @ None))) => *last = Some(offset),
+                        (Offset(_), _) => return Err("expected a side before the offset"),
+                        (keyword, _) => groups.push((keyword, None)),
+                    }
+                }
+                if groups.len() != 2 {
+                    return Err("expected a horizontal and a vertical side, each with an offset");
+                }
+                let mut groups = groups.into_iter();
+                let (mut first, mut second) = (groups.next().unwrap(), groups.next().unwrap());
+                if matches!(first.0, Top | Bottom) || matches!(second.0, Left | Right) {
+                    std::mem::swap(&mut first, &mut second);
+                }
+                let x = match first {
+                    (Left, offset) => PositionComponent::Side(HorizontalSide::Left, offset),
+                    (Right, offset) => PositionComponent::Side(HorizontalSide::Right, offset),
+                    (Center, None) => PositionComponent::Center,
+                    _ => return Err("expected a horizontal side and a vertical side"),
+                };
+                let y = match second {
+                    (Top, offset) => PositionComponent::Side(VerticalSide::Top, offset),
+                    (Bottom, offset) => PositionComponent::Side(VerticalSide::Bottom, offset),
+                    (Center, None) => PositionComponent::Center,
+                    _ => return Err("expected a horizontal side and a vertical side"),
+                };
+                Ok(PositionValue { x, y })
+            }
+            _ => Err("expected 1 to 4 values for a position"),
+        }
+    }
+
+    /// Whether the position has to be written with the 3 or 4 value syntax.
+    fn has_side_offset(&self) -> bool {
+        matches!(self.x, PositionComponent::Side(_, Some(_)))
+            || matches!(self.y, PositionComponent::Side(_, Some(_)))
+    }
+}
+
+fn x_component(value: OriginValue) -> Option<PositionComponent<HorizontalSide>> {
+    match value {
+        OriginValue::Left => Some(PositionComponent::Side(HorizontalSide::Left, None)),
+        OriginValue::Right => Some(PositionComponent::Side(HorizontalSide::Right, None)),
+        OriginValue::Center => Some(PositionComponent::Center),
+        OriginValue::Offset(v) => Some(PositionComponent::Offset(v)),
+        OriginValue::Top | OriginValue::Bottom => None,
+    }
+}
+
+fn y_component(value: OriginValue) -> Option<PositionComponent<VerticalSide>> {
+    match value {
+        OriginValue::Top => Some(PositionComponent::Side(VerticalSide::Top, None)),
+        OriginValue::Bottom => Some(PositionComponent::Side(VerticalSide::Bottom, None)),
+        OriginValue::Center => Some(PositionComponent::Center),
+        OriginValue::Offset(v) => Some(PositionComponent::Offset(v)),
+        OriginValue::Left | OriginValue::Right => None,
+    }
+}
+
+impl fmt::Display for PositionValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.has_side_offset() {
+            return write!(f, "{} {}", self.x, self.y);
+        }
+        // Plain offsets aren't allowed alongside side offsets, so write them from the start side.
+        match &self.x {
+            PositionComponent::Offset(v) => write!(f, "{} {}", HorizontalSide::Left, v)?,
+            x => write!(f, "{}", x)?,
+        }
+        match &self.y {
+            PositionComponent::Offset(v) => write!(f, " {} {}", VerticalSide::Top, v),
+            y => write!(f, " {}", y),
+        }
+    }
+}
+
+/// One axis of a position, where `S` is the type of the sides for that axis.
+///
+/// This is also the value of `background-position-x` and `background-position-y`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PositionComponent<S> {
+    Center,
+    /// An offset from the left or top edge.
+    Offset(Calc),
+    /// A side, with an optional offset towards the center.
+    Side(S, Option<Calc>),
+}
+
+impl<S> PositionComponent<S> {
+    fn resolve_with(&self, size: f64, font_size: f64, is_end: impl Fn(&S) -> bool) -> f64 {
+        match self {
+            PositionComponent::Center => size / 2.0,
+            PositionComponent::Offset(v) => v.to_px(size, font_size),
+            PositionComponent::Side(side, offset) => {
+                let offset = offset
+                    .as_ref()
+                    .map(|v| v.to_px(size, font_size))
+                    .unwrap_or(0.0);
+                if is_end(side) {
+                    size - offset
+                } else {
+                    offset
+                }
+            }
+        }
+    }
+}
+
+impl PositionComponent<HorizontalSide> {
+    /// The distance in css pixels from the left of a box `width` wide.
+    pub fn resolve(&self, width: f64, font_size: f64) -> f64 {
+        self.resolve_with(width, font_size, |side| *side == HorizontalSide::Right)
+    }
+}
+
+impl PositionComponent<VerticalSide> {
+    /// The distance in css pixels from the top of a box `height` high.
+    pub fn resolve(&self, height: f64, font_size: f64) -> f64 {
+        self.resolve_with(height, font_size, |side| *side == VerticalSide::Bottom)
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for PositionComponent<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PositionComponent::Center => f.write_str("center"),
+            PositionComponent::Offset(v) => v.fmt(f),
+            PositionComponent::Side(side, None) => side.fmt(f),
+            PositionComponent::Side(side, Some(offset)) => write!(f, "{} {}", side, offset),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve() {
+        let reference = ReferenceBox {
+            width: 200.0,
+            height: 100.0,
+            font_size: 10.0,
+        };
+        for (input, point) in [
+            ("center", [100.0, 50.0]),
+            ("top", [100.0, 0.0]),
+            ("25% 1em", [50.0, 10.0]),
+            ("bottom right", [200.0, 100.0]),
+            ("right 10px bottom 20%", [190.0, 80.0]),
+            ("center bottom 10px", [100.0, 90.0]),
+            ("top 5px left", [0.0, 5.0]),
+        ] {
+            let position: PositionValue = input.parse().unwrap();
+            assert_eq!(position.resolve(&reference), point, "input: {}", input);
+        }
+    }
+}
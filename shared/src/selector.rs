@@ -0,0 +1,817 @@
+//! Typed selectors, from https://www.w3.org/TR/selectors-4/.
+//!
+//! Selectors are parsed from css text (see `string::parse`). The macros write the prelude of a
+//! rule out as text and then parse it the same way.
+use std::{fmt, ops::Add};
+
+/// A comma-separated list of selectors, like `a:hover, .nav > li`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SelectorList(pub Vec<Selector>);
+
+impl SelectorList {
+    /// The highest specificity of the selectors in the list, as used by `:is()`, `:not()` and
+    /// `:has()`.
+    pub fn max_specificity(&self) -> Specificity {
+        self.0
+            .iter()
+            .map(Selector::specificity)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for SelectorList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_comma_list(f, &self.0)
+    }
+}
+
+/// A complex selector: compound selectors joined by combinators, like `ul > li.active a`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Selector {
+    pub first: CompoundSelector,
+    pub rest: Vec<(Combinator, CompoundSelector)>,
+}
+
+impl Selector {
+    /// https://www.w3.org/TR/selectors-4/#specificity-rules
+    pub fn specificity(&self) -> Specificity {
+        self.rest
+            .iter()
+            .fold(self.first.specificity(), |acc, (_, compound)| {
+                acc + compound.specificity()
+            })
+    }
+
+    /// The compound selector that must match the element itself (the rightmost one).
+    pub fn last(&self) -> &CompoundSelector {
+        self.rest
+            .last()
+            .map(|(_, compound)| compound)
+            .unwrap_or(&self.first)
+    }
+}
+
+impl From<CompoundSelector> for Selector {
+    fn from(first: CompoundSelector) -> Self {
+        Selector {
+            first,
+            rest: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.first)?;
+        for (combinator, compound) in self.rest.iter() {
+            write!(f, "{}{}", combinator, compound)?;
+        }
+        Ok(())
+    }
+}
+
+/// A selector in `:has()`, which starts with an optional combinator (like `:has(> img)`).
+///
+/// https://www.w3.org/TR/selectors-4/#relative
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelativeSelector {
+    /// The relationship to the anchor element (`Descendant` if no combinator was given).
+    pub combinator: Combinator,
+    pub selector: Selector,
+}
+
+impl fmt::Display for RelativeSelector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.combinator {
+            Combinator::Descendant => write!(f, "{}", self.selector),
+            combinator => write!(f, "{} {}", combinator.symbol(), self.selector),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/selectors-4/#combinators
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combinator {
+    /// Whitespace, e.g. `ul li`.
+    Descendant,
+    /// `>`
+    Child,
+    /// `+`
+    NextSibling,
+    /// `~`
+    SubsequentSibling,
+}
+
+impl Combinator {
+    fn symbol(self) -> &'static str {
+        match self {
+            Combinator::Descendant => " ",
+            Combinator::Child => ">",
+            Combinator::NextSibling => "+",
+            Combinator::SubsequentSibling => "~",
+        }
+    }
+}
+
+impl fmt::Display for Combinator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Combinator::Descendant => f.write_str(" "),
+            other => write!(f, " {} ", other.symbol()),
+        }
+    }
+}
+
+/// A sequence of simple selectors that all apply to the same element, like `a.external:hover`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CompoundSelector {
+    /// `None` is the same as a universal selector in the default namespace.
+    pub type_selector: Option<TypeSelector>,
+    pub subclasses: Vec<SubclassSelector>,
+    pub pseudo_element: Option<PseudoElementSelector>,
+}
+
+impl CompoundSelector {
+    pub fn specificity(&self) -> Specificity {
+        let mut specificity = Specificity::default();
+        if let Some(type_selector) = &self.type_selector {
+            specificity = specificity + type_selector.specificity();
+        }
+        for subclass in self.subclasses.iter() {
+            specificity = specificity + subclass.specificity();
+        }
+        if let Some(pseudo_element) = &self.pseudo_element {
+            specificity = specificity + pseudo_element.specificity();
+        }
+        specificity
+    }
+}
+
+impl fmt::Display for CompoundSelector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.type_selector {
+            Some(type_selector) => write!(f, "{}", type_selector)?,
+            // An empty compound selector is only valid as `*`.
+            None if self.subclasses.is_empty() && self.pseudo_element.is_none() => {
+                f.write_str("*")?
+            }
+            None => (),
+        }
+        for subclass in self.subclasses.iter() {
+            write!(f, "{}", subclass)?;
+        }
+        if let Some(pseudo_element) = &self.pseudo_element {
+            write!(f, "{}", pseudo_element)?;
+        }
+        Ok(())
+    }
+}
+
+/// An element name or `*`, optionally with a namespace, like `svg|a`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeSelector {
+    pub namespace: Option<NamespacePrefix>,
+    pub name: TypeName,
+}
+
+impl TypeSelector {
+    pub fn specificity(&self) -> Specificity {
+        match self.name {
+            TypeName::Universal => Specificity::default(),
+            TypeName::Name(_) => Specificity::new(0, 0, 1),
+        }
+    }
+}
+
+impl fmt::Display for TypeSelector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(namespace) = &self.namespace {
+            write!(f, "{}", namespace)?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeName {
+    /// `*`
+    Universal,
+    Name(String),
+}
+
+impl fmt::Display for TypeName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TypeName::Universal => f.write_str("*"),
+            TypeName::Name(name) => write_ident(f, name),
+        }
+    }
+}
+
+/// The part of a type or attribute selector before the `|`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NamespacePrefix {
+    /// `*|`, any namespace (including none).
+    Any,
+    /// `|`, no namespace.
+    None,
+    /// `prefix|`, the namespace declared with `@namespace prefix ...`.
+    Named(String),
+}
+
+impl fmt::Display for NamespacePrefix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NamespacePrefix::Any => f.write_str("*|"),
+            NamespacePrefix::None => f.write_str("|"),
+            NamespacePrefix::Named(prefix) => {
+                write_ident(f, prefix)?;
+                f.write_str("|")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubclassSelector {
+    /// `#id`
+    Id(String),
+    /// `.class`
+    Class(String),
+    Attribute(AttributeSelector),
+    PseudoClass(PseudoClass),
+}
+
+impl SubclassSelector {
+    pub fn specificity(&self) -> Specificity {
+        match self {
+            SubclassSelector::Id(_) => Specificity::new(1, 0, 0),
+            SubclassSelector::Class(_) | SubclassSelector::Attribute(_) => {
+                Specificity::new(0, 1, 0)
+            }
+            SubclassSelector::PseudoClass(pseudo) => pseudo.specificity(),
+        }
+    }
+}
+
+impl fmt::Display for SubclassSelector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubclassSelector::Id(id) => {
+                f.write_str("#")?;
+                write_ident(f, id)
+            }
+            SubclassSelector::Class(class) => {
+                f.write_str(".")?;
+                write_ident(f, class)
+            }
+            SubclassSelector::Attribute(attr) => attr.fmt(f),
+            SubclassSelector::PseudoClass(pseudo) => pseudo.fmt(f),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/selectors-4/#attribute-selectors
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeSelector {
+    pub namespace: Option<NamespacePrefix>,
+    pub name: String,
+    /// `None` if the selector only checks that the attribute exists, like `[href]`.
+    pub matcher: Option<AttributeMatcher>,
+}
+
+impl fmt::Display for AttributeSelector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("[")?;
+        if let Some(namespace) = &self.namespace {
+            write!(f, "{}", namespace)?;
+        }
+        write_ident(f, &self.name)?;
+        if let Some(matcher) = &self.matcher {
+            write!(f, "{}", matcher)?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// The part of an attribute selector after the name, like `^="https" i`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeMatcher {
+    pub operator: AttributeOperator,
+    pub value: String,
+    /// The `i` or `s` flag, or `None` to use the document language's default.
+    pub case: Option<CaseSensitivity>,
+}
+
+impl fmt::Display for AttributeMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.operator)?;
+        write_string(f, &self.value)?;
+        match self.case {
+            Some(CaseSensitivity::Insensitive) => f.write_str(" i"),
+            Some(CaseSensitivity::Sensitive) => f.write_str(" s"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeOperator {
+    /// `=`
+    Equals,
+    /// `~=`, one of a whitespace-separated list of words.
+    Includes,
+    /// `|=`, exactly the value or the value followed by `-`.
+    DashMatch,
+    /// `^=`
+    Prefix,
+    /// `$=`
+    Suffix,
+    /// `*=`
+    Substring,
+}
+
+impl fmt::Display for AttributeOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            AttributeOperator::Equals => "=",
+            AttributeOperator::Includes => "~=",
+            AttributeOperator::DashMatch => "|=",
+            AttributeOperator::Prefix => "^=",
+            AttributeOperator::Suffix => "$=",
+            AttributeOperator::Substring => "*=",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseSensitivity {
+    /// `i`
+    Insensitive,
+    /// `s`
+    Sensitive,
+}
+
+/// https://www.w3.org/TR/selectors-4/#pseudo-classes
+///
+/// Pseudo-classes that depend on the structure of the document are parsed into their own
+/// variants. All others (e.g. `:hover`, `:lang(en)`) are kept by name.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PseudoClass {
+    /// `:is()`, which ignores selectors in the list that fail to parse.
+    Is(SelectorList),
+    /// `:where()`, like `:is()` but with zero specificity.
+    Where(SelectorList),
+    Not(SelectorList),
+    Has(Vec<RelativeSelector>),
+    /// `:nth-child(An+B [of S]?)`
+    NthChild(Nth, Option<SelectorList>),
+    /// `:nth-last-child(An+B [of S]?)`
+    NthLastChild(Nth, Option<SelectorList>),
+    NthOfType(Nth),
+    NthLastOfType(Nth),
+    FirstChild,
+    LastChild,
+    OnlyChild,
+    FirstOfType,
+    LastOfType,
+    OnlyOfType,
+    Root,
+    Empty,
+    /// Any other pseudo-class without arguments, like `:hover`. The name is lowercase.
+    Named(String),
+    /// Any other functional pseudo-class, like `:lang(en)`. The name is lowercase, and the
+    /// arguments are kept as text.
+    Function(String, String),
+}
+
+impl PseudoClass {
+    pub fn specificity(&self) -> Specificity {
+        match self {
+            PseudoClass::Is(list) | PseudoClass::Not(list) => list.max_specificity(),
+            PseudoClass::Where(_) => Specificity::default(),
+            PseudoClass::Has(list) => list
+                .iter()
+                .map(|relative| relative.selector.specificity())
+                .max()
+                .unwrap_or_default(),
+            PseudoClass::NthChild(_, Some(of)) | PseudoClass::NthLastChild(_, Some(of)) => {
+                Specificity::new(0, 1, 0) + of.max_specificity()
+            }
+            _ => Specificity::new(0, 1, 0),
+        }
+    }
+}
+
+impl fmt::Display for PseudoClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn nth(
+            f: &mut fmt::Formatter,
+            name: &str,
+            nth: &Nth,
+            of: &Option<SelectorList>,
+        ) -> fmt::Result {
+            write!(f, ":{}({}", name, nth)?;
+            if let Some(of) = of {
+                write!(f, " of {}", of)?;
+            }
+            f.write_str(")")
+        }
+        match self {
+            PseudoClass::Is(list) => write!(f, ":is({})", list),
+            PseudoClass::Where(list) => write!(f, ":where({})", list),
+            PseudoClass::Not(list) => write!(f, ":not({})", list),
+            PseudoClass::Has(list) => {
+                f.write_str(":has(")?;
+                write_comma_list(f, list)?;
+                f.write_str(")")
+            }
+            PseudoClass::NthChild(n, of) => nth(f, "nth-child", n, of),
+            PseudoClass::NthLastChild(n, of) => nth(f, "nth-last-child", n, of),
+            PseudoClass::NthOfType(n) => nth(f, "nth-of-type", n, &None),
+            PseudoClass::NthLastOfType(n) => nth(f, "nth-last-of-type", n, &None),
+            PseudoClass::FirstChild => f.write_str(":first-child"),
+            PseudoClass::LastChild => f.write_str(":last-child"),
+            PseudoClass::OnlyChild => f.write_str(":only-child"),
+            PseudoClass::FirstOfType => f.write_str(":first-of-type"),
+            PseudoClass::LastOfType => f.write_str(":last-of-type"),
+            PseudoClass::OnlyOfType => f.write_str(":only-of-type"),
+            PseudoClass::Root => f.write_str(":root"),
+            PseudoClass::Empty => f.write_str(":empty"),
+            PseudoClass::Named(name) => {
+                f.write_str(":")?;
+                write_ident(f, name)
+            }
+            PseudoClass::Function(name, args) => {
+                f.write_str(":")?;
+                write_ident(f, name)?;
+                write!(f, "({})", args)
+            }
+        }
+    }
+}
+
+/// The `An+B` argument of `:nth-child()` and friends, which matches the indices `a*n + b` for
+/// `n = 0, 1, 2, ...`.
+///
+/// https://www.w3.org/TR/css-syntax-3/#anb-microsyntax
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Nth {
+    pub a: i32,
+    pub b: i32,
+}
+
+impl Nth {
+    pub fn new(a: i32, b: i32) -> Self {
+        Nth { a, b }
+    }
+}
+
+impl fmt::Display for Nth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.a {
+            0 => return write!(f, "{}", self.b),
+            1 => f.write_str("n")?,
+            -1 => f.write_str("-n")?,
+            a => write!(f, "{}n", a)?,
+        }
+        match self.b {
+            0 => Ok(()),
+            b if b > 0 => write!(f, "+{}", b),
+            b => write!(f, "{}", b),
+        }
+    }
+}
+
+/// A pseudo-element, along with any pseudo-classes that follow it (like `::before:hover`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PseudoElementSelector {
+    pub element: PseudoElement,
+    pub pseudo_classes: Vec<PseudoClass>,
+}
+
+impl PseudoElementSelector {
+    pub fn specificity(&self) -> Specificity {
+        self.pseudo_classes
+            .iter()
+            .fold(Specificity::new(0, 0, 1), |acc, pseudo| {
+                acc + pseudo.specificity()
+            })
+    }
+}
+
+impl fmt::Display for PseudoElementSelector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.element)?;
+        for pseudo in self.pseudo_classes.iter() {
+            write!(f, "{}", pseudo)?;
+        }
+        Ok(())
+    }
+}
+
+/// https://www.w3.org/TR/selectors-4/#pseudo-elements
+#[derive(Debug, Clone, PartialEq)]
+pub enum PseudoElement {
+    Before,
+    After,
+    FirstLine,
+    FirstLetter,
+    Marker,
+    Placeholder,
+    Selection,
+    Backdrop,
+    /// Any other pseudo-element, like `::-webkit-scrollbar`. The name is lowercase.
+    Named(String),
+    /// A functional pseudo-element, like `::part(label)`. The name is lowercase, and the
+    /// arguments are kept as text.
+    Function(String, String),
+}
+
+impl PseudoElement {
+    /// The pseudo-element with name `name`, which should be lowercase.
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "before" => PseudoElement::Before,
+            "after" => PseudoElement::After,
+            "first-line" => PseudoElement::FirstLine,
+            "first-letter" => PseudoElement::FirstLetter,
+            "marker" => PseudoElement::Marker,
+            "placeholder" => PseudoElement::Placeholder,
+            "selection" => PseudoElement::Selection,
+            "backdrop" => PseudoElement::Backdrop,
+            other => PseudoElement::Named(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for PseudoElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("::")?;
+        match self {
+            PseudoElement::Before => f.write_str("before"),
+            PseudoElement::After => f.write_str("after"),
+            PseudoElement::FirstLine => f.write_str("first-line"),
+            PseudoElement::FirstLetter => f.write_str("first-letter"),
+            PseudoElement::Marker => f.write_str("marker"),
+            PseudoElement::Placeholder => f.write_str("placeholder"),
+            PseudoElement::Selection => f.write_str("selection"),
+            PseudoElement::Backdrop => f.write_str("backdrop"),
+            PseudoElement::Named(name) => write_ident(f, name),
+            PseudoElement::Function(name, args) => {
+                write_ident(f, name)?;
+                write!(f, "({})", args)
+            }
+        }
+    }
+}
+
+/// The specificity of a selector, which is compared lexicographically: the number of id
+/// selectors, then classes (including attributes and pseudo-classes), then types (including
+/// pseudo-elements).
+///
+/// https://www.w3.org/TR/selectors-4/#specificity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Specificity {
+    pub ids: u32,
+    pub classes: u32,
+    pub types: u32,
+}
+
+impl Specificity {
+    pub fn new(ids: u32, classes: u32, types: u32) -> Self {
+        Specificity {
+            ids,
+            classes,
+            types,
+        }
+    }
+}
+
+impl Add for Specificity {
+    type Output = Specificity;
+
+    fn add(self, other: Specificity) -> Specificity {
+        Specificity {
+            ids: self.ids + other.ids,
+            classes: self.classes + other.classes,
+            types: self.types + other.types,
+        }
+    }
+}
+
+impl fmt::Display for Specificity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.ids, self.classes, self.types)
+    }
+}
+
+fn write_comma_list<T: fmt::Display>(f: &mut fmt::Formatter, list: &[T]) -> fmt::Result {
+    for (idx, item) in list.iter().enumerate() {
+        if idx > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{}", item)?;
+    }
+    Ok(())
+}
+
+/// Write `ident` as a css identifier, escaping characters where necessary.
+///
+/// https://drafts.csswg.org/cssom/#serialize-an-identifier
+pub(crate) fn write_ident(f: &mut impl fmt::Write, ident: &str) -> fmt::Result {
+    if ident == "-" {
+        return f.write_str("\\-");
+    }
+    let starts_with_hyphen = ident.starts_with('-');
+    for (idx, ch) in ident.chars().enumerate() {
+        match ch {
+            '\0' => f.write_char('\u{fffd}')?,
+            '\u{1}'..='\u{1f}' | '\u{7f}' => write!(f, "\\{:x} ", ch as u32)?,
+            '0'..='9' if idx == 0 || (idx == 1 && starts_with_hyphen) => {
+                write!(f, "\\{:x} ", ch as u32)?
+            }
+            '-' | '_' | '0'..='9' | 'a'..='z' | 'A'..='Z' => f.write_char(ch)?,
+            ch if ch as u32 >= 0x80 => f.write_char(ch)?,
+            ch => write!(f, "\\{}", ch)?,
+        }
+    }
+    Ok(())
+}
+
+/// Write `value` as a double-quoted css string.
+///
+/// https://drafts.csswg.org/cssom/#serialize-a-string
+pub(crate) fn write_string(f: &mut impl fmt::Write, value: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for ch in value.chars() {
+        match ch {
+            '\0' => f.write_char('\u{fffd}')?,
+            '\u{1}'..='\u{1f}' | '\u{7f}' => write!(f, "\\{:x} ", ch as u32)?,
+            '"' | '\\' => write!(f, "\\{}", ch)?,
+            ch => f.write_char(ch)?,
+        }
+    }
+    f.write_char('"')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for (input, output) in [
+            ("*", "*"),
+            ("a", "a"),
+            ("*.a", ".a"),
+            ("svg|a, *|*, |b", "svg|a, *|*, |b"),
+            ("ul   li>a +b~ c", "ul li > a + b ~ c"),
+            ("a#x.y.z", "a#x.y.z"),
+            ("[href]", "[href]"),
+            ("[ href = x ]", "[href=\"x\"]"),
+            ("[lang|=en]", "[lang|=\"en\"]"),
+            (
+                "[a~='b' i][c^=d S][e$=f][g*=h]",
+                "[a~=\"b\" i][c^=\"d\" s][e$=\"f\"][g*=\"h\"]",
+            ),
+            ("[*|a][xml|lang]", "[*|a][xml|lang]"),
+            ("a:HOVER:first-child", "a:hover:first-child"),
+            ("a:is(.b, !, .c)", "a:is(.b, .c)"),
+            ("a:is(.b, ::before, .c)", "a:is(.b, .c)"),
+            ("a:where(b > c)", "a:where(b > c)"),
+            ("a:not(.b, .c)", "a:not(.b, .c)"),
+            ("a:has(> img, + b, c)", "a:has(> img, + b, c)"),
+            (
+                "li:nth-child( 2n + 1 of .a, .b)",
+                "li:nth-child(2n+1 of .a, .b)",
+            ),
+            ("li:nth-last-child(-n+3)", "li:nth-last-child(-n+3)"),
+            (
+                "li:nth-of-type(odd):nth-last-of-type(even)",
+                "li:nth-of-type(2n+1):nth-last-of-type(2n)",
+            ),
+            ("li:nth-child(5)", "li:nth-child(5)"),
+            ("p:lang(en)", "p:lang(en)"),
+            ("p:before", "p::before"),
+            ("p::first-line", "p::first-line"),
+            ("::selection:window-inactive", "::selection:window-inactive"),
+            ("::part(label)", "::part(label)"),
+            (".\\31 0", ".\\31 0"),
+            ("#a\\.b", "#a\\.b"),
+        ] {
+            let selectors: SelectorList = match input.parse() {
+                Ok(selectors) => selectors,
+                Err(e) => panic!("error parsing {:?}: {}", input, e),
+            };
+            assert_eq!(selectors.to_string(), output, "input: {:?}", input);
+            assert_eq!(output.parse::<SelectorList>().unwrap(), selectors);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for input in [
+            "",
+            "a,",
+            "a >",
+            "> a",
+            "a. b",
+            "a: hover",
+            "#1",
+            "[a=]",
+            "[a~ =b]",
+            "[a=b c]",
+            "a|",
+            "::before.a",
+            "::before::after",
+            "a::before .b",
+            "a::before > b",
+            "::after:hover b",
+            ":has(:has(a))",
+            ":has(a :is(b:has(c)))",
+            ":has(::before)",
+            ":has(> a::after)",
+            ":not(::before)",
+            ":nth-child(2n of a::before)",
+            ":not(.a,)",
+            ":has()",
+            ":nth-child(2n+)",
+            ":nth-child(n 1)",
+            ":nth-child(1.5n)",
+            ":nth-of-type(2n of a)",
+            ":nth-child",
+        ] {
+            assert!(input.parse::<SelectorList>().is_err(), "input: {:?}", input);
+        }
+    }
+
+    #[test]
+    fn nesting_limit() {
+        let nested = |depth| format!("{}a{}", ":is(".repeat(depth), ")".repeat(depth));
+        assert!(nested(64).parse::<SelectorList>().is_ok());
+        let err = nested(65).parse::<SelectorList>().unwrap_err();
+        // the error is at the name of the 65th `:is(`
+        assert_eq!(err.span().start(), 64 * 4 + 1);
+        // this would overflow the stack without the limit
+        assert!(nested(200_000).parse::<SelectorList>().is_err());
+        assert!(":not(".repeat(200_000).parse::<SelectorList>().is_err());
+    }
+
+    #[test]
+    fn nth() {
+        for (input, a, b) in [
+            ("odd", 2, 1),
+            ("EVEN", 2, 0),
+            ("3", 0, 3),
+            ("-3", 0, -3),
+            ("n", 1, 0),
+            ("+n", 1, 0),
+            ("-n-1", -1, -1),
+            ("2n-1", 2, -1),
+            ("-2n- 1", -2, -1),
+            ("10N + 10", 10, 10),
+        ] {
+            let selector = format!(":nth-child({})", input);
+            let selectors: SelectorList = selector.parse().unwrap();
+            match &selectors.0[0].first.subclasses[0] {
+                SubclassSelector::PseudoClass(PseudoClass::NthChild(nth, None)) => {
+                    assert_eq!(*nth, Nth::new(a, b), "input: {:?}", input)
+                }
+                other => panic!("unexpected {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn specificity() {
+        for (input, ids, classes, types) in [
+            ("*", 0, 0, 0),
+            ("li", 0, 0, 1),
+            ("ul li", 0, 0, 2),
+            ("ul ol+li", 0, 0, 3),
+            ("h1 + *[rel=up]", 0, 1, 1),
+            ("ul ol li.red", 0, 1, 3),
+            ("li.red.level", 0, 2, 1),
+            ("#x34y", 1, 0, 0),
+            ("#s12:not(FOO)", 1, 0, 1),
+            (".foo :is(.bar, #baz)", 1, 1, 0),
+            (".foo :where(.bar, #baz)", 0, 1, 0),
+            ("a:has(> #b, c)", 1, 0, 1),
+            ("li:nth-child(2n of .a, #b)", 1, 1, 1),
+            ("li:nth-child(2n)", 0, 1, 1),
+            ("p::before:hover", 0, 1, 2),
+        ] {
+            let selectors: SelectorList = input.parse().unwrap();
+            assert_eq!(
+                selectors.0[0].specificity(),
+                Specificity::new(ids, classes, types),
+                "input: {:?}",
+                input
+            );
+        }
+        assert!(Specificity::new(1, 0, 0) > Specificity::new(0, 10, 10));
+        assert!(Specificity::new(0, 1, 0) > Specificity::new(0, 0, 10));
+    }
+}
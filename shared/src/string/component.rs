@@ -0,0 +1,466 @@
+//! Group tokens into component values: blocks, functions, and everything else.
+//!
+//! This is the "consume a component value" layer from
+//! https://www.w3.org/TR/css-syntax-3/#parsing. It doesn't know anything about particular rules
+//! or properties, so it is shared by everything that needs to find the extent of a declaration,
+//! a rule prelude or a block without fully parsing it.
+use super::{
+    lexer::{Lexer, Span, Token, TokenKind},
+    parse::ParseError,
+};
+
+/// https://www.w3.org/TR/css-syntax-3/#component-value
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComponentValue {
+    /// Any token that isn't the start of a block or function (including unmatched closing
+    /// brackets).
+    Preserved(Token),
+    Function(Function),
+    Block(SimpleBlock),
+}
+
+impl ComponentValue {
+    /// The span of the source that this value covers.
+    pub fn span(&self) -> Span {
+        match self {
+            ComponentValue::Preserved(tok) => tok.span,
+            ComponentValue::Function(f) => f.span,
+            ComponentValue::Block(b) => b.span,
+        }
+    }
+
+    /// The token, if this is a preserved token.
+    pub fn token(&self) -> Option<&Token> {
+        match self {
+            ComponentValue::Preserved(tok) => Some(tok),
+            _ => None,
+        }
+    }
+
+    /// Whether this is a preserved token of the given kind.
+    pub fn is(&self, kind: &TokenKind) -> bool {
+        matches!(self.token(), Some(tok) if tok.kind == *kind)
+    }
+
+    /// Whether this is whitespace.
+    pub fn is_whitespace(&self) -> bool {
+        self.is(&TokenKind::Whitespace)
+    }
+}
+
+/// A function, like `calc(100% - 10px)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function {
+    /// The name of the function, with escapes replaced.
+    pub name: String,
+    /// The arguments, not including the closing `)`.
+    pub args: Vec<ComponentValue>,
+    /// The span of the name and the opening `(`.
+    pub open: Span,
+    /// The span of the closing `)`, or `None` if the function was closed by the end of input.
+    pub close: Option<Span>,
+    /// The span from the name to the closing `)` (or the last argument if it was unclosed).
+    pub span: Span,
+}
+
+/// A block, like `{ color: red }`.
+///
+/// https://www.w3.org/TR/css-syntax-3/#simple-block
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimpleBlock {
+    pub kind: BlockKind,
+    /// The content, not including the brackets.
+    pub values: Vec<ComponentValue>,
+    /// The span of the opening bracket.
+    pub open: Span,
+    /// The span of the closing bracket, or `None` if the block was closed by the end of input.
+    pub close: Option<Span>,
+    /// The span including the brackets (or up to the last value if it was unclosed).
+    pub span: Span,
+}
+
+/// The type of bracket that a block uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockKind {
+    /// `{}`
+    Brace,
+    /// `()`
+    Paren,
+    /// `[]`
+    Bracket,
+}
+
+impl BlockKind {
+    /// The block kind started by `kind`, if any.
+    fn opened_by(kind: &TokenKind) -> Option<Self> {
+        match kind {
+            TokenKind::LBrace => Some(BlockKind::Brace),
+            TokenKind::LParen => Some(BlockKind::Paren),
+            TokenKind::LBracket => Some(BlockKind::Bracket),
+            _ => None,
+        }
+    }
+
+    /// The token that opens this kind of block.
+    pub fn opening(self) -> TokenKind {
+        match self {
+            BlockKind::Brace => TokenKind::LBrace,
+            BlockKind::Paren => TokenKind::LParen,
+            BlockKind::Bracket => TokenKind::LBracket,
+        }
+    }
+
+    /// The token that closes this kind of block.
+    pub fn closing(self) -> TokenKind {
+        match self {
+            BlockKind::Brace => TokenKind::RBrace,
+            BlockKind::Paren => TokenKind::RParen,
+            BlockKind::Bracket => TokenKind::RBracket,
+        }
+    }
+
+    fn closing_char(self) -> char {
+        match self {
+            BlockKind::Brace => '}',
+            BlockKind::Paren => ')',
+            BlockKind::Bracket => ']',
+        }
+    }
+}
+
+/// How deeply blocks and functions can be nested. Consuming a block recurses, so this stops
+/// untrusted input from overflowing the stack.
+pub const MAX_NESTING: usize = 64;
+
+/// Parse a list of component values from css text.
+///
+/// Parsing always succeeds: unclosed blocks and functions are closed at the end of input, and
+/// unmatched closing brackets are kept as preserved tokens. The errors that were recovered from
+/// are returned alongside the values.
+///
+/// https://www.w3.org/TR/css-syntax-3/#parse-list-of-component-values
+pub fn parse_component_values(src: &str) -> (Vec<ComponentValue>, Vec<ParseError>) {
+    let mut parser = ComponentParser::new(src);
+    let values = parser.consume_list();
+    (values, parser.errors)
+}
+
+/// Consumes component values from a `Lexer`, keeping track of the errors it recovers from.
+///
+/// Comments are dropped, but whitespace is kept. Blocks and functions nested more than
+/// `MAX_NESTING` deep aren't consumed: their opening token is kept as a preserved token, and
+/// `nesting_error` is set.
+#[derive(Debug, Clone)]
+pub struct ComponentParser<'src> {
+    lexer: Lexer<'src>,
+    errors: Vec<ParseError>,
+    depth: usize,
+    nesting_error: Option<ParseError>,
+}
+
+impl<'src> ComponentParser<'src> {
+    pub fn new(src: &'src str) -> Self {
+        ComponentParser {
+            lexer: Lexer::new(src),
+            errors: Vec::new(),
+            depth: 0,
+            nesting_error: None,
+        }
+    }
+
+    /// The errors recovered from so far.
+    pub fn errors(&self) -> &[ParseError] {
+        &self.errors
+    }
+
+    /// Take the errors recovered from so far.
+    pub fn take_errors(&mut self) -> Vec<ParseError> {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// The first block or function that was nested too deeply, if any.
+    ///
+    /// Unlike the other errors, the values can't be recovered, so this should be treated as a
+    /// failure.
+    pub fn nesting_error(&self) -> Option<&ParseError> {
+        self.nesting_error.as_ref()
+    }
+
+    /// Consume the next token, skipping comments.
+    pub fn next_token(&mut self) -> Option<Token> {
+        loop {
+            match self.lexer.next_token()? {
+                Token {
+                    kind: TokenKind::Comment,
+                    ..
+                } => (),
+                Token {
+                    kind: TokenKind::UnclosedComment,
+                    span,
+                } => self.errors.push(ParseError::new(span, "unclosed comment")),
+                tok => return Some(tok),
+            }
+        }
+    }
+
+    /// Peek the next token, skipping comments.
+    pub fn peek_token(&self) -> Option<Token> {
+        self.clone().next_token()
+    }
+
+    /// Consume component values until the end of input.
+    pub fn consume_list(&mut self) -> Vec<ComponentValue> {
+        let mut values = Vec::new();
+        while let Some(value) = self.consume_component_value() {
+            values.push(value);
+        }
+        values
+    }
+
+    /// https://www.w3.org/TR/css-syntax-3/#consume-component-value
+    pub fn consume_component_value(&mut self) -> Option<ComponentValue> {
+        let tok = self.next_token()?;
+        Some(self.component_value_from(tok))
+    }
+
+    /// Finish consuming a component value that starts with `tok`.
+    pub fn component_value_from(&mut self, tok: Token) -> ComponentValue {
+        let opens =
+            BlockKind::opened_by(&tok.kind).is_some() || matches!(tok.kind, TokenKind::Function(_));
+        if opens && self.depth == MAX_NESTING {
+            let err = ParseError::new(
+                tok.span,
+                format!("blocks and functions nested more than {} deep", MAX_NESTING),
+            );
+            self.nesting_error.get_or_insert_with(|| err.clone());
+            self.errors.push(err);
+            return ComponentValue::Preserved(tok);
+        }
+        if let Some(kind) = BlockKind::opened_by(&tok.kind) {
+            ComponentValue::Block(self.consume_block(kind, tok.span))
+        } else if let TokenKind::Function(name) = tok.kind {
+            let (args, close, span) = self.consume_until(BlockKind::Paren, tok.span);
+            ComponentValue::Function(Function {
+                name,
+                args,
+                open: tok.span,
+                close,
+                span,
+            })
+        } else {
+            ComponentValue::Preserved(tok)
+        }
+    }
+
+    /// Consume the rest of a block, where `open` is the span of the opening bracket.
+    ///
+    /// https://www.w3.org/TR/css-syntax-3/#consume-simple-block
+    pub fn consume_block(&mut self, kind: BlockKind, open: Span) -> SimpleBlock {
+        let (values, close, span) = self.consume_until(kind, open);
+        SimpleBlock {
+            kind,
+            values,
+            open,
+            close,
+            span,
+        }
+    }
+
+    /// Consume component values up to and including the closing bracket of `kind`.
+    fn consume_until(
+        &mut self,
+        kind: BlockKind,
+        open: Span,
+    ) -> (Vec<ComponentValue>, Option<Span>, Span) {
+        let closing = kind.closing();
+        let mut values = Vec::new();
+        self.depth += 1;
+        let close = loop {
+            match self.next_token() {
+                Some(tok) if tok.kind == closing => break Some(tok.span),
+                Some(tok) => values.push(self.component_value_from(tok)),
+                None => {
+                    self.errors.push(ParseError::new(
+                        open,
+                        format!("missing closing `{}`", kind.closing_char()),
+                    ));
+                    break None;
+                }
+            }
+        };
+        self.depth -= 1;
+        let span = match close {
+            Some(close) => open.join(close),
+            None => values.last().map(|v| open.join(v.span())).unwrap_or(open),
+        };
+        (values, close, span)
+    }
+}
+
+/// A token from a flattened list of component values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlatToken {
+    pub token: Token,
+    /// The number of tokens in the component value that this token starts, including this one.
+    /// It's 1 for preserved tokens, and for tokens inside a block or function that don't start
+    /// a component value (its closing bracket).
+    pub len: usize,
+}
+
+/// Turn component values back into a list of tokens, recording where each block and function
+/// ends so they can be skipped over without matching brackets again.
+///
+/// Every block and function ends with its closing token. Those that were closed by the end of
+/// input get an empty one at `end`.
+pub fn flatten(values: &[ComponentValue], end: usize) -> Vec<FlatToken> {
+    let mut out = Vec::new();
+    for value in values {
+        flatten_value(value, end, &mut out);
+    }
+    out
+}
+
+fn flatten_value(value: &ComponentValue, end: usize, out: &mut Vec<FlatToken>) {
+    let (open, children, kind, close) = match value {
+        ComponentValue::Preserved(token) => {
+            out.push(FlatToken {
+                token: token.clone(),
+                len: 1,
+            });
+            return;
+        }
+        ComponentValue::Function(f) => (
+            Token {
+                kind: TokenKind::Function(f.name.clone()),
+                span: f.open,
+            },
+            &f.args,
+            BlockKind::Paren,
+            f.close,
+        ),
+        ComponentValue::Block(b) => (
+            Token {
+                kind: b.kind.opening(),
+                span: b.open,
+            },
+            &b.values,
+            b.kind,
+            b.close,
+        ),
+    };
+    let start = out.len();
+    out.push(FlatToken {
+        token: open,
+        len: 0,
+    });
+    for child in children {
+        flatten_value(child, end, out);
+    }
+    out.push(FlatToken {
+        token: Token {
+            kind: kind.closing(),
+            span: close.unwrap_or_else(|| Span::new(end, end)),
+        },
+        len: 1,
+    });
+    out[start].len = out.len() - start;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A compact description of some component values for comparing in tests.
+    fn describe(values: &[ComponentValue]) -> String {
+        values
+            .iter()
+            .map(|value| match value {
+                ComponentValue::Preserved(tok) => match &tok.kind {
+                    TokenKind::Ident(name) => name.clone(),
+                    TokenKind::Whitespace => "_".into(),
+                    kind => format!("{:?}", kind),
+                },
+                ComponentValue::Function(f) => format!("{}({})", f.name, describe(&f.args)),
+                ComponentValue::Block(b) => {
+                    let close = b.kind.closing_char();
+                    let open = match close {
+                        '}' => '{',
+                        ')' => '(',
+                        _ => '[',
+                    };
+                    format!("{}{}{}", open, describe(&b.values), close)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn blocks_and_functions() {
+        let (values, errors) = parse_component_values("a { b: f(c, [d]) } /* e */ (g)");
+        assert!(errors.is_empty());
+        assert_eq!(
+            describe(&values),
+            "a _ {_ b Colon _ f(c Comma _ [d]) _} _ _ (g)"
+        );
+        let src = "a { b: f(c) }";
+        let (values, _) = parse_component_values(src);
+        assert_eq!(values[2].span(), Span::new(2, src.len()));
+        match &values[2] {
+            ComponentValue::Block(block) => assert_eq!(block.values[4].span(), Span::new(7, 11)),
+            _ => panic!("expected block"),
+        }
+    }
+
+    #[test]
+    fn recovery() {
+        for (input, output, error_count) in [
+            // unmatched closing brackets are preserved tokens
+            ("a)b", "a RParen b", 0),
+            ("{ a ] }", "{_ a _ RBracket _}", 0),
+            // the closing bracket must match the block
+            ("( } )", "(_ RBrace _)", 0),
+            // unclosed blocks are closed at the end of input
+            ("f(a", "f(a)", 1),
+            ("{ ( }", "{_ (_ RBrace)}", 2),
+            ("[a /* b", "[a _]", 2),
+        ] {
+            let (values, errors) = parse_component_values(input);
+            assert_eq!(describe(&values), output, "input: {:?}", input);
+            assert_eq!(errors.len(), error_count, "input: {:?}", input);
+        }
+
+        let (_, errors) = parse_component_values("a f(b");
+        assert_eq!(errors[0].span(), Span::new(2, 4));
+        assert_eq!(errors[0].message(), "missing closing `)`");
+    }
+
+    #[test]
+    fn flatten_values() {
+        let src = "f(a) [b {";
+        let (values, _) = parse_component_values(src);
+        let tokens = flatten(&values, src.len());
+        let lens = tokens.iter().map(|tok| tok.len).collect::<Vec<_>>();
+        assert_eq!(lens, vec![3, 1, 1, 1, 6, 1, 1, 2, 1, 1]);
+        assert_eq!(tokens[9].token.kind, TokenKind::RBracket);
+        assert_eq!(tokens[9].token.span, Span::new(src.len(), src.len()));
+        assert_eq!(tokens[8].token.kind, TokenKind::RBrace);
+    }
+
+    #[test]
+    fn nesting_limit() {
+        let src = "(".repeat(MAX_NESTING);
+        let mut parser = ComponentParser::new(&src);
+        parser.consume_list();
+        assert!(parser.nesting_error().is_none());
+
+        let src = "(".repeat(MAX_NESTING + 1);
+        let mut parser = ComponentParser::new(&src);
+        parser.consume_list();
+        let err = parser.nesting_error().unwrap();
+        assert_eq!(err.span(), Span::new(MAX_NESTING, MAX_NESTING + 1));
+
+        let (values, _) = parse_component_values(&"f([{".repeat(200_000));
+        assert_eq!(values.len(), 1);
+    }
+}
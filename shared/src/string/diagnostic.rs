@@ -0,0 +1,188 @@
+//! Render errors from the lexer and parser with a snippet of the source that caused them.
+//!
+//! The output looks like
+//!
+//! ```text
+//! error: expected one of `block`, `flex`, `inline`
+//!  --> 1:10
+//!   |
+//! 1 | display: flx
+//!   |          ^^^
+//! ```
+use super::{
+    lexer::{SourceMap, Span},
+    parse::ParseError,
+};
+use std::fmt;
+
+/// An error message along with the source it refers to, which renders the offending source with
+/// the error location underlined.
+#[derive(Debug, Clone)]
+pub struct Diagnostic<'src> {
+    source_map: SourceMap<'src>,
+    span: Span,
+    message: String,
+}
+
+impl<'src> Diagnostic<'src> {
+    /// Create a diagnostic for `span` in `src`.
+    ///
+    /// Panics if `span` is not within `src`.
+    pub fn new(src: &'src str, span: Span, message: impl fmt::Display) -> Self {
+        Self::with_source_map(SourceMap::new(src), span, message)
+    }
+
+    /// Create a diagnostic using an existing source map (e.g. from `Lexer::source_map`).
+    pub fn with_source_map(
+        source_map: SourceMap<'src>,
+        span: Span,
+        message: impl fmt::Display,
+    ) -> Self {
+        assert!(
+            span.end() <= source_map.source().len(),
+            "span is outside the source"
+        );
+        Diagnostic {
+            source_map,
+            span,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (start, end) = self.source_map.span_line_cols(self.span);
+        let line = self.source_map.line(start.line);
+        // Spans over multiple lines are underlined to the end of their first line.
+        let end_column = if end.line == start.line {
+            end.column
+        } else {
+            line.chars().count() + 1
+        };
+        let gutter = " ".repeat(start.line.to_string().len());
+
+        writeln!(f, "error: {}", self.message)?;
+        writeln!(f, "{}--> {}", gutter, start)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", start.line, line)?;
+        write!(f, "{} | ", gutter)?;
+        // Copy tabs from the source so the carets line up.
+        for ch in line.chars().take(start.column - 1) {
+            f.write_str(if ch == '\t' { "\t" } else { " " })?;
+        }
+        // Always show at least one caret, even for an empty span (e.g. at the end of input).
+        for _ in 0..(end_column - start.column).max(1) {
+            f.write_str("^")?;
+        }
+        Ok(())
+    }
+}
+
+impl ParseError {
+    /// Render this error, showing where it occurred in `src`.
+    ///
+    /// `src` must be the input that was parsed.
+    pub fn diagnostic<'src>(&self, src: &'src str) -> Diagnostic<'src> {
+        Diagnostic::new(src, self.span(), self.message())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{string::lexer::LineCol, Style};
+
+    #[test]
+    fn source_map() {
+        let map = SourceMap::new("a\nbé c\n\nd");
+        assert_eq!(map.line_count(), 4);
+        for (pos, line, column) in [
+            (0, 1, 1),
+            (1, 1, 2),
+            (2, 2, 1),
+            (5, 2, 3),
+            (8, 3, 1),
+            (9, 4, 1),
+            (10, 4, 2),
+        ] {
+            assert_eq!(map.line_col(pos), LineCol { line, column }, "pos {}", pos);
+        }
+        assert_eq!(map.line(2), "bé c");
+        assert_eq!(map.line(3), "");
+        assert_eq!(map.line(4), "d");
+
+        let map = SourceMap::new("a\r\nb\rc\u{c}d");
+        assert_eq!(map.line_count(), 4);
+        assert_eq!(map.line_col(3), LineCol { line: 2, column: 1 });
+        assert_eq!(map.line_col(5), LineCol { line: 3, column: 1 });
+        assert_eq!(map.line_col(7), LineCol { line: 4, column: 1 });
+        assert_eq!(map.line(1), "a");
+        assert_eq!(map.line(2), "b");
+        assert_eq!(map.line(3), "c");
+    }
+
+    #[test]
+    fn parse_error() {
+        let src = "display: block;\ncolor: red;\n  display: flx;";
+        let (_, errors) = crate::Styles::parse_with_errors(src).unwrap();
+        assert_eq!(
+            errors[0].diagnostic(src).to_string(),
+            "error: expected one of `block`, `flex`, `inline`\n \
+              --> 3:12\n  \
+               |\n\
+             3 |   display: flx;\n  \
+               |            ^^^"
+        );
+
+        let src = "padding-left:\t\tcalk(10px";
+        let err = src.parse::<Style>().unwrap_err();
+        assert_eq!(
+            err.diagnostic(src).to_string(),
+            "error: expected a number followed by one of `em`, `ex`, `in`, `cm`, `mm`, `pt`, \
+             `pc`, `px`, or 0\n \
+              --> 1:16\n  \
+               |\n\
+             1 | padding-left:\t\tcalk(10px\n  \
+               |              \t\t^^^^^"
+        );
+    }
+
+    #[test]
+    fn end_of_input() {
+        let src = "display:";
+        let err = src.parse::<Style>().unwrap_err();
+        assert_eq!(err.span().start(), 8);
+        assert!(err
+            .diagnostic(src)
+            .to_string()
+            .ends_with("1 | display:\n  |         ^"));
+    }
+
+    #[test]
+    fn preprocessed() {
+        let src = "color: red;\r\n\u{c}display: \0";
+        let (_, errors) = crate::Styles::parse_with_errors(src).unwrap();
+        let err = &errors[0];
+        assert_eq!(err.span().start(), 23);
+        assert!(err
+            .diagnostic(src)
+            .to_string()
+            .starts_with("error: expected one of `block`, `flex`, `inline`\n --> 3:10\n"));
+    }
+
+    #[test]
+    fn multi_line_span() {
+        let map = SourceMap::new("ab\ncd");
+        let diagnostic = Diagnostic::with_source_map(map, Span::new(1, 4), "oops");
+        assert!(diagnostic.to_string().ends_with("1 | ab\n  |  ^"));
+    }
+}
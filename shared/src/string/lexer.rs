@@ -1,8 +1,8 @@
 //! Parse the various css types from strings directly (avoid pulling in syn if working at runtime)
 //!
-//! Differences to spec:
-//!  - Exponential floats are not supported for now.
-use std::{char, fmt, iter};
+//! The tokenizer follows https://www.w3.org/TR/css-syntax-3/#tokenization, except that comments
+//! are kept as tokens rather than discarded.
+use std::{borrow::Cow, char, fmt, iter};
 
 const REPLACEMENT_CHAR: char = '�';
 
@@ -16,55 +16,245 @@ pub struct Span {
 }
 
 impl Span {
-    fn new(start: usize, end: usize) -> Self {
-        assert!(end > start, "end must be greater than start");
+    pub(crate) fn new(start: usize, end: usize) -> Self {
+        assert!(end >= start, "end must not be before start");
         Span { start, end }
     }
 
     pub fn len(&self) -> usize {
         self.end - self.start
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.end == self.start
+    }
+
+    /// The byte offset of the start of the span in the source.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// The byte offset of the end of the span in the source.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// A span covering both `self` and `other`, and everything in between.
+    pub fn join(&self, other: Span) -> Span {
+        Span {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
 }
 
-#[derive(Debug)]
-pub struct InvalidChar {
-    ch: char,
-    pos: usize,
+/// A position in the source, for showing to humans.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LineCol {
+    /// The line number, starting at 1.
+    pub line: usize,
+    /// The column number in characters, starting at 1.
+    pub column: usize,
 }
 
-impl fmt::Display for InvalidChar {
+impl fmt::Display for LineCol {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "invalid character `{}` found at position {}",
-            self.ch.escape_debug(),
-            self.pos
-        )
+        write!(f, "{}:{}", self.line, self.column)
     }
 }
 
-#[derive(Debug)]
-pub struct Lexer<'src> {
+/// Resolves byte offsets in the source to lines and columns.
+#[derive(Debug, Clone)]
+pub struct SourceMap<'src> {
     src: &'src str,
+    /// The byte offset of the start of each line.
+    line_starts: Vec<usize>,
+}
+
+impl<'src> SourceMap<'src> {
+    pub fn new(src: &'src str) -> Self {
+        // lines are split the same way as in preprocessing, at `\r\n`, `\n`, `\r` and form feeds
+        let mut line_starts = vec![0];
+        let mut ch_iter = src.char_indices().peekable();
+        while let Some((idx, ch)) = ch_iter.next() {
+            let len = match ch {
+                '\r' if matches!(ch_iter.peek(), Some((_, '\n'))) => {
+                    ch_iter.next();
+                    2
+                }
+                '\n' | '\r' | '\u{c}' => 1,
+                _ => continue,
+            };
+            line_starts.push(idx + len);
+        }
+        SourceMap { src, line_starts }
+    }
+
+    /// The source this map is for.
+    pub fn source(&self) -> &'src str {
+        self.src
+    }
+
+    /// The number of lines in the source.
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// The line and column of the byte offset `pos`.
+    ///
+    /// Panics if `pos` is past the end of the source, or not on a char boundary.
+    pub fn line_col(&self, pos: usize) -> LineCol {
+        assert!(
+            pos <= self.src.len(),
+            "position is past the end of the source"
+        );
+        let line_idx = match self.line_starts.binary_search(&pos) {
+            Ok(idx) => idx,
+            Err(idx) => idx - 1,
+        };
+        let line_start = self.line_starts[line_idx];
+        LineCol {
+            line: line_idx + 1,
+            column: self.src[line_start..pos].chars().count() + 1,
+        }
+    }
+
+    /// The line and column of the start and end of `span`.
+    pub fn span_line_cols(&self, span: Span) -> (LineCol, LineCol) {
+        (self.line_col(span.start), self.line_col(span.end))
+    }
+
+    /// The text of line number `line` (starting at 1), without the trailing line break.
+    pub fn line(&self, line: usize) -> &'src str {
+        let start = self.line_starts[line - 1];
+        let end = match self.line_starts.get(line) {
+            Some(&next) if self.src[..next].ends_with("\r\n") => next - 2,
+            Some(&next) => next - 1,
+            None => self.src.len(),
+        };
+        &self.src[start..end]
+    }
+}
+
+/// Preprocess css text as specified at https://www.w3.org/TR/css-syntax-3/#input-preprocessing.
+///
+/// `\r\n`, `\r` and form feeds are replaced with `\n`, and NUL is replaced with U+FFFD. The input
+/// is only copied if something needs replacing.
+pub fn preprocess(src: &str) -> Cow<'_, str> {
+    OffsetMap::preprocess(src).0
+}
+
+/// Maps offsets in preprocessed text back to offsets in the original text.
+#[derive(Debug, Clone, Default)]
+struct OffsetMap {
+    /// Pairs of `(preprocessed offset, original offset)` just after each replacement. Offsets
+    /// between replacements differ by the same amount as the previous pair.
+    anchors: Vec<(usize, usize)>,
+}
+
+impl OffsetMap {
+    fn preprocess(src: &str) -> (Cow<'_, str>, OffsetMap) {
+        let is_special = |ch| ch == '\r' || ch == '\u{c}' || ch == '\0';
+        let first = match src.find(is_special) {
+            Some(first) => first,
+            None => return (Cow::Borrowed(src), OffsetMap::default()),
+        };
+        let mut output = String::with_capacity(src.len());
+        let mut map = OffsetMap::default();
+        output.push_str(&src[..first]);
+        let mut ch_iter = src[first..]
+            .char_indices()
+            .map(|(idx, ch)| (idx + first, ch))
+            .peekable();
+        while let Some((idx, ch)) = ch_iter.next() {
+            let replaced_len = match ch {
+                '\r' => {
+                    output.push('\n');
+                    if let Some((_, '\n')) = ch_iter.peek() {
+                        ch_iter.next();
+                        2
+                    } else {
+                        1
+                    }
+                }
+                '\u{c}' => {
+                    output.push('\n');
+                    1
+                }
+                '\0' => {
+                    output.push(REPLACEMENT_CHAR);
+                    1
+                }
+                ch => {
+                    output.push(ch);
+                    continue;
+                }
+            };
+            map.anchors.push((output.len(), idx + replaced_len));
+        }
+        (Cow::Owned(output), map)
+    }
+
+    /// Convert an offset in the preprocessed text to an offset in the original.
+    fn to_original(&self, pos: usize) -> usize {
+        // both offsets in `anchors` are increasing, so the last anchor at or before `pos` can be
+        // found with a binary search
+        let idx = self
+            .anchors
+            .partition_point(|(processed, _)| *processed <= pos);
+        match idx.checked_sub(1).map(|idx| self.anchors[idx]) {
+            Some((processed, original)) => original + (pos - processed),
+            None => pos,
+        }
+    }
+
+    /// Convert an offset in the original text to an offset in the preprocessed text.
+    fn to_processed(&self, pos: usize) -> usize {
+        let idx = self
+            .anchors
+            .partition_point(|(_, original)| *original <= pos);
+        match idx.checked_sub(1).map(|idx| self.anchors[idx]) {
+            Some((processed, original)) => processed + (pos - original),
+            None => pos,
+        }
+    }
+}
+
+/// A css tokenizer.
+///
+/// The input is preprocessed before tokenizing, but the spans of tokens always refer to the
+/// original input.
+#[derive(Debug, Clone)]
+pub struct Lexer<'src> {
+    /// The input as given, which spans refer to.
+    original: &'src str,
+    /// The preprocessed input, which is what gets tokenized.
+    src: Cow<'src, str>,
+    offsets: OffsetMap,
+    /// The current position in `src`.
     cursor: usize,
 }
 
 impl<'src> Lexer<'src> {
-    pub fn new(src: &'src str) -> Result<Lexer<'src>, InvalidChar> {
-        // Check that the user has already replaced characters as specified at
-        // https://www.w3.org/TR/css-syntax-3/#input-preprocessing
-        for (pos, ch) in src.char_indices() {
-            if ch == '\r' || ch == '\u{d}' || ch == '\0' {
-                return Err(InvalidChar { ch, pos });
-            }
+    pub fn new(src: &'src str) -> Lexer<'src> {
+        let (processed, offsets) = OffsetMap::preprocess(src);
+        Lexer {
+            original: src,
+            src: processed,
+            offsets,
+            cursor: 0,
         }
-        Ok(Lexer { src, cursor: 0 })
     }
 
     fn len(&self) -> usize {
         self.src.len()
     }
 
+    /// Create a map for finding the line and column of the spans of this lexer's tokens.
+    pub fn source_map(&self) -> SourceMap<'src> {
+        SourceMap::new(self.original)
+    }
+
     fn remaining(&self) -> usize {
         self.src.len() - self.cursor
     }
@@ -80,7 +270,17 @@ impl<'src> Lexer<'src> {
     }
 
     pub fn peek(&self) -> Option<Token> {
-        // https://www.w3.org/TR/css-syntax-3/#tokenizer-definitions
+        let mut tok = self.peek_processed()?;
+        tok.span = Span::new(
+            self.offsets.to_original(tok.span.start),
+            self.offsets.to_original(tok.span.end),
+        );
+        Some(tok)
+    }
+
+    /// Get the next token, with its span in the preprocessed input.
+    fn peek_processed(&self) -> Option<Token> {
+        // https://www.w3.org/TR/css-syntax-3/#consume-token
         if let Some(comment) = self.comment() {
             return Some(comment);
         }
@@ -90,50 +290,72 @@ impl<'src> Lexer<'src> {
         if let Some(tok) = self.string() {
             return Some(tok);
         }
-        match self.chars().next() {
-            Some(other) => Some(Token::new(
-                TokenKind::Error,
-                Span::new(self.cursor, self.cursor + other.len_utf8()),
-            )),
-            None => None,
+        if let Some(tok) = self.hash() {
+            return Some(tok);
+        }
+        if let Some(tok) = self.number() {
+            return Some(tok);
+        }
+        if let Some(tok) = self.cdo_cdc() {
+            return Some(tok);
         }
+        if let Some(tok) = self.at_keyword() {
+            return Some(tok);
+        }
+        if let Some(tok) = self.ident_like() {
+            return Some(tok);
+        }
+        self.chars().next().map(|ch| {
+            let kind = match ch {
+                ':' => TokenKind::Colon,
+                ';' => TokenKind::Semicolon,
+                ',' => TokenKind::Comma,
+                '[' => TokenKind::LBracket,
+                ']' => TokenKind::RBracket,
+                '(' => TokenKind::LParen,
+                ')' => TokenKind::RParen,
+                '{' => TokenKind::LBrace,
+                '}' => TokenKind::RBrace,
+                ch => TokenKind::Delim(ch),
+            };
+            Token::new(kind, self.span(ch.len_utf8()))
+        })
     }
 
+    /// Peek the token `n` tokens ahead (`peek_n(0)` is the same as `peek()`).
     pub fn peek_n(&self, n: usize) -> Option<Token> {
-        todo!()
+        let mut fork = self.fork();
+        for _ in 0..n {
+            fork.next_token()?;
+        }
+        fork.peek()
     }
 
     pub fn is_empty(&self) -> bool {
-        todo!() //self.peek().is_none()
+        self.remaining() == 0
     }
 
+    /// The original source text of `span`.
     pub fn resolve_span(&self, span: Span) -> &'src str {
-        if span.end > self.len() {
+        if span.end > self.original.len() {
             panic!("End of requested span is past the end of the source");
         }
-        &self.src[span.start..span.end]
+        &self.original[span.start..span.end]
     }
 
     /// Create another independent lexer at the given start point
-    fn fork(&self) -> Lexer {
-        Lexer {
-            src: self.src,
-            cursor: self.cursor,
-        }
+    fn fork(&self) -> Lexer<'src> {
+        self.clone()
     }
 
     pub fn consume(&mut self, tok: &Token) {
+        let end = self.offsets.to_processed(tok.span.end);
         assert!(
-            tok.len() <= self.remaining(),
+            end >= self.cursor && end <= self.len(),
             "trying to consume a token that would be bigger \
             than all remaining text"
         );
-        self.cursor += tok.len();
-    }
-
-    /// Resolve a position from cursor to position from start of src
-    fn resolve_pos(&self, pos: usize) -> usize {
-        self.cursor + pos
+        self.cursor = end;
     }
 
     /// Create a span from the current position with the given length
@@ -147,14 +369,19 @@ impl<'src> Lexer<'src> {
         Span::new(self.cursor, self.len())
     }
 
+    /// The remaining input
+    fn rest(&self) -> &str {
+        &self.src[self.cursor..]
+    }
+
     /// Iterate over the remaining chars of the input
-    fn chars(&self) -> std::str::Chars {
-        self.src[self.cursor..].chars()
+    fn chars(&self) -> std::str::Chars<'_> {
+        self.rest().chars()
     }
 
     /// Iterate over the remaining chars of the input
-    fn char_indices(&self) -> std::str::CharIndices {
-        self.src[self.cursor..].char_indices()
+    fn char_indices(&self) -> std::str::CharIndices<'_> {
+        self.rest().char_indices()
     }
 
     /// Parse a comment
@@ -228,8 +455,10 @@ impl<'src> Lexer<'src> {
                         span: self.span(end + 1), // '\n'.len_utf8() == 1
                     });
                 }
-                Some((_, '\\')) => match ch_iter.peek() {
-                    Some((_, ch)) => {
+                // A backslash at the end of input is dropped, and the string is finished on
+                // the next pass.
+                Some((_, '\\')) => {
+                    if let Some((_, ch)) = ch_iter.peek() {
                         if *ch == '\n' {
                             // do nothing - skip the backslash and newline.
                             ch_iter.next().unwrap();
@@ -239,16 +468,12 @@ impl<'src> Lexer<'src> {
                             decoded_string.push(ch_iter.next().unwrap().1);
                         }
                     }
-                    None => {
-                        // The spec says not to add the last '\'.
-                        // a bad string will be returned on next pass
-                        ch_iter.next().unwrap();
-                    }
-                },
+                }
                 Some((_, ch)) => decoded_string.push(ch),
+                // The spec says a string left open at the end of input is still a string.
                 None => {
                     return Some(Token {
-                        kind: TokenKind::BadString(decoded_string),
+                        kind: TokenKind::String(decoded_string),
                         span: self.span_to_end(),
                     })
                 }
@@ -256,22 +481,163 @@ impl<'src> Lexer<'src> {
         }
     }
 
-    /*
+    /// Parse a hash (e.g. `#fff`). A `#` not followed by a name is a delimiter.
     fn hash(&self) -> Option<Token> {
-        let mut iter = self.char_indices();
-        match iter.next() {
-            Some((_, '#')) => (),
-            None => return None,
-        };
-        match iter.next() {
-            Some((_, '\\')) => {}
-            _ => Some(Token {
-                kind: TokenKind::Delim('#'),
-                span: self.span(1),
-            }),
+        let rest = self.rest();
+        if !rest.starts_with('#') {
+            return None;
+        }
+        let name = &rest[1..];
+        match name_len(name) {
+            0 => None,
+            len => Some(Token::new(
+                TokenKind::Hash {
+                    value: unescape_name(&name[..len]),
+                    is_id: starts_ident(name),
+                },
+                self.span(1 + len),
+            )),
+        }
+    }
+
+    /// Parse a number, percentage or dimension (a number followed by a unit, e.g. `10px`).
+    fn number(&self) -> Option<Token> {
+        let rest = self.rest();
+        let len = number_len(rest)?;
+        let number = Numeric::from_str(&rest[..len]);
+        let after = &rest[len..];
+        Some(if after.starts_with('%') {
+            Token::new(TokenKind::Percentage(number), self.span(len + 1))
+        } else if starts_ident(after) {
+            let unit_len = name_len(after);
+            Token::new(
+                TokenKind::Dimension {
+                    value: number,
+                    unit: unescape_name(&after[..unit_len]),
+                },
+                self.span(len + unit_len),
+            )
+        } else {
+            Token::new(TokenKind::Number(number), self.span(len))
+        })
+    }
+
+    /// Parse `<!--` or `-->`.
+    fn cdo_cdc(&self) -> Option<Token> {
+        let rest = self.rest();
+        if rest.starts_with("<!--") {
+            Some(Token::new(TokenKind::CDO, self.span(4)))
+        } else if rest.starts_with("-->") {
+            Some(Token::new(TokenKind::CDC, self.span(3)))
+        } else {
+            None
+        }
+    }
+
+    /// Parse an at-keyword (e.g. `@media`). A `@` not followed by an identifier is a delimiter.
+    fn at_keyword(&self) -> Option<Token> {
+        let rest = self.rest();
+        if rest.starts_with('@') && starts_ident(&rest[1..]) {
+            let len = name_len(&rest[1..]);
+            Some(Token::new(
+                TokenKind::At(unescape_name(&rest[1..1 + len])),
+                self.span(1 + len),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Parse an ident, a function if the ident is immediately followed by `(`, or a url.
+    ///
+    /// https://www.w3.org/TR/css-syntax-3/#consume-ident-like-token
+    fn ident_like(&self) -> Option<Token> {
+        let rest = self.rest();
+        if !starts_ident(rest) {
+            return None;
+        }
+        let len = name_len(rest);
+        let name = unescape_name(&rest[..len]);
+        if !rest[len..].starts_with('(') {
+            return Some(Token::new(TokenKind::Ident(name), self.span(len)));
+        }
+        if name.eq_ignore_ascii_case("url") {
+            // A quoted url is a normal function, followed by a string.
+            let after_ws = rest[len + 1..].trim_start_matches(is_whitespace);
+            if !(after_ws.starts_with('"') || after_ws.starts_with('\'')) {
+                return Some(self.url(len + 1));
+            }
+        }
+        Some(Token::new(TokenKind::Function(name), self.span(len + 1)))
+    }
+
+    /// Parse the rest of an unquoted url, where `start` is the length of the `url(` prefix.
+    ///
+    /// https://www.w3.org/TR/css-syntax-3/#consume-url-token
+    fn url(&self, start: usize) -> Token {
+        let mut ch_iter = self
+            .char_indices()
+            .skip_while(|(idx, _)| *idx < start)
+            .peekable();
+        let mut value = String::new();
+        // skip leading whitespace
+        while let Some((_, ch)) = ch_iter.peek() {
+            if !is_whitespace(*ch) {
+                break;
+            }
+            ch_iter.next();
+        }
+        loop {
+            match ch_iter.next() {
+                Some((end, ')')) => {
+                    return Token::new(TokenKind::Url(value), self.span(end + 1));
+                }
+                // An unclosed url is still a url.
+                None => return Token::new(TokenKind::Url(value), self.span_to_end()),
+                Some((_, ch)) if is_whitespace(ch) => {
+                    while let Some((_, ch)) = ch_iter.peek() {
+                        if !is_whitespace(*ch) {
+                            break;
+                        }
+                        ch_iter.next();
+                    }
+                    return match ch_iter.next() {
+                        Some((end, ')')) => Token::new(TokenKind::Url(value), self.span(end + 1)),
+                        None => Token::new(TokenKind::Url(value), self.span_to_end()),
+                        Some(_) => self.bad_url(ch_iter),
+                    };
+                }
+                Some((_, '"')) | Some((_, '\'')) | Some((_, '(')) => return self.bad_url(ch_iter),
+                Some((_, ch)) if is_non_printable(ch) => return self.bad_url(ch_iter),
+                Some((_, '\\')) => match ch_iter.peek() {
+                    Some((_, '\n')) => return self.bad_url(ch_iter),
+                    _ => value.push(unescape_char(&mut ch_iter)),
+                },
+                Some((_, ch)) => value.push(ch),
+            }
+        }
+    }
+
+    /// Consume the remnants of a bad url, up to and including the closing `)`.
+    ///
+    /// https://www.w3.org/TR/css-syntax-3/#consume-remnants-of-bad-url
+    fn bad_url(&self, mut ch_iter: iter::Peekable<impl Iterator<Item = (usize, char)>>) -> Token {
+        loop {
+            match ch_iter.next() {
+                Some((end, ')')) => return Token::new(TokenKind::BadUrl, self.span(end + 1)),
+                None => return Token::new(TokenKind::BadUrl, self.span_to_end()),
+                Some((_, '\\')) => {
+                    // an escaped `)` doesn't end the url.
+                    if let Some((_, ch)) = ch_iter.peek() {
+                        if *ch != '\n' {
+                            unescape_char(&mut ch_iter);
+                        }
+                    }
+                }
+                Some(_) => (),
+            }
         }
     }
-    */
 }
 
 impl<'src> Iterator for Lexer<'src> {
@@ -282,7 +648,7 @@ impl<'src> Iterator for Lexer<'src> {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 #[non_exhaustive]
 pub struct Token {
     pub kind: TokenKind,
@@ -297,22 +663,40 @@ impl Token {
     pub fn len(&self) -> usize {
         self.span.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.span.is_empty()
+    }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TokenKind {
-    Ident,
-    Function,
-    At,
-    Hash,
+    /// An identifier, with escapes replaced.
+    Ident(String),
+    /// The name of a function, not including the `(`.
+    Function(String),
+    /// An at-keyword, not including the `@`.
+    At(String),
+    Hash {
+        /// The name after the `#`.
+        value: String,
+        /// Whether the name would be a valid identifier (the "id" type flag in the spec).
+        is_id: bool,
+    },
     String(String),
     BadString(String),
-    Url,
+    /// An unquoted url, like `url(image.png)`, with escapes replaced.
+    Url(String),
     BadUrl,
     Delim(char),
-    Number,
-    Percentage,
-    Dimension,
+    Number(Numeric),
+    /// A percentage, where `value` is the number before the `%`.
+    Percentage(Numeric),
+    /// A number followed by a unit, e.g. `10px`.
+    Dimension {
+        value: Numeric,
+        unit: String,
+    },
     Whitespace,
     /// <!--
     CDO,
@@ -338,12 +722,162 @@ pub enum TokenKind {
     RBrace,
     Comment,
     UnclosedComment,
-    /// Could not parse the next token
-    Error,
+}
+
+/// The value of a number, percentage or dimension token.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Numeric {
+    pub value: f64,
+    /// Whether the number was written as an integer (without a `.` or exponent).
+    pub is_integer: bool,
+}
+
+impl Numeric {
+    /// Convert a string matched by `number_len`.
+    ///
+    /// https://www.w3.org/TR/css-syntax-3/#convert-a-string-to-a-number
+    fn from_str(input: &str) -> Self {
+        Numeric {
+            // rust's float syntax is a superset of the css number syntax.
+            value: input.parse().unwrap(),
+            is_integer: !input.contains(['.', 'e', 'E']),
+        }
+    }
 }
 
 // Helpers
 
+/// https://www.w3.org/TR/css-syntax-3/#whitespace
+fn is_whitespace(ch: char) -> bool {
+    ch == '\n' || ch == '\t' || ch == ' '
+}
+
+/// https://www.w3.org/TR/css-syntax-3/#non-printable-code-point
+fn is_non_printable(ch: char) -> bool {
+    matches!(ch, '\0'..='\u{8}' | '\u{b}' | '\u{e}'..='\u{1f}' | '\u{7f}')
+}
+
+/// https://www.w3.org/TR/css-syntax-3/#name-start-code-point
+fn is_name_start(ch: char) -> bool {
+    ch.is_ascii_alphabetic() || ch == '_' || !ch.is_ascii()
+}
+
+/// https://www.w3.org/TR/css-syntax-3/#name-code-point
+fn is_name(ch: char) -> bool {
+    is_name_start(ch) || ch.is_ascii_digit() || ch == '-'
+}
+
+/// Whether `input` starts with a valid escape.
+///
+/// https://www.w3.org/TR/css-syntax-3/#starts-with-a-valid-escape
+fn starts_escape(input: &str) -> bool {
+    let mut chars = input.chars();
+    chars.next() == Some('\\') && chars.next() != Some('\n')
+}
+
+/// The length in bytes of the escape at the start of `input`, which must start with a valid
+/// escape.
+fn escape_len(input: &str) -> usize {
+    debug_assert!(starts_escape(input));
+    let mut ch_iter = input.char_indices().skip(1).peekable();
+    unescape_char(&mut ch_iter);
+    ch_iter.peek().map(|(idx, _)| *idx).unwrap_or(input.len())
+}
+
+/// The length in bytes of the name (including escapes) at the start of `input`.
+///
+/// https://www.w3.org/TR/css-syntax-3/#consume-name
+fn name_len(input: &str) -> usize {
+    let mut len = 0;
+    loop {
+        let rest = &input[len..];
+        match rest.chars().next() {
+            Some(ch) if is_name(ch) => len += ch.len_utf8(),
+            Some('\\') if starts_escape(rest) => len += escape_len(rest),
+            _ => return len,
+        }
+    }
+}
+
+/// Whether `input` starts with an identifier.
+///
+/// https://www.w3.org/TR/css-syntax-3/#would-start-an-identifier
+fn starts_ident(input: &str) -> bool {
+    let mut chars = input.chars();
+    match chars.next() {
+        Some('-') => match chars.next() {
+            Some(ch) if is_name_start(ch) || ch == '-' => true,
+            Some('\\') => starts_escape(&input[1..]),
+            _ => false,
+        },
+        Some('\\') => starts_escape(input),
+        Some(ch) => is_name_start(ch),
+        None => false,
+    }
+}
+
+/// The length in bytes of the number at the start of `input`, or `None` if `input` doesn't start
+/// with a number.
+///
+/// https://www.w3.org/TR/css-syntax-3/#consume-number
+fn number_len(input: &str) -> Option<usize> {
+    fn digits(input: &[u8]) -> usize {
+        input.iter().take_while(|b| b.is_ascii_digit()).count()
+    }
+    let bytes = input.as_bytes();
+    let mut len = match bytes.first() {
+        Some(b'+') | Some(b'-') => 1,
+        _ => 0,
+    };
+    let int_len = digits(&bytes[len..]);
+    len += int_len;
+    let mut frac_len = 0;
+    if bytes.get(len) == Some(&b'.') {
+        frac_len = digits(&bytes[len + 1..]);
+        if frac_len > 0 {
+            len += 1 + frac_len;
+        }
+    }
+    if int_len == 0 && frac_len == 0 {
+        return None;
+    }
+    if let Some(b'e') | Some(b'E') = bytes.get(len) {
+        let sign_len = match bytes.get(len + 1) {
+            Some(b'+') | Some(b'-') => 1,
+            _ => 0,
+        };
+        let exp_len = digits(&bytes[(len + 1 + sign_len).min(bytes.len())..]);
+        if exp_len > 0 {
+            len += 1 + sign_len + exp_len;
+        }
+    }
+    Some(len)
+}
+
+/// Replace the escapes in a name with the characters they represent.
+fn unescape_name(input: &str) -> String {
+    let mut ch_iter = input.char_indices().peekable();
+    let mut output = String::with_capacity(input.len());
+    while let Some((_, ch)) = ch_iter.next() {
+        if ch == '\\' {
+            output.push(unescape_char(&mut ch_iter));
+        } else {
+            output.push(ch);
+        }
+    }
+    output
+}
+
+/// Consume an escaped character, after the `\`.
+///
+/// https://www.w3.org/TR/css-syntax-3/#consume-escaped-code-point
+fn unescape_char(input: &mut iter::Peekable<impl Iterator<Item = (usize, char)>>) -> char {
+    match unescape(input) {
+        Some(ch) => ch,
+        None => input.next().map(|(_, ch)| ch).unwrap_or(REPLACEMENT_CHAR),
+    }
+}
+
 /// Hex to char (up to 6 characters, e.g. "ffffff").
 ///
 /// For example `"5c" => '\'`. Returns None if first char is not hex.  Consumes the hex values.
@@ -354,7 +888,7 @@ fn unescape(input: &mut iter::Peekable<impl Iterator<Item = (usize, char)>>) ->
     }
 
     let (_, ch) = match input.peek() {
-        Some((idx, ch)) if ch.is_ascii_hexdigit() => input.next().unwrap(),
+        Some((_, ch)) if ch.is_ascii_hexdigit() => input.next().unwrap(),
         _ => return None,
     };
 
@@ -386,7 +920,7 @@ fn unescape(input: &mut iter::Peekable<impl Iterator<Item = (usize, char)>>) ->
     // maybe we could just directly use `char::from_u32(acc).unwrap_or(REPLACEMENT_CHAR)`
     // null, surrogate, or too big
     Some(
-        if acc == 0 || (acc >= 0xd800 && acc < 0xe000) || acc >= 0x110000 {
+        if acc == 0 || (0xd800..0xe000).contains(&acc) || acc >= 0x110000 {
             REPLACEMENT_CHAR
         } else {
             char::from_u32(acc).unwrap() // there should be no other invalid chars.
@@ -396,12 +930,12 @@ fn unescape(input: &mut iter::Peekable<impl Iterator<Item = (usize, char)>>) ->
 
 #[cfg(test)]
 mod test {
-    use super::{Lexer, Span, Token, TokenKind};
+    use super::{Lexer, Numeric, Token, TokenKind};
 
     #[test]
     fn comment() {
         println!();
-        let mut input = Lexer::new("/* a valid comment */").unwrap();
+        let mut input = Lexer::new("/* a valid comment */");
         match input.next_token() {
             Some(Token {
                 kind: TokenKind::Comment,
@@ -416,7 +950,7 @@ mod test {
             _ => panic!("not a comment"),
         };
 
-        let mut input = Lexer::new("/* a comment").unwrap();
+        let mut input = Lexer::new("/* a comment");
         match input.next_token() {
             Some(Token {
                 kind: TokenKind::UnclosedComment,
@@ -428,11 +962,11 @@ mod test {
             _ => panic!("not a comment"),
         };
 
-        let mut input = Lexer::new("/!* not a comment").unwrap();
+        let mut input = Lexer::new("/!* not a comment");
         match input.next_token() {
             Some(Token {
-                kind: TokenKind::Error,
-                span,
+                kind: TokenKind::Delim('/'),
+                span: _,
             }) => {}
             _ => panic!("not a comment"),
         };
@@ -441,7 +975,7 @@ mod test {
     #[test]
     fn string() {
         println!("h");
-        let mut input = Lexer::new("\" a vali\\64\\e9 \\\n string \"").unwrap();
+        let mut input = Lexer::new("\" a vali\\64\\e9 \\\n string \"");
         match input.next_token() {
             Some(Token {
                 kind: TokenKind::String(s),
@@ -453,7 +987,7 @@ mod test {
             _ => panic!("not a string"),
         };
 
-        let mut input = Lexer::new("' a valid string '").unwrap();
+        let mut input = Lexer::new("' a valid string '");
         match input.next_token() {
             Some(Token {
                 kind: TokenKind::String(s),
@@ -465,10 +999,10 @@ mod test {
             _ => panic!("not a string"),
         };
 
-        let mut input = Lexer::new("\" a string").unwrap();
+        let mut input = Lexer::new("\" a string");
         match input.next_token() {
             Some(Token {
-                kind: TokenKind::BadString(s),
+                kind: TokenKind::String(s),
                 span,
             }) => {
                 assert_eq!(s, " a string".to_string());
@@ -476,12 +1010,34 @@ mod test {
             }
             _ => panic!("not a string"),
         };
+
+        let mut input = Lexer::new("'abc\\");
+        match input.next_token() {
+            Some(Token {
+                kind: TokenKind::String(s),
+                span,
+            }) => {
+                assert_eq!(s, "abc".to_string());
+                assert_eq!(span.len(), 5);
+            }
+            _ => panic!("not a string"),
+        };
+        assert!(input.next_token().is_none());
+
+        let mut input = Lexer::new("\"a\nb\"");
+        match input.next_token() {
+            Some(Token {
+                kind: TokenKind::BadString(s),
+                ..
+            }) => assert_eq!(s, "a".to_string()),
+            _ => panic!("not a bad string"),
+        };
     }
 
     #[test]
     fn whitespace() {
         println!();
-        let mut input = Lexer::new("\n\t ").unwrap();
+        let mut input = Lexer::new("\n\t ");
         match input.next_token() {
             Some(Token {
                 kind: TokenKind::Whitespace,
@@ -494,6 +1050,263 @@ mod test {
         };
     }
 
+    fn kinds(input: &str) -> Vec<TokenKind> {
+        Lexer::new(input).map(|tok| tok.kind).collect()
+    }
+
+    fn ident(name: &str) -> TokenKind {
+        TokenKind::Ident(name.into())
+    }
+
+    fn number(value: f64, is_integer: bool) -> TokenKind {
+        TokenKind::Number(Numeric { value, is_integer })
+    }
+
+    fn dimension(value: f64, is_integer: bool, unit: &str) -> TokenKind {
+        TokenKind::Dimension {
+            value: Numeric { value, is_integer },
+            unit: unit.into(),
+        }
+    }
+
+    fn hash(value: &str, is_id: bool) -> TokenKind {
+        TokenKind::Hash {
+            value: value.into(),
+            is_id,
+        }
+    }
+
+    #[test]
+    fn ident_like() {
+        use TokenKind::*;
+        for (input, output) in [
+            ("foo", vec![ident("foo")]),
+            ("-foo", vec![ident("-foo")]),
+            ("--custom-prop", vec![ident("--custom-prop")]),
+            ("\\31 23", vec![ident("123")]),
+            ("-\\31", vec![ident("-1")]),
+            ("_élan", vec![ident("_élan")]),
+            ("\\", vec![ident("\u{fffd}")]),
+            ("-1a", vec![dimension(-1.0, true, "a")]),
+            ("- a", vec![Delim('-'), Whitespace, ident("a")]),
+            ("\\\n", vec![Delim('\\'), Whitespace]),
+            ("rgb(", vec![Function("rgb".into())]),
+            (
+                "fit-content(10px)",
+                vec![
+                    Function("fit-content".into()),
+                    dimension(10.0, true, "px"),
+                    RParen,
+                ],
+            ),
+            ("foo (", vec![ident("foo"), Whitespace, LParen]),
+        ] {
+            assert_eq!(kinds(input), output, "input: {:?}", input);
+        }
+    }
+
+    #[test]
+    fn url() {
+        use TokenKind::*;
+        for (input, output) in [
+            ("url(foo.png)", vec![Url("foo.png".into())]),
+            ("URL(foo.png)", vec![Url("foo.png".into())]),
+            ("u\\72l(foo.png)", vec![Url("foo.png".into())]),
+            ("url(  foo.png  )", vec![Url("foo.png".into())]),
+            ("url()", vec![Url("".into())]),
+            ("url(a\\)b)", vec![Url("a)b".into())]),
+            ("url(\\66 oo)", vec![Url("foo".into())]),
+            ("url(foo", vec![Url("foo".into())]),
+            ("url(foo bar)", vec![BadUrl]),
+            ("url(a\"b) c", vec![BadUrl, Whitespace, ident("c")]),
+            ("url(a(b)", vec![BadUrl]),
+            ("url(a\u{1}b)", vec![BadUrl]),
+            ("url(a b\\) c) d", vec![BadUrl, Whitespace, ident("d")]),
+            (
+                "url( 'foo' )",
+                vec![
+                    Function("url".into()),
+                    Whitespace,
+                    String("foo".into()),
+                    Whitespace,
+                    RParen,
+                ],
+            ),
+            (
+                "url(\"foo\")",
+                vec![Function("url".into()), String("foo".into()), RParen],
+            ),
+        ] {
+            assert_eq!(kinds(input), output, "input: {:?}", input);
+        }
+    }
+
+    #[test]
+    fn at_keyword_and_hash() {
+        use TokenKind::*;
+        for (input, output) in [
+            ("@media", vec![At("media".into())]),
+            ("@-moz-document", vec![At("-moz-document".into())]),
+            ("@\\6d edia", vec![At("media".into())]),
+            ("@1", vec![Delim('@'), number(1.0, true)]),
+            ("@ ", vec![Delim('@'), Whitespace]),
+            ("#fff", vec![hash("fff", true)]),
+            ("#0ee", vec![hash("0ee", false)]),
+            ("#-\\31", vec![hash("-1", true)]),
+            ("#--", vec![hash("--", true)]),
+            ("#-1", vec![hash("-1", false)]),
+            ("# ", vec![Delim('#'), Whitespace]),
+        ] {
+            assert_eq!(kinds(input), output, "input: {:?}", input);
+        }
+    }
+
+    #[test]
+    fn numeric() {
+        use TokenKind::*;
+        for (input, output, len) in [
+            ("12", number(12.0, true), 2),
+            ("+12", number(12.0, true), 3),
+            ("+.5", number(0.5, false), 3),
+            ("-1.5", number(-1.5, false), 4),
+            ("1e3", number(1000.0, false), 3),
+            ("1E+3", number(1000.0, false), 4),
+            ("-2.5e-1", number(-0.25, false), 7),
+            ("1e", dimension(1.0, true, "e"), 2),
+            ("1em", dimension(1.0, true, "em"), 3),
+            ("1.5PX", dimension(1.5, false, "PX"), 5),
+            ("1e3px", dimension(1000.0, false, "px"), 5),
+            ("10\\70 x", dimension(10.0, true, "px"), 7),
+            (
+                "50%",
+                Percentage(Numeric {
+                    value: 50.0,
+                    is_integer: true,
+                }),
+                3,
+            ),
+            (
+                "1.5e1%",
+                Percentage(Numeric {
+                    value: 15.0,
+                    is_integer: false,
+                }),
+                6,
+            ),
+        ] {
+            let mut lexer = Lexer::new(input);
+            let tok = lexer.next_token().unwrap();
+            assert_eq!(
+                (tok.kind, tok.span.len()),
+                (output, len),
+                "input: {:?}",
+                input
+            );
+            assert!(lexer.is_empty());
+        }
+        for (input, output) in [
+            ("1.", vec![number(1.0, true), Delim('.')]),
+            ("1.a", vec![number(1.0, true), Delim('.'), ident("a")]),
+            (".", vec![Delim('.')]),
+            ("+", vec![Delim('+')]),
+            ("1e+", vec![dimension(1.0, true, "e"), Delim('+')]),
+            ("1-2", vec![number(1.0, true), number(-2.0, true)]),
+            ("1-a", vec![dimension(1.0, true, "-a")]),
+            (
+                "1 -2",
+                vec![number(1.0, true), Whitespace, number(-2.0, true)],
+            ),
+        ] {
+            assert_eq!(kinds(input), output, "input: {:?}", input);
+        }
+    }
+
+    #[test]
+    fn punctuation() {
+        use TokenKind::*;
+        let (a, b, c, d, e) = (ident("a"), ident("b"), ident("c"), ident("d"), ident("e"));
+        assert_eq!(
+            kinds("<!-- a{b:c;d,e}[](-->"),
+            vec![
+                CDO,
+                Whitespace,
+                a.clone(),
+                LBrace,
+                b.clone(),
+                Colon,
+                c.clone(),
+                Semicolon,
+                d.clone(),
+                Comma,
+                e.clone(),
+                RBrace,
+                LBracket,
+                RBracket,
+                LParen,
+                CDC
+            ]
+        );
+        assert_eq!(
+            kinds("a<b!c>d/e"),
+            vec![
+                a,
+                Delim('<'),
+                b,
+                Delim('!'),
+                c,
+                Delim('>'),
+                d,
+                Delim('/'),
+                e
+            ]
+        );
+    }
+
+    #[test]
+    fn preprocess() {
+        use std::borrow::Cow;
+        assert!(matches!(super::preprocess("a\nb"), Cow::Borrowed("a\nb")));
+        assert_eq!(
+            super::preprocess("a\r\nb\rc\u{c}d\0e\r\r\n"),
+            "a\nb\nc\nd\u{fffd}e\n\n"
+        );
+
+        // spans are in the original input
+        let src = "a\r\n\0b\r/* c */";
+        let tokens = Lexer::new(src)
+            .map(|tok| (tok.kind, src[tok.span.start()..tok.span.end()].to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            vec![
+                (ident("a"), "a".into()),
+                (TokenKind::Whitespace, "\r\n".into()),
+                (ident("\u{fffd}b"), "\0b".into()),
+                (TokenKind::Whitespace, "\r".into()),
+                (TokenKind::Comment, "/* c */".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn offset_map() {
+        let (processed, map) = super::OffsetMap::preprocess("a\r\nb\rc\0\0d");
+        assert_eq!(processed, "a\nb\nc\u{fffd}\u{fffd}d");
+        for (processed, original) in [
+            (0, 0),
+            (1, 1),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 6),
+            (8, 7),
+            (11, 8),
+        ] {
+            assert_eq!(map.to_original(processed), original);
+            assert_eq!(map.to_processed(original), processed);
+        }
+    }
+
     #[test]
     fn escape() {
         let mut iter = "e9".char_indices().peekable();
@@ -1 +1,4 @@
+pub mod component;
+pub mod diagnostic;
 pub mod lexer;
+pub mod parse;
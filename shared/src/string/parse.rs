@@ -0,0 +1,6905 @@
+//! Runtime parsing of styles from css text, built on the tokens produced by `Lexer`.
+//!
+//! This mirrors the `syn::parse::Parse` implementations used by the macros, so the same css
+//! produces the same values whichever way it is parsed. Unlike the macros, the input here is real
+//! css, so `1.5em` and `#0ee` can be written as-is.
+// TODO share more of the logic with `syn_parse` - most of the parsers are very similar.
+use super::{
+    component::{self, ComponentParser, FlatToken},
+    lexer::{Span, Token, TokenKind},
+};
+use crate::*;
+use std::{
+    cell::RefCell,
+    collections::BTreeSet,
+    convert::TryFrom,
+    error,
+    fmt::{self, Write},
+    ops::RangeBounds,
+    str,
+};
+
+/// The result of a runtime parse.
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+/// Something that can be parsed from css text at runtime.
+pub trait Parse: Sized {
+    fn parse(s: &mut Parser) -> Result<Self>;
+}
+
+/// Parse a value from css text.
+///
+/// The whole input must be consumed, except for trailing whitespace and comments. Blocks and
+/// functions that aren't closed are closed at the end of input, as in
+/// https://www.w3.org/TR/css-syntax-3/#consume-simple-block.
+pub fn parse_str<T: Parse>(input: &str) -> Result<T> {
+    parse_str_with_errors(input).map(|(value, _)| value)
+}
+
+/// Parse a value from css text, also returning the errors that were recovered from, in source
+/// order.
+///
+/// These are the invalid declarations that were dropped, and blocks, functions and comments that
+/// weren't closed.
+pub fn parse_str_with_errors<T: Parse>(input: &str) -> Result<(T, Vec<ParseError>)> {
+    let mut components = ComponentParser::new(input);
+    let values = components.consume_list();
+    if let Some(err) = components.nesting_error() {
+        return Err(err.clone());
+    }
+    let tokens = component::flatten(&values, input.len());
+    let recovered = RefCell::new(components.take_errors());
+    let value = Parser {
+        recovered: Some(&recovered),
+        ..Parser::new(input, &tokens, input.len())
+    }
+    .parse_all()?;
+    let mut errors = recovered.into_inner();
+    errors.sort_by_key(|err| err.span().start());
+    Ok((value, errors))
+}
+
+/// An error that occurred while parsing css text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    span: Span,
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(span: Span, message: impl fmt::Display) -> Self {
+        ParseError {
+            span,
+            message: message.to_string(),
+        }
+    }
+
+    /// Where in the source the error occurred.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// A description of the error, without the location.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.span.start())
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// A cursor over a list of tokens, similar to `syn::parse::ParseStream`.
+///
+/// Whitespace is skipped over, except where a parser asks for it explicitly. The parser is cheap
+/// to copy, so to speculatively parse something, fork it and then `advance_to` the fork if the
+/// parse succeeded.
+///
+/// The tokens come from `component::flatten`, so blocks and functions can be skipped over as a
+/// whole.
+#[derive(Debug, Clone, Copy)]
+pub struct Parser<'a> {
+    src: &'a str,
+    tokens: &'a [FlatToken],
+    /// The byte offset where the input ends (used for errors at the end of input).
+    end: usize,
+    /// Where to put errors that were recovered from, if anyone wants them.
+    recovered: Option<&'a RefCell<Vec<ParseError>>>,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(src: &'a str, tokens: &'a [FlatToken], end: usize) -> Self {
+        Parser {
+            src,
+            tokens,
+            end,
+            recovered: None,
+        }
+    }
+
+    /// Parse a `T`.
+    pub fn parse<T: Parse>(&mut self) -> Result<T> {
+        T::parse(self)
+    }
+
+    /// Parse a `T`, which must use up the rest of the input.
+    pub fn parse_all<T: Parse>(&mut self) -> Result<T> {
+        let value = T::parse(self)?;
+        if !self.is_empty() {
+            return Err(self.error("unexpected trailing input"));
+        }
+        Ok(value)
+    }
+
+    /// Whether there is nothing but whitespace left.
+    pub fn is_empty(&self) -> bool {
+        self.peek().is_none()
+    }
+
+    /// Create an independent copy of this parser.
+    pub fn fork(&self) -> Self {
+        *self
+    }
+
+    /// Move this parser to the position of `fork`.
+    pub fn advance_to(&mut self, fork: &Self) {
+        *self = *fork;
+    }
+
+    /// The next token that isn't whitespace.
+    pub fn peek(&self) -> Option<&'a Token> {
+        self.tokens
+            .iter()
+            .map(|tok| &tok.token)
+            .find(|tok| tok.kind != TokenKind::Whitespace)
+    }
+
+    /// Whether the next token is whitespace.
+    pub fn peek_whitespace(&self) -> bool {
+        matches!(self.tokens.first(), Some(tok) if tok.token.kind == TokenKind::Whitespace)
+    }
+
+    /// Consume and return the next token that isn't whitespace.
+    pub fn next_token(&mut self) -> Option<&'a Token> {
+        self.skip_whitespace();
+        let (first, rest) = self.tokens.split_first()?;
+        self.tokens = rest;
+        Some(&first.token)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek_whitespace() {
+            self.tokens = &self.tokens[1..];
+        }
+    }
+
+    /// The source text of a token.
+    pub fn text(&self, tok: &Token) -> &'a str {
+        &self.src[tok.span.start()..tok.span.end()]
+    }
+
+    /// The span of the next token, or the end of input if there are no tokens left.
+    pub fn span(&self) -> Span {
+        match self.peek() {
+            Some(tok) => tok.span,
+            None => Span::new(self.end, self.end),
+        }
+    }
+
+    /// Create an error at the next token.
+    pub fn error(&self, message: impl fmt::Display) -> ParseError {
+        ParseError::new(self.span(), message)
+    }
+
+    /// Whether the next token is of the given kind.
+    pub fn peek_kind(&self, kind: &TokenKind) -> bool {
+        self.peek_is(|k| k == kind)
+    }
+
+    /// Whether the next token matches `pred`.
+    pub fn peek_is(&self, pred: impl FnOnce(&TokenKind) -> bool) -> bool {
+        matches!(self.peek(), Some(tok) if pred(&tok.kind))
+    }
+
+    /// Consume a token of the given kind, or error with `expected` as the description.
+    pub fn expect(&mut self, kind: &TokenKind, expected: &str) -> Result<Span> {
+        if self.peek_kind(kind) {
+            Ok(self.next_token().unwrap().span)
+        } else {
+            Err(self.error(format!("expected {}", expected)))
+        }
+    }
+
+    /// Whether the next token is the delimiter `ch`.
+    pub fn peek_delim(&self, ch: char) -> bool {
+        self.peek_kind(&TokenKind::Delim(ch))
+    }
+
+    /// Whether the next token is the identifier `name` (ignoring ascii case).
+    pub fn peek_ident(&self, name: &str) -> bool {
+        self.peek_is(
+            |kind| matches!(kind, TokenKind::Ident(ident) if ident.eq_ignore_ascii_case(name)),
+        )
+    }
+
+    /// Whether the next token is the start of the function `name` (ignoring ascii case).
+    pub fn peek_function(&self, name: &str) -> bool {
+        self.peek_is(
+            |kind| matches!(kind, TokenKind::Function(function) if function.eq_ignore_ascii_case(name)),
+        )
+    }
+
+    /// Consume the function `name`, returning a parser for its arguments.
+    pub fn function(&mut self, name: &str) -> Result<Parser<'a>> {
+        if !self.peek_function(name) {
+            return Err(self.error(format!("expected `{}(`", name)));
+        }
+        Ok(self.enclosed())
+    }
+
+    /// Consume a `{}` block, returning a parser for its content.
+    pub fn block(&mut self) -> Result<Parser<'a>> {
+        if !self.peek_kind(&TokenKind::LBrace) {
+            return Err(self.error("expected `{`"));
+        }
+        Ok(self.enclosed())
+    }
+
+    /// Consume a `[]` block, returning a parser for its content.
+    pub fn brackets(&mut self) -> Result<Parser<'a>> {
+        if !self.peek_kind(&TokenKind::LBracket) {
+            return Err(self.error("expected `[`"));
+        }
+        Ok(self.enclosed())
+    }
+
+    /// Consume the block or function at the next token, returning a parser for its content.
+    fn enclosed(&mut self) -> Parser<'a> {
+        self.skip_whitespace();
+        let (block, rest) = self.tokens.split_at(self.tokens[0].len);
+        self.tokens = rest;
+        match block {
+            [_, content @ .., close] => Parser {
+                tokens: content,
+                end: close.token.span.start(),
+                ..*self
+            },
+            // the opening token of a block nested too deeply, which `parse_str` doesn't get to.
+            _ => Parser {
+                tokens: &[],
+                end: self.span().start(),
+                ..*self
+            },
+        }
+    }
+
+    /// Consume the rest of the input, returning its source text with each run of whitespace
+    /// replaced by a single space, and leading and trailing whitespace removed.
+    pub fn css_text(&mut self) -> String {
+        let mut out = String::new();
+        let mut space = false;
+        for tok in self.tokens.iter().map(|tok| &tok.token) {
+            if tok.kind == TokenKind::Whitespace {
+                space = !out.is_empty();
+            } else {
+                if space {
+                    out.push(' ');
+                    space = false;
+                }
+                out.push_str(self.text(tok));
+            }
+        }
+        self.tokens = &[];
+        out
+    }
+
+    /// Split off the tokens up to the first top-level token matching `pred`, returning a parser
+    /// for them. `self` is left at the matching token (or at the end if there wasn't one).
+    pub fn until(&mut self, pred: impl Fn(&TokenKind) -> bool) -> Parser<'a> {
+        let mut idx = 0;
+        while let Some(tok) = self.tokens.get(idx) {
+            if pred(&tok.token.kind) {
+                break;
+            }
+            idx += tok.len;
+        }
+        let (content, rest) = self.tokens.split_at(idx);
+        let end = match rest.first() {
+            Some(tok) => tok.token.span.start(),
+            None => self.end,
+        };
+        self.tokens = rest;
+        Parser {
+            tokens: content,
+            end,
+            ..*self
+        }
+    }
+
+    /// Record an error that was recovered from, like an invalid declaration that was dropped.
+    pub fn recover(&self, err: ParseError) {
+        if let Some(recovered) = self.recovered {
+            recovered.borrow_mut().push(err);
+        }
+    }
+}
+
+/// Invalid declarations, including unknown properties, are dropped as in
+/// https://www.w3.org/TR/css-syntax-3/#consume-list-of-declarations. Use
+/// [`Styles::parse_with_errors`] to find out why.
+impl Parse for Styles {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut rules = Vec::new();
+        loop {
+            let mut decl = s.until(|kind| *kind == TokenKind::Semicolon);
+            if !decl.is_empty() {
+                match decl.parse_all() {
+                    Ok(style) => rules.push(style),
+                    Err(err) => s.recover(err),
+                }
+            }
+            if s.next_token().is_none() {
+                break;
+            }
+        }
+        Ok(Styles::from(rules))
+    }
+}
+
+impl Styles {
+    /// Parse a list of declarations, also returning the errors for the declarations that were
+    /// invalid and so were dropped.
+    ///
+    /// This only fails if blocks and functions are nested too deeply.
+    pub fn parse_with_errors(css: &str) -> Result<(Self, Vec<ParseError>)> {
+        parse_str_with_errors(css)
+    }
+}
+
+impl Stylesheet {
+    /// Parse a stylesheet, also returning the errors for the declarations that were invalid and
+    /// so were dropped.
+    pub fn parse_with_errors(css: &str) -> Result<(Self, Vec<ParseError>)> {
+        parse_str_with_errors(css)
+    }
+}
+
+impl Parse for Stylesheet {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut rules = Vec::new();
+        loop {
+            // `<!--` and `-->` are ignored at the top level, for compatibility with old browsers.
+            while s.peek_is(|kind| matches!(kind, TokenKind::CDO | TokenKind::CDC)) {
+                s.next_token();
+            }
+            if s.is_empty() {
+                break;
+            }
+            rules.push(s.parse()?);
+        }
+        Ok(Stylesheet { rules })
+    }
+}
+
+impl Parse for Rule {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_is(|kind| matches!(kind, TokenKind::At(_))) {
+            Ok(Rule::At(s.parse()?))
+        } else {
+            Ok(Rule::Qualified(s.parse()?))
+        }
+    }
+}
+
+impl Parse for QualifiedRule {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut prelude = s.until(|kind| *kind == TokenKind::LBrace);
+        if prelude.is_empty() {
+            return Err(prelude.error("expected selector"));
+        }
+        let selectors = prelude.parse_all()?;
+        let styles = s.block()?.parse()?;
+        Ok(QualifiedRule { selectors, styles })
+    }
+}
+
+impl Parse for AtRule {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name = match s.peek() {
+            Some(Token {
+                kind: TokenKind::At(name),
+                ..
+            }) => name.clone(),
+            _ => return Err(s.error("expected at-rule")),
+        };
+        s.next_token();
+        let prelude = s
+            .until(|kind| matches!(kind, TokenKind::LBrace | TokenKind::Semicolon))
+            .css_text();
+        let block = if s.is_empty() {
+            None
+        } else if s.peek_kind(&TokenKind::Semicolon) {
+            s.next_token();
+            None
+        } else {
+            let mut content = s.block()?;
+            Some(if AtRule::contains_rules(&name) {
+                AtRuleBlock::Rules(content.parse::<Stylesheet>()?.rules)
+            } else if AtRule::contains_keyframes(&name) {
+                let mut keyframes = Vec::new();
+                while !content.is_empty() {
+                    keyframes.push(content.parse()?);
+                }
+                AtRuleBlock::Keyframes(keyframes)
+            } else {
+                AtRuleBlock::Raw(content.css_text())
+            })
+        };
+        Ok(AtRule {
+            name,
+            prelude,
+            block,
+        })
+    }
+}
+
+impl Parse for Style {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        // Split off `!important` first, so the value parsers don't need to know about it.
+        let mut decl = s.until(|kind| *kind == TokenKind::Delim('!'));
+        let style = declaration(&mut decl)?;
+        if s.is_empty() {
+            return Ok(style);
+        }
+        s.next_token();
+        let word: Ident = s.parse()?;
+        word.expect("important")?;
+        Ok(style.important())
+    }
+}
+
+/// Parse a declaration, without the `!important` flag.
+fn declaration(s: &mut Parser) -> Result<Style> {
+    if let Some(Token {
+        kind: TokenKind::String(unchecked),
+        ..
+    }) = s.peek()
+    {
+        s.next_token();
+        return Ok(Style::Unchecked(unchecked.clone()));
+    }
+
+    let name: Ident = s.parse()?;
+    if name.try_match("dummy") {
+        return Ok(Style::Dummy);
+    }
+
+    s.expect(&TokenKind::Colon, "`:`")?;
+
+    let output = if name.try_match("align-content") {
+        Style::AlignContent(s.parse()?)
+    } else if name.try_match("align-items") {
+        Style::AlignItems(s.parse()?)
+    } else if name.try_match("align-self") {
+        Style::AlignSelf(s.parse()?)
+    // all
+    } else if name.try_match("animation") {
+        Style::Animation(s.parse()?)
+    } else if name.try_match("animation-delay") {
+        Style::AnimationDelay(s.parse()?)
+    } else if name.try_match("animation-direction") {
+        Style::AnimationDirection(s.parse()?)
+    } else if name.try_match("animation-duration") {
+        Style::AnimationDuration(durations(s)?)
+    } else if name.try_match("animation-fill-mode") {
+        Style::AnimationFillMode(s.parse()?)
+    } else if name.try_match("animation-iteration-count") {
+        Style::AnimationIterationCount(s.parse()?)
+    } else if name.try_match("animation-name") {
+        Style::AnimationName(s.parse()?)
+    } else if name.try_match("animation-play-state") {
+        Style::AnimationPlayState(s.parse()?)
+    } else if name.try_match("animation-timing-function") {
+        Style::AnimationTimingFunction(s.parse()?)
+    } else if name.try_match("backface-visibility") {
+        Style::BackfaceVisibility(s.parse()?)
+    } else if name.try_match("background") {
+        Style::Background(s.parse()?)
+    } else if name.try_match("background-attachment") {
+        Style::BackgroundAttachment(s.parse()?)
+    } else if name.try_match("background-blend-mode") {
+        Style::BackgroundBlendMode(s.parse()?)
+    } else if name.try_match("background-clip") {
+        Style::BackgroundClip(s.parse()?)
+    } else if name.try_match("background-color") {
+        Style::BackgroundColor(s.parse()?)
+    } else if name.try_match("background-image") {
+        Style::BackgroundImage(s.parse()?)
+    } else if name.try_match("background-origin") {
+        Style::BackgroundOrigin(s.parse()?)
+    } else if name.try_match("background-position") {
+        Style::BackgroundPosition(s.parse()?)
+    } else if name.try_match("background-position-x") {
+        Style::BackgroundPositionX(s.parse()?)
+    } else if name.try_match("background-position-y") {
+        Style::BackgroundPositionY(s.parse()?)
+    } else if name.try_match("background-repeat") {
+        Style::BackgroundRepeat(s.parse()?)
+    } else if name.try_match("background-size") {
+        Style::BackgroundSize(s.parse()?)
+    } else if name.try_match("border") {
+        Style::Border(s.parse()?)
+    } else if name.try_match("border-bottom") {
+        Style::BorderBottom(s.parse()?)
+    } else if name.try_match("border-bottom-color") {
+        Style::BorderBottomColor(s.parse()?)
+    } else if name.try_match("border-bottom-left-radius") {
+        Style::BorderBottomLeftRadius(s.parse()?)
+    } else if name.try_match("border-bottom-right-radius") {
+        Style::BorderBottomRightRadius(s.parse()?)
+    } else if name.try_match("border-bottom-style") {
+        Style::BorderBottomStyle(s.parse()?)
+    } else if name.try_match("border-bottom-width") {
+        Style::BorderBottomWidth(s.parse()?)
+    } else if name.try_match("border-collapse") {
+        Style::BorderCollapse(s.parse()?)
+    } else if name.try_match("border-color") {
+        Style::BorderColor(s.parse()?)
+    } else if name.try_match("border-image") {
+        Style::BorderImage(s.parse()?)
+    } else if name.try_match("border-image-outset") {
+        Style::BorderImageOutset(s.parse()?)
+    } else if name.try_match("border-image-repeat") {
+        Style::BorderImageRepeat(s.parse()?)
+    } else if name.try_match("border-image-slice") {
+        Style::BorderImageSlice(s.parse()?)
+    } else if name.try_match("border-image-source") {
+        Style::BorderImageSource(s.parse()?)
+    } else if name.try_match("border-image-width") {
+        Style::BorderImageWidth(s.parse()?)
+    } else if name.try_match("border-left") {
+        Style::BorderLeft(s.parse()?)
+    } else if name.try_match("border-left-color") {
+        Style::BorderLeftColor(s.parse()?)
+    } else if name.try_match("border-left-style") {
+        Style::BorderLeftStyle(s.parse()?)
+    } else if name.try_match("border-left-width") {
+        Style::BorderLeftWidth(s.parse()?)
+    } else if name.try_match("border-radius") {
+        Style::BorderRadius(s.parse()?)
+    } else if name.try_match("border-right") {
+        Style::BorderRight(s.parse()?)
+    } else if name.try_match("border-right-color") {
+        Style::BorderRightColor(s.parse()?)
+    } else if name.try_match("border-right-style") {
+        Style::BorderRightStyle(s.parse()?)
+    } else if name.try_match("border-right-width") {
+        Style::BorderRightWidth(s.parse()?)
+    // border-spacing
+    } else if name.try_match("border-style") {
+        Style::BorderStyle(s.parse()?)
+    } else if name.try_match("border-top") {
+        Style::BorderTop(s.parse()?)
+    } else if name.try_match("border-top-color") {
+        Style::BorderTopColor(s.parse()?)
+    } else if name.try_match("border-top-left-radius") {
+        Style::BorderTopLeftRadius(s.parse()?)
+    } else if name.try_match("border-top-right-radius") {
+        Style::BorderTopRightRadius(s.parse()?)
+    } else if name.try_match("border-top-style") {
+        Style::BorderTopStyle(s.parse()?)
+    } else if name.try_match("border-top-width") {
+        Style::BorderTopWidth(s.parse()?)
+    } else if name.try_match("border-width") {
+        Style::BorderWidth(s.parse()?)
+    } else if name.try_match("bottom") {
+        Style::Bottom(s.parse()?)
+    // box-decoration-break
+    } else if name.try_match("box-shadow") {
+        Style::BoxShadow(s.parse()?)
+    } else if name.try_match("box-sizing") {
+        Style::BoxSizing(s.parse()?)
+    // break-after
+    // break-before
+    // break-inside
+    // caption-side
+    // caret-color
+    } else if name.try_match("clear") {
+        Style::Clear(s.parse()?)
+    // clip
+    // clip-path
+    // clip-rule
+    } else if name.try_match("column-count") {
+        Style::ColumnCount(s.parse()?)
+    } else if name.try_match("color") {
+        Style::Color(s.parse()?)
+    // contain
+    // content
+    // counter-increment
+    // counter-reset
+    // cue
+    // cue-after
+    // cue-before
+    } else if name.try_match("cursor") {
+        Style::Cursor(s.parse()?)
+    } else if name.try_match("direction") {
+        Style::Direction(s.parse()?)
+    } else if name.try_match("display") {
+        Style::Display(s.parse()?)
+    // elevation
+    // empty-cells
+    } else if name.try_match("flex") {
+        Style::Flex(s.parse()?)
+    } else if name.try_match("flex-basis") {
+        Style::FlexBasis(s.parse()?)
+    } else if name.try_match("flex-direction") {
+        Style::FlexDirection(s.parse()?)
+    } else if name.try_match("flex-flow") {
+        Style::FlexFlow(s.parse()?)
+    } else if name.try_match("flex-grow") {
+        let number: Number = s.parse()?;
+        number.empty_suffix()?;
+        Style::FlexGrow(number.value)
+    } else if name.try_match("flex-shrink") {
+        let number: Number = s.parse()?;
+        number.empty_suffix()?;
+        Style::FlexShrink(number.value)
+    } else if name.try_match("flex-wrap") {
+        Style::FlexWrap(s.parse()?)
+    } else if name.try_match("float") {
+        Style::Float(s.parse()?)
+    } else if name.try_match("font") {
+        Style::Font(s.parse()?)
+    } else if name.try_match("font-family") {
+        Style::FontFamily(s.parse()?)
+    } else if name.try_match("font-feature-settings") {
+        Style::FontFeatureSettings(s.parse()?)
+    } else if name.try_match("font-kerning") {
+        Style::FontKerning(s.parse()?)
+    } else if name.try_match("font-size") {
+        Style::FontSize(s.parse()?)
+    } else if name.try_match("font-size-adjust") {
+        Style::FontSizeAdjust(s.parse()?)
+    } else if name.try_match("font-stretch") {
+        Style::FontStretch(s.parse()?)
+    } else if name.try_match("font-style") {
+        Style::FontStyle(s.parse()?)
+    } else if name.try_match("font-synthesis") {
+        Style::FontSynthesis(s.parse()?)
+    } else if name.try_match("font-variant") {
+        Style::FontVariant(s.parse()?)
+    } else if name.try_match("font-variant-alternates") {
+        Style::FontVariantAlternates(s.parse()?)
+    } else if name.try_match("font-variant-caps") {
+        Style::FontVariantCaps(s.parse()?)
+    } else if name.try_match("font-variant-east-asian") {
+        Style::FontVariantEastAsian(s.parse()?)
+    } else if name.try_match("font-variant-emoji") {
+        Style::FontVariantEmoji(s.parse()?)
+    } else if name.try_match("font-variant-ligatures") {
+        Style::FontVariantLigatures(s.parse()?)
+    } else if name.try_match("font-variant-numeric") {
+        Style::FontVariantNumeric(s.parse()?)
+    } else if name.try_match("font-variant-position") {
+        Style::FontVariantPosition(s.parse()?)
+    } else if name.try_match("font-variation-settings") {
+        Style::FontVariationSettings(s.parse()?)
+    } else if name.try_match("font-weight") {
+        Style::FontWeight(s.parse()?)
+    // glyph-orientation-vertical
+    } else if name.try_match("grid") {
+        Style::Grid(s.parse()?)
+    } else if name.try_match("grid-area") {
+        Style::GridArea(s.parse()?)
+    } else if name.try_match("grid-auto-columns") {
+        Style::GridAutoColumns(s.parse()?)
+    } else if name.try_match("grid-auto-flow") {
+        Style::GridAutoFlow(s.parse()?)
+    } else if name.try_match("grid-auto-rows") {
+        Style::GridAutoRows(s.parse()?)
+    } else if name.try_match("grid-column") {
+        Style::GridColumn(s.parse()?)
+    } else if name.try_match("grid-column-end") {
+        Style::GridColumnEnd(s.parse()?)
+    } else if name.try_match("grid-column-start") {
+        Style::GridColumnStart(s.parse()?)
+    } else if name.try_match("grid-row") {
+        Style::GridRow(s.parse()?)
+    } else if name.try_match("grid-row-end") {
+        Style::GridRowEnd(s.parse()?)
+    } else if name.try_match("grid-row-start") {
+        Style::GridRowStart(s.parse()?)
+    } else if name.try_match("grid-template") {
+        Style::GridTemplate(s.parse()?)
+    } else if name.try_match("grid-template-areas") {
+        Style::GridTemplateAreas(s.parse()?)
+    } else if name.try_match("grid-template-columns") {
+        Style::GridTemplateColumns(s.parse()?)
+    } else if name.try_match("grid-template-rows") {
+        Style::GridTemplateRows(s.parse()?)
+    } else if name.try_match("hanging-punctuation") {
+        Style::HangingPunctuation(s.parse()?)
+    } else if name.try_match("height") {
+        Style::Height(s.parse()?)
+    } else if name.try_match("hyphenate-character") {
+        Style::HyphenateCharacter(s.parse()?)
+    } else if name.try_match("hyphens") {
+        Style::Hyphens(s.parse()?)
+    // image-orientation
+    // image-rendering
+    // isolation
+    } else if name.try_match("justify-content") {
+        Style::JustifyContent(s.parse()?)
+    } else if name.try_match("left") {
+        Style::Left(s.parse()?)
+    } else if name.try_match("letter-spacing") {
+        Style::LetterSpacing(s.parse()?)
+    } else if name.try_match("line-break") {
+        Style::LineBreak(s.parse()?)
+    } else if name.try_match("line-height") {
+        Style::LineHeight(s.parse()?)
+    // list-style
+    // list-style-image
+    // list-style-position
+    } else if name.try_match("list-style-type") {
+        Style::ListStyleType(s.parse()?)
+    } else if name.try_match("margin") {
+        Style::Margin(s.parse()?)
+    } else if name.try_match("margin-bottom") {
+        Style::MarginBottom(s.parse()?)
+    } else if name.try_match("margin-left") {
+        Style::MarginLeft(s.parse()?)
+    } else if name.try_match("margin-right") {
+        Style::MarginRight(s.parse()?)
+    } else if name.try_match("margin-top") {
+        Style::MarginTop(s.parse()?)
+    // mask
+    // mask-border
+    // mask-border-mode
+    // mask-border-outset
+    // mask-border-repeat
+    // mask-border-slice
+    // mask-border-source
+    // mask-border-width
+    // mask-clip
+    // mask-composite
+    // mask-image
+    // mask-mode
+    // mask-origin
+    } else if name.try_match("mask-position") {
+        Style::MaskPosition(s.parse()?)
+    // mask-repeat
+    // mask-size
+    // mask-type
+    } else if name.try_match("max-height") {
+        Style::MaxHeight(s.parse()?)
+    } else if name.try_match("max-width") {
+        Style::MaxWidth(s.parse()?)
+    } else if name.try_match("min-height") {
+        Style::MinHeight(s.parse()?)
+    } else if name.try_match("min-width") {
+        Style::MinWidth(s.parse()?)
+    // mix-blend-mode
+    } else if name.try_match("object-fit") {
+        Style::ObjectFit(s.parse()?)
+    } else if name.try_match("object-position") {
+        Style::ObjectPosition(s.parse()?)
+    // opacity
+    // order
+    // orphans
+    // outline
+    // outline-color
+    // outline-offset
+    // outline-style
+    // outline-width
+    } else if name.try_match("overflow") {
+        Style::Overflow(s.parse()?)
+    } else if name.try_match("overflow-wrap") {
+        Style::OverflowWrap(s.parse()?)
+    } else if name.try_match("overflow-x") {
+        Style::OverflowX(s.parse()?)
+    } else if name.try_match("overflow-y") {
+        Style::OverflowY(s.parse()?)
+    } else if name.try_match("padding") {
+        Style::Padding(s.parse()?)
+    } else if name.try_match("padding-bottom") {
+        Style::PaddingBottom(s.parse()?)
+    } else if name.try_match("padding-left") {
+        Style::PaddingLeft(s.parse()?)
+    } else if name.try_match("padding-right") {
+        Style::PaddingRight(s.parse()?)
+    } else if name.try_match("padding-top") {
+        Style::PaddingTop(s.parse()?)
+    // page-break-after
+    // page-break-before
+    // page-break-inside
+    // pause
+    // pause-after
+    // pause-before
+    } else if name.try_match("perspective") {
+        Style::Perspective(s.parse()?)
+    } else if name.try_match("perspective-origin") {
+        Style::PerspectiveOrigin(s.parse()?)
+    // pitch
+    // pitch-range
+    // play-during
+    } else if name.try_match("position") {
+        Style::Position(s.parse()?)
+    // quotes
+    } else if name.try_match("resize") {
+        Style::Resize(s.parse()?)
+    // richness
+    } else if name.try_match("right") {
+        Style::Right(s.parse()?)
+    } else if name.try_match("rotate") {
+        Style::Rotate(s.parse()?)
+    } else if name.try_match("scale") {
+        Style::Scale(s.parse()?)
+    // scroll-margin
+    // scroll-margin-block
+    // scroll-margin-block-end
+    // scroll-margin-block-start
+    // scroll-margin-bottom
+    // scroll-margin-inline
+    // scroll-margin-inline-end
+    // scroll-margin-inline-start
+    // scroll-margin-left
+    // scroll-margin-right
+    // scroll-margin-top
+    // scroll-padding
+    // scroll-padding-block
+    // scroll-padding-block-end
+    // scroll-padding-block-start
+    // scroll-padding-bottom
+    // scroll-padding-inline
+    // scroll-padding-inline-end
+    // scroll-padding-inline-start
+    // scroll-padding-left
+    // scroll-padding-right
+    // scroll-padding-top
+    // scroll-snap-align
+    // scroll-snap-stop
+    // scroll-snap-type
+    // shape-image-threshold
+    // shape-margin
+    // shape-outside
+    // speak
+    // speak-header
+    // speak-numeral
+    // speak-punctuation
+    // speech-rate
+    // stress
+    } else if name.try_match("tab-size") {
+        Style::TabSize(s.parse()?)
+    // table-layout
+    } else if name.try_match("text-align") {
+        Style::TextAlign(s.parse()?)
+    } else if name.try_match("text-combine-upright") {
+        Style::TextCombineUpright(s.parse()?)
+    } else if name.try_match("text-decoration") {
+        Style::TextDecoration(s.parse()?)
+    } else if name.try_match("text-decoration-color") {
+        Style::TextDecorationColor(s.parse()?)
+    } else if name.try_match("text-decoration-line") {
+        Style::TextDecorationLine(s.parse()?)
+    } else if name.try_match("text-decoration-style") {
+        Style::TextDecorationStyle(s.parse()?)
+    } else if name.try_match("text-decoration-thickness") {
+        Style::TextDecorationThickness(s.parse()?)
+    // text-emphasis
+    // text-emphasis-color
+    // text-emphasis-position
+    // text-emphasis-style
+    } else if name.try_match("text-indent") {
+        Style::TextIndent(s.parse()?)
+    } else if name.try_match("text-orientation") {
+        Style::TextOrientation(s.parse()?)
+    // text-overflow
+    } else if name.try_match("text-shadow") {
+        Style::TextShadow(s.parse()?)
+    } else if name.try_match("text-transform") {
+        Style::TextTransform(s.parse()?)
+    } else if name.try_match("text-underline-offset") {
+        Style::TextUnderlineOffset(s.parse()?)
+    } else if name.try_match("text-underline-position") {
+        Style::TextUnderlinePosition(s.parse()?)
+    } else if name.try_match("text-wrap") {
+        Style::TextWrap(s.parse()?)
+    } else if name.try_match("text-wrap-mode") {
+        Style::TextWrapMode(s.parse()?)
+    } else if name.try_match("text-wrap-style") {
+        Style::TextWrapStyle(s.parse()?)
+    } else if name.try_match("top") {
+        Style::Top(s.parse()?)
+    } else if name.try_match("transform") {
+        Style::Transform(s.parse()?)
+    // transform-box
+    } else if name.try_match("transform-origin") {
+        Style::TransformOrigin(s.parse()?)
+    } else if name.try_match("transform-style") {
+        Style::TransformStyle(s.parse()?)
+    } else if name.try_match("transition") {
+        Style::Transition(transitions(s)?)
+    } else if name.try_match("transition-behavior") {
+        Style::TransitionBehavior(s.parse()?)
+    } else if name.try_match("transition-delay") {
+        Style::TransitionDelay(s.parse()?)
+    } else if name.try_match("transition-duration") {
+        Style::TransitionDuration(durations(s)?)
+    } else if name.try_match("transition-property") {
+        Style::TransitionProperty(s.parse()?)
+    } else if name.try_match("transition-timing-function") {
+        Style::TransitionTimingFunction(s.parse()?)
+    } else if name.try_match("translate") {
+        Style::Translate(s.parse()?)
+    } else if name.try_match("unicode-bidi") {
+        Style::UnicodeBidi(s.parse()?)
+    // vertical-align
+    // visibility
+    // voice-family
+    // volume
+    } else if name.try_match("white-space") {
+        Style::WhiteSpace(s.parse()?)
+    } else if name.try_match("white-space-collapse") {
+        Style::WhiteSpaceCollapse(s.parse()?)
+    } else if name.try_match("widows") {
+        Style::Widows(integer(s, 1..)?)
+    } else if name.try_match("width") {
+        Style::Width(s.parse()?)
+    // will-change
+    } else if name.try_match("word-break") {
+        Style::WordBreak(s.parse()?)
+    } else if name.try_match("word-wrap") {
+        // legacy name for `overflow-wrap`
+        Style::OverflowWrap(s.parse()?)
+    } else if name.try_match("word-spacing") {
+        Style::WordSpacing(s.parse()?)
+    } else if name.try_match("writing-mode") {
+        Style::WritingMode(s.parse()?)
+    // z-index
+    } else {
+        return Err(name.error());
+    };
+
+    if !s.is_empty() {
+        return Err(s.error("unexpected trailing tokens in style rule"));
+    }
+
+    Ok(output)
+}
+
+impl Parse for AlignContent {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+
+        if name.try_match("flex-start") {
+            Ok(AlignContent::FlexStart)
+        } else if name.try_match("flex-end") {
+            Ok(AlignContent::FlexEnd)
+        } else if name.try_match("center") {
+            Ok(AlignContent::Center)
+        } else if name.try_match("space-between") {
+            Ok(AlignContent::SpaceBetween)
+        } else if name.try_match("space-around") {
+            Ok(AlignContent::SpaceAround)
+        } else if name.try_match("stretch") {
+            Ok(AlignContent::Stretch)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for AlignItems {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("normal") {
+            Ok(AlignItems::Normal)
+        } else if word.try_match("stretch") {
+            Ok(AlignItems::Stretch)
+        } else if word.try_match("center") {
+            Ok(AlignItems::Center)
+        } else if word.try_match("start") {
+            Ok(AlignItems::Start)
+        } else if word.try_match("end") {
+            Ok(AlignItems::End)
+        } else if word.try_match("flex-start") {
+            Ok(AlignItems::FlexStart)
+        } else if word.try_match("flex-end") {
+            Ok(AlignItems::FlexEnd)
+        } else if word.try_match("baseline") {
+            Ok(AlignItems::Baseline)
+        } else if word.try_match("first") {
+            s.parse::<Ident>()?.expect("baseline")?;
+            Ok(AlignItems::FirstBaseline)
+        } else if word.try_match("last") {
+            s.parse::<Ident>()?.expect("baseline")?;
+            Ok(AlignItems::LastBaseline)
+        } else if word.try_match("safe") {
+            s.parse::<Ident>()?.expect("center")?;
+            Ok(AlignItems::SafeCenter)
+        } else if word.try_match("unsafe") {
+            s.parse::<Ident>()?.expect("center")?;
+            Ok(AlignItems::UnsafeCenter)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for AlignSelf {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("auto") {
+            Ok(AlignSelf::Auto)
+        } else if word.try_match("normal") {
+            Ok(AlignSelf::Normal)
+        } else if word.try_match("center") {
+            Ok(AlignSelf::Center)
+        } else if word.try_match("start") {
+            Ok(AlignSelf::Start)
+        } else if word.try_match("end") {
+            Ok(AlignSelf::End)
+        } else if word.try_match("self-start") {
+            Ok(AlignSelf::SelfStart)
+        } else if word.try_match("self-end") {
+            Ok(AlignSelf::SelfEnd)
+        } else if word.try_match("flex-start") {
+            Ok(AlignSelf::FlexStart)
+        } else if word.try_match("flex-end") {
+            Ok(AlignSelf::FlexEnd)
+        } else if word.try_match("baseline") {
+            Ok(AlignSelf::Baseline)
+        } else if word.try_match("first") {
+            s.parse::<Ident>()?.expect("baseline")?;
+            Ok(AlignSelf::FirstBaseline)
+        } else if word.try_match("last") {
+            s.parse::<Ident>()?.expect("baseline")?;
+            Ok(AlignSelf::LastBaseline)
+        } else if word.try_match("stretch") {
+            Ok(AlignSelf::Stretch)
+        } else if word.try_match("safe") {
+            s.parse::<Ident>()?.expect("center")?;
+            Ok(AlignSelf::SafeCenter)
+        } else if word.try_match("unsafe") {
+            s.parse::<Ident>()?.expect("center")?;
+            Ok(AlignSelf::UnsafeCenter)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for BackgroundAttachment {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("scroll") {
+            Ok(BackgroundAttachment::Scroll)
+        } else if word.try_match("fixed") {
+            Ok(BackgroundAttachment::Fixed)
+        } else if word.try_match("local") {
+            Ok(BackgroundAttachment::Local)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for BlendMode {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("normal") {
+            Ok(BlendMode::Normal)
+        } else if word.try_match("multiply") {
+            Ok(BlendMode::Multiply)
+        } else if word.try_match("screen") {
+            Ok(BlendMode::Screen)
+        } else if word.try_match("overlay") {
+            Ok(BlendMode::Overlay)
+        } else if word.try_match("darken") {
+            Ok(BlendMode::Darken)
+        } else if word.try_match("lighten") {
+            Ok(BlendMode::Lighten)
+        } else if word.try_match("color-dodge") {
+            Ok(BlendMode::ColorDodge)
+        } else if word.try_match("color-burn") {
+            Ok(BlendMode::ColorBurn)
+        } else if word.try_match("hard-light") {
+            Ok(BlendMode::HardLight)
+        } else if word.try_match("soft-light") {
+            Ok(BlendMode::SoftLight)
+        } else if word.try_match("difference") {
+            Ok(BlendMode::Difference)
+        } else if word.try_match("exclusion") {
+            Ok(BlendMode::Exclusion)
+        } else if word.try_match("hue") {
+            Ok(BlendMode::Hue)
+        } else if word.try_match("saturation") {
+            Ok(BlendMode::Saturation)
+        } else if word.try_match("color") {
+            Ok(BlendMode::Color)
+        } else if word.try_match("luminosity") {
+            Ok(BlendMode::Luminosity)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for BackgroundImage {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("none") {
+            s.next_token();
+            Ok(BackgroundImage::None)
+        } else {
+            s.parse().map(BackgroundImage::Image)
+        }
+    }
+}
+
+impl Parse for BackgroundBox {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("border-box") {
+            Ok(BackgroundBox::BorderBox)
+        } else if word.try_match("padding-box") {
+            Ok(BackgroundBox::PaddingBox)
+        } else if word.try_match("content-box") {
+            Ok(BackgroundBox::ContentBox)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for BackgroundRepeat {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("repeat-x") {
+            s.next_token();
+            Ok(BackgroundRepeat::RepeatX)
+        } else if s.peek_ident("repeat-y") {
+            s.next_token();
+            Ok(BackgroundRepeat::RepeatY)
+        } else {
+            Ok(BackgroundRepeat::SingleOrDouble(s.parse()?))
+        }
+    }
+}
+
+impl Parse for BgRepeatPart {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("repeat") {
+            Ok(BgRepeatPart::Repeat)
+        } else if word.try_match("space") {
+            Ok(BgRepeatPart::Space)
+        } else if word.try_match("round") {
+            Ok(BgRepeatPart::Round)
+        } else if word.try_match("no-repeat") {
+            Ok(BgRepeatPart::NoRepeat)
+        } else {
+            word.add_expected("`repeat-x`");
+            word.add_expected("`repeat-y`");
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for BackgroundSize {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("cover") {
+            s.next_token();
+            Ok(BackgroundSize::Cover)
+        } else if s.peek_ident("contain") {
+            s.next_token();
+            Ok(BackgroundSize::Contain)
+        } else {
+            s.parse().map(BackgroundSize::SingleOrDouble).map_err(|_| {
+                s.error("expected one of `contain`, `cover`, `auto`, <length>, <percentage>")
+            })
+        }
+    }
+}
+
+impl Parse for Border {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut border = Border::new();
+        while !(border.is_full() || s.is_empty()) {
+            let mut matched_something = false; // prevents an infinite loop when no matches
+            let mut width_fork = s.fork();
+            if let Ok(line_width) = width_fork.parse::<LineWidth>() {
+                if border.has_line_width() {
+                    return Err(s.error("the border width was specified more than once"));
+                }
+                matched_something = true;
+                border.line_width = Some(line_width);
+                s.advance_to(&width_fork);
+            }
+            let mut style_fork = s.fork();
+            if let Ok(line_style) = style_fork.parse::<LineStyle>() {
+                if border.has_line_style() {
+                    return Err(s.error("the border style was specified more than once"));
+                }
+                matched_something = true;
+                border.line_style = Some(line_style);
+                s.advance_to(&style_fork);
+            }
+            let mut color_fork = s.fork();
+            if let Ok(color) = color_fork.parse::<Color>() {
+                if border.has_color() {
+                    return Err(s.error("the border color was specified more than once"));
+                }
+                matched_something = true;
+                border.color = Some(color);
+                s.advance_to(&color_fork);
+            }
+            if !(matched_something || s.is_empty()) {
+                return Err(s.error(
+                    "unexpected input - expected one of border-width, border-style, color",
+                ));
+            }
+        }
+        Ok(border)
+    }
+}
+
+impl Parse for BorderCollapse {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("collapse") {
+            Ok(BorderCollapse::Collapse)
+        } else if word.try_match("separate") {
+            Ok(BorderCollapse::Separate)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for BoxShadow {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("none") {
+            s.next_token();
+            Ok(BoxShadow::None)
+        } else {
+            Ok(BoxShadow::Shadows(s.parse()?))
+        }
+    }
+}
+
+impl Parse for BoxSizing {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("border-box") {
+            Ok(BoxSizing::BorderBox)
+        } else if word.try_match("content-box") {
+            Ok(BoxSizing::ContentBox)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for Clear {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("none") {
+            Ok(Clear::None)
+        } else if word.try_match("left") {
+            Ok(Clear::Left)
+        } else if word.try_match("right") {
+            Ok(Clear::Right)
+        } else if word.try_match("both") {
+            Ok(Clear::Both)
+        } else if word.try_match("inline-start") {
+            Ok(Clear::InlineStart)
+        } else if word.try_match("inline-end") {
+            Ok(Clear::InlineEnd)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for ColumnCount {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_is(|kind| matches!(kind, TokenKind::Number(_))) {
+            Ok(ColumnCount::Fixed(integer(s, ..)?))
+        } else {
+            let word: Ident = s.parse()?;
+            word.add_expected("integer");
+            if word.try_match("auto") {
+                Ok(ColumnCount::Auto)
+            } else {
+                Err(word.error())
+            }
+        }
+    }
+}
+
+impl Parse for Cursor {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("auto") {
+            Ok(Cursor::Auto)
+        } else if word.try_match("default") {
+            Ok(Cursor::Default)
+        } else if word.try_match("none") {
+            Ok(Cursor::None)
+        } else if word.try_match("context-menu") {
+            Ok(Cursor::ContextMenu)
+        } else if word.try_match("help") {
+            Ok(Cursor::Help)
+        } else if word.try_match("pointer") {
+            Ok(Cursor::Pointer)
+        } else if word.try_match("progress") {
+            Ok(Cursor::Progress)
+        } else if word.try_match("wait") {
+            Ok(Cursor::Wait)
+        } else if word.try_match("cell") {
+            Ok(Cursor::Cell)
+        } else if word.try_match("crosshair") {
+            Ok(Cursor::Crosshair)
+        } else if word.try_match("text") {
+            Ok(Cursor::Text)
+        } else if word.try_match("vertical-text") {
+            Ok(Cursor::VerticalText)
+        } else if word.try_match("alias") {
+            Ok(Cursor::Alias)
+        } else if word.try_match("copy") {
+            Ok(Cursor::Copy)
+        } else if word.try_match("move") {
+            Ok(Cursor::Move)
+        } else if word.try_match("no-drop") {
+            Ok(Cursor::NoDrop)
+        } else if word.try_match("not-allowed") {
+            Ok(Cursor::NotAllowed)
+        } else if word.try_match("grab") {
+            Ok(Cursor::Grab)
+        } else if word.try_match("grabbing") {
+            Ok(Cursor::Grabbing)
+        } else if word.try_match("e-resize") {
+            Ok(Cursor::EResize)
+        } else if word.try_match("n-resize") {
+            Ok(Cursor::NResize)
+        } else if word.try_match("ne-resize") {
+            Ok(Cursor::NEResize)
+        } else if word.try_match("nw-resize") {
+            Ok(Cursor::NWResize)
+        } else if word.try_match("s-resize") {
+            Ok(Cursor::SResize)
+        } else if word.try_match("se-resize") {
+            Ok(Cursor::SEResize)
+        } else if word.try_match("sw-resize") {
+            Ok(Cursor::SWResize)
+        } else if word.try_match("w-resize") {
+            Ok(Cursor::WResize)
+        } else if word.try_match("ew-resize") {
+            Ok(Cursor::EWResize)
+        } else if word.try_match("ns-resize") {
+            Ok(Cursor::NSResize)
+        } else if word.try_match("nesw-resize") {
+            Ok(Cursor::NESWResize)
+        } else if word.try_match("nwse-resize") {
+            Ok(Cursor::NWSEResize)
+        } else if word.try_match("col-resize") {
+            Ok(Cursor::ColResize)
+        } else if word.try_match("row-resize") {
+            Ok(Cursor::RowResize)
+        } else if word.try_match("all-scroll") {
+            Ok(Cursor::AllScroll)
+        } else if word.try_match("zoom-in") {
+            Ok(Cursor::ZoomIn)
+        } else if word.try_match("zoom-out") {
+            Ok(Cursor::ZoomOut)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for Display {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("block") {
+            Ok(Display::Block)
+        } else if word.try_match("flex") {
+            Ok(Display::Flex)
+        } else if word.try_match("inline") {
+            Ok(Display::Inline)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for FlexBasis {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("content") {
+            s.next_token();
+            Ok(FlexBasis::Content)
+        } else {
+            Ok(FlexBasis::Width(s.parse()?))
+        }
+    }
+}
+
+impl Parse for FlexDirection {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("column") {
+            Ok(FlexDirection::Column)
+        } else if word.try_match("column-reverse") {
+            Ok(FlexDirection::ColumnReverse)
+        } else if word.try_match("row") {
+            Ok(FlexDirection::Row)
+        } else if word.try_match("row-reverse") {
+            Ok(FlexDirection::RowReverse)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for FlexWrap {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("wrap") {
+            Ok(FlexWrap::Wrap)
+        } else if word.try_match("nowrap") {
+            Ok(FlexWrap::Nowrap)
+        } else if word.try_match("wrap-reverse") {
+            Ok(FlexWrap::WrapReverse)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for FlexFlow {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut flow = FlexFlow {
+            direction: None,
+            wrap: None,
+        };
+        while !s.is_empty() {
+            if flow.direction.is_none() {
+                if let Some(direction) = try_parse(s) {
+                    flow.direction = Some(direction);
+                    continue;
+                }
+            }
+            if flow.wrap.is_none() {
+                if let Some(wrap) = try_parse(s) {
+                    flow.wrap = Some(wrap);
+                    continue;
+                }
+            }
+            return Err(s.error("expected flex direction or flex wrap"));
+        }
+        if flow.direction.is_none() && flow.wrap.is_none() {
+            return Err(s.error("expected flex direction or flex wrap"));
+        }
+        Ok(flow)
+    }
+}
+
+impl Parse for Flex {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut fork = s.fork();
+        if let Ok(word) = fork.parse::<Ident>() {
+            for (keyword, flex) in [
+                ("none", Flex::None),
+                ("auto", Flex::Auto),
+                ("initial", Flex::Initial),
+            ] {
+                if word.try_match(keyword) && fork.is_empty() {
+                    s.advance_to(&fork);
+                    return Ok(flex);
+                }
+            }
+        }
+
+        let mut factors: Option<(f64, Option<f64>)> = None;
+        let mut basis = None;
+        while !s.is_empty() {
+            // A unitless number is a flex factor, even `0`. The shrink factor must follow the
+            // grow factor directly.
+            if factors.is_none() {
+                if let Some(grow) = flex_factor(s)? {
+                    factors = Some((grow, flex_factor(s)?));
+                    continue;
+                }
+            }
+            if basis.is_none() {
+                basis = Some(s.parse()?);
+                continue;
+            }
+            return Err(s.error("unexpected value in flex"));
+        }
+        if factors.is_none() && basis.is_none() {
+            return Err(s.error("expected flex"));
+        }
+        let (grow, shrink) = factors.unwrap_or((1.0, None));
+        Ok(Flex::Values {
+            grow,
+            shrink: shrink.unwrap_or(1.0),
+            basis: basis.unwrap_or(FlexBasis::Width(Width21::LengthPercentage(Calc::Normal(
+                LengthPercentage::Length(Length::Zero),
+            )))),
+        })
+    }
+}
+
+/// A non-negative unitless number, if there is one.
+fn flex_factor(s: &mut Parser) -> Result<Option<f64>> {
+    let span = s.span();
+    match s.peek().map(|tok| &tok.kind) {
+        Some(TokenKind::Number(n)) => {
+            let value = n.value;
+            s.next_token();
+            if value < 0.0 {
+                return Err(ParseError::new(span, "flex factors can't be negative"));
+            }
+            Ok(Some(value))
+        }
+        _ => Ok(None),
+    }
+}
+
+impl Parse for Float {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("none") {
+            Ok(Float::None)
+        } else if word.try_match("left") {
+            Ok(Float::Left)
+        } else if word.try_match("right") {
+            Ok(Float::Right)
+        } else if word.try_match("inline-start") {
+            Ok(Float::InlineStart)
+        } else if word.try_match("inline-end") {
+            Ok(Float::InlineEnd)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for Font {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if let Some(Token {
+            kind: TokenKind::String(name),
+            ..
+        }) = s.peek()
+        {
+            s.next_token();
+            return Ok(Font::Named(name.clone()));
+        }
+        let name: Ident = s.parse()?;
+        name.add_expected("named font");
+        if name.try_match("serif") {
+            Ok(Font::Serif)
+        } else if name.try_match("sans-serif") {
+            Ok(Font::SansSerif)
+        } else if name.try_match("cursive") {
+            Ok(Font::Cursive)
+        } else if name.try_match("fantasy") {
+            Ok(Font::Fantasy)
+        } else if name.try_match("monospace") {
+            Ok(Font::Monospace)
+        } else if name.try_match("system-ui") {
+            Ok(Font::SystemUi)
+        } else if name.try_match("emoji") {
+            Ok(Font::Emoji)
+        } else if name.try_match("math") {
+            Ok(Font::Math)
+        } else if name.try_match("fangsong") {
+            Ok(Font::Fangsong)
+        } else if name.try_match("ui-serif") {
+            Ok(Font::UiSerif)
+        } else if name.try_match("ui-sans-serif") {
+            Ok(Font::UiSansSerif)
+        } else if name.try_match("ui-monospace") {
+            Ok(Font::UiMonospace)
+        } else if name.try_match("ui-rounded") {
+            Ok(Font::UiRounded)
+        } else if let Some(first) = name.word {
+            // An unquoted family name is a list of identifiers, e.g. `Times New Roman`.
+            let mut family = first;
+            while let Some(Token {
+                kind: TokenKind::Ident(word),
+                ..
+            }) = s.peek()
+            {
+                s.next_token();
+                write!(family, " {}", word).unwrap();
+            }
+            Ok(Font::Named(family))
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for FontSize {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut word_fork = s.fork();
+        let name: Ident = word_fork.parse()?;
+
+        if name.try_match("xx-small") {
+            s.advance_to(&word_fork);
+            Ok(FontSize::XXSmall)
+        } else if name.try_match("x-small") {
+            s.advance_to(&word_fork);
+            Ok(FontSize::XSmall)
+        } else if name.try_match("small") {
+            s.advance_to(&word_fork);
+            Ok(FontSize::Small)
+        } else if name.try_match("medium") {
+            s.advance_to(&word_fork);
+            Ok(FontSize::Medium)
+        } else if name.try_match("large") {
+            s.advance_to(&word_fork);
+            Ok(FontSize::Large)
+        } else if name.try_match("x-large") {
+            s.advance_to(&word_fork);
+            Ok(FontSize::XLarge)
+        } else if name.try_match("xx-large") {
+            s.advance_to(&word_fork);
+            Ok(FontSize::XXLarge)
+        } else if name.try_match("xxx-large") {
+            s.advance_to(&word_fork);
+            Ok(FontSize::XXXLarge)
+        } else if name.try_match("larger") {
+            s.advance_to(&word_fork);
+            Ok(FontSize::Larger)
+        } else if name.try_match("smaller") {
+            s.advance_to(&word_fork);
+            Ok(FontSize::Smaller)
+        } else {
+            s.parse().map(FontSize::LengthPercentage).map_err(|_| {
+                name.add_expected("length");
+                name.add_expected("percentage");
+                name.error()
+            })
+        }
+    }
+}
+
+impl Parse for FontStyle {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+
+        if name.try_match("normal") {
+            Ok(FontStyle::Normal)
+        } else if name.try_match("italic") {
+            Ok(FontStyle::Italic)
+        } else if name.try_match("oblique") {
+            Ok(FontStyle::Oblique)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for FontWeight {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        name.add_expected("number where 1 <= number <= 1000");
+
+        if name.try_match("normal") {
+            Ok(FontWeight::Normal)
+        } else if name.try_match("bold") {
+            Ok(FontWeight::Bold)
+        } else if name.try_match("lighter") {
+            Ok(FontWeight::Lighter)
+        } else if name.try_match("bolder") {
+            Ok(FontWeight::Bolder)
+        } else {
+            let n: Number = s.parse().map_err(|_| name.error())?;
+            if n.suffix.is_empty() && n.value >= 1.0 && n.value <= 1000.0 {
+                Ok(FontWeight::Number(n.value))
+            } else {
+                Err(name.error())
+            }
+        }
+    }
+}
+
+impl Parse for Grid {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if let Some((dense, auto_rows)) = auto_flow(s)? {
+            s.expect(&TokenKind::Delim('/'), "`/`")?;
+            return Ok(Grid::AutoRows {
+                dense,
+                auto_rows,
+                columns: s.parse()?,
+            });
+        }
+        if peek_area_row(s)
+            || (s.peek_ident("none") && {
+                let mut fork = s.fork();
+                fork.next_token();
+                fork.is_empty()
+            })
+        {
+            return Ok(Grid::Template(s.parse()?));
+        }
+        let rows = s.parse()?;
+        s.expect(&TokenKind::Delim('/'), "`/`")?;
+        Ok(match auto_flow(s)? {
+            Some((dense, auto_columns)) => Grid::AutoColumns {
+                rows,
+                dense,
+                auto_columns,
+            },
+            None => Grid::Template(GridTemplate::RowsColumns(rows, s.parse()?)),
+        })
+    }
+}
+
+/// Parse `auto-flow && dense?` followed by optional track sizes, if it is next.
+fn auto_flow(s: &mut Parser) -> Result<Option<(bool, Option<GridAutoTracks>)>> {
+    let dense = if s.peek_ident("auto-flow") {
+        s.next_token();
+        let dense = s.peek_ident("dense");
+        if dense {
+            s.next_token();
+        }
+        dense
+    } else if s.peek_ident("dense") {
+        s.next_token();
+        s.parse::<Ident>()?.expect("auto-flow")?;
+        true
+    } else {
+        return Ok(None);
+    };
+    let mut fork = s.fork();
+    let tracks = match fork.parse::<GridAutoTracks>() {
+        Ok(tracks) => {
+            s.advance_to(&fork);
+            Some(tracks)
+        }
+        Err(_) => None,
+    };
+    Ok(Some((dense, tracks)))
+}
+
+impl Parse for GridArea {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let row_start = s.parse()?;
+        let mut lines = Vec::new();
+        while lines.len() < 3 && s.peek_delim('/') {
+            s.next_token();
+            lines.push(s.parse()?);
+        }
+        let mut lines = lines.into_iter();
+        Ok(GridArea {
+            row_start,
+            column_start: lines.next(),
+            row_end: lines.next(),
+            column_end: lines.next(),
+        })
+    }
+}
+
+impl Parse for GridAutoFlow {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut flow = GridAutoFlow::default();
+        let (mut direction, mut dense) = (false, false);
+        loop {
+            let mut fork = s.fork();
+            let name: Ident = fork.parse()?;
+            if !direction && name.try_match("row") {
+                direction = true;
+            } else if !direction && name.try_match("column") {
+                direction = true;
+                flow.column = true;
+            } else if !dense && name.try_match("dense") {
+                dense = true;
+                flow.dense = true;
+            } else if direction || dense {
+                return Ok(flow);
+            } else {
+                return Err(name.error());
+            }
+            s.advance_to(&fork);
+        }
+    }
+}
+
+impl Parse for GridAutoTracks {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut sizes = vec![s.parse()?];
+        loop {
+            let mut fork = s.fork();
+            match fork.parse() {
+                Ok(size) => {
+                    s.advance_to(&fork);
+                    sizes.push(size);
+                }
+                Err(_) => return Ok(GridAutoTracks(sizes)),
+            }
+        }
+    }
+}
+
+impl Parse for GridLine {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut fork = s.fork();
+        let name: Ident = fork.parse()?;
+        if name.try_match("auto") {
+            s.advance_to(&fork);
+            return Ok(GridLine::Auto);
+        }
+        let span = name.try_match("span");
+        if span {
+            s.advance_to(&fork);
+        }
+        let mut number = None;
+        let mut ident = None;
+        for _ in 0..2 {
+            if number.is_none() && s.peek_is(|kind| matches!(kind, TokenKind::Number(_))) {
+                number = Some((s.span(), integer::<i32, _>(s, ..)?));
+            } else if ident.is_none() && s.peek_is(|kind| matches!(kind, TokenKind::Ident(_))) {
+                ident = Some(grid_line_name(s)?);
+            }
+        }
+        match (span, number, ident) {
+            (_, None, None) => {
+                name.add_expected("integer");
+                name.add_expected("line name");
+                Err(name.error())
+            }
+            (true, Some((span, n)), _) if n < 1 => {
+                Err(ParseError::new(span, "expected a positive integer"))
+            }
+            (true, number, ident) => Ok(GridLine::Span(
+                number.map(|(_, n)| n as u32).unwrap_or(1),
+                ident,
+            )),
+            (false, Some((span, 0)), _) => Err(ParseError::new(span, "grid lines can't be 0")),
+            (false, Some((_, n)), ident) => Ok(GridLine::Line(n, ident)),
+            (false, None, Some(ident)) => Ok(GridLine::Name(ident)),
+        }
+    }
+}
+
+/// A custom identifier naming a grid line or area.
+fn grid_line_name(s: &mut Parser) -> Result<String> {
+    match s.peek().map(|tok| &tok.kind) {
+        Some(TokenKind::Ident(name))
+            if !(name.eq_ignore_ascii_case("auto") || name.eq_ignore_ascii_case("span")) =>
+        {
+            s.next_token();
+            Ok(name.clone())
+        }
+        _ => Err(s.error("expected line name")),
+    }
+}
+
+impl Parse for GridPlacement {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let start = s.parse()?;
+        let end = if s.peek_delim('/') {
+            s.next_token();
+            Some(s.parse()?)
+        } else {
+            None
+        };
+        Ok(GridPlacement { start, end })
+    }
+}
+
+impl Parse for GridTemplate {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if !peek_area_row(s) {
+            let mut fork = s.fork();
+            if fork.peek_ident("none") {
+                fork.next_token();
+                if fork.is_empty() {
+                    s.advance_to(&fork);
+                    return Ok(GridTemplate::None);
+                }
+            }
+            let rows = s.parse()?;
+            s.expect(&TokenKind::Delim('/'), "`/`")?;
+            return Ok(GridTemplate::RowsColumns(rows, s.parse()?));
+        }
+
+        let span = s.span();
+        let mut strings = Vec::new();
+        let mut rows = Vec::new();
+        while peek_area_row(s) {
+            let names_before = optional_line_names(s)?;
+            strings.push(s.string()?);
+            let mut fork = s.fork();
+            let size = match fork.parse() {
+                Ok(size) => {
+                    s.advance_to(&fork);
+                    Some(size)
+                }
+                Err(_) => None,
+            };
+            rows.push(GridTemplateRow {
+                names_before,
+                size,
+                names_after: optional_line_names(s)?,
+            });
+        }
+        let areas = GridAreas::new(strings).map_err(|e| ParseError::new(span, e))?;
+        let columns = if s.peek_delim('/') {
+            s.next_token();
+            let span = s.span();
+            let columns: TrackList = s.parse()?;
+            if columns.has_repeat() {
+                return Err(ParseError::new(
+                    span,
+                    "`repeat()` can't be used with grid areas",
+                ));
+            }
+            Some(columns)
+        } else {
+            None
+        };
+        Ok(GridTemplate::Areas {
+            areas,
+            rows,
+            columns,
+        })
+    }
+}
+
+/// Whether the next tokens start a row of `grid-template` areas (a string, optionally after line
+/// names).
+fn peek_area_row(s: &Parser) -> bool {
+    let is_string = |kind: &TokenKind| matches!(kind, TokenKind::String(_));
+    if s.peek_kind(&TokenKind::LBracket) {
+        let mut fork = s.fork();
+        fork.brackets().is_ok() && fork.peek_is(is_string)
+    } else {
+        s.peek_is(is_string)
+    }
+}
+
+impl Parse for GridTemplateAreas {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if !s.peek_is(|kind| matches!(kind, TokenKind::String(_))) {
+            let name: Ident = s.parse()?;
+            name.add_expected("string");
+            name.expect("none")?;
+            return Ok(GridTemplateAreas::None);
+        }
+        let span = s.span();
+        let mut rows = Vec::new();
+        while s.peek_is(|kind| matches!(kind, TokenKind::String(_))) {
+            rows.push(s.string()?);
+        }
+        GridAreas::new(rows)
+            .map(GridTemplateAreas::Areas)
+            .map_err(|e| ParseError::new(span, e))
+    }
+}
+
+impl Parse for GridTemplateTracks {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("none") {
+            s.next_token();
+            Ok(GridTemplateTracks::None)
+        } else if s.peek_ident("subgrid") {
+            s.next_token();
+            let mut names = Vec::new();
+            loop {
+                if s.peek_kind(&TokenKind::LBracket) {
+                    names.push(SubgridLineNames::Names(s.parse()?));
+                } else if s.peek_function("repeat") {
+                    let mut content = s.function("repeat")?;
+                    let count = repeat_count(&mut content)?;
+                    if count == RepeatCount::AutoFit {
+                        return Err(content.error("expected integer or `auto-fill`"));
+                    }
+                    content.expect(&TokenKind::Comma, "`,`")?;
+                    let mut repeated = vec![content.parse()?];
+                    while !content.is_empty() {
+                        repeated.push(content.parse()?);
+                    }
+                    names.push(SubgridLineNames::Repeat(count, repeated));
+                } else {
+                    return Ok(GridTemplateTracks::Subgrid(names));
+                }
+            }
+        } else {
+            s.parse().map(GridTemplateTracks::TrackList)
+        }
+    }
+}
+
+impl Parse for LineNames {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut content = s.brackets()?;
+        let mut names = Vec::new();
+        while !content.is_empty() {
+            names.push(grid_line_name(&mut content)?);
+        }
+        Ok(LineNames(names))
+    }
+}
+
+fn optional_line_names(s: &mut Parser) -> Result<Option<LineNames>> {
+    if s.peek_kind(&TokenKind::LBracket) {
+        s.parse().map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+fn repeat_count(s: &mut Parser) -> Result<RepeatCount> {
+    if s.peek_is(|kind| matches!(kind, TokenKind::Number(_))) {
+        return Ok(RepeatCount::Count(integer(s, 1..)?));
+    }
+    let name: Ident = s.parse()?;
+    name.add_expected("integer");
+    if name.try_match("auto-fill") {
+        Ok(RepeatCount::AutoFill)
+    } else if name.try_match("auto-fit") {
+        Ok(RepeatCount::AutoFit)
+    } else {
+        Err(name.error())
+    }
+}
+
+impl Parse for TrackList {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let span = s.span();
+        let mut items = Vec::new();
+        loop {
+            if s.peek_kind(&TokenKind::LBracket) {
+                items.push(TrackListItem::LineNames(s.parse()?));
+            } else if s.peek_function("repeat") {
+                let mut content = s.function("repeat")?;
+                let count = repeat_count(&mut content)?;
+                content.expect(&TokenKind::Comma, "`,`")?;
+                let tracks = content.parse()?;
+                if !content.is_empty() {
+                    return Err(content.error("unexpected tokens in `repeat()`"));
+                }
+                items.push(TrackListItem::Repeat(TrackRepeat { count, tracks }));
+            } else {
+                let mut fork = s.fork();
+                match fork.parse() {
+                    Ok(size) => {
+                        s.advance_to(&fork);
+                        items.push(TrackListItem::Size(size));
+                    }
+                    Err(e) if items.is_empty() => return Err(e),
+                    Err(_) => break,
+                }
+            }
+        }
+        let list = TrackList(items);
+        list.validate().map_err(|e| ParseError::new(span, e))?;
+        Ok(list)
+    }
+}
+
+impl Parse for TrackSize {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_function("minmax") {
+            let mut content = s.function("minmax")?;
+            let min = content.parse()?;
+            if let TrackBreadth::Flex(_) = min {
+                return Err(content.error("the minimum of `minmax()` can't be flexible"));
+            }
+            content.expect(&TokenKind::Comma, "`,`")?;
+            let max = content.parse()?;
+            if !content.is_empty() {
+                return Err(content.error("trailing tokens"));
+            }
+            Ok(TrackSize::MinMax(min, max))
+        } else if s.peek_function("fit-content") {
+            let mut content = s.function("fit-content")?;
+            let lp = content.parse()?;
+            if !content.is_empty() {
+                return Err(content.error("trailing tokens"));
+            }
+            Ok(TrackSize::FitContent(lp))
+        } else {
+            s.parse().map(TrackSize::Breadth)
+        }
+    }
+}
+
+impl Parse for TrackBreadth {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut fork = s.fork();
+        let name: Ident = fork.parse()?;
+        if name.try_match("auto") {
+            s.advance_to(&fork);
+            return Ok(TrackBreadth::Auto);
+        } else if name.try_match("min-content") {
+            s.advance_to(&fork);
+            return Ok(TrackBreadth::MinContent);
+        } else if name.try_match("max-content") {
+            s.advance_to(&fork);
+            return Ok(TrackBreadth::MaxContent);
+        }
+        if let Ok(n) = fork.parse::<Number>() {
+            if n.suffix.eq_ignore_ascii_case("fr") {
+                if n.value < 0.0 {
+                    return Err(ParseError::new(n.span, "flex can't be negative"));
+                }
+                s.advance_to(&fork);
+                return Ok(TrackBreadth::Flex(n.value));
+            }
+        }
+        s.parse().map(TrackBreadth::LengthPercentage).map_err(|_| {
+            name.add_expected("length");
+            name.add_expected("percentage");
+            name.add_expected("flex");
+            name.error()
+        })
+    }
+}
+
+impl Parse for JustifyContent {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+
+        if name.try_match("flex-start") {
+            Ok(JustifyContent::FlexStart)
+        } else if name.try_match("flex-end") {
+            Ok(JustifyContent::FlexEnd)
+        } else if name.try_match("center") {
+            Ok(JustifyContent::Center)
+        } else if name.try_match("space-between") {
+            Ok(JustifyContent::SpaceBetween)
+        } else if name.try_match("space-around") {
+            Ok(JustifyContent::SpaceAround)
+        } else if name.try_match("start") {
+            // - not in level 1 spec
+            Ok(JustifyContent::FlexStart)
+        } else if name.try_match("end") {
+            // - not in level 1 spec
+            Ok(JustifyContent::FlexEnd)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for Length {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let length = match s.peek().map(|tok| &tok.kind) {
+            Some(TokenKind::Dimension { value, unit }) => {
+                let value = value.value;
+                match unit.to_ascii_lowercase().as_str() {
+                    "em" => Length::Em(value),
+                    "ex" => Length::Ex(value),
+                    "in" => Length::In(value),
+                    "cm" => Length::Cm(value),
+                    "mm" => Length::Mm(value),
+                    "pt" => Length::Pt(value),
+                    "pc" => Length::Pc(value),
+                    "px" => Length::Px(value),
+                    _ => {
+                        return Err(s.error(
+                            "expected one of `em`, `ex`, `in`, `cm`, `mm`, `pt`, `pc`, `px` \
+                            after number",
+                        ))
+                    }
+                }
+            }
+            Some(TokenKind::Number(n)) if n.value == 0.0 => Length::Zero,
+            _ => {
+                return Err(s.error(
+                    "expected a number followed by one of `em`, `ex`, `in`, `cm`, `mm`, `pt`, \
+                    `pc`, `px`, or 0",
+                ))
+            }
+        };
+        s.next_token();
+        Ok(length)
+    }
+}
+
+impl Parse for LineStyle {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("none") {
+            Ok(LineStyle::None)
+        } else if name.try_match("hidden") {
+            Ok(LineStyle::Hidden)
+        } else if name.try_match("dotted") {
+            Ok(LineStyle::Dotted)
+        } else if name.try_match("dashed") {
+            Ok(LineStyle::Dashed)
+        } else if name.try_match("solid") {
+            Ok(LineStyle::Solid)
+        } else if name.try_match("double") {
+            Ok(LineStyle::Double)
+        } else if name.try_match("groove") {
+            Ok(LineStyle::Groove)
+        } else if name.try_match("ridge") {
+            Ok(LineStyle::Ridge)
+        } else if name.try_match("inset") {
+            Ok(LineStyle::Inset)
+        } else if name.try_match("outset") {
+            Ok(LineStyle::Outset)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for LineWidth {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("thin") {
+            Ok(LineWidth::Thin)
+        } else if name.try_match("medium") {
+            Ok(LineWidth::Medium)
+        } else if name.try_match("thick") {
+            Ok(LineWidth::Thick)
+        } else {
+            match s.parse::<Length>() {
+                Ok(l) => Ok(LineWidth::Length(l)),
+                Err(_) => {
+                    name.add_expected("length");
+                    Err(name.error())
+                }
+            }
+        }
+    }
+}
+
+impl Parse for ListStyleType {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+
+        if name.try_match("disc") {
+            Ok(ListStyleType::Disc)
+        } else if name.try_match("circle") {
+            Ok(ListStyleType::Circle)
+        } else if name.try_match("square") {
+            Ok(ListStyleType::Square)
+        } else if name.try_match("decimal") {
+            Ok(ListStyleType::Decimal)
+        } else if name.try_match("decimal-leading-zero") {
+            Ok(ListStyleType::DecimalLeadingZero)
+        } else if name.try_match("lower-roman") {
+            Ok(ListStyleType::LowerRoman)
+        } else if name.try_match("upper-roman") {
+            Ok(ListStyleType::UpperRoman)
+        } else if name.try_match("lower-greek") {
+            Ok(ListStyleType::LowerGreek)
+        } else if name.try_match("upper-greek") {
+            Ok(ListStyleType::UpperGreek)
+        } else if name.try_match("lower-latin") {
+            Ok(ListStyleType::LowerLatin)
+        } else if name.try_match("upper-latin") {
+            Ok(ListStyleType::UpperLatin)
+        } else if name.try_match("armenian") {
+            Ok(ListStyleType::Armenian)
+        } else if name.try_match("georgian") {
+            Ok(ListStyleType::Georgian)
+        } else if name.try_match("lower-alpha") {
+            Ok(ListStyleType::LowerAlpha)
+        } else if name.try_match("upper-alpha") {
+            Ok(ListStyleType::UpperAlpha)
+        } else if name.try_match("none") {
+            Ok(ListStyleType::None)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for MaxWidthHeight {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_function("fit-content") {
+            let mut content = s.function("fit-content")?;
+            let lp = content.parse()?;
+            return if content.is_empty() {
+                Ok(MaxWidthHeight::FitContent(lp))
+            } else {
+                Err(content.error("trailing tokens"))
+            };
+        }
+        let name: Ident = s.parse()?;
+        name.add_expected("length");
+        name.add_expected("percentage");
+        name.add_expected("`fit-content(`");
+        if name.try_match("none") {
+            Ok(MaxWidthHeight::None)
+        } else if name.try_match("min-content") {
+            Ok(MaxWidthHeight::MinContent)
+        } else if name.try_match("max-content") {
+            Ok(MaxWidthHeight::MaxContent)
+        } else {
+            s.parse()
+                .map(MaxWidthHeight::LengthPercentage)
+                .map_err(|_| name.error())
+        }
+    }
+}
+
+impl<T> Parse for Rect<T>
+where
+    T: Parse,
+{
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let first = s.parse::<T>()?;
+        let mut fork = s.fork();
+        let second = match fork.parse::<T>() {
+            Ok(v) => {
+                s.advance_to(&fork);
+                v
+            }
+            Err(_) => return Ok(Rect::All(first)),
+        };
+        let third = match fork.parse::<T>() {
+            Ok(v) => {
+                s.advance_to(&fork);
+                v
+            }
+            Err(_) => return Ok(Rect::VerticalHorizontal(first, second)),
+        };
+        match fork.parse::<T>() {
+            Ok(v) => {
+                s.advance_to(&fork);
+                Ok(Rect::TopRightBottomLeft(first, second, third, v))
+            }
+            Err(_) => Ok(Rect::TopHorizontalBottom(first, second, third)),
+        }
+    }
+}
+
+impl Parse for AutoLengthPercentage {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("auto") {
+            s.next_token();
+            Ok(AutoLengthPercentage::Auto)
+        } else {
+            Ok(AutoLengthPercentage::LengthPercentage(s.parse()?))
+        }
+    }
+}
+
+impl Parse for ObjectFit {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("fill") {
+            Ok(ObjectFit::Fill)
+        } else if name.try_match("none") {
+            Ok(ObjectFit::None)
+        } else if name.try_match("contain") {
+            if s.peek_is(|kind| matches!(kind, TokenKind::Ident(_))) {
+                s.parse::<Ident>()?.expect("scale-down")?;
+                Ok(ObjectFit::Contain { scale_down: true })
+            } else {
+                Ok(ObjectFit::Contain { scale_down: false })
+            }
+        } else if name.try_match("cover") {
+            if s.peek_is(|kind| matches!(kind, TokenKind::Ident(_))) {
+                s.parse::<Ident>()?.expect("scale-down")?;
+                Ok(ObjectFit::Cover { scale_down: true })
+            } else {
+                Ok(ObjectFit::Cover { scale_down: false })
+            }
+        } else if name.try_match("scale-down") {
+            if s.peek_is(|kind| matches!(kind, TokenKind::Ident(_))) {
+                let cover_contain: Ident = s.parse()?;
+                if cover_contain.try_match("cover") {
+                    Ok(ObjectFit::Cover { scale_down: true })
+                } else if cover_contain.try_match("contain") {
+                    Ok(ObjectFit::Contain { scale_down: true })
+                } else {
+                    Err(cover_contain.error())
+                }
+            } else {
+                // defaults to contain when cover/contain not present
+                Ok(ObjectFit::Contain { scale_down: true })
+            }
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for Overflow {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let first = s.parse::<OverflowXY>()?;
+        let mut fork = s.fork();
+        Ok(match fork.parse::<OverflowXY>() {
+            Ok(second) => {
+                s.advance_to(&fork);
+                Overflow::XY(first, second)
+            }
+            Err(_) => Overflow::Both(first),
+        })
+    }
+}
+
+impl Parse for OverflowXY {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+
+        if name.try_match("visible") {
+            Ok(OverflowXY::Visible)
+        } else if name.try_match("hidden") {
+            Ok(OverflowXY::Hidden)
+        } else if name.try_match("clip") {
+            Ok(OverflowXY::Clip)
+        } else if name.try_match("scroll") {
+            Ok(OverflowXY::Scroll)
+        } else if name.try_match("auto") {
+            Ok(OverflowXY::Auto)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for Position {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("static") {
+            Ok(Position::Static)
+        } else if name.try_match("relative") {
+            Ok(Position::Relative)
+        } else if name.try_match("absolute") {
+            Ok(Position::Absolute)
+        } else if name.try_match("fixed") {
+            Ok(Position::Fixed)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for Percentage {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let n: Number = s.parse()?;
+        if n.suffix == "%" {
+            Ok(Percentage(n.value))
+        } else {
+            Err(ParseError::new(n.span, "expected percentage"))
+        }
+    }
+}
+
+impl Parse for Width21 {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("auto") {
+            s.next_token();
+            Ok(Width21::Auto)
+        } else {
+            Ok(Width21::LengthPercentage(s.parse()?))
+        }
+    }
+}
+
+impl Parse for WidthHeight {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_function("fit-content") {
+            let mut content = s.function("fit-content")?;
+            let lp = content.parse()?;
+            return if content.is_empty() {
+                Ok(WidthHeight::FitContent(lp))
+            } else {
+                Err(content.error("trailing tokens"))
+            };
+        }
+        let mut fork = s.fork();
+        let name: Ident = fork.parse()?;
+
+        if name.try_match("auto") {
+            s.advance_to(&fork);
+            Ok(WidthHeight::Auto)
+        } else if name.try_match("min-content") {
+            s.advance_to(&fork);
+            Ok(WidthHeight::MinContent)
+        } else if name.try_match("max-content") {
+            s.advance_to(&fork);
+            Ok(WidthHeight::MaxContent)
+        } else {
+            s.parse().map(WidthHeight::LengthPercentage).map_err(|_| {
+                name.add_expected("`fit-content(`");
+                name.add_expected("length");
+                name.add_expected("percentage");
+                name.error()
+            })
+        }
+    }
+}
+
+impl Parse for LengthPercentage {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_is(|kind| matches!(kind, TokenKind::Percentage(_))) {
+            Ok(LengthPercentage::Percentage(s.parse()?))
+        } else {
+            Ok(LengthPercentage::Length(s.parse()?))
+        }
+    }
+}
+
+impl Parse for Resize {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+
+        if name.try_match("none") {
+            Ok(Resize::None)
+        } else if name.try_match("both") {
+            Ok(Resize::Both)
+        } else if name.try_match("horizontal") {
+            Ok(Resize::Horizontal)
+        } else if name.try_match("vertical") {
+            Ok(Resize::Vertical)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for Shadow {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut inset_val = false;
+        let mut length: Option<ShadowLength> = None;
+        let mut color: Option<Color> = None;
+        // keep trying all three until we're done or there is an error
+        loop {
+            let mut parsed_something = false;
+            // inset (easiest)
+            if s.peek_ident("inset") {
+                if inset_val {
+                    return Err(s.error("`inset` must be specified 0 or 1 times"));
+                }
+                s.next_token();
+                inset_val = true;
+                parsed_something = true;
+            }
+
+            // color
+            let mut fork = s.fork();
+            if let Ok(parsed_color) = fork.parse::<Color>() {
+                if color.is_some() {
+                    return Err(s.error("color must be specified 0 or 1 times"));
+                }
+                color = Some(parsed_color);
+                s.advance_to(&fork);
+                parsed_something = true;
+            }
+
+            // length
+            let mut fork = s.fork();
+            if let Ok(parsed_length) = fork.parse::<ShadowLength>() {
+                if length.is_some() {
+                    return Err(s.error("shadow length must be specified once"));
+                }
+                length = Some(parsed_length);
+                s.advance_to(&fork);
+                parsed_something = true;
+            }
+
+            // if we've failed to parse anything, end the loop.
+            if !parsed_something {
+                break;
+            }
+        }
+        if let Some(length) = length {
+            Ok(Shadow {
+                color,
+                length,
+                inset: inset_val,
+            })
+        } else {
+            Err(s.error("expected color, length, or `inset`"))
+        }
+    }
+}
+
+impl Parse for ShadowLength {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let horizontal: Length = s.parse()?;
+        let vertical: Length = s.parse()?;
+
+        // blur
+        let mut fork = s.fork();
+        let blur = match fork.parse::<Length>() {
+            Ok(blur) => {
+                s.advance_to(&fork);
+                blur
+            }
+            Err(_) => {
+                return Ok(ShadowLength::Offsets {
+                    horizontal,
+                    vertical,
+                });
+            }
+        };
+
+        // spread
+        match fork.parse::<Length>() {
+            Ok(spread) => {
+                s.advance_to(&fork);
+
+                Ok(ShadowLength::OffsetsBlurSpread {
+                    horizontal,
+                    vertical,
+                    blur,
+                    spread,
+                })
+            }
+            Err(_) => Ok(ShadowLength::OffsetsBlur {
+                horizontal,
+                vertical,
+                blur,
+            }),
+        }
+    }
+}
+
+impl Parse for TextAlign {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("left") {
+            Ok(TextAlign::Left)
+        } else if word.try_match("right") {
+            Ok(TextAlign::Right)
+        } else if word.try_match("center") {
+            Ok(TextAlign::Center)
+        } else if word.try_match("justify") {
+            Ok(TextAlign::Justify)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+// border
+// ======
+
+impl Parse for BorderRadius {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let horizontal = s.parse()?;
+        let vertical = if s.peek_delim('/') {
+            s.next_token();
+            Some(s.parse()?)
+        } else {
+            None
+        };
+        Ok(BorderRadius {
+            horizontal,
+            vertical,
+        })
+    }
+}
+
+impl Parse for BorderImage {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.is_empty() {
+            return Err(s.error("expected a border image"));
+        }
+        let mut image = BorderImage::default();
+        while !s.is_empty() {
+            if image.source.is_none() {
+                if let Some(v) = try_parse(s) {
+                    image.source = Some(v);
+                    continue;
+                }
+            }
+            if image.slice.is_none() {
+                if let Some(v) = try_parse(s) {
+                    image.slice = Some(v);
+                    // `slice / width`, `slice / / outset` or `slice / width / outset`
+                    if s.peek_delim('/') {
+                        s.next_token();
+                        if !s.peek_delim('/') {
+                            image.width = Some(s.parse()?);
+                        }
+                        if s.peek_delim('/') {
+                            s.next_token();
+                            image.outset = Some(s.parse()?);
+                        }
+                    }
+                    continue;
+                }
+            }
+            if image.repeat.is_none() {
+                if let Some(v) = try_parse(s) {
+                    image.repeat = Some(v);
+                    continue;
+                }
+            }
+            return Err(s.error("unexpected or repeated value in border image"));
+        }
+        Ok(image)
+    }
+}
+
+impl Parse for BorderImageSource {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("none") {
+            s.next_token();
+            Ok(BorderImageSource::None)
+        } else {
+            s.parse().map(BorderImageSource::Image)
+        }
+    }
+}
+
+impl Parse for BorderImageSlice {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        // `fill` can go before or after the offsets
+        let mut fill = s.peek_ident("fill");
+        if fill {
+            s.next_token();
+        }
+        let offsets = s.parse()?;
+        if !fill && s.peek_ident("fill") {
+            s.next_token();
+            fill = true;
+        }
+        Ok(BorderImageSlice { offsets, fill })
+    }
+}
+
+impl Parse for NumberOrPercentage {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let n: Number = s.parse()?;
+        if n.value < 0.0 {
+            return Err(ParseError::new(
+                n.span,
+                "border image slice can't be negative",
+            ));
+        }
+        if n.suffix.is_empty() {
+            Ok(NumberOrPercentage::Number(n.value))
+        } else if n.suffix == "%" {
+            Ok(NumberOrPercentage::Percentage(Percentage(n.value)))
+        } else {
+            Err(ParseError::new(n.span, "expected number or percentage"))
+        }
+    }
+}
+
+impl Parse for BorderImageWidth {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("auto") {
+            s.next_token();
+            return Ok(BorderImageWidth::Auto);
+        }
+        let span = s.span();
+        let width = if s.peek_is(|kind| matches!(kind, TokenKind::Number(_))) {
+            let n: Number = s.parse()?;
+            BorderImageWidth::Number(n.value)
+        } else {
+            BorderImageWidth::LengthPercentage(s.parse()?)
+        };
+        let negative = match &width {
+            BorderImageWidth::Number(v) => *v < 0.0,
+            BorderImageWidth::LengthPercentage(LengthPercentage::Percentage(v)) => v.0 < 0.0,
+            BorderImageWidth::LengthPercentage(LengthPercentage::Length(v)) => v.is_negative(),
+            BorderImageWidth::Auto => false,
+        };
+        if negative {
+            return Err(ParseError::new(
+                span,
+                "border image width can't be negative",
+            ));
+        }
+        Ok(width)
+    }
+}
+
+impl Parse for NumberOrLength {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_is(|kind| matches!(kind, TokenKind::Number(_))) {
+            let n: Number = s.parse()?;
+            if n.value < 0.0 {
+                return Err(ParseError::new(n.span, "number can't be negative"));
+            }
+            Ok(NumberOrLength::Number(n.value))
+        } else {
+            non_negative_length(s).map(NumberOrLength::Length)
+        }
+    }
+}
+
+impl Parse for BorderImageRepeat {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        Ok(BorderImageRepeat {
+            horizontal: s.parse()?,
+            vertical: try_parse(s),
+        })
+    }
+}
+
+impl Parse for BorderImageRepeatMode {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("stretch") {
+            Ok(BorderImageRepeatMode::Stretch)
+        } else if word.try_match("repeat") {
+            Ok(BorderImageRepeatMode::Repeat)
+        } else if word.try_match("round") {
+            Ok(BorderImageRepeatMode::Round)
+        } else if word.try_match("space") {
+            Ok(BorderImageRepeatMode::Space)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+// text
+// ====
+
+impl Parse for Spacing {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("normal") {
+            s.next_token();
+            Ok(Spacing::Normal)
+        } else {
+            s.parse().map(Spacing::Length)
+        }
+    }
+}
+
+impl Parse for TextIndent {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut length = None;
+        let (mut hanging, mut each_line) = (false, false);
+        while !s.is_empty() {
+            if length.is_none() {
+                if let Some(v) = try_parse(s) {
+                    length = Some(v);
+                    continue;
+                }
+            }
+            let word: Ident = s.parse()?;
+            if !hanging && word.try_match("hanging") {
+                hanging = true;
+            } else if !each_line && word.try_match("each-line") {
+                each_line = true;
+            } else {
+                if length.is_none() {
+                    word.add_expected("length");
+                    word.add_expected("percentage");
+                }
+                return Err(word.error());
+            }
+        }
+        match length {
+            Some(length) => Ok(TextIndent {
+                length,
+                hanging,
+                each_line,
+            }),
+            None => Err(s.error("expected a length or percentage")),
+        }
+    }
+}
+
+impl Parse for TextTransform {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut transform = TextTransform::default();
+        if s.peek_ident("none") {
+            s.next_token();
+            return Ok(transform);
+        }
+        while !s.is_empty() {
+            if transform.case.is_none() {
+                if let Some(v) = try_parse(s) {
+                    transform.case = Some(v);
+                    continue;
+                }
+            }
+            let word: Ident = s.parse()?;
+            if !transform.full_width && word.try_match("full-width") {
+                transform.full_width = true;
+            } else if !transform.full_size_kana && word.try_match("full-size-kana") {
+                transform.full_size_kana = true;
+            } else {
+                return Err(word.error());
+            }
+        }
+        if transform == TextTransform::default() {
+            return Err(s.error("expected text transform"));
+        }
+        Ok(transform)
+    }
+}
+
+impl Parse for TextTransformCase {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("capitalize") {
+            Ok(TextTransformCase::Capitalize)
+        } else if word.try_match("uppercase") {
+            Ok(TextTransformCase::Uppercase)
+        } else if word.try_match("lowercase") {
+            Ok(TextTransformCase::Lowercase)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for TextDecoration {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.is_empty() {
+            return Err(s.error("expected a text decoration"));
+        }
+        let mut decoration = TextDecoration::default();
+        while !s.is_empty() {
+            if decoration.line.is_none() {
+                if let Some(v) = try_parse(s) {
+                    decoration.line = Some(v);
+                    continue;
+                }
+            }
+            if decoration.thickness.is_none() {
+                if let Some(v) = try_parse(s) {
+                    decoration.thickness = Some(v);
+                    continue;
+                }
+            }
+            if decoration.style.is_none() {
+                if let Some(v) = try_parse(s) {
+                    decoration.style = Some(v);
+                    continue;
+                }
+            }
+            if decoration.color.is_none() {
+                if let Some(v) = try_parse(s) {
+                    decoration.color = Some(v);
+                    continue;
+                }
+            }
+            return Err(s.error("unexpected or repeated value in text decoration"));
+        }
+        Ok(decoration)
+    }
+}
+
+impl Parse for TextDecorationLine {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut line = TextDecorationLine::default();
+        if s.peek_ident("none") {
+            s.next_token();
+            return Ok(line);
+        }
+        loop {
+            if !line.underline && s.peek_ident("underline") {
+                line.underline = true;
+            } else if !line.overline && s.peek_ident("overline") {
+                line.overline = true;
+            } else if !line.line_through && s.peek_ident("line-through") {
+                line.line_through = true;
+            } else if !line.blink && s.peek_ident("blink") {
+                line.blink = true;
+            } else {
+                break;
+            }
+            s.next_token();
+        }
+        if line == TextDecorationLine::default() {
+            return Err(s.error(
+                "expected one of `none`, `underline`, `overline`, `line-through`, `blink`",
+            ));
+        }
+        Ok(line)
+    }
+}
+
+impl Parse for TextDecorationStyle {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("solid") {
+            Ok(TextDecorationStyle::Solid)
+        } else if word.try_match("double") {
+            Ok(TextDecorationStyle::Double)
+        } else if word.try_match("dotted") {
+            Ok(TextDecorationStyle::Dotted)
+        } else if word.try_match("dashed") {
+            Ok(TextDecorationStyle::Dashed)
+        } else if word.try_match("wavy") {
+            Ok(TextDecorationStyle::Wavy)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for TextDecorationColor {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("currentcolor") {
+            s.next_token();
+            Ok(TextDecorationColor::CurrentColor)
+        } else {
+            s.parse().map(TextDecorationColor::Color)
+        }
+    }
+}
+
+impl Parse for TextDecorationThickness {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("auto") {
+            s.next_token();
+            Ok(TextDecorationThickness::Auto)
+        } else if s.peek_ident("from-font") {
+            s.next_token();
+            Ok(TextDecorationThickness::FromFont)
+        } else {
+            s.parse().map(TextDecorationThickness::LengthPercentage)
+        }
+    }
+}
+
+impl Parse for TextUnderlinePosition {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("auto") {
+            s.next_token();
+            return Ok(TextUnderlinePosition::Auto);
+        } else if s.peek_ident("from-font") {
+            s.next_token();
+            return Ok(TextUnderlinePosition::FromFont);
+        }
+        let (mut under, mut side) = (false, None);
+        loop {
+            if !under && s.peek_ident("under") {
+                s.next_token();
+                under = true;
+            } else if side.is_none() {
+                match try_parse(s) {
+                    Some(v) => side = Some(v),
+                    None => break,
+                }
+            } else {
+                break;
+            }
+        }
+        if !under && side.is_none() {
+            return Err(s.error("expected one of `auto`, `from-font`, `under`, `left`, `right`"));
+        }
+        Ok(TextUnderlinePosition::Position { under, side })
+    }
+}
+
+impl Parse for TextShadow {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("none") {
+            s.next_token();
+            return Ok(TextShadow::None);
+        }
+        let span = s.span();
+        let list: NonemptyCommaList<Shadow> = s.parse()?;
+        for shadow in std::iter::once(&list.first).chain(&list.rest) {
+            if shadow.inset {
+                return Err(ParseError::new(span, "text shadows can't be `inset`"));
+            }
+            if let ShadowLength::OffsetsBlurSpread { .. } = shadow.length {
+                return Err(ParseError::new(span, "text shadows can't have a spread"));
+            }
+        }
+        Ok(TextShadow::Shadows(list))
+    }
+}
+
+impl Parse for LineHeight {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("normal") {
+            s.next_token();
+            return Ok(LineHeight::Normal);
+        }
+        let span = s.span();
+        let value = if s.peek_is(|kind| matches!(kind, TokenKind::Number(_))) {
+            let n: Number = s.parse()?;
+            if n.value < 0.0 {
+                return Err(ParseError::new(span, "line height can't be negative"));
+            }
+            return Ok(LineHeight::Number(n.value));
+        } else {
+            s.parse::<Calc>()?
+        };
+        let negative = match &value {
+            Calc::Normal(LengthPercentage::Length(v)) => v.is_negative(),
+            Calc::Normal(LengthPercentage::Percentage(v)) => v.0 < 0.0,
+            Calc::Calculated(_) => false,
+        };
+        if negative {
+            return Err(ParseError::new(span, "line height can't be negative"));
+        }
+        Ok(LineHeight::LengthPercentage(value))
+    }
+}
+
+impl Parse for WhiteSpace {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut fork = s.fork();
+        let name: Ident = fork.parse()?;
+        let keyword = if name.try_match("normal") {
+            Some(WhiteSpace::Normal)
+        } else if name.try_match("pre") {
+            Some(WhiteSpace::Pre)
+        } else if name.try_match("pre-wrap") {
+            Some(WhiteSpace::PreWrap)
+        } else if name.try_match("pre-line") {
+            Some(WhiteSpace::PreLine)
+        } else {
+            None
+        };
+        if let Some(keyword) = keyword {
+            s.advance_to(&fork);
+            return Ok(keyword);
+        }
+        let (mut collapse, mut wrap_mode) = (None, None);
+        loop {
+            if collapse.is_none() {
+                if let Some(v) = try_parse(s) {
+                    collapse = Some(v);
+                    continue;
+                }
+            }
+            if wrap_mode.is_none() {
+                if let Some(v) = try_parse(s) {
+                    wrap_mode = Some(v);
+                    continue;
+                }
+            }
+            break;
+        }
+        Ok(match (collapse, wrap_mode) {
+            (None, None) => {
+                name.add_expected("white space collapse");
+                name.add_expected("text wrap mode");
+                return Err(name.error());
+            }
+            (None, Some(TextWrapMode::Nowrap)) => WhiteSpace::Nowrap,
+            (Some(WhiteSpaceCollapse::BreakSpaces), None) => WhiteSpace::BreakSpaces,
+            (collapse, wrap_mode) => WhiteSpace::Longhands {
+                collapse,
+                wrap_mode,
+            },
+        })
+    }
+}
+
+impl Parse for WordBreak {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("normal") {
+            Ok(WordBreak::Normal)
+        } else if word.try_match("break-all") {
+            Ok(WordBreak::BreakAll)
+        } else if word.try_match("keep-all") {
+            Ok(WordBreak::KeepAll)
+        } else if word.try_match("break-word") {
+            Ok(WordBreak::BreakWord)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for OverflowWrap {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("normal") {
+            Ok(OverflowWrap::Normal)
+        } else if word.try_match("break-word") {
+            Ok(OverflowWrap::BreakWord)
+        } else if word.try_match("anywhere") {
+            Ok(OverflowWrap::Anywhere)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for LineBreak {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("auto") {
+            Ok(LineBreak::Auto)
+        } else if word.try_match("loose") {
+            Ok(LineBreak::Loose)
+        } else if word.try_match("normal") {
+            Ok(LineBreak::Normal)
+        } else if word.try_match("strict") {
+            Ok(LineBreak::Strict)
+        } else if word.try_match("anywhere") {
+            Ok(LineBreak::Anywhere)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for Hyphens {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("none") {
+            Ok(Hyphens::None)
+        } else if word.try_match("manual") {
+            Ok(Hyphens::Manual)
+        } else if word.try_match("auto") {
+            Ok(Hyphens::Auto)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for HyphenateCharacter {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("auto") {
+            s.next_token();
+            Ok(HyphenateCharacter::Auto)
+        } else {
+            s.string().map(HyphenateCharacter::String)
+        }
+    }
+}
+
+impl Parse for TextWrap {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mode = try_parse(s);
+        let style = try_parse(s);
+        let mode = match mode {
+            Some(mode) => Some(mode),
+            None if style.is_some() => try_parse(s),
+            None => None,
+        };
+        if mode.is_none() && style.is_none() {
+            return Err(s.error("expected text wrap mode or style"));
+        }
+        Ok(TextWrap { mode, style })
+    }
+}
+
+impl Parse for TextWrapMode {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("wrap") {
+            Ok(TextWrapMode::Wrap)
+        } else if word.try_match("nowrap") {
+            Ok(TextWrapMode::Nowrap)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for TextWrapStyle {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("auto") {
+            Ok(TextWrapStyle::Auto)
+        } else if word.try_match("balance") {
+            Ok(TextWrapStyle::Balance)
+        } else if word.try_match("stable") {
+            Ok(TextWrapStyle::Stable)
+        } else if word.try_match("pretty") {
+            Ok(TextWrapStyle::Pretty)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for WhiteSpaceCollapse {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("collapse") {
+            Ok(WhiteSpaceCollapse::Collapse)
+        } else if word.try_match("discard") {
+            Ok(WhiteSpaceCollapse::Discard)
+        } else if word.try_match("preserve") {
+            Ok(WhiteSpaceCollapse::Preserve)
+        } else if word.try_match("preserve-breaks") {
+            Ok(WhiteSpaceCollapse::PreserveBreaks)
+        } else if word.try_match("preserve-spaces") {
+            Ok(WhiteSpaceCollapse::PreserveSpaces)
+        } else if word.try_match("break-spaces") {
+            Ok(WhiteSpaceCollapse::BreakSpaces)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for HangingPunctuation {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut hanging = HangingPunctuation::default();
+        if s.peek_ident("none") {
+            s.next_token();
+            return Ok(hanging);
+        }
+        loop {
+            if !hanging.first && s.peek_ident("first") {
+                s.next_token();
+                hanging.first = true;
+            } else if !hanging.last && s.peek_ident("last") {
+                s.next_token();
+                hanging.last = true;
+            } else if hanging.end.is_none() {
+                match try_parse(s) {
+                    Some(end) => hanging.end = Some(end),
+                    None => break,
+                }
+            } else {
+                break;
+            }
+        }
+        if hanging == HangingPunctuation::default() {
+            return Err(
+                s.error("expected one of `none`, `first`, `force-end`, `allow-end`, `last`")
+            );
+        }
+        Ok(hanging)
+    }
+}
+
+impl Parse for HangingPunctuationEnd {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("force-end") {
+            Ok(HangingPunctuationEnd::ForceEnd)
+        } else if word.try_match("allow-end") {
+            Ok(HangingPunctuationEnd::AllowEnd)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+// writing modes
+
+impl Parse for Direction {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("ltr") {
+            Ok(Direction::Ltr)
+        } else if word.try_match("rtl") {
+            Ok(Direction::Rtl)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for UnicodeBidi {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("normal") {
+            Ok(UnicodeBidi::Normal)
+        } else if word.try_match("embed") {
+            Ok(UnicodeBidi::Embed)
+        } else if word.try_match("isolate") {
+            Ok(UnicodeBidi::Isolate)
+        } else if word.try_match("bidi-override") {
+            Ok(UnicodeBidi::BidiOverride)
+        } else if word.try_match("isolate-override") {
+            Ok(UnicodeBidi::IsolateOverride)
+        } else if word.try_match("plaintext") {
+            Ok(UnicodeBidi::Plaintext)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for WritingMode {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("horizontal-tb") {
+            Ok(WritingMode::HorizontalTb)
+        } else if word.try_match("vertical-rl") {
+            Ok(WritingMode::VerticalRl)
+        } else if word.try_match("vertical-lr") {
+            Ok(WritingMode::VerticalLr)
+        } else if word.try_match("sideways-rl") {
+            Ok(WritingMode::SidewaysRl)
+        } else if word.try_match("sideways-lr") {
+            Ok(WritingMode::SidewaysLr)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for TextOrientation {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("mixed") {
+            Ok(TextOrientation::Mixed)
+        } else if word.try_match("upright") {
+            Ok(TextOrientation::Upright)
+        } else if word.try_match("sideways") {
+            Ok(TextOrientation::Sideways)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for TextCombineUpright {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let word: Ident = s.parse()?;
+        if word.try_match("none") {
+            Ok(TextCombineUpright::None)
+        } else if word.try_match("all") {
+            Ok(TextCombineUpright::All)
+        } else if word.try_match("digits") {
+            let digits = if s.peek_is(|kind| matches!(kind, TokenKind::Number(_))) {
+                Some(integer(s, 2..=4)?)
+            } else {
+                None
+            };
+            Ok(TextCombineUpright::Digits(digits))
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+// font
+// ====
+
+impl Parse for FontShorthand {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut fork = s.fork();
+        let word: Ident = fork.parse()?;
+        for (keyword, system) in [
+            ("caption", SystemFont::Caption),
+            ("icon", SystemFont::Icon),
+            ("menu", SystemFont::Menu),
+            ("message-box", SystemFont::MessageBox),
+            ("small-caption", SystemFont::SmallCaption),
+            ("status-bar", SystemFont::StatusBar),
+        ] {
+            if word.try_match(keyword) && fork.is_empty() {
+                s.advance_to(&fork);
+                return Ok(FontShorthand::System(system));
+            }
+        }
+
+        let mut style = None;
+        let mut small_caps = false;
+        let mut weight = None;
+        let mut stretch = None;
+        // Up to four values can come before the size, in any order. `normal` leaves one of them
+        // at its initial value.
+        for _ in 0..4 {
+            if s.peek_ident("normal") {
+                s.next_token();
+                continue;
+            }
+            if !small_caps && s.peek_ident("small-caps") {
+                s.next_token();
+                small_caps = true;
+                continue;
+            }
+            if style.is_none() {
+                if let Some(v) = try_parse(s) {
+                    style = Some(v);
+                    continue;
+                }
+            }
+            if weight.is_none() {
+                if let Some(v) = try_parse(s) {
+                    weight = Some(v);
+                    continue;
+                }
+            }
+            if stretch.is_none() {
+                let mut fork = s.fork();
+                match fork.parse() {
+                    Ok(FontStretch::Percentage(_)) | Err(_) => (),
+                    Ok(v) => {
+                        s.advance_to(&fork);
+                        stretch = Some(v);
+                        continue;
+                    }
+                }
+            }
+            break;
+        }
+        let size = s.parse()?;
+        let line_height = if s.peek_kind(&TokenKind::Delim('/')) {
+            s.next_token();
+            Some(s.parse()?)
+        } else {
+            None
+        };
+        Ok(FontShorthand::Values {
+            style,
+            small_caps,
+            weight,
+            stretch,
+            size,
+            line_height,
+            family: s.parse()?,
+        })
+    }
+}
+
+impl Parse for FontStretch {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut fork = s.fork();
+        let name: Ident = fork.parse()?;
+        for (keyword, stretch) in [
+            ("normal", FontStretch::Normal),
+            ("ultra-condensed", FontStretch::UltraCondensed),
+            ("extra-condensed", FontStretch::ExtraCondensed),
+            ("condensed", FontStretch::Condensed),
+            ("semi-condensed", FontStretch::SemiCondensed),
+            ("semi-expanded", FontStretch::SemiExpanded),
+            ("expanded", FontStretch::Expanded),
+            ("extra-expanded", FontStretch::ExtraExpanded),
+            ("ultra-expanded", FontStretch::UltraExpanded),
+        ] {
+            if name.try_match(keyword) {
+                s.advance_to(&fork);
+                return Ok(stretch);
+            }
+        }
+        let span = s.span();
+        match s.parse() {
+            Ok(Percentage(v)) if v >= 0.0 => Ok(FontStretch::Percentage(Percentage(v))),
+            Ok(_) => Err(ParseError::new(span, "font-stretch can't be negative")),
+            Err(_) => {
+                name.add_expected("percentage");
+                Err(name.error())
+            }
+        }
+    }
+}
+
+impl Parse for FontKerning {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("auto") {
+            Ok(FontKerning::Auto)
+        } else if name.try_match("normal") {
+            Ok(FontKerning::Normal)
+        } else if name.try_match("none") {
+            Ok(FontKerning::None)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for FontSizeAdjust {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("none") {
+            s.next_token();
+            return Ok(FontSizeAdjust::None);
+        }
+        let metric = try_parse(s).unwrap_or(FontMetric::ExHeight);
+        if s.peek_ident("from-font") {
+            s.next_token();
+            return Ok(FontSizeAdjust::Adjust {
+                metric,
+                value: None,
+            });
+        }
+        let n: Number = s.parse()?;
+        n.empty_suffix()?;
+        Ok(FontSizeAdjust::Adjust {
+            metric,
+            value: Some(n.value),
+        })
+    }
+}
+
+impl Parse for FontMetric {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("ex-height") {
+            Ok(FontMetric::ExHeight)
+        } else if name.try_match("cap-height") {
+            Ok(FontMetric::CapHeight)
+        } else if name.try_match("ch-width") {
+            Ok(FontMetric::ChWidth)
+        } else if name.try_match("ic-width") {
+            Ok(FontMetric::IcWidth)
+        } else if name.try_match("ic-height") {
+            Ok(FontMetric::IcHeight)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for FontSynthesis {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut out = FontSynthesis {
+            weight: false,
+            style: false,
+            small_caps: false,
+            position: false,
+        };
+        if s.peek_ident("none") {
+            s.next_token();
+            return Ok(out);
+        }
+        while add_keyword(s, &mut out, FontSynthesis::add_keyword) {}
+        if !out.weight && !out.style && !out.small_caps && !out.position {
+            return Err(s.error("expected `none`, `weight`, `style`, `small-caps` or `position`"));
+        }
+        Ok(out)
+    }
+}
+
+impl Parse for FontVariant {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut out = FontVariant::default();
+        if s.peek_ident("normal") {
+            s.next_token();
+            return Ok(out);
+        } else if s.peek_ident("none") {
+            s.next_token();
+            out.ligatures = FontVariantLigatures::None;
+            return Ok(out);
+        }
+        while !s.is_empty() {
+            if !(add_keyword(s, &mut out.ligatures, FontVariantLigatures::add_keyword)
+                || set_keyword(s, &mut out.caps)
+                || alternates_part(s, &mut out.alternates)?
+                || add_keyword(s, &mut out.numeric, FontVariantNumeric::add_keyword)
+                || add_keyword(s, &mut out.east_asian, FontVariantEastAsian::add_keyword)
+                || set_keyword(s, &mut out.position)
+                || set_keyword(s, &mut out.emoji))
+            {
+                return Err(s.error("unexpected value in font-variant"));
+            }
+        }
+        if out == FontVariant::default() {
+            return Err(s.error("expected font-variant"));
+        }
+        Ok(out)
+    }
+}
+
+impl Parse for FontVariantLigatures {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("normal") {
+            s.next_token();
+            return Ok(FontVariantLigatures::Normal);
+        } else if s.peek_ident("none") {
+            s.next_token();
+            return Ok(FontVariantLigatures::None);
+        }
+        let mut out = FontVariantLigatures::Normal;
+        while add_keyword(s, &mut out, FontVariantLigatures::add_keyword) {}
+        if out == FontVariantLigatures::Normal {
+            return Err(s.error("expected `normal`, `none` or ligature keyword"));
+        }
+        Ok(out)
+    }
+}
+
+impl Parse for FontVariantCaps {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("normal") {
+            Ok(FontVariantCaps::Normal)
+        } else if name.try_match("small-caps") {
+            Ok(FontVariantCaps::SmallCaps)
+        } else if name.try_match("all-small-caps") {
+            Ok(FontVariantCaps::AllSmallCaps)
+        } else if name.try_match("petite-caps") {
+            Ok(FontVariantCaps::PetiteCaps)
+        } else if name.try_match("all-petite-caps") {
+            Ok(FontVariantCaps::AllPetiteCaps)
+        } else if name.try_match("unicase") {
+            Ok(FontVariantCaps::Unicase)
+        } else if name.try_match("titling-caps") {
+            Ok(FontVariantCaps::TitlingCaps)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for FontVariantAlternates {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut out = FontVariantAlternates::default();
+        if s.peek_ident("normal") {
+            s.next_token();
+            return Ok(out);
+        }
+        while alternates_part(s, &mut out)? {}
+        if out == FontVariantAlternates::default() {
+            return Err(s.error("expected `normal` or alternates"));
+        }
+        Ok(out)
+    }
+}
+
+/// Parse one keyword or function of `font-variant-alternates` into `alternates`, returning
+/// whether there was one that hadn't already been set.
+fn alternates_part(s: &mut Parser, alternates: &mut FontVariantAlternates) -> Result<bool> {
+    let name = match s.peek().map(|tok| &tok.kind) {
+        Some(TokenKind::Ident(name)) if name.eq_ignore_ascii_case("historical-forms") => {
+            if alternates.historical_forms {
+                return Ok(false);
+            }
+            s.next_token();
+            alternates.historical_forms = true;
+            return Ok(true);
+        }
+        Some(TokenKind::Function(name)) => name.to_ascii_lowercase(),
+        _ => return Ok(false),
+    };
+    let content = match name.as_str() {
+        "stylistic" | "swash" | "ornaments" | "annotation" => {
+            let field = match name.as_str() {
+                "stylistic" => &mut alternates.stylistic,
+                "swash" => &mut alternates.swash,
+                "ornaments" => &mut alternates.ornaments,
+                _ => &mut alternates.annotation,
+            };
+            if field.is_some() {
+                return Ok(false);
+            }
+            let mut content = s.function(&name)?;
+            *field = Some(feature_value_name(&mut content)?);
+            content
+        }
+        "styleset" | "character-variant" => {
+            let field = match name.as_str() {
+                "styleset" => &mut alternates.styleset,
+                _ => &mut alternates.character_variant,
+            };
+            if !field.is_empty() {
+                return Ok(false);
+            }
+            let mut content = s.function(&name)?;
+            *field = vec![feature_value_name(&mut content)?];
+            while content.peek_kind(&TokenKind::Comma) {
+                content.next_token();
+                field.push(feature_value_name(&mut content)?);
+            }
+            content
+        }
+        _ => return Ok(false),
+    };
+    if !content.is_empty() {
+        return Err(content.error("trailing tokens"));
+    }
+    Ok(true)
+}
+
+/// The name of a value defined in a `@font-feature-values` rule.
+fn feature_value_name(s: &mut Parser) -> Result<String> {
+    let name: Ident = s.parse()?;
+    name.word
+        .ok_or_else(|| s.error("expected feature value name"))
+}
+
+impl Parse for FontVariantNumeric {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut out = FontVariantNumeric::default();
+        if s.peek_ident("normal") {
+            s.next_token();
+            return Ok(out);
+        }
+        while add_keyword(s, &mut out, FontVariantNumeric::add_keyword) {}
+        if out == FontVariantNumeric::default() {
+            return Err(s.error("expected `normal` or numeric figure, spacing or fraction"));
+        }
+        Ok(out)
+    }
+}
+
+impl Parse for FontVariantEastAsian {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut out = FontVariantEastAsian::default();
+        if s.peek_ident("normal") {
+            s.next_token();
+            return Ok(out);
+        }
+        while add_keyword(s, &mut out, FontVariantEastAsian::add_keyword) {}
+        if out == FontVariantEastAsian::default() {
+            return Err(s.error("expected `normal` or east asian variant, width or `ruby`"));
+        }
+        Ok(out)
+    }
+}
+
+impl Parse for FontVariantPosition {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("normal") {
+            Ok(FontVariantPosition::Normal)
+        } else if name.try_match("sub") {
+            Ok(FontVariantPosition::Sub)
+        } else if name.try_match("super") {
+            Ok(FontVariantPosition::Super)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for FontVariantEmoji {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("normal") {
+            Ok(FontVariantEmoji::Normal)
+        } else if name.try_match("text") {
+            Ok(FontVariantEmoji::Text)
+        } else if name.try_match("emoji") {
+            Ok(FontVariantEmoji::Emoji)
+        } else if name.try_match("unicode") {
+            Ok(FontVariantEmoji::Unicode)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+/// Consume the next word if `add` accepts it as a keyword of `value`.
+fn add_keyword<T>(s: &mut Parser, value: &mut T, add: fn(&mut T, &str) -> bool) -> bool {
+    match s.peek().map(|tok| &tok.kind) {
+        Some(TokenKind::Ident(word)) if add(value, word) => {
+            s.next_token();
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Parse a value other than the default into `value`, if it is still the default.
+fn set_keyword<T: Parse + Default + PartialEq>(s: &mut Parser, value: &mut T) -> bool {
+    if *value != T::default() {
+        return false;
+    }
+    let mut fork = s.fork();
+    match fork.parse() {
+        Ok(v) if v != T::default() => {
+            s.advance_to(&fork);
+            *value = v;
+            true
+        }
+        _ => false,
+    }
+}
+
+impl Parse for FontFeatureSettings {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_is(|kind| matches!(kind, TokenKind::String(_))) {
+            return Ok(FontFeatureSettings::Features(s.parse()?));
+        }
+        let name: Ident = s.parse()?;
+        name.add_expected("feature tag");
+        if name.try_match("normal") {
+            Ok(FontFeatureSettings::Normal)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for FeatureTagValue {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let tag = opentype_tag(s)?;
+        let value = if finished_item(s) {
+            1
+        } else if s.peek_is(|kind| matches!(kind, TokenKind::Number(_))) {
+            integer(s, 0..)?
+        } else {
+            let name: Ident = s.parse()?;
+            name.add_expected("integer");
+            if name.try_match("on") {
+                1
+            } else if name.try_match("off") {
+                0
+            } else {
+                return Err(name.error());
+            }
+        };
+        Ok(FeatureTagValue { tag, value })
+    }
+}
+
+impl Parse for FontVariationSettings {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_is(|kind| matches!(kind, TokenKind::String(_))) {
+            return Ok(FontVariationSettings::Axes(s.parse()?));
+        }
+        let name: Ident = s.parse()?;
+        name.add_expected("axis tag");
+        if name.try_match("normal") {
+            Ok(FontVariationSettings::Normal)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for VariationAxis {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        Ok(VariationAxis {
+            tag: opentype_tag(s)?,
+            value: plain_number(s)?,
+        })
+    }
+}
+
+/// A quoted OpenType feature or axis tag.
+fn opentype_tag(s: &mut Parser) -> Result<String> {
+    let span = s.span();
+    match s.peek().map(|tok| &tok.kind) {
+        Some(TokenKind::String(tag)) => {
+            s.next_token();
+            if is_tag(tag) {
+                Ok(tag.clone())
+            } else {
+                Err(ParseError::new(span, "tags must be four ASCII characters"))
+            }
+        }
+        _ => Err(s.error("expected quoted tag")),
+    }
+}
+
+// image
+// =====
+
+impl Parse for Url {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if let Some(Token {
+            kind: TokenKind::Url(url),
+            ..
+        }) = s.peek()
+        {
+            s.next_token();
+            return Ok(Url {
+                url: url.clone(),
+                modifiers: Vec::new(),
+            });
+        }
+        let mut content = s.function("url")?;
+        let url = content.string()?;
+        let mut modifiers = Vec::new();
+        while !content.is_empty() {
+            modifiers.push(content.parse()?);
+        }
+        Ok(Url { url, modifiers })
+    }
+}
+
+impl Parse for UrlModifier {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let (content, modifier) = if s.peek_function("crossorigin") {
+            let mut content = s.function("crossorigin")?;
+            let value = content.parse()?;
+            (content, UrlModifier::CrossOrigin(value))
+        } else if s.peek_function("integrity") {
+            let mut content = s.function("integrity")?;
+            let value = content.string()?;
+            (content, UrlModifier::Integrity(value))
+        } else if s.peek_function("referrerpolicy") {
+            let mut content = s.function("referrerpolicy")?;
+            let value = content.parse()?;
+            (content, UrlModifier::ReferrerPolicy(value))
+        } else {
+            return Err(s.error("expected one of `crossorigin(`, `integrity(`, `referrerpolicy(`"));
+        };
+        if !content.is_empty() {
+            return Err(content.error("trailing tokens"));
+        }
+        Ok(modifier)
+    }
+}
+
+impl Parse for CrossOrigin {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("anonymous") {
+            Ok(CrossOrigin::Anonymous)
+        } else if name.try_match("use-credentials") {
+            Ok(CrossOrigin::UseCredentials)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for ReferrerPolicy {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("no-referrer") {
+            Ok(ReferrerPolicy::NoReferrer)
+        } else if name.try_match("no-referrer-when-downgrade") {
+            Ok(ReferrerPolicy::NoReferrerWhenDowngrade)
+        } else if name.try_match("same-origin") {
+            Ok(ReferrerPolicy::SameOrigin)
+        } else if name.try_match("origin") {
+            Ok(ReferrerPolicy::Origin)
+        } else if name.try_match("strict-origin") {
+            Ok(ReferrerPolicy::StrictOrigin)
+        } else if name.try_match("origin-when-cross-origin") {
+            Ok(ReferrerPolicy::OriginWhenCrossOrigin)
+        } else if name.try_match("strict-origin-when-cross-origin") {
+            Ok(ReferrerPolicy::StrictOriginWhenCrossOrigin)
+        } else if name.try_match("unsafe-url") {
+            Ok(ReferrerPolicy::UnsafeUrl)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for Image {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_function("url") || s.peek_is(|kind| matches!(kind, TokenKind::Url(_))) {
+            s.parse().map(Image::Url)
+        } else if s.peek_function("image-set") {
+            s.parse().map(Image::ImageSet)
+        } else {
+            s.parse().map(|v| Image::Gradient(Box::new(v)))
+        }
+    }
+}
+
+impl Parse for Gradient {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let span = s.span();
+        let name = match s.peek().map(|tok| &tok.kind) {
+            Some(TokenKind::Function(name)) => name.to_ascii_lowercase(),
+            _ => return Err(s.error("expected one of `url(`, `image-set(`, or a gradient")),
+        };
+        let (repeating, kind) = match name.strip_prefix("repeating-") {
+            Some(kind) => (true, kind),
+            None => (false, name.as_str()),
+        };
+        let mut content = s.function(&name)?;
+        let c = &mut content;
+        let gradient = match kind {
+            "linear-gradient" => {
+                let direction = try_parse(c);
+                if direction.is_some() {
+                    c.expect(&TokenKind::Comma, "`,`")?;
+                }
+                Gradient::Linear(LinearGradient {
+                    repeating,
+                    direction,
+                    stops: c.parse()?,
+                })
+            }
+            "radial-gradient" => {
+                let (shape, size) = radial_shape(c)?;
+                let position = gradient_position(c)?;
+                if shape.is_some() || size.is_some() || position.is_some() {
+                    c.expect(&TokenKind::Comma, "`,`")?;
+                }
+                Gradient::Radial(RadialGradient {
+                    repeating,
+                    shape,
+                    size,
+                    position,
+                    stops: c.parse()?,
+                })
+            }
+            "conic-gradient" => {
+                let from = if c.peek_ident("from") {
+                    c.next_token();
+                    Some(c.parse()?)
+                } else {
+                    None
+                };
+                let position = gradient_position(c)?;
+                if from.is_some() || position.is_some() {
+                    c.expect(&TokenKind::Comma, "`,`")?;
+                }
+                Gradient::Conic(ConicGradient {
+                    repeating,
+                    from,
+                    position,
+                    stops: c.parse()?,
+                })
+            }
+            _ => {
+                return Err(ParseError::new(
+                    span,
+                    format!("unknown image function `{}`", name),
+                ))
+            }
+        };
+        if !content.is_empty() {
+            return Err(content.error("trailing tokens"));
+        }
+        Ok(gradient)
+    }
+}
+
+impl Parse for LineDirection {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if !s.peek_ident("to") {
+            return s.parse().map(LineDirection::Angle);
+        }
+        let span = s.next_token().unwrap().span;
+        let mut horizontal = try_parse(s);
+        let vertical = try_parse(s);
+        if horizontal.is_none() {
+            horizontal = try_parse(s);
+        }
+        if horizontal.is_none() && vertical.is_none() {
+            return Err(ParseError::new(
+                span,
+                "expected a side or corner after `to`",
+            ));
+        }
+        Ok(LineDirection::To {
+            horizontal,
+            vertical,
+        })
+    }
+}
+
+impl Parse for HorizontalSide {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("left") {
+            Ok(HorizontalSide::Left)
+        } else if name.try_match("right") {
+            Ok(HorizontalSide::Right)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for VerticalSide {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("top") {
+            Ok(VerticalSide::Top)
+        } else if name.try_match("bottom") {
+            Ok(VerticalSide::Bottom)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for EndingShape {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("circle") {
+            Ok(EndingShape::Circle)
+        } else if name.try_match("ellipse") {
+            Ok(EndingShape::Ellipse)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+/// The shape and size of a radial gradient, in either order.
+fn radial_shape(s: &mut Parser) -> Result<(Option<EndingShape>, Option<RadialSize>)> {
+    let span = s.span();
+    let mut shape = None;
+    let mut size = None;
+    let mut lengths = None;
+    loop {
+        if shape.is_none() {
+            if let Some(v) = try_parse(s) {
+                shape = Some(v);
+                continue;
+            }
+        }
+        if size.is_none() && lengths.is_none() {
+            if let Some(v) = radial_extent(s) {
+                size = Some(v);
+                continue;
+            }
+            if let Some(first) = try_parse::<Calc>(s) {
+                lengths = Some((first, try_parse(s)));
+                continue;
+            }
+        }
+        break;
+    }
+    if let Some((first, second)) = lengths {
+        size = Some(
+            RadialSize::from_lengths(shape, first, second).map_err(|e| ParseError::new(span, e))?,
+        );
+    }
+    Ok((shape, size))
+}
+
+/// A keyword for the size of a radial gradient.
+fn radial_extent(s: &mut Parser) -> Option<RadialSize> {
+    let extent = match s.peek().map(|tok| &tok.kind) {
+        Some(TokenKind::Ident(name)) => match name.to_ascii_lowercase().as_str() {
+            "closest-side" => RadialSize::ClosestSide,
+            "closest-corner" => RadialSize::ClosestCorner,
+            "farthest-side" => RadialSize::FarthestSide,
+            "farthest-corner" => RadialSize::FarthestCorner,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    s.next_token();
+    Some(extent)
+}
+
+/// The `at <position>` of a radial or conic gradient, if there is one.
+fn gradient_position(s: &mut Parser) -> Result<Option<PositionValue>> {
+    if !s.peek_ident("at") {
+        return Ok(None);
+    }
+    s.next_token();
+    s.parse().map(Some)
+}
+
+impl Parse for AnglePercentage {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        match try_parse(s) {
+            Some(v) => Ok(AnglePercentage::Percentage(v)),
+            None => s.parse().map(AnglePercentage::Angle),
+        }
+    }
+}
+
+impl<P: Parse> Parse for ColorStopList<P> {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let span = s.span();
+        let list: NonemptyCommaList<GradientItem<P>> = s.parse()?;
+        let items: Vec<_> = std::iter::once(list.first).chain(list.rest).collect();
+        if !ColorStopList::is_valid(&items) {
+            return Err(ParseError::new(
+                span,
+                "expected at least two color stops, with at most one hint between each pair",
+            ));
+        }
+        Ok(ColorStopList(items))
+    }
+}
+
+impl<P: Parse> Parse for GradientItem<P> {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let color = match try_parse(s) {
+            Some(color) => color,
+            None => return s.parse().map(GradientItem::Hint),
+        };
+        let start = if finished_item(s) {
+            None
+        } else {
+            Some(s.parse()?)
+        };
+        let end = if start.is_none() || finished_item(s) {
+            None
+        } else {
+            Some(s.parse()?)
+        };
+        Ok(GradientItem::Stop { color, start, end })
+    }
+}
+
+impl Parse for ImageSet {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut content = s.function("image-set")?;
+        let options = content.parse()?;
+        if !content.is_empty() {
+            return Err(content.error("trailing tokens"));
+        }
+        Ok(ImageSet(options))
+    }
+}
+
+impl Parse for ImageSetOption {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let span = s.span();
+        let image = if s.peek_is(|kind| matches!(kind, TokenKind::String(_))) {
+            Image::Url(Url {
+                url: s.string()?,
+                modifiers: Vec::new(),
+            })
+        } else {
+            s.parse()?
+        };
+        if let Image::ImageSet(_) = image {
+            return Err(ParseError::new(span, "`image-set()` can't be nested"));
+        }
+        let mut resolution = None;
+        let mut mime_type = None;
+        while !finished_item(s) {
+            if resolution.is_none() {
+                if let Some(v) = try_parse(s) {
+                    resolution = Some(v);
+                    continue;
+                }
+            }
+            if mime_type.is_none() && s.peek_function("type") {
+                let mut content = s.function("type")?;
+                mime_type = Some(content.string()?);
+                if !content.is_empty() {
+                    return Err(content.error("trailing tokens"));
+                }
+                continue;
+            }
+            return Err(s.error("expected a resolution or `type(`"));
+        }
+        Ok(ImageSetOption {
+            image: Box::new(image),
+            resolution,
+            mime_type,
+        })
+    }
+}
+
+impl Parse for Resolution {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let n: Number = s.parse()?;
+        if n.value < 0.0 {
+            return Err(ParseError::new(n.span, "resolution can't be negative"));
+        }
+        match n.suffix.to_ascii_lowercase().as_str() {
+            "x" => Ok(Resolution::X(n.value)),
+            "dppx" => Ok(Resolution::Dppx(n.value)),
+            "dpi" => Ok(Resolution::Dpi(n.value)),
+            "dpcm" => Ok(Resolution::Dpcm(n.value)),
+            _ => Err(ParseError::new(
+                n.span,
+                "expected one of `x`, `dppx`, `dpi`, `dpcm` after number",
+            )),
+        }
+    }
+}
+
+impl Parse for Background {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let (first, mut color) = background_layer(s)?;
+        let mut rest = Vec::new();
+        while s.peek_kind(&TokenKind::Comma) {
+            if color.is_some() {
+                return Err(s.error("the background color can only be given in the last layer"));
+            }
+            s.next_token();
+            let (layer, layer_color) = background_layer(s)?;
+            rest.push(layer);
+            color = layer_color;
+        }
+        Ok(Background {
+            layers: NonemptyCommaList { first, rest },
+            color,
+        })
+    }
+}
+
+/// A layer of the `background` shorthand, and the color if there is one.
+fn background_layer(s: &mut Parser) -> Result<(BackgroundLayer, Option<Color>)> {
+    if finished_item(s) {
+        return Err(s.error("expected a background layer"));
+    }
+    let mut layer = BackgroundLayer::default();
+    let mut color = None;
+    while !finished_item(s) {
+        if layer.image.is_none() {
+            if let Some(v) = try_parse(s) {
+                layer.image = Some(v);
+                continue;
+            }
+        }
+        if layer.position.is_none() {
+            if let Some(v) = try_parse(s) {
+                layer.position = Some(v);
+                if s.peek_delim('/') {
+                    s.next_token();
+                    layer.size = Some(s.parse()?);
+                }
+                continue;
+            }
+        }
+        if layer.repeat.is_none() {
+            if let Some(v) = try_parse(s) {
+                layer.repeat = Some(v);
+                continue;
+            }
+        }
+        if layer.attachment.is_none() {
+            if let Some(v) = try_parse(s) {
+                layer.attachment = Some(v);
+                continue;
+            }
+        }
+        if layer.clip.is_none() {
+            if let Some(v) = try_parse(s) {
+                if layer.origin.is_none() {
+                    layer.origin = Some(v);
+                } else {
+                    layer.clip = Some(v);
+                }
+                continue;
+            }
+        }
+        if color.is_none() {
+            if let Some(v) = try_parse(s) {
+                color = Some(v);
+                continue;
+            }
+        }
+        return Err(s.error("unexpected or repeated value in background layer"));
+    }
+    Ok((layer, color))
+}
+
+// position
+// ========
+
+impl Parse for PositionValue {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let span = s.span();
+        let mut values = vec![s.parse()?];
+        while values.len() < 4 {
+            match try_parse(s) {
+                Some(value) => values.push(value),
+                None => break,
+            }
+        }
+        PositionValue::from_values(values).map_err(|e| ParseError::new(span, e))
+    }
+}
+
+impl<S: Parse> Parse for PositionComponent<S> {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if let Some(side) = try_parse(s) {
+            return Ok(PositionComponent::Side(side, try_parse(s)));
+        }
+        let mut fork = s.fork();
+        let name: Ident = fork.parse()?;
+        if name.try_match("center") {
+            s.advance_to(&fork);
+            return Ok(PositionComponent::Center);
+        }
+        s.parse().map(PositionComponent::Offset).map_err(|_| {
+            name.add_expected("side");
+            name.add_expected("length");
+            name.add_expected("percentage");
+            name.error()
+        })
+    }
+}
+
+// animation
+// =========
+
+/// Parse a `T` if possible, otherwise leave the parser where it was.
+fn try_parse<T: Parse>(s: &mut Parser) -> Option<T> {
+    let mut fork = s.fork();
+    match fork.parse() {
+        Ok(v) => {
+            s.advance_to(&fork);
+            Some(v)
+        }
+        Err(_) => None,
+    }
+}
+
+/// Whether we are at the end of an item in a comma-separated list.
+fn finished_item(s: &Parser) -> bool {
+    s.is_empty() || s.peek_kind(&TokenKind::Comma)
+}
+
+/// Whether the next token is the start of an easing function.
+fn peek_easing(s: &Parser) -> bool {
+    s.peek_is(|kind| match kind {
+        TokenKind::Ident(name) | TokenKind::Function(name) => EasingFunction::is_name(name),
+        _ => false,
+    })
+}
+
+impl Parse for Time {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let n: Number = s.parse()?;
+        match n.suffix.to_ascii_lowercase().as_str() {
+            "s" => Ok(Time::S(n.value)),
+            "ms" => Ok(Time::Ms(n.value)),
+            _ => Err(ParseError::new(n.span, "expected `s` or `ms` after number")),
+        }
+    }
+}
+
+/// Check that a `<time>` used as a duration isn't negative.
+fn non_negative_time(time: Time, span: Span) -> Result<Time> {
+    if time.to_seconds() < 0.0 {
+        return Err(ParseError::new(span, "duration can't be negative"));
+    }
+    Ok(time)
+}
+
+/// Parse the value of `animation-duration` or `transition-duration`.
+fn durations(s: &mut Parser) -> Result<NonemptyCommaList<Time>> {
+    let duration = |s: &mut Parser| {
+        let span = s.span();
+        non_negative_time(s.parse()?, span)
+    };
+    let first = duration(s)?;
+    let mut rest = Vec::new();
+    while s.peek_kind(&TokenKind::Comma) {
+        s.next_token();
+        rest.push(duration(s)?);
+    }
+    Ok(NonemptyCommaList { first, rest })
+}
+
+/// Parse the value of `transition`, where `none` can only be used if there is one transition.
+fn transitions(s: &mut Parser) -> Result<NonemptyCommaList<SingleTransition>> {
+    let span = s.span();
+    let list: NonemptyCommaList<SingleTransition> = s.parse()?;
+    let is_none = |t: &SingleTransition| t.property == Some(TransitionProperty::None);
+    if !list.rest.is_empty() && (is_none(&list.first) || list.rest.iter().any(is_none)) {
+        return Err(ParseError::new(
+            span,
+            "`none` can only be used when there is a single transition",
+        ));
+    }
+    Ok(list)
+}
+
+impl Parse for EasingFunction {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let span = s.span();
+        let name = match s.peek().map(|tok| &tok.kind) {
+            Some(TokenKind::Function(name)) => name.to_ascii_lowercase(),
+            _ => {
+                let name: Ident = s.parse()?;
+                return if name.try_match("linear") {
+                    Ok(EasingFunction::Linear)
+                } else if name.try_match("ease") {
+                    Ok(EasingFunction::Ease)
+                } else if name.try_match("ease-in") {
+                    Ok(EasingFunction::EaseIn)
+                } else if name.try_match("ease-out") {
+                    Ok(EasingFunction::EaseOut)
+                } else if name.try_match("ease-in-out") {
+                    Ok(EasingFunction::EaseInOut)
+                } else if name.try_match("step-start") {
+                    Ok(EasingFunction::StepStart)
+                } else if name.try_match("step-end") {
+                    Ok(EasingFunction::StepEnd)
+                } else {
+                    name.add_expected("easing function");
+                    Err(name.error())
+                };
+            }
+        };
+        let mut content = s.function(&name)?;
+        let c = &mut content;
+        let function = match name.as_str() {
+            "linear" => {
+                let mut stops = vec![c.parse()?];
+                while c.peek_kind(&TokenKind::Comma) {
+                    c.next_token();
+                    stops.push(c.parse()?);
+                }
+                if stops.len() < 2 {
+                    return Err(ParseError::new(span, "`linear()` needs at least two stops"));
+                }
+                EasingFunction::LinearStops(stops)
+            }
+            "cubic-bezier" => {
+                let [x1, y1, x2, y2] = numbers(c)?;
+                if !((0.0..=1.0).contains(&x1) && (0.0..=1.0).contains(&x2)) {
+                    return Err(ParseError::new(
+                        span,
+                        "the x values of `cubic-bezier()` must be between 0 and 1",
+                    ));
+                }
+                EasingFunction::CubicBezier(x1, y1, x2, y2)
+            }
+            "steps" => {
+                let count = integer(c, 1..)?;
+                let position =
+                    optional_arg(c, StepPosition::parse)?.unwrap_or(StepPosition::JumpEnd);
+                if position == StepPosition::JumpNone && count < 2 {
+                    return Err(ParseError::new(
+                        span,
+                        "`steps()` with `jump-none` needs at least 2 steps",
+                    ));
+                }
+                EasingFunction::Steps(count, position)
+            }
+            _ => {
+                return Err(ParseError::new(
+                    span,
+                    format!("unknown easing function `{}`", name),
+                ))
+            }
+        };
+        if !content.is_empty() {
+            return Err(content.error("trailing tokens"));
+        }
+        Ok(function)
+    }
+}
+
+impl Parse for StepPosition {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("jump-start") || name.try_match("start") {
+            Ok(StepPosition::JumpStart)
+        } else if name.try_match("jump-end") || name.try_match("end") {
+            Ok(StepPosition::JumpEnd)
+        } else if name.try_match("jump-none") {
+            Ok(StepPosition::JumpNone)
+        } else if name.try_match("jump-both") {
+            Ok(StepPosition::JumpBoth)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for LinearStop {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let span = s.span();
+        let mut output = None;
+        let mut inputs = Vec::new();
+        while !finished_item(s) {
+            let n: Number = s.parse()?;
+            if n.suffix == "%" {
+                inputs.push(Percentage(n.value));
+            } else if output.is_none() {
+                n.empty_suffix()?;
+                output = Some(n.value);
+            } else {
+                return Err(ParseError::new(n.span, "expected percentage"));
+            }
+        }
+        match (output, inputs.len()) {
+            (Some(output), 0..=2) => Ok(LinearStop {
+                output,
+                start: inputs.first().copied(),
+                end: inputs.get(1).copied(),
+            }),
+            (Some(_), _) => Err(ParseError::new(
+                span,
+                "a `linear()` stop can have at most two percentages",
+            )),
+            (None, _) => Err(ParseError::new(span, "expected number")),
+        }
+    }
+}
+
+impl Parse for TransitionProperty {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("none") {
+            Ok(TransitionProperty::None)
+        } else if name.try_match("all") {
+            Ok(TransitionProperty::All)
+        } else if let Some(word) = name.word {
+            Ok(TransitionProperty::Property(word))
+        } else {
+            name.add_expected("property name");
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for TransitionBehavior {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("normal") {
+            Ok(TransitionBehavior::Normal)
+        } else if name.try_match("allow-discrete") {
+            Ok(TransitionBehavior::AllowDiscrete)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for SingleTransition {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut out = SingleTransition::default();
+        let span = s.span();
+        while !finished_item(s) {
+            if out.delay.is_none() {
+                let time_span = s.span();
+                if let Some(time) = try_parse(s) {
+                    if out.duration.is_none() {
+                        out.duration = Some(non_negative_time(time, time_span)?);
+                    } else {
+                        out.delay = Some(time);
+                    }
+                    continue;
+                }
+            }
+            if out.timing_function.is_none() && peek_easing(s) {
+                out.timing_function = Some(s.parse()?);
+            } else if out.behavior.is_none()
+                && (s.peek_ident("normal") || s.peek_ident("allow-discrete"))
+            {
+                out.behavior = Some(s.parse()?);
+            } else if out.property.is_none()
+                && s.peek_is(|kind| matches!(kind, TokenKind::Ident(_)))
+            {
+                out.property = Some(s.parse()?);
+            } else {
+                return Err(s.error("unexpected value in transition"));
+            }
+        }
+        if out == SingleTransition::default() {
+            return Err(ParseError::new(span, "expected transition"));
+        }
+        Ok(out)
+    }
+}
+
+impl Parse for AnimationName {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if let Some(Token {
+            kind: TokenKind::String(name),
+            ..
+        }) = s.peek()
+        {
+            s.next_token();
+            return Ok(AnimationName::Name(name.clone()));
+        }
+        let name: Ident = s.parse()?;
+        if name.try_match("none") {
+            Ok(AnimationName::None)
+        } else if let Some(word) = name.word {
+            Ok(AnimationName::Name(word))
+        } else {
+            name.add_expected("animation name");
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for AnimationIterationCount {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_is(|kind| matches!(kind, TokenKind::Ident(_))) {
+            let name: Ident = s.parse()?;
+            name.expect("infinite")?;
+            return Ok(AnimationIterationCount::Infinite);
+        }
+        let span = s.span();
+        let count = plain_number(s)?;
+        if count < 0.0 {
+            return Err(ParseError::new(span, "iteration count can't be negative"));
+        }
+        Ok(AnimationIterationCount::Count(count))
+    }
+}
+
+impl Parse for AnimationDirection {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("normal") {
+            Ok(AnimationDirection::Normal)
+        } else if name.try_match("reverse") {
+            Ok(AnimationDirection::Reverse)
+        } else if name.try_match("alternate") {
+            Ok(AnimationDirection::Alternate)
+        } else if name.try_match("alternate-reverse") {
+            Ok(AnimationDirection::AlternateReverse)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for AnimationFillMode {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("none") {
+            Ok(AnimationFillMode::None)
+        } else if name.try_match("forwards") {
+            Ok(AnimationFillMode::Forwards)
+        } else if name.try_match("backwards") {
+            Ok(AnimationFillMode::Backwards)
+        } else if name.try_match("both") {
+            Ok(AnimationFillMode::Both)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for AnimationPlayState {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("running") {
+            Ok(AnimationPlayState::Running)
+        } else if name.try_match("paused") {
+            Ok(AnimationPlayState::Paused)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for SingleAnimation {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut out = SingleAnimation::default();
+        let span = s.span();
+        while !finished_item(s) {
+            if out.delay.is_none() {
+                let time_span = s.span();
+                if let Some(time) = try_parse(s) {
+                    if out.duration.is_none() {
+                        out.duration = Some(non_negative_time(time, time_span)?);
+                    } else {
+                        out.delay = Some(time);
+                    }
+                    continue;
+                }
+            }
+            if out.timing_function.is_none() && peek_easing(s) {
+                out.timing_function = Some(s.parse()?);
+                continue;
+            }
+            // A keyword is only the name if it isn't valid for anything else.
+            if out.iteration_count.is_none() {
+                if let Some(v) = try_parse(s) {
+                    out.iteration_count = Some(v);
+                    continue;
+                }
+            }
+            if out.direction.is_none() {
+                if let Some(v) = try_parse(s) {
+                    out.direction = Some(v);
+                    continue;
+                }
+            }
+            if out.fill_mode.is_none() {
+                if let Some(v) = try_parse(s) {
+                    out.fill_mode = Some(v);
+                    continue;
+                }
+            }
+            if out.play_state.is_none() {
+                if let Some(v) = try_parse(s) {
+                    out.play_state = Some(v);
+                    continue;
+                }
+            }
+            if out.name.is_none() {
+                out.name = Some(s.parse()?);
+                continue;
+            }
+            return Err(s.error("unexpected value in animation"));
+        }
+        if out == SingleAnimation::default() {
+            return Err(ParseError::new(span, "expected animation"));
+        }
+        Ok(out)
+    }
+}
+
+impl Parse for Keyframe {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut prelude = s.until(|kind| *kind == TokenKind::LBrace);
+        let mut selectors = Vec::new();
+        loop {
+            if prelude.peek_ident("from") {
+                prelude.next_token();
+                selectors.push(Percentage(0.0));
+            } else if prelude.peek_ident("to") {
+                prelude.next_token();
+                selectors.push(Percentage(100.0));
+            } else {
+                let span = prelude.span();
+                let selector: Percentage = prelude
+                    .parse()
+                    .map_err(|_| prelude.error("expected `from`, `to` or percentage"))?;
+                if !(0.0..=100.0).contains(&selector.0) {
+                    return Err(ParseError::new(
+                        span,
+                        "keyframe selectors must be between 0% and 100%",
+                    ));
+                }
+                selectors.push(selector);
+            }
+            if prelude.is_empty() {
+                break;
+            }
+            prelude.expect(&TokenKind::Comma, "`,`")?;
+        }
+        let styles = s.block()?.parse()?;
+        Ok(Keyframe { selectors, styles })
+    }
+}
+
+// transform
+// =========
+
+impl Parse for Angle {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let angle = match s.peek().map(|tok| &tok.kind) {
+            Some(TokenKind::Dimension { value, unit }) => {
+                let value = value.value;
+                match unit.to_ascii_lowercase().as_str() {
+                    "deg" => Angle::Deg(value),
+                    "grad" => Angle::Grad(value),
+                    "rad" => Angle::Rad(value),
+                    "turn" => Angle::Turn(value),
+                    _ => {
+                        return Err(
+                            s.error("expected one of `deg`, `grad`, `rad`, `turn` after number")
+                        )
+                    }
+                }
+            }
+            Some(TokenKind::Number(n)) if n.value == 0.0 => Angle::Zero,
+            _ => {
+                return Err(s.error(
+                    "expected a number followed by one of `deg`, `grad`, `rad`, `turn`, or 0",
+                ))
+            }
+        };
+        s.next_token();
+        Ok(angle)
+    }
+}
+
+impl Parse for TransformList {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("none") {
+            s.next_token();
+            return Ok(TransformList::none());
+        }
+        let mut functions = vec![s.parse()?];
+        while !s.is_empty() {
+            functions.push(s.parse()?);
+        }
+        Ok(TransformList(functions))
+    }
+}
+
+impl Parse for TransformFunction {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let span = s.span();
+        let name = match s.peek().map(|tok| &tok.kind) {
+            Some(TokenKind::Function(name)) => name.to_ascii_lowercase(),
+            _ => return Err(s.error("expected a transform function")),
+        };
+        let mut content = s.function(&name)?;
+        let c = &mut content;
+        let function = match name.as_str() {
+            "matrix" => TransformFunction::Matrix(numbers(c)?),
+            "matrix3d" => TransformFunction::Matrix3d(numbers(c)?),
+            "translate" => TransformFunction::Translate(c.parse()?, optional_arg(c, Calc::parse)?),
+            "translatex" => TransformFunction::TranslateX(c.parse()?),
+            "translatey" => TransformFunction::TranslateY(c.parse()?),
+            "translatez" => TransformFunction::TranslateZ(c.parse()?),
+            "translate3d" => {
+                let x = c.parse()?;
+                c.expect(&TokenKind::Comma, "`,`")?;
+                let y = c.parse()?;
+                c.expect(&TokenKind::Comma, "`,`")?;
+                TransformFunction::Translate3d(x, y, c.parse()?)
+            }
+            "scale" => TransformFunction::Scale(
+                number_or_percentage(c)?,
+                optional_arg(c, number_or_percentage)?,
+            ),
+            "scalex" => TransformFunction::ScaleX(number_or_percentage(c)?),
+            "scaley" => TransformFunction::ScaleY(number_or_percentage(c)?),
+            "scalez" => TransformFunction::ScaleZ(number_or_percentage(c)?),
+            "scale3d" => {
+                let x = number_or_percentage(c)?;
+                c.expect(&TokenKind::Comma, "`,`")?;
+                let y = number_or_percentage(c)?;
+                c.expect(&TokenKind::Comma, "`,`")?;
+                TransformFunction::Scale3d(x, y, number_or_percentage(c)?)
+            }
+            "rotate" => TransformFunction::Rotate(c.parse()?),
+            "rotatex" => TransformFunction::RotateX(c.parse()?),
+            "rotatey" => TransformFunction::RotateY(c.parse()?),
+            "rotatez" => TransformFunction::RotateZ(c.parse()?),
+            "rotate3d" => {
+                let [x, y, z] = numbers(c)?;
+                c.expect(&TokenKind::Comma, "`,`")?;
+                TransformFunction::Rotate3d(x, y, z, c.parse()?)
+            }
+            "skew" => TransformFunction::Skew(c.parse()?, optional_arg(c, Angle::parse)?),
+            "skewx" => TransformFunction::SkewX(c.parse()?),
+            "skewy" => TransformFunction::SkewY(c.parse()?),
+            "perspective" => {
+                if c.peek_ident("none") {
+                    c.next_token();
+                    TransformFunction::Perspective(None)
+                } else {
+                    TransformFunction::Perspective(Some(non_negative_length(c)?))
+                }
+            }
+            _ => {
+                return Err(ParseError::new(
+                    span,
+                    format!("unknown transform function `{}`", name),
+                ))
+            }
+        };
+        if !content.is_empty() {
+            return Err(content.error("trailing tokens"));
+        }
+        Ok(function)
+    }
+}
+
+/// A number with no unit.
+fn plain_number(s: &mut Parser) -> Result<f64> {
+    let n: Number = s.parse()?;
+    n.empty_suffix()?;
+    Ok(n.value)
+}
+
+/// A number or a percentage, with percentages converted to numbers (so `50%` is `0.5`).
+fn number_or_percentage(s: &mut Parser) -> Result<f64> {
+    let n: Number = s.parse()?;
+    if n.suffix == "%" {
+        Ok(n.value / 100.0)
+    } else {
+        n.empty_suffix()?;
+        Ok(n.value)
+    }
+}
+
+/// `N` comma-separated numbers.
+fn numbers<const N: usize>(s: &mut Parser) -> Result<[f64; N]> {
+    let mut out = [0.0; N];
+    for (idx, value) in out.iter_mut().enumerate() {
+        if idx > 0 {
+            s.expect(&TokenKind::Comma, "`,`")?;
+        }
+        *value = plain_number(s)?;
+    }
+    Ok(out)
+}
+
+/// An optional argument, after a comma.
+fn optional_arg<T>(s: &mut Parser, parse: fn(&mut Parser) -> Result<T>) -> Result<Option<T>> {
+    if s.peek_kind(&TokenKind::Comma) {
+        s.next_token();
+        parse(s).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+fn non_negative_length(s: &mut Parser) -> Result<Length> {
+    let span = s.span();
+    let length: Length = s.parse()?;
+    if length.is_negative() {
+        return Err(ParseError::new(span, "length can't be negative"));
+    }
+    Ok(length)
+}
+
+impl Parse for TransformOrigin {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let span = s.span();
+        let first = s.parse()?;
+        let second = if s.is_empty() { None } else { Some(s.parse()?) };
+        let (x, y) =
+            TransformOrigin::from_values(first, second).map_err(|e| ParseError::new(span, e))?;
+        let z = if s.is_empty() { None } else { Some(s.parse()?) };
+        Ok(TransformOrigin { x, y, z })
+    }
+}
+
+impl Parse for OriginValue {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut fork = s.fork();
+        let name: Ident = fork.parse()?;
+        let value = if name.try_match("left") {
+            OriginValue::Left
+        } else if name.try_match("center") {
+            OriginValue::Center
+        } else if name.try_match("right") {
+            OriginValue::Right
+        } else if name.try_match("top") {
+            OriginValue::Top
+        } else if name.try_match("bottom") {
+            OriginValue::Bottom
+        } else {
+            return s.parse().map(OriginValue::Offset).map_err(|_| {
+                name.add_expected("length");
+                name.add_expected("percentage");
+                name.error()
+            });
+        };
+        s.advance_to(&fork);
+        Ok(value)
+    }
+}
+
+impl Parse for TransformStyle {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("flat") {
+            Ok(TransformStyle::Flat)
+        } else if name.try_match("preserve-3d") {
+            Ok(TransformStyle::Preserve3d)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for Perspective {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("none") {
+            s.next_token();
+            Ok(Perspective::None)
+        } else {
+            non_negative_length(s).map(Perspective::Length)
+        }
+    }
+}
+
+impl Parse for BackfaceVisibility {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let name: Ident = s.parse()?;
+        if name.try_match("visible") {
+            Ok(BackfaceVisibility::Visible)
+        } else if name.try_match("hidden") {
+            Ok(BackfaceVisibility::Hidden)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for Translate {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("none") {
+            s.next_token();
+            return Ok(Translate::None);
+        }
+        let x = s.parse()?;
+        let y = if s.is_empty() { None } else { Some(s.parse()?) };
+        let z = if y.is_none() || s.is_empty() {
+            None
+        } else {
+            Some(s.parse()?)
+        };
+        Ok(Translate::Offset { x, y, z })
+    }
+}
+
+impl Parse for Rotate {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("none") {
+            s.next_token();
+            return Ok(Rotate::None);
+        }
+        // A unitless number starts an axis, not an angle.
+        if s.peek_is(|kind| matches!(kind, TokenKind::Ident(_) | TokenKind::Number(_))) {
+            let axis = s.parse()?;
+            return Ok(Rotate::Axis(axis, s.parse()?));
+        }
+        let angle = s.parse()?;
+        if s.is_empty() {
+            Ok(Rotate::Angle(angle))
+        } else {
+            Ok(Rotate::Axis(s.parse()?, angle))
+        }
+    }
+}
+
+impl Parse for RotateAxis {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut fork = s.fork();
+        let name: Ident = fork.parse()?;
+        let axis = if name.try_match("x") {
+            RotateAxis::X
+        } else if name.try_match("y") {
+            RotateAxis::Y
+        } else if name.try_match("z") {
+            RotateAxis::Z
+        } else {
+            name.add_expected("number");
+            if name.word.is_some() {
+                return Err(name.error());
+            }
+            let x = plain_number(s)?;
+            let y = plain_number(s)?;
+            return Ok(RotateAxis::Vector(x, y, plain_number(s)?));
+        };
+        s.advance_to(&fork);
+        Ok(axis)
+    }
+}
+
+impl Parse for Scale {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_ident("none") {
+            s.next_token();
+            return Ok(Scale::None);
+        }
+        let x = number_or_percentage(s)?;
+        let y = if s.is_empty() {
+            None
+        } else {
+            Some(number_or_percentage(s)?)
+        };
+        let z = if y.is_none() || s.is_empty() {
+            None
+        } else {
+            Some(number_or_percentage(s)?)
+        };
+        Ok(Scale::Factors { x, y, z })
+    }
+}
+
+// calc
+// ====
+
+impl Parse for Calc {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_function("calc") {
+            let mut content = s.function("calc")?;
+            let sum = content.parse()?;
+            if !content.is_empty() {
+                return Err(content.error("unexpected trailing tokens in `calc()`"));
+            }
+            Ok(Calc::Calculated(sum))
+        } else {
+            Ok(Calc::Normal(s.parse()?))
+        }
+    }
+}
+
+impl Parse for CalcSum {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let first: CalcProduct = s.parse()?;
+        let mut rest: Vec<SumOp> = vec![];
+        while s.peek_delim('+') || s.peek_delim('-') {
+            rest.push(s.parse()?);
+        }
+        Ok(CalcSum { first, rest })
+    }
+}
+
+impl Parse for SumOp {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_delim('+') {
+            s.next_token();
+            Ok(SumOp::Add(s.parse()?))
+        } else if s.peek_delim('-') {
+            s.next_token();
+            Ok(SumOp::Sub(s.parse()?))
+        } else {
+            Err(s.error("expected one of `+`, `-`"))
+        }
+    }
+}
+
+impl Parse for CalcProduct {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let first: CalcValue = s.parse()?;
+        let mut rest: Vec<ProductOp> = vec![];
+        while s.peek_delim('*') {
+            rest.push(s.parse()?);
+        }
+        Ok(CalcProduct { first, rest })
+    }
+}
+
+impl Parse for ProductOp {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if s.peek_delim('*') {
+            s.next_token();
+            Ok(ProductOp::Mul(s.parse()?))
+        } else {
+            Err(s.error("expected `*`"))
+        }
+    }
+}
+
+impl Parse for CalcValue {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        Ok(CalcValue::LengthPercentage(s.parse()?))
+    }
+}
+
+// color
+// =====
+
+impl Parse for Color {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        if let Some(Token {
+            kind: TokenKind::Hash { value, .. },
+            span,
+        }) = s.peek()
+        {
+            s.next_token();
+            return color::parse_hex(value).ok_or_else(|| {
+                ParseError::new(*span, "expected a hex color with 3, 6 or 8 digits")
+            });
+        }
+        if s.peek_function("hsl") {
+            let mut content = s.function("hsl")?;
+            return parse_hsl_color(&mut content, false);
+        }
+        if s.peek_function("hsla") {
+            let mut content = s.function("hsla")?;
+            return parse_hsl_color(&mut content, true);
+        }
+        let name: Ident = s.parse()?;
+        name.add_expected("`hsl(`");
+        name.add_expected("`hsla(`");
+        name.add_expected("hex color");
+        if let Some(name) = name.word.as_ref() {
+            if let Some(color) = Color::from_named(&name.to_ascii_lowercase()) {
+                return Ok(color);
+            }
+        }
+        name.add_expected("named color");
+        Err(name.error())
+    }
+}
+
+impl Parse for DynamicColor {
+    /// Only literal colors can be written in css text.
+    fn parse(s: &mut Parser) -> Result<Self> {
+        Ok(DynamicColor::Literal(s.parse()?))
+    }
+}
+
+fn parse_hsl_color(s: &mut Parser, with_alpha: bool) -> Result<Color> {
+    let n: Number = s.parse()?;
+    n.empty_suffix()?;
+    let hue = n.value;
+    if !(0.0..360.0).contains(&hue) {
+        return Err(ParseError::new(
+            n.span,
+            "hue should be in the range `0 <= hue < 360`",
+        ));
+    }
+    s.expect(&TokenKind::Comma, "`,`")?;
+    let n: Number = s.parse()?;
+    if n.suffix != "%" {
+        return Err(ParseError::new(
+            n.span,
+            "saturation should be a percentage (followed by `%`)",
+        ));
+    }
+    let sat = n.value;
+    if !(0.0..=100.0).contains(&sat) {
+        return Err(ParseError::new(
+            n.span,
+            "saturation should be in the range `0 <= sat < 100`",
+        ));
+    }
+    s.expect(&TokenKind::Comma, "`,`")?;
+    let n: Number = s.parse()?;
+    if n.suffix != "%" {
+        return Err(ParseError::new(
+            n.span,
+            "lightness should be a percentage (followed by `%`)",
+        ));
+    }
+    let light = n.value;
+    if !(0.0..=100.0).contains(&light) {
+        return Err(ParseError::new(
+            n.span,
+            "lightness should be in the range `0 <= light < 100`",
+        ));
+    }
+    if !with_alpha {
+        return if s.is_empty() {
+            Ok(Color::HSL(hue, sat, light))
+        } else {
+            Err(s.error("trailing characters"))
+        };
+    }
+    // we are a hsla
+    s.expect(&TokenKind::Comma, "`,`")?;
+    let n: Number = s.parse()?;
+    n.empty_suffix()?;
+    let alpha = n.value;
+    if !(0.0..=1.0).contains(&alpha) {
+        return Err(ParseError::new(
+            n.span,
+            "alpha should be in the range `0 <= alpha < 1`",
+        ));
+    }
+    if s.is_empty() {
+        Ok(Color::HSLA(hue, sat, light, alpha))
+    } else {
+        Err(s.error("unexpected trailing characters"))
+    }
+}
+
+// Selectors
+// =========
+
+impl Parse for SelectorList {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut selectors = vec![s.parse()?];
+        while s.peek_kind(&TokenKind::Comma) {
+            s.next_token();
+            selectors.push(s.parse()?);
+        }
+        Ok(SelectorList(selectors))
+    }
+}
+
+/// Parse the rest of the input as the selector list argument of a pseudo-class like `:not()`.
+fn argument_selector_list(s: &mut Parser) -> Result<SelectorList> {
+    let mut selectors = vec![argument_selector(s)?];
+    while s.peek_kind(&TokenKind::Comma) {
+        s.next_token();
+        selectors.push(argument_selector(s)?);
+    }
+    if !s.is_empty() {
+        return Err(s.error("unexpected trailing input"));
+    }
+    Ok(SelectorList(selectors))
+}
+
+/// Parse a selector in the arguments of a pseudo-class, where pseudo-elements aren't allowed.
+fn argument_selector(s: &mut Parser) -> Result<Selector> {
+    s.skip_whitespace();
+    let span = s.span();
+    let selector: Selector = s.parse()?;
+    if selector.last().pseudo_element.is_some() {
+        return Err(ParseError::new(
+            span,
+            "pseudo-elements are not allowed in pseudo-class arguments",
+        ));
+    }
+    Ok(selector)
+}
+
+/// Parse the rest of the input as a forgiving selector list (for `:is()` and `:where()`), where
+/// selectors that fail to parse are left out.
+fn forgiving_selector_list(s: &mut Parser) -> SelectorList {
+    let mut selectors = Vec::new();
+    loop {
+        let mut item = s.until(|kind| *kind == TokenKind::Comma);
+        let selector = argument_selector(&mut item).and_then(|selector| {
+            if !item.is_empty() {
+                return Err(item.error("unexpected trailing input"));
+            }
+            Ok(selector)
+        });
+        if let Ok(selector) = selector {
+            selectors.push(selector);
+        }
+        if s.next_token().is_none() {
+            break;
+        }
+    }
+    SelectorList(selectors)
+}
+
+impl Parse for Selector {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let first: CompoundSelector = s.parse()?;
+        let mut pseudo_element = first.pseudo_element.is_some();
+        let mut rest = Vec::new();
+        loop {
+            let whitespace = s.peek_whitespace();
+            if s.is_empty() || s.peek_kind(&TokenKind::Comma) {
+                break;
+            }
+            if pseudo_element {
+                return Err(s.error("a pseudo-element must be at the end of a selector"));
+            }
+            let combinator = match combinator(s) {
+                Some(combinator) => combinator,
+                None if whitespace => Combinator::Descendant,
+                None => return Err(s.error("expected combinator")),
+            };
+            let compound: CompoundSelector = s.parse()?;
+            pseudo_element = compound.pseudo_element.is_some();
+            rest.push((combinator, compound));
+        }
+        Ok(Selector { first, rest })
+    }
+}
+
+impl Parse for RelativeSelector {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        Ok(RelativeSelector {
+            combinator: combinator(s).unwrap_or(Combinator::Descendant),
+            selector: argument_selector(s)?,
+        })
+    }
+}
+
+/// Consume a `>`, `+` or `~` combinator if there is one.
+fn combinator(s: &mut Parser) -> Option<Combinator> {
+    let combinator = match s.peek()?.kind {
+        TokenKind::Delim('>') => Combinator::Child,
+        TokenKind::Delim('+') => Combinator::NextSibling,
+        TokenKind::Delim('~') => Combinator::SubsequentSibling,
+        _ => return None,
+    };
+    s.next_token();
+    Some(combinator)
+}
+
+impl Parse for CompoundSelector {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        s.skip_whitespace();
+        let span = s.span();
+        let mut type_selector = s.parse::<Option<TypeSelector>>()?;
+        let mut subclasses = Vec::new();
+        let mut pseudo_element: Option<PseudoElementSelector> = None;
+        // The simple selectors in a compound selector can't be separated by whitespace.
+        while !s.peek_whitespace() {
+            match s.peek().map(|tok| &tok.kind) {
+                Some(TokenKind::Colon) if peek_pseudo_element(s) => {
+                    if pseudo_element.is_some() {
+                        return Err(s.error("only one pseudo-element is allowed"));
+                    }
+                    pseudo_element = Some(PseudoElementSelector {
+                        element: s.parse()?,
+                        pseudo_classes: Vec::new(),
+                    });
+                }
+                Some(TokenKind::Colon) => {
+                    let pseudo_class = s.parse()?;
+                    match &mut pseudo_element {
+                        Some(element) => element.pseudo_classes.push(pseudo_class),
+                        None => subclasses.push(SubclassSelector::PseudoClass(pseudo_class)),
+                    }
+                }
+                Some(TokenKind::Hash { .. })
+                | Some(TokenKind::Delim('.'))
+                | Some(TokenKind::LBracket)
+                    if pseudo_element.is_some() =>
+                {
+                    return Err(s.error("expected pseudo-class after pseudo-element"))
+                }
+                Some(TokenKind::Hash { value, is_id }) => {
+                    if !is_id {
+                        return Err(s.error("expected id"));
+                    }
+                    subclasses.push(SubclassSelector::Id(value.clone()));
+                    s.next_token();
+                }
+                Some(TokenKind::Delim('.')) => {
+                    s.next_token();
+                    subclasses.push(SubclassSelector::Class(selector_ident(s)?));
+                }
+                Some(TokenKind::LBracket) => {
+                    subclasses.push(SubclassSelector::Attribute(s.parse()?));
+                }
+                _ => break,
+            }
+        }
+        if type_selector.is_none() && subclasses.is_empty() && pseudo_element.is_none() {
+            return Err(ParseError::new(span, "expected selector"));
+        }
+        // `*.a` is the same as `.a`.
+        if !subclasses.is_empty() || pseudo_element.is_some() {
+            if let Some(TypeSelector {
+                namespace: None,
+                name: TypeName::Universal,
+            }) = type_selector
+            {
+                type_selector = None;
+            }
+        }
+        Ok(CompoundSelector {
+            type_selector,
+            subclasses,
+            pseudo_element,
+        })
+    }
+}
+
+/// An identifier that isn't preceded by whitespace, as used in class and pseudo-class names.
+fn selector_ident(s: &mut Parser) -> Result<String> {
+    match s.peek() {
+        Some(Token {
+            kind: TokenKind::Ident(ident),
+            ..
+        }) if !s.peek_whitespace() => {
+            s.next_token();
+            Ok(ident.clone())
+        }
+        _ => Err(s.error("expected identifier")),
+    }
+}
+
+impl Parse for Option<TypeSelector> {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let namespace = namespace_prefix(s);
+        let name = match s.peek().map(|tok| &tok.kind) {
+            Some(TokenKind::Ident(name)) => TypeName::Name(name.clone()),
+            Some(TokenKind::Delim('*')) => TypeName::Universal,
+            _ if namespace.is_some() => return Err(s.error("expected element name or `*`")),
+            _ => return Ok(None),
+        };
+        if namespace.is_some() && s.peek_whitespace() {
+            return Err(s.error("unexpected whitespace after namespace"));
+        }
+        s.next_token();
+        Ok(Some(TypeSelector { namespace, name }))
+    }
+}
+
+/// Consume a namespace prefix (`ns|`, `*|` or `|`) if there is one.
+fn namespace_prefix(s: &mut Parser) -> Option<NamespacePrefix> {
+    let mut fork = s.fork();
+    let prefix = match &fork.next_token()?.kind {
+        TokenKind::Ident(prefix) => NamespacePrefix::Named(prefix.clone()),
+        TokenKind::Delim('*') => NamespacePrefix::Any,
+        TokenKind::Delim('|') => {
+            s.advance_to(&fork);
+            return Some(NamespacePrefix::None);
+        }
+        _ => return None,
+    };
+    if fork.peek_whitespace() || !fork.peek_delim('|') {
+        return None;
+    }
+    fork.next_token();
+    // `[lang|=en]` is an attribute operator, not a namespace.
+    if !fork.peek_whitespace() && fork.peek_delim('=') {
+        return None;
+    }
+    s.advance_to(&fork);
+    Some(prefix)
+}
+
+impl Parse for AttributeSelector {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let mut content = s.brackets()?;
+        let namespace = namespace_prefix(&mut content);
+        let name = match content.peek() {
+            Some(Token {
+                kind: TokenKind::Ident(name),
+                ..
+            }) => name.clone(),
+            _ => return Err(content.error("expected attribute name")),
+        };
+        content.next_token();
+        if content.is_empty() {
+            return Ok(AttributeSelector {
+                namespace,
+                name,
+                matcher: None,
+            });
+        }
+
+        let operator = match content.peek().map(|tok| &tok.kind) {
+            Some(TokenKind::Delim('=')) => AttributeOperator::Equals,
+            Some(TokenKind::Delim(ch)) => {
+                let operator = match ch {
+                    '~' => AttributeOperator::Includes,
+                    '|' => AttributeOperator::DashMatch,
+                    '^' => AttributeOperator::Prefix,
+                    '$' => AttributeOperator::Suffix,
+                    '*' => AttributeOperator::Substring,
+                    _ => return Err(content.error("expected attribute operator")),
+                };
+                content.next_token();
+                if content.peek_whitespace() || !content.peek_delim('=') {
+                    return Err(content.error("expected `=`"));
+                }
+                operator
+            }
+            _ => return Err(content.error("expected attribute operator")),
+        };
+        content.next_token();
+
+        let value = match content.peek().map(|tok| &tok.kind) {
+            Some(TokenKind::Ident(value)) | Some(TokenKind::String(value)) => value.clone(),
+            _ => return Err(content.error("expected attribute value")),
+        };
+        content.next_token();
+
+        let case = if content.peek_ident("i") {
+            Some(CaseSensitivity::Insensitive)
+        } else if content.peek_ident("s") {
+            Some(CaseSensitivity::Sensitive)
+        } else {
+            None
+        };
+        if case.is_some() {
+            content.next_token();
+        }
+        if !content.is_empty() {
+            return Err(content.error("unexpected trailing input in attribute selector"));
+        }
+        Ok(AttributeSelector {
+            namespace,
+            name,
+            matcher: Some(AttributeMatcher {
+                operator,
+                value,
+                case,
+            }),
+        })
+    }
+}
+
+/// Whether the next tokens are a pseudo-element: either `::name`, or one of the pseudo-elements
+/// from css 2 that can be written with a single colon (like `:before`).
+fn peek_pseudo_element(s: &Parser) -> bool {
+    let mut fork = s.fork();
+    fork.next_token();
+    if fork.peek_whitespace() {
+        return false;
+    }
+    fork.peek_kind(&TokenKind::Colon)
+        || ["before", "after", "first-line", "first-letter"]
+            .iter()
+            .any(|name| fork.peek_ident(name))
+}
+
+/// Consume the name of a pseudo-class or pseudo-element, returning the lowercase name, and a
+/// parser for the arguments if it is a function.
+fn pseudo_name<'a>(s: &mut Parser<'a>) -> Result<(String, Option<Parser<'a>>)> {
+    if s.peek_whitespace() {
+        return Err(s.error("unexpected whitespace after `:`"));
+    }
+    match s.peek().map(|tok| &tok.kind) {
+        Some(TokenKind::Ident(name)) => {
+            let name = name.to_ascii_lowercase();
+            s.next_token();
+            Ok((name, None))
+        }
+        Some(TokenKind::Function(name)) => {
+            let name = name.to_ascii_lowercase();
+            let args = s.function(&name)?;
+            Ok((name, Some(args)))
+        }
+        _ => Err(s.error("expected pseudo-class or pseudo-element name")),
+    }
+}
+
+impl Parse for PseudoClass {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        s.expect(&TokenKind::Colon, "`:`")?;
+        let span = s.span();
+        let (name, args) = pseudo_name(s)?;
+        Ok(match (name.as_str(), args) {
+            ("is", Some(mut args)) => PseudoClass::Is(forgiving_selector_list(&mut args)),
+            ("where", Some(mut args)) => PseudoClass::Where(forgiving_selector_list(&mut args)),
+            ("not", Some(mut args)) => PseudoClass::Not(argument_selector_list(&mut args)?),
+            ("has", Some(mut args)) => {
+                let args_span = args.span();
+                let mut list: Vec<RelativeSelector> = vec![args.parse()?];
+                while args.peek_kind(&TokenKind::Comma) {
+                    args.next_token();
+                    list.push(args.parse()?);
+                }
+                if !args.is_empty() {
+                    return Err(args.error("unexpected trailing input"));
+                }
+                if list.iter().any(|relative| contains_has(&relative.selector)) {
+                    return Err(ParseError::new(args_span, "`:has()` can't be nested"));
+                }
+                PseudoClass::Has(list)
+            }
+            ("nth-child", Some(mut args)) => {
+                let (nth, of) = nth_of(&mut args)?;
+                PseudoClass::NthChild(nth, of)
+            }
+            ("nth-last-child", Some(mut args)) => {
+                let (nth, of) = nth_of(&mut args)?;
+                PseudoClass::NthLastChild(nth, of)
+            }
+            ("nth-of-type", Some(mut args)) => PseudoClass::NthOfType(nth(&mut args)?),
+            ("nth-last-of-type", Some(mut args)) => PseudoClass::NthLastOfType(nth(&mut args)?),
+            (
+                "is" | "where" | "not" | "has" | "nth-child" | "nth-last-child" | "nth-of-type"
+                | "nth-last-of-type",
+                None,
+            ) => return Err(ParseError::new(span, format!("expected `{}(`", name))),
+            (_, Some(mut args)) => PseudoClass::Function(name, args.css_text()),
+            ("first-child", None) => PseudoClass::FirstChild,
+            ("last-child", None) => PseudoClass::LastChild,
+            ("only-child", None) => PseudoClass::OnlyChild,
+            ("first-of-type", None) => PseudoClass::FirstOfType,
+            ("last-of-type", None) => PseudoClass::LastOfType,
+            ("only-of-type", None) => PseudoClass::OnlyOfType,
+            ("root", None) => PseudoClass::Root,
+            ("empty", None) => PseudoClass::Empty,
+            (_, None) => PseudoClass::Named(name),
+        })
+    }
+}
+
+/// Parse the arguments of `:nth-child()` and `:nth-last-child()`: `An+B [of S]?`.
+fn nth_of(s: &mut Parser) -> Result<(Nth, Option<SelectorList>)> {
+    let mut anb =
+        s.until(|kind| matches!(kind, TokenKind::Ident(of) if of.eq_ignore_ascii_case("of")));
+    let nth = nth(&mut anb)?;
+    if s.is_empty() {
+        return Ok((nth, None));
+    }
+    s.next_token();
+    Ok((nth, Some(argument_selector_list(s)?)))
+}
+
+/// Whether `:has()` appears anywhere in the selector, including in the arguments of other
+/// pseudo-classes.
+fn contains_has(selector: &Selector) -> bool {
+    let compounds = Some(&selector.first)
+        .into_iter()
+        .chain(selector.rest.iter().map(|(_, compound)| compound));
+    compounds
+        .flat_map(|compound| compound.subclasses.iter())
+        .any(|subclass| match subclass {
+            SubclassSelector::PseudoClass(PseudoClass::Has(_)) => true,
+            SubclassSelector::PseudoClass(
+                PseudoClass::Is(list)
+                | PseudoClass::Where(list)
+                | PseudoClass::Not(list)
+                | PseudoClass::NthChild(_, Some(list))
+                | PseudoClass::NthLastChild(_, Some(list)),
+            ) => list.0.iter().any(contains_has),
+            _ => false,
+        })
+}
+
+/// Parse the rest of the input as `An+B` (including the keywords `odd` and `even`).
+///
+/// The tokens that make up `An+B` are odd (e.g. `-n-1` is a single identifier), so this works
+/// on the text rather than the tokens.
+fn nth(s: &mut Parser) -> Result<Nth> {
+    let span = s.span();
+    let text = s
+        .css_text()
+        .split_whitespace()
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let sign_and_digits = |text: &str| {
+        let digits = text.strip_prefix(['+', '-']).unwrap_or(text);
+        !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+    };
+    let nth = match text.as_str() {
+        "odd" => Some(Nth::new(2, 1)),
+        "even" => Some(Nth::new(2, 0)),
+        text => match text.find('n') {
+            Some(idx) => {
+                let (a, b) = (&text[..idx], &text[idx + 1..]);
+                let a = match a {
+                    "" | "+" => Some(1),
+                    "-" => Some(-1),
+                    a if sign_and_digits(a) => a.parse().ok(),
+                    _ => None,
+                };
+                let b = match b {
+                    "" => Some(0),
+                    b if b.starts_with(['+', '-']) && sign_and_digits(b) => b.parse().ok(),
+                    _ => None,
+                };
+                a.zip(b).map(|(a, b)| Nth::new(a, b))
+            }
+            None if sign_and_digits(text) => text.parse().ok().map(|b| Nth::new(0, b)),
+            None => None,
+        },
+    };
+    nth.ok_or_else(|| ParseError::new(span, "expected `An+B`, `odd` or `even`"))
+}
+
+impl Parse for PseudoElement {
+    fn parse(s: &mut Parser) -> Result<Self> {
+        s.expect(&TokenKind::Colon, "`:`")?;
+        if !s.peek_whitespace() && s.peek_kind(&TokenKind::Colon) {
+            s.next_token();
+        }
+        let (name, args) = pseudo_name(s)?;
+        Ok(match args {
+            Some(mut args) => PseudoElement::Function(name, args.css_text()),
+            None => PseudoElement::from_name(&name),
+        })
+    }
+}
+
+// Util
+// ====
+
+impl<T> Parse for NonemptyCommaList<T>
+where
+    T: Parse,
+{
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let first = s.parse()?;
+        let mut rest = Vec::new();
+        while s.peek_kind(&TokenKind::Comma) {
+            s.next_token();
+            rest.push(s.parse()?);
+        }
+        Ok(Self { first, rest })
+    }
+}
+
+impl<T> Parse for SingleOrDouble<T>
+where
+    T: Parse,
+{
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let first = T::parse(s)?;
+        let mut fork = s.fork();
+        Ok(match T::parse(&mut fork) {
+            Ok(second) => {
+                s.advance_to(&fork);
+                SingleOrDouble::Double {
+                    horiz: first,
+                    vert: second,
+                }
+            }
+            Err(_) => SingleOrDouble::Single(first),
+        })
+    }
+}
+
+impl<'a> Parser<'a> {
+    /// Parse a string, returning its (unescaped) value.
+    pub fn string(&mut self) -> Result<String> {
+        match self.peek() {
+            Some(Token {
+                kind: TokenKind::String(value),
+                ..
+            }) => {
+                self.next_token();
+                Ok(value.clone())
+            }
+            _ => Err(self.error("expected string")),
+        }
+    }
+}
+
+/// A number, percentage or dimension (a number followed by a unit, like `10px`).
+///
+/// Percentages have a suffix of `%`, and plain numbers have an empty suffix.
+#[derive(Debug)]
+struct Number {
+    value: f64,
+    suffix: String,
+    span: Span,
+}
+
+impl Number {
+    fn empty_suffix(&self) -> Result<()> {
+        if !self.suffix.is_empty() {
+            Err(ParseError::new(
+                self.span,
+                "unexpected characters after number",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Parse for Number {
+    fn parse(s: &mut Parser) -> Result<Number> {
+        let span = s.span();
+        let (value, suffix) = match s.peek().map(|tok| &tok.kind) {
+            Some(TokenKind::Number(n)) => (n.value, String::new()),
+            Some(TokenKind::Percentage(n)) => (n.value, "%".to_string()),
+            Some(TokenKind::Dimension { value, unit }) => (value.value, unit.clone()),
+            _ => return Err(s.error("expected number")),
+        };
+        s.next_token();
+        Ok(Number {
+            value,
+            suffix,
+            span,
+        })
+    }
+}
+
+/// An identifier like `space-around`, which keeps track of the keywords it has been compared
+/// with to build a helpful error message.
+#[derive(Debug)]
+struct Ident {
+    span: Span,
+    word: Option<String>,
+    /// List of tried matches - for building error.
+    tried: TryList,
+}
+
+impl Ident {
+    /// Whether the identifier is `other` (ignoring ascii case).
+    fn try_match(&self, other: &str) -> bool {
+        match self.word.as_deref() {
+            Some(word) if word.eq_ignore_ascii_case(other) => true,
+            _ => {
+                self.tried.add_literal(other);
+                false
+            }
+        }
+    }
+
+    /// Error unless the identifier is `other`.
+    fn expect(&self, other: &str) -> Result<()> {
+        if self.try_match(other) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn add_expected(&self, ty: &str) {
+        self.tried.add(ty);
+    }
+
+    /// Panics if there were no calls to `try_match` before calling this function.
+    fn error(&self) -> ParseError {
+        self.tried.to_error(self.span)
+    }
+}
+
+impl Parse for Ident {
+    /// Like `HyphenWord`, if the next token isn't an identifier the parser will not advance, and
+    /// the word will be empty.
+    fn parse(s: &mut Parser) -> Result<Self> {
+        let span = s.span();
+        let word = match s.peek() {
+            Some(Token {
+                kind: TokenKind::Ident(word),
+                ..
+            }) => {
+                s.next_token();
+                Some(word.clone())
+            }
+            _ => None,
+        };
+        Ok(Ident {
+            span,
+            word,
+            tried: TryList::new(),
+        })
+    }
+}
+
+/// Keeps track of a list of tokens that have been tried.
+#[derive(Debug)]
+struct TryList(RefCell<BTreeSet<String>>);
+
+impl TryList {
+    fn new() -> Self {
+        TryList(RefCell::new(BTreeSet::new()))
+    }
+
+    /// Same as add, but with quotes
+    fn add_literal(&self, lit: &str) {
+        self.add(format!("`{}`", lit));
+    }
+
+    fn add(&self, ty: impl Into<String>) {
+        self.0.borrow_mut().insert(ty.into());
+    }
+
+    fn to_error(&self, span: Span) -> ParseError {
+        let tried = self.0.borrow();
+        let mut iter = tried.iter();
+        let start = iter.next().unwrap().to_owned();
+        let list = iter.fold(start, |mut acc, itm| {
+            write!(acc, ", {}", itm).unwrap();
+            acc
+        });
+        ParseError::new(span, format!("expected one of {}", list))
+    }
+}
+
+/// Parse an integer, with an optional allowed range.
+fn integer<T, R>(s: &mut Parser, range: R) -> Result<T>
+where
+    R: RangeBounds<T> + fmt::Debug,
+    T: TryFrom<i64> + fmt::Display + PartialOrd<T>,
+{
+    let span = s.span();
+    let value = match s.peek().map(|tok| &tok.kind) {
+        Some(TokenKind::Number(n)) if n.is_integer => T::try_from(n.value as i64).ok(),
+        _ => None,
+    }
+    .ok_or_else(|| s.error("expected an integer"))?;
+    s.next_token();
+    if range.contains(&value) {
+        Ok(value)
+    } else {
+        Err(ParseError::new(
+            span,
+            format!(
+                "expected a number in the range {:?}, found {}",
+                range, value
+            ),
+        ))
+    }
+}
+
+macro_rules! from_str {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl str::FromStr for $ty {
+                type Err = ParseError;
+
+                fn from_str(s: &str) -> Result<Self> {
+                    parse_str(s)
+                }
+            }
+        )*
+    };
+    (generic $($ty:ident),* $(,)?) => {
+        $(
+            impl<T: Parse> str::FromStr for $ty<T> {
+                type Err = ParseError;
+
+                fn from_str(s: &str) -> Result<Self> {
+                    parse_str(s)
+                }
+            }
+        )*
+    };
+}
+
+from_str!(
+    Stylesheet,
+    Rule,
+    QualifiedRule,
+    AtRule,
+    SelectorList,
+    Selector,
+    RelativeSelector,
+    CompoundSelector,
+    AttributeSelector,
+    PseudoClass,
+    PseudoElement,
+    Styles,
+    Style,
+    AlignContent,
+    AlignItems,
+    AlignSelf,
+    Angle,
+    AnimationDirection,
+    AnimationFillMode,
+    AnimationIterationCount,
+    AnimationName,
+    AnimationPlayState,
+    AutoLengthPercentage,
+    BackfaceVisibility,
+    Background,
+    BackgroundAttachment,
+    BackgroundBox,
+    BackgroundImage,
+    BackgroundRepeat,
+    BackgroundSize,
+    BgRepeatPart,
+    BlendMode,
+    Border,
+    BorderCollapse,
+    BorderImage,
+    BorderImageRepeat,
+    BorderImageRepeatMode,
+    BorderImageSlice,
+    BorderImageSource,
+    BorderImageWidth,
+    BorderRadius,
+    BoxShadow,
+    BoxSizing,
+    Calc,
+    CalcProduct,
+    CalcSum,
+    CalcValue,
+    Clear,
+    Color,
+    ColumnCount,
+    Cursor,
+    Direction,
+    Display,
+    DynamicColor,
+    EasingFunction,
+    FeatureTagValue,
+    Flex,
+    FlexBasis,
+    FlexDirection,
+    FlexFlow,
+    FlexWrap,
+    Float,
+    Font,
+    FontFeatureSettings,
+    FontKerning,
+    FontMetric,
+    FontShorthand,
+    FontSize,
+    FontSizeAdjust,
+    FontStretch,
+    FontStyle,
+    FontSynthesis,
+    FontVariant,
+    FontVariantAlternates,
+    FontVariantCaps,
+    FontVariantEastAsian,
+    FontVariantEmoji,
+    FontVariantLigatures,
+    FontVariantNumeric,
+    FontVariantPosition,
+    FontVariationSettings,
+    FontWeight,
+    Gradient,
+    Grid,
+    GridArea,
+    GridAutoFlow,
+    GridAutoTracks,
+    GridLine,
+    GridPlacement,
+    GridTemplate,
+    GridTemplateAreas,
+    GridTemplateTracks,
+    HangingPunctuation,
+    HangingPunctuationEnd,
+    HyphenateCharacter,
+    Hyphens,
+    Image,
+    ImageSet,
+    JustifyContent,
+    Keyframe,
+    Length,
+    LengthPercentage,
+    LineBreak,
+    LineHeight,
+    LineNames,
+    LineStyle,
+    LineWidth,
+    LinearStop,
+    ListStyleType,
+    MaxWidthHeight,
+    NumberOrLength,
+    NumberOrPercentage,
+    ObjectFit,
+    Overflow,
+    OverflowWrap,
+    OverflowXY,
+    Percentage,
+    Perspective,
+    Position,
+    PositionValue,
+    Resize,
+    Resolution,
+    Rotate,
+    RotateAxis,
+    Scale,
+    Shadow,
+    ShadowLength,
+    SingleAnimation,
+    SingleTransition,
+    Spacing,
+    StepPosition,
+    TextAlign,
+    TextCombineUpright,
+    TextDecoration,
+    TextDecorationColor,
+    TextDecorationLine,
+    TextDecorationStyle,
+    TextDecorationThickness,
+    TextIndent,
+    TextOrientation,
+    TextShadow,
+    TextTransform,
+    TextTransformCase,
+    TextUnderlinePosition,
+    TextWrap,
+    TextWrapMode,
+    TextWrapStyle,
+    Time,
+    TrackBreadth,
+    TrackList,
+    TrackSize,
+    TransformFunction,
+    TransformList,
+    TransformOrigin,
+    TransformStyle,
+    TransitionBehavior,
+    TransitionProperty,
+    Translate,
+    UnicodeBidi,
+    Url,
+    VariationAxis,
+    WhiteSpace,
+    WhiteSpaceCollapse,
+    Width21,
+    WidthHeight,
+    WordBreak,
+    WritingMode,
+);
+
+from_str!(generic NonemptyCommaList, Rect, SingleOrDouble);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check that the runtime parser gives the same result as the macro parser.
+    ///
+    /// `css` is the input for the runtime parser, and `rust` is the same input written so that
+    /// rust's tokenizer accepts it.
+    fn same_as_syn<T>(css: &str, rust: &str)
+    where
+        T: Parse + syn::parse::Parse + PartialEq + fmt::Debug,
+    {
+        let runtime = match parse_str::<T>(css) {
+            Ok(v) => v,
+            Err(e) => panic!("error parsing \"{}\": {}", css, e),
+        };
+        assert_eq!(runtime, syn::parse_str::<T>(rust).unwrap());
+    }
+
+    #[test]
+    fn styles() {
+        same_as_syn::<Styles>(
+            "padding: 0 1em; color: #0ee",
+            "padding: 0 1\"em\"; color: #\"0ee\"",
+        );
+        same_as_syn::<Styles>(
+            "dummy;
+            height: 10px;
+            display: flex;
+            justify-content: space-around;
+            font-family: \"Amatic SC\", serif;
+            font-weight: 200;",
+            "dummy;
+            height: 10px;
+            display: flex;
+            justify-content: space-around;
+            font-family: \"Amatic SC\", serif;
+            font-weight: 200;",
+        );
+        let styles: Styles = "/* comment */ display : block ;; ".parse().unwrap();
+        assert_eq!(styles.rules, vec![Style::Display(Display::Block)]);
+        // invalid declarations are dropped
+        let styles: Styles = "display: flex block; color: red; display flex; \
+            -webkit-not-a-property: flex; height: 1px !importnt; width: calc(1px }"
+            .parse()
+            .unwrap();
+        assert_eq!(styles.to_string(), "color:red;");
+        let (styles, errors) =
+            Styles::parse_with_errors("display: flx; color: rde; height: 1px; width: calc(1px")
+                .unwrap();
+        assert_eq!(styles.to_string(), "height:1px;width:calc(1px);");
+        assert_eq!(
+            errors
+                .iter()
+                .map(|err| err.span().start())
+                .collect::<Vec<_>>(),
+            vec![9, 21, 46]
+        );
+        assert_eq!(errors[2].message(), "missing closing `)`");
+        // blocks and functions are closed at the end of input
+        let styles: Styles = "color: red; grid-template-columns: repeat(2, minmax(1px, 1fr"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            styles.to_string(),
+            "color:red;grid-template-columns:repeat(2, minmax(1px, 1fr));"
+        );
+    }
+
+    #[test]
+    fn style() {
+        for (css, rust) in vec![
+            ("align-content: flex-start", "align-content: flex-start"),
+            ("flex: 1 0 1em", "flex: 1 0 1\"em\""),
+            (
+                "font: italic bold 12px/1.5 Times New Roman, ui-serif",
+                "font: italic bold 12px/1.5 \"Times New Roman\", ui-serif",
+            ),
+            ("font: status-bar", "font: status-bar"),
+            ("font-stretch: 87.5%", "font-stretch: 87.5%"),
+            (
+                "font-size-adjust: ic-width 0.6",
+                "font-size-adjust: ic-width 0.6",
+            ),
+            (
+                "font-synthesis: position style",
+                "font-synthesis: position style",
+            ),
+            (
+                "font-variant: historical-ligatures character-variant(x, y) unicode",
+                "font-variant: historical-ligatures character-variant(x, y) unicode",
+            ),
+            (
+                "font-variant-east-asian: ruby proportional-width",
+                "font-variant-east-asian: ruby proportional-width",
+            ),
+            (
+                "font-feature-settings: 'ss01' off, \"dlig\"",
+                "font-feature-settings: \"ss01\" off, \"dlig\"",
+            ),
+            (
+                "font-variation-settings: \"wdth\" 87.5",
+                "font-variation-settings: \"wdth\" 87.5",
+            ),
+            ("flex: 0 auto", "flex: 0 auto"),
+            ("flex: initial", "flex: initial"),
+            (
+                "flex-flow: column-reverse nowrap",
+                "flex-flow: column-reverse nowrap",
+            ),
+            ("align-items: first baseline", "align-items: first baseline"),
+            (
+                "background-image: url(\"a.png\")",
+                "background-image: url(\"a.png\")",
+            ),
+            (
+                "background: url(a.png) left/50% auto repeat space, #FFF",
+                "background: url(\"a.png\") left / 50% auto repeat space, #\"FFF\"",
+            ),
+            (
+                "background-image: Linear-Gradient(to left, red 10%, 30%, hsl(0, 50%, 50%))",
+                "background-image: linear-gradient(to left, red 10%, 30%, hsl(0, 50%, 50%))",
+            ),
+            (
+                "background-image: repeating-radial-gradient(closest-corner circle at 10px, red, blue)",
+                "background-image: repeating-radial-gradient(closest-corner circle at 10px, red, blue)",
+            ),
+            (
+                "background-image: conic-gradient(from 0.25turn, red 0 25%, blue 25% 50%)",
+                "background-image: conic-gradient(from 0.25turn, red 0 25%, blue 25% 50%)",
+            ),
+            (
+                "background-image: image-set('a.avif' type('image/avif'), url(b.png) 2dppx)",
+                "background-image: image-set(\"a.avif\" type(\"image/avif\"), url(\"b.png\") 2dppx)",
+            ),
+            (
+                "background-image: url('a.png' integrity('sha384-abc') crossorigin(anonymous))",
+                "background-image: url(\"a.png\" integrity(\"sha384-abc\") crossorigin(anonymous))",
+            ),
+            (
+                "background-position: Right 10px BOTTOM 20%",
+                "background-position: right 10px bottom 20%",
+            ),
+            ("background-position-y: 1em", "background-position-y: 1\"em\""),
+            ("object-position: top left", "object-position: top left"),
+            ("perspective-origin: 25%", "perspective-origin: 25%"),
+            ("background-repeat: repeat-x", "background-repeat: repeat-x"),
+            ("background-size: cover", "background-size: cover"),
+            ("border: 1px solid red", "border: 1px solid red"),
+            ("border-width: 0 2px 50pt 0", "border-width: 0 2px 50pt 0"),
+            (
+                "border-radius: 4px 50% / 10% 2px 3px",
+                "border-radius: 4px 50% / 10% 2px 3px",
+            ),
+            (
+                "border-image: url(frame.png) 30 Fill / 1em / 2 round",
+                "border-image: url(\"frame.png\") 30 fill / 1\"em\" / 2 round",
+            ),
+            (
+                "border-image: 10% 20 / / 2px 0 space",
+                "border-image: 10% 20 / / 2px 0 space",
+            ),
+            (
+                "border-image-width: auto 2 25%",
+                "border-image-width: auto 2 25%",
+            ),
+            (
+                "border-bottom-left-radius: 30% 3px",
+                "border-bottom-left-radius: 30% 3px",
+            ),
+            (
+                "box-shadow: 0 10px 5px black inset",
+                "box-shadow: 0 10px 5px black inset",
+            ),
+            ("column-count: 3", "column-count: 3"),
+            ("flex-grow: 1", "flex-grow: 1"),
+            ("flex-shrink: 0.5", "flex-shrink: 0.5"),
+            ("font-size: 1.5em", "font-size: 1.5\"em\""),
+            ("font-weight: bold", "font-weight: bold"),
+            ("line-height: 1.5", "line-height: 1.5"),
+            ("line-height: Normal", "line-height: normal"),
+            ("line-height: 1.25em", "line-height: 1.25\"em\""),
+            ("word-wrap: anywhere", "overflow-wrap: anywhere"),
+            ("hyphenate-character: '‐'", "hyphenate-character: \"‐\""),
+            ("tab-size: 8", "tab-size: 8"),
+            ("text-wrap: Stable", "text-wrap: stable"),
+            ("white-space: break-spaces", "white-space: break-spaces"),
+            ("white-space: preserve wrap", "white-space: preserve wrap"),
+            ("hanging-punctuation: first", "hanging-punctuation: first"),
+            ("direction: RTL", "direction: rtl"),
+            ("unicode-bidi: isolate-override", "unicode-bidi: isolate-override"),
+            ("writing-mode: vertical-rl", "writing-mode: vertical-rl"),
+            ("text-orientation: upright", "text-orientation: upright"),
+            ("text-combine-upright: digits 3", "text-combine-upright: digits 3"),
+            ("letter-spacing: -0.5px", "letter-spacing: -0.5px"),
+            ("text-indent: 1em Hanging", "text-indent: 1\"em\" hanging"),
+            (
+                "text-transform: capitalize full-size-kana",
+                "text-transform: capitalize full-size-kana",
+            ),
+            (
+                "text-decoration: underline line-through from-font #f00",
+                "text-decoration: underline line-through from-font #\"f00\"",
+            ),
+            (
+                "text-decoration-color: currentColor",
+                "text-decoration-color: currentcolor",
+            ),
+            ("text-underline-position: under", "text-underline-position: under"),
+            (
+                "text-shadow: 1px 2px blue, red 0 0 4px",
+                "text-shadow: 1px 2px blue, red 0 0 4px",
+            ),
+            ("margin: auto 10%", "margin: auto 10%"),
+            (
+                "max-width: fit-content(200px)",
+                "max-width: fit-content(200px)",
+            ),
+            (
+                "min-width: calc(100% - 60px)",
+                "min-width: calc(100% - 60px)",
+            ),
+            ("object-fit: scale-down", "object-fit: scale-down"),
+            ("object-fit: contain", "object-fit: contain"),
+            (
+                "background-repeat: no-repeat space",
+                "background-repeat: no-repeat space",
+            ),
+            ("background-size: auto 50%", "background-size: auto 50%"),
+            ("overflow: hidden scroll", "overflow: hidden scroll"),
+            ("width: -2.5em", "width: -2.5\"em\""),
+            ("widows: 2", "widows: 2"),
+        ] {
+            same_as_syn::<Style>(css, rust);
+        }
+        assert_eq!(
+            "\"display: grid\"".parse::<Style>().unwrap(),
+            Style::Unchecked("display: grid".into())
+        );
+        assert_eq!(
+            "background-image: url(a.png)".parse::<Style>().unwrap(),
+            Style::BackgroundImage(NonemptyCommaList {
+                first: BackgroundImage::Image(Image::Url(Url {
+                    url: "a.png".into(),
+                    modifiers: vec![],
+                })),
+                rest: vec![],
+            })
+        );
+        // a backslash at the end of input is dropped, and the open string is kept
+        assert_eq!(
+            "font-family: 'abc\\".parse::<Style>().unwrap(),
+            "font-family: 'abc'".parse::<Style>().unwrap()
+        );
+        assert!("widows: 0".parse::<Style>().is_err());
+        assert!("background: red, none".parse::<Style>().is_err());
+        assert!("border-image-slice: -10".parse::<Style>().is_err());
+        assert!("line-height: -1".parse::<Style>().is_err());
+        assert!("tab-size: -4".parse::<Style>().is_err());
+        assert!("text-combine-upright: digits 5".parse::<Style>().is_err());
+        assert!("text-shadow: 1px 1px inset".parse::<Style>().is_err());
+        assert!("text-decoration-line: none none".parse::<Style>().is_err());
+        assert!("border-radius: 4px / / 2px".parse::<Style>().is_err());
+        assert!("border-image-width: -1".parse::<Style>().is_err());
+        assert!("border-image-outset: -2px".parse::<Style>().is_err());
+        assert!("background-image: image-set(\"a.png\" -1x)"
+            .parse::<Style>()
+            .is_err());
+        assert!("flex-grow: 1px".parse::<Style>().is_err());
+        assert!("column-count: 3.0".parse::<Style>().is_err());
+        assert!("column-count: 1e1".parse::<Style>().is_err());
+    }
+
+    #[test]
+    fn case_insensitive() {
+        assert_eq!(
+            "DISPLAY: Flex".parse::<Style>().unwrap(),
+            Style::Display(Display::Flex)
+        );
+        assert_eq!("10PX".parse::<Length>().unwrap(), Length::Px(10.0));
+        assert_eq!("Red".parse::<Color>().unwrap(), Color::Red);
+    }
+
+    #[test]
+    fn stylesheet() {
+        let css = "<!-- a:hover , .b > c { color : red ; display: flex } -->
+            @import url(\"x.css\") ;
+            @media screen and (min-width:600px) { #d{ height: 10px } }
+            @font-face { font-family: x;  src: local(x) }";
+        let sheet: Stylesheet = css.parse().unwrap();
+        assert_eq!(sheet.rules.len(), 4);
+        assert_eq!(
+            sheet.to_string(),
+            "a:hover, .b > c{color:red;display:flex;}\
+             @import url(\"x.css\");\
+             @media screen and (min-width:600px){#d{height:10px;}}\
+             @font-face{font-family: x; src: local(x)}"
+        );
+        // Serializing and parsing again gives the same stylesheet.
+        assert_eq!(sheet.to_string().parse::<Stylesheet>().unwrap(), sheet);
+
+        same_as_syn::<Stylesheet>(
+            "div.a:not(.b)::before, #c[href] { padding: 0 1em; color: #0ee }
+             @media print and (min-width:600px) { p { display: block } }",
+            "div.a:not(.b)::before, #c[href] { padding: 0 1\"em\"; color: #\"0ee\" }
+             @media print and (min-width: 600px) { p { display: block } }",
+        );
+
+        for input in ["{ color: red }", "a"] {
+            assert!(input.parse::<Stylesheet>().is_err(), "input: {}", input);
+        }
+        // the `}` is part of `calc(`, so the rule is closed at the end of input.
+        let sheet: Stylesheet = "a { color: red; width: calc(1px } b { color: red }"
+            .parse()
+            .unwrap();
+        assert_eq!(sheet.to_string(), "a{color:red;}");
+        let sheet: Stylesheet = "a { color: red".parse().unwrap();
+        assert_eq!(sheet.to_string(), "a{color:red;}");
+        let sheet: Stylesheet = "a { color: rde; foo: bar } b { color: red }
+            @keyframes x { from { opacity: 0; height: 0 } }"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            sheet.to_string(),
+            "a{}b{color:red;}@keyframes x{0%{height:0;}}"
+        );
+        let (sheet, errors) =
+            Stylesheet::parse_with_errors("a { color: rde } @media print { b { foo: 1 }").unwrap();
+        assert_eq!(sheet.to_string(), "a{}@media print{b{}}");
+        assert_eq!(
+            errors
+                .iter()
+                .map(|err| err.span().start())
+                .collect::<Vec<_>>(),
+            vec![11, 30, 36]
+        );
+        assert_eq!(errors[1].message(), "missing closing `}`");
+    }
+
+    #[test]
+    fn grid() {
+        same_as_syn::<Styles>(
+            "grid-template-columns: [full-start] minmax(1em, 1fr) repeat(auto-fit, [col] 10px); \
+                grid-template-rows: subgrid repeat(2, [a]) [b]; \
+                grid-template-areas: 'head head' 'nav main'; \
+                grid-template: [top] 'a a' 40px [middle] 'b c' / 1fr 2fr; \
+                grid: auto-flow 1fr / 100px; \
+                grid-auto-rows: min-content fit-content(10%); \
+                grid-auto-flow: column; \
+                grid-row: -1 / span 2 a; \
+                grid-area: main / 1",
+            r#"grid-template-columns: [full-start] minmax(1"em", 1fr) repeat(auto-fit, [col] 10px);
+                grid-template-rows: subgrid repeat(2, [a]) [b];
+                grid-template-areas: "head head" "nav main";
+                grid-template: [top] "a a" 40px [middle] "b c" / 1fr 2fr;
+                grid: auto-flow 1fr / 100px;
+                grid-auto-rows: min-content fit-content(10%);
+                grid-auto-flow: column;
+                grid-row: -1 / span 2 a;
+                grid-area: main / 1"#,
+        );
+        let style: Style = "grid-template-areas: 'a a .' 'a a b'".parse().unwrap();
+        assert_eq!(style.to_string(), r#"grid-template-areas:"a a ." "a a b""#);
+        for (input, message) in [
+            (
+                "grid-template-areas: 'a b' 'b a'",
+                "the grid area `a` is not a rectangle",
+            ),
+            (
+                "grid-template-rows: repeat(0, 1fr)",
+                "expected a number in the range 1.., found 0",
+            ),
+            ("grid-column: span -1", "expected a positive integer"),
+            ("grid-template-columns: -1fr", "flex can't be negative"),
+            (
+                "grid-template-columns: repeat(2, minmax(0, -0.5fr))",
+                "flex can't be negative",
+            ),
+            (
+                "grid-template-columns: 1fr repeat(auto-fill, 10px)",
+                "all track sizes must be fixed when using `auto-fill` or `auto-fit`",
+            ),
+        ] {
+            assert_eq!(
+                input.parse::<Style>().unwrap_err().message(),
+                message,
+                "input: {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn transform() {
+        same_as_syn::<Styles>(
+            "transform: translate(-50%, 1.5em) rotateZ(-0.25turn) scale3d(1, 50%, 2); \
+                transform-origin: 10px bottom; \
+                transform-style: flat; \
+                perspective: none; \
+                perspective-origin: center; \
+                backface-visibility: visible; \
+                translate: -10px; \
+                rotate: 0 0 1 10grad; \
+                scale: 150% 1 2",
+            r#"transform: translate(-50%, 1.5"em") rotateZ(-0.25turn) scale3d(1, 50%, 2);
+                transform-origin: 10px bottom;
+                transform-style: flat;
+                perspective: none;
+                perspective-origin: center;
+                backface-visibility: visible;
+                translate: -10px;
+                rotate: 0 0 1 10grad;
+                scale: 150% 1 2"#,
+        );
+        same_as_syn::<TransformFunction>(
+            "matrix3d(1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1)",
+            "matrix3d(1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1)",
+        );
+        // function names aren't case sensitive in css
+        let style: Style = "transform: TRANSLATEX(1px)".parse().unwrap();
+        assert_eq!(style.to_string(), "transform:translateX(1px)");
+        for (input, message) in [
+            (
+                "transform: spin(1turn)",
+                "unknown transform function `spin`",
+            ),
+            ("perspective: -1px", "length can't be negative"),
+            (
+                "transform-origin: left left",
+                "expected a horizontal position followed by a vertical position",
+            ),
+        ] {
+            assert_eq!(
+                input.parse::<Style>().unwrap_err().message(),
+                message,
+                "input: {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn animation() {
+        same_as_syn::<Styles>(
+            "transition: opacity 200ms ease-in-out 1s, transform .5s cubic-bezier(0.1, 0.7, 1, 0.1); \
+                transition-timing-function: steps(4, start), linear(0, 0.25 75%, 1); \
+                transition-behavior: allow-discrete; \
+                animation: 2s ease 1s infinite alternate both paused spin, 1s forwards none; \
+                animation-name: none, 'spin'; \
+                animation-iteration-count: 2.5",
+            r#"transition: opacity 200ms ease-in-out 1s, transform 0.5s cubic-bezier(0.1, 0.7, 1, 0.1);
+                transition-timing-function: steps(4, start), linear(0, 0.25 75%, 1);
+                transition-behavior: allow-discrete;
+                animation: 2s ease 1s infinite alternate both paused spin, 1s forwards none;
+                animation-name: none, "spin";
+                animation-iteration-count: 2.5"#,
+        );
+        same_as_syn::<Stylesheet>(
+            "@keyframes fade { from, 50% { height: 0 } to { height: 1em } }",
+            r#"@keyframes fade { from, 50% { height: 0 } to { height: 1"em" } }"#,
+        );
+        let style: Style = "transition-duration: 1S".parse().unwrap();
+        assert_eq!(style.to_string(), "transition-duration:1s");
+        for (input, message) in [
+            (
+                "transition: opacity 1s cubic-bezier(2, 0, 0, 1)",
+                "the x values of `cubic-bezier()` must be between 0 and 1",
+            ),
+            (
+                "animation-timing-function: steps(1, jump-none)",
+                "`steps()` with `jump-none` needs at least 2 steps",
+            ),
+            (
+                "transition-timing-function: linear(0)",
+                "`linear()` needs at least two stops",
+            ),
+            ("transition-delay: 1px", "expected `s` or `ms` after number"),
+            ("transition-duration: -1s", "duration can't be negative"),
+            ("animation-duration: 1s, -2s", "duration can't be negative"),
+            ("transition: opacity -1s", "duration can't be negative"),
+            ("animation: -1ms spin", "duration can't be negative"),
+            (
+                "transition: opacity 1s, none 2s",
+                "`none` can only be used when there is a single transition",
+            ),
+        ] {
+            assert_eq!(
+                input.parse::<Style>().unwrap_err().message(),
+                message,
+                "input: {}",
+                input
+            );
+        }
+        assert!("@keyframes fade { 150% { height: 0 } }"
+            .parse::<Stylesheet>()
+            .is_err());
+    }
+
+    #[test]
+    fn important() {
+        same_as_syn::<Styles>(
+            "color: red !important; border: 1px solid ! IMPORTANT; \"x: y\" !important",
+            "color: red !important; border: 1px solid !important; \"x: y\" !important",
+        );
+        let style: Style = "display:flex!important".parse().unwrap();
+        assert!(style.is_important());
+        assert_eq!(style.name(), Some("display"));
+        assert_eq!(style.without_importance(), &Style::Display(Display::Flex));
+        assert_eq!(style.to_string(), "display:flex !important");
+        for input in [
+            "color: red !",
+            "color: red !importan",
+            "color: red important",
+        ] {
+            assert!(input.parse::<Style>().is_err(), "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn length() {
+        for (input, output) in [
+            ("0", Length::Zero),
+            ("1px", Length::Px(1.0)),
+            ("-1.5em", Length::Em(-1.5)),
+            ("+.5in", Length::In(0.5)),
+            ("1e1pt", Length::Pt(10.0)),
+            ("10\\70 x", Length::Px(10.0)),
+        ] {
+            assert_eq!(input.parse::<Length>().unwrap(), output);
+        }
+        for input in ["1", "1xx", "px", "1 px"] {
+            assert!(input.parse::<Length>().is_err());
+        }
+    }
+
+    #[test]
+    fn color() {
+        for (css, rust) in [
+            ("#ffffffff", "#ffffffff"),
+            ("#0ee", "#\"0ee\""),
+            ("hsl(100, 50%, 50%)", "hsl(100, 50%, 50%)"),
+            ("hsla(60, 0%, 0%, 0.2)", "hsla(60, 0%, 0%, 0.2)"),
+            ("yellow", "yellow"),
+        ] {
+            same_as_syn::<Color>(css, rust);
+        }
+        for input in [
+            "#ff",
+            "hsl(400, 50%, 50%)",
+            "hsl(100, 50%, 50%, 1)",
+            "notacolor",
+        ] {
+            assert!(input.parse::<Color>().is_err());
+        }
+    }
+
+    #[test]
+    fn font_family() {
+        assert_eq!(
+            "Times New Roman, serif".parse::<FontFamily>().unwrap(),
+            FontFamily {
+                first: Font::Named("Times New Roman".into()),
+                rest: vec![Font::Serif],
+            }
+        );
+        same_as_syn::<FontFamily>("'Amatic SC', monospace", "\"Amatic SC\", monospace");
+    }
+
+    #[test]
+    fn error() {
+        let err = "display: flx".parse::<Style>().unwrap_err();
+        assert_eq!(err.span().start(), 9);
+        assert_eq!(
+            err.to_string(),
+            "expected one of `block`, `flex`, `inline` at position 9"
+        );
+        assert_eq!(
+            "padding-left: calc(10px".parse::<Style>().unwrap(),
+            "padding-left: calc(10px)".parse::<Style>().unwrap()
+        );
+        // offsets are in the original input, even when preprocessing changes it.
+        let (_, errors) = Styles::parse_with_errors("width: 10px;\r\n\0: 1px").unwrap();
+        assert_eq!(errors[0].span().start(), 14);
+        assert_eq!(errors[0].span().end(), 15);
+    }
+}
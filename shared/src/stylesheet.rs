@@ -0,0 +1,195 @@
+//! Whole stylesheets: lists of rules, each with a prelude and a block.
+use crate::{Percentage, SelectorList, Styles};
+use std::fmt;
+
+/// A css stylesheet, like the contents of a `.css` file.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Stylesheet {
+    pub rules: Vec<Rule>,
+}
+
+impl Stylesheet {
+    pub fn new() -> Self {
+        Stylesheet { rules: Vec::new() }
+    }
+
+    pub fn add(&mut self, rule: impl Into<Rule>) {
+        self.rules.push(rule.into());
+    }
+
+    /// The keyframes of the last top-level `@keyframes` rule called `name`.
+    pub fn keyframes(&self, name: &str) -> Option<&[Keyframe]> {
+        self.rules.iter().rev().find_map(|rule| match rule {
+            Rule::At(AtRule {
+                prelude,
+                block: Some(AtRuleBlock::Keyframes(keyframes)),
+                ..
+            }) if prelude.trim().trim_matches(|c| c == '"' || c == '\'') == name => {
+                Some(keyframes.as_slice())
+            }
+            _ => None,
+        })
+    }
+}
+
+impl From<Vec<Rule>> for Stylesheet {
+    fn from(rules: Vec<Rule>) -> Self {
+        Stylesheet { rules }
+    }
+}
+
+impl fmt::Display for Stylesheet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for rule in self.rules.iter() {
+            write!(f, "{}", rule)?;
+        }
+        Ok(())
+    }
+}
+
+/// https://www.w3.org/TR/css-syntax-3/#css-rule
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rule {
+    /// A style rule, like `a:hover { color: red }`.
+    Qualified(QualifiedRule),
+    /// An at-rule, like `@media print { ... }`.
+    At(AtRule),
+}
+
+impl From<QualifiedRule> for Rule {
+    fn from(rule: QualifiedRule) -> Self {
+        Rule::Qualified(rule)
+    }
+}
+
+impl From<AtRule> for Rule {
+    fn from(rule: AtRule) -> Self {
+        Rule::At(rule)
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::Qualified(rule) => rule.fmt(f),
+            Rule::At(rule) => rule.fmt(f),
+        }
+    }
+}
+
+/// A selector prelude followed by a block of declarations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QualifiedRule {
+    /// The selectors that the styles apply to.
+    pub selectors: SelectorList,
+    pub styles: Styles,
+}
+
+impl fmt::Display for QualifiedRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{{{}}}", self.selectors, self.styles)
+    }
+}
+
+/// An at-rule. The prelude is kept as text. The block is parsed for conditional rules like
+/// `@media` and for `@keyframes`, and kept as text for all others.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AtRule {
+    /// The name of the rule, without the `@`.
+    pub name: String,
+    /// Everything between the name and the block (or `;`), e.g. the media query of `@media`.
+    pub prelude: String,
+    /// `None` for statement at-rules like `@import "a.css";`.
+    pub block: Option<AtRuleBlock>,
+}
+
+impl AtRule {
+    /// Whether the block of at-rule `name` contains rules (like `@media`), rather than
+    /// declarations or something else.
+    pub fn contains_rules(name: &str) -> bool {
+        matches!(
+            unprefixed(name).as_str(),
+            "media" | "supports" | "document" | "layer" | "container" | "scope" | "starting-style"
+        )
+    }
+
+    /// Whether the block of at-rule `name` is a list of keyframes.
+    pub fn contains_keyframes(name: &str) -> bool {
+        unprefixed(name) == "keyframes"
+    }
+}
+
+/// The lowercase name of an at-rule, without any vendor prefix.
+fn unprefixed(name: &str) -> String {
+    let name = name.to_ascii_lowercase();
+    match name
+        .strip_prefix("-webkit-")
+        .or_else(|| name.strip_prefix("-moz-"))
+    {
+        Some(unprefixed) => unprefixed.to_string(),
+        None => name,
+    }
+}
+
+impl fmt::Display for AtRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "@{}", self.name)?;
+        if !self.prelude.is_empty() {
+            write!(f, " {}", self.prelude)?;
+        }
+        match &self.block {
+            Some(block) => write!(f, "{{{}}}", block),
+            None => write!(f, ";"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AtRuleBlock {
+    /// Nested rules, for at-rules like `@media`.
+    Rules(Vec<Rule>),
+    /// The keyframes of `@keyframes`.
+    Keyframes(Vec<Keyframe>),
+    /// The content of the block, for all other at-rules.
+    Raw(String),
+}
+
+impl fmt::Display for AtRuleBlock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AtRuleBlock::Rules(rules) => {
+                for rule in rules.iter() {
+                    write!(f, "{}", rule)?;
+                }
+                Ok(())
+            }
+            AtRuleBlock::Keyframes(keyframes) => {
+                for keyframe in keyframes.iter() {
+                    write!(f, "{}", keyframe)?;
+                }
+                Ok(())
+            }
+            AtRuleBlock::Raw(raw) => write!(f, "{}", raw),
+        }
+    }
+}
+
+/// A rule in a `@keyframes` block, like `from, 50% { opacity: 0 }`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframe {
+    /// Where the keyframe is in the animation. `from` is `0%` and `to` is `100%`.
+    pub selectors: Vec<Percentage>,
+    pub styles: Styles,
+}
+
+impl fmt::Display for Keyframe {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, selector) in self.selectors.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", selector)?;
+        }
+        write!(f, "{{{}}}", self.styles)
+    }
+}
@@ -7,7 +7,7 @@
 // TODO Split out extra "Dynamic" layer for each type for use in proc macro (so we can have `{ <arbitary
 // rust code> }`)
 use crate::*;
-use proc_macro2::Span;
+use proc_macro2::{Delimiter, Literal, Spacing, Span, TokenStream, TokenTree};
 use std::{
     cell::RefCell,
     collections::BTreeSet,
@@ -52,7 +52,7 @@ impl Parse for Style {
     fn parse(s: ParseStream) -> syn::Result<Self> {
         if s.peek(syn::LitStr) {
             let unchecked: syn::LitStr = s.parse()?;
-            return Ok(Style::Unchecked(unchecked.value()));
+            return importance(s, Style::Unchecked(unchecked.value()));
         }
 
         let name: HyphenWord = s.parse()?;
@@ -69,7 +69,28 @@ impl Parse for Style {
         } else if name.try_match("align-self") {
             Style::AlignSelf(s.parse()?)
         // all
-        // background
+        } else if name.try_match("animation") {
+            Style::Animation(s.parse()?)
+        } else if name.try_match("animation-delay") {
+            Style::AnimationDelay(s.parse()?)
+        } else if name.try_match("animation-direction") {
+            Style::AnimationDirection(s.parse()?)
+        } else if name.try_match("animation-duration") {
+            Style::AnimationDuration(durations(s)?)
+        } else if name.try_match("animation-fill-mode") {
+            Style::AnimationFillMode(s.parse()?)
+        } else if name.try_match("animation-iteration-count") {
+            Style::AnimationIterationCount(s.parse()?)
+        } else if name.try_match("animation-name") {
+            Style::AnimationName(s.parse()?)
+        } else if name.try_match("animation-play-state") {
+            Style::AnimationPlayState(s.parse()?)
+        } else if name.try_match("animation-timing-function") {
+            Style::AnimationTimingFunction(s.parse()?)
+        } else if name.try_match("backface-visibility") {
+            Style::BackfaceVisibility(s.parse()?)
+        } else if name.try_match("background") {
+            Style::Background(s.parse()?)
         } else if name.try_match("background-attachment") {
             Style::BackgroundAttachment(s.parse()?)
         } else if name.try_match("background-blend-mode") {
@@ -84,6 +105,10 @@ impl Parse for Style {
             Style::BackgroundOrigin(s.parse()?)
         } else if name.try_match("background-position") {
             Style::BackgroundPosition(s.parse()?)
+        } else if name.try_match("background-position-x") {
+            Style::BackgroundPositionX(s.parse()?)
+        } else if name.try_match("background-position-y") {
+            Style::BackgroundPositionY(s.parse()?)
         } else if name.try_match("background-repeat") {
             Style::BackgroundRepeat(s.parse()?)
         } else if name.try_match("background-size") {
@@ -106,12 +131,18 @@ impl Parse for Style {
             Style::BorderCollapse(s.parse()?)
         } else if name.try_match("border-color") {
             Style::BorderColor(s.parse()?)
-        // border-image
-        // border-image-outset
-        // border-image-repeat
-        // border-image-slice
-        // border-image-source
-        // border-image-width
+        } else if name.try_match("border-image") {
+            Style::BorderImage(s.parse()?)
+        } else if name.try_match("border-image-outset") {
+            Style::BorderImageOutset(s.parse()?)
+        } else if name.try_match("border-image-repeat") {
+            Style::BorderImageRepeat(s.parse()?)
+        } else if name.try_match("border-image-slice") {
+            Style::BorderImageSlice(s.parse()?)
+        } else if name.try_match("border-image-source") {
+            Style::BorderImageSource(s.parse()?)
+        } else if name.try_match("border-image-width") {
+            Style::BorderImageWidth(s.parse()?)
         } else if name.try_match("border-left") {
             Style::BorderLeft(s.parse()?)
         } else if name.try_match("border-left-color") {
@@ -177,17 +208,20 @@ impl Parse for Style {
         // cue-before
         } else if name.try_match("cursor") {
             Style::Cursor(s.parse()?)
-        // direction
+        } else if name.try_match("direction") {
+            Style::Direction(s.parse()?)
         } else if name.try_match("display") {
             Style::Display(s.parse()?)
         // elevation
         // empty-cells
-        // flex
+        } else if name.try_match("flex") {
+            Style::Flex(s.parse()?)
         } else if name.try_match("flex-basis") {
             Style::FlexBasis(s.parse()?)
         } else if name.try_match("flex-direction") {
             Style::FlexDirection(s.parse()?)
-        // flex-flow
+        } else if name.try_match("flex-flow") {
+            Style::FlexFlow(s.parse()?)
         } else if name.try_match("flex-grow") {
             let number: Number = s.parse()?;
             if !number.suffix.is_empty() {
@@ -204,44 +238,83 @@ impl Parse for Style {
             Style::FlexWrap(s.parse()?)
         } else if name.try_match("float") {
             Style::Float(s.parse()?)
-        // font
+        } else if name.try_match("font") {
+            Style::Font(s.parse()?)
         } else if name.try_match("font-family") {
             Style::FontFamily(s.parse()?)
-        // font-feature-settings
-        // font-kerning
+        } else if name.try_match("font-feature-settings") {
+            Style::FontFeatureSettings(s.parse()?)
+        } else if name.try_match("font-kerning") {
+            Style::FontKerning(s.parse()?)
         } else if name.try_match("font-size") {
             Style::FontSize(s.parse()?)
-        // font-size-adjust
-        // font-stretch
+        } else if name.try_match("font-size-adjust") {
+            Style::FontSizeAdjust(s.parse()?)
+        } else if name.try_match("font-stretch") {
+            Style::FontStretch(s.parse()?)
         } else if name.try_match("font-style") {
             Style::FontStyle(s.parse()?)
-        // font-synthesis
-        // font-variant
-        // font-variant-caps
-        // font-variant-east-asian
-        // font-variant-ligatures
-        // font-variant-numeric
-        // font-variant-position
+        } else if name.try_match("font-synthesis") {
+            Style::FontSynthesis(s.parse()?)
+        } else if name.try_match("font-variant") {
+            Style::FontVariant(s.parse()?)
+        } else if name.try_match("font-variant-alternates") {
+            Style::FontVariantAlternates(s.parse()?)
+        } else if name.try_match("font-variant-caps") {
+            Style::FontVariantCaps(s.parse()?)
+        } else if name.try_match("font-variant-east-asian") {
+            Style::FontVariantEastAsian(s.parse()?)
+        } else if name.try_match("font-variant-emoji") {
+            Style::FontVariantEmoji(s.parse()?)
+        } else if name.try_match("font-variant-ligatures") {
+            Style::FontVariantLigatures(s.parse()?)
+        } else if name.try_match("font-variant-numeric") {
+            Style::FontVariantNumeric(s.parse()?)
+        } else if name.try_match("font-variant-position") {
+            Style::FontVariantPosition(s.parse()?)
+        } else if name.try_match("font-variation-settings") {
+            Style::FontVariationSettings(s.parse()?)
         } else if name.try_match("font-weight") {
             Style::FontWeight(s.parse()?)
         // glyph-orientation-vertical
-        // grid
-        // grid-area
-        // grid-auto-columns
-        // grid-auto-flow
-        // grid-auto-rows
-        // grid-column
-        // grid-column-end
-        // grid-column-start
-        // grid-row
-        // grid-row-end
-        // grid-row-start
-        // grid-template
-        // grid-template-areas
-        // grid-template-columns
-        // grid-template-rows
+        } else if name.try_match("grid") {
+            Style::Grid(s.parse()?)
+        } else if name.try_match("grid-area") {
+            Style::GridArea(s.parse()?)
+        } else if name.try_match("grid-auto-columns") {
+            Style::GridAutoColumns(s.parse()?)
+        } else if name.try_match("grid-auto-flow") {
+            Style::GridAutoFlow(s.parse()?)
+        } else if name.try_match("grid-auto-rows") {
+            Style::GridAutoRows(s.parse()?)
+        } else if name.try_match("grid-column") {
+            Style::GridColumn(s.parse()?)
+        } else if name.try_match("grid-column-end") {
+            Style::GridColumnEnd(s.parse()?)
+        } else if name.try_match("grid-column-start") {
+            Style::GridColumnStart(s.parse()?)
+        } else if name.try_match("grid-row") {
+            Style::GridRow(s.parse()?)
+        } else if name.try_match("grid-row-end") {
+            Style::GridRowEnd(s.parse()?)
+        } else if name.try_match("grid-row-start") {
+            Style::GridRowStart(s.parse()?)
+        } else if name.try_match("grid-template") {
+            Style::GridTemplate(s.parse()?)
+        } else if name.try_match("grid-template-areas") {
+            Style::GridTemplateAreas(s.parse()?)
+        } else if name.try_match("grid-template-columns") {
+            Style::GridTemplateColumns(s.parse()?)
+        } else if name.try_match("grid-template-rows") {
+            Style::GridTemplateRows(s.parse()?)
+        } else if name.try_match("hanging-punctuation") {
+            Style::HangingPunctuation(s.parse()?)
         } else if name.try_match("height") {
             Style::Height(s.parse()?)
+        } else if name.try_match("hyphenate-character") {
+            Style::HyphenateCharacter(s.parse()?)
+        } else if name.try_match("hyphens") {
+            Style::Hyphens(s.parse()?)
         // image-orientation
         // image-rendering
         // isolation
@@ -249,7 +322,10 @@ impl Parse for Style {
             Style::JustifyContent(s.parse()?)
         } else if name.try_match("left") {
             Style::Left(s.parse()?)
-        // letter-spacing
+        } else if name.try_match("letter-spacing") {
+            Style::LetterSpacing(s.parse()?)
+        } else if name.try_match("line-break") {
+            Style::LineBreak(s.parse()?)
         } else if name.try_match("line-height") {
             Style::LineHeight(s.parse()?)
         // list-style
@@ -280,7 +356,8 @@ impl Parse for Style {
         // mask-image
         // mask-mode
         // mask-origin
-        // mask-position
+        } else if name.try_match("mask-position") {
+            Style::MaskPosition(s.parse()?)
         // mask-repeat
         // mask-size
         // mask-type
@@ -295,7 +372,8 @@ impl Parse for Style {
         // mix-blend-mode
         } else if name.try_match("object-fit") {
             Style::ObjectFit(s.parse()?)
-        // object-position
+        } else if name.try_match("object-position") {
+            Style::ObjectPosition(s.parse()?)
         // opacity
         // order
         // orphans
@@ -306,6 +384,8 @@ impl Parse for Style {
         // outline-width
         } else if name.try_match("overflow") {
             Style::Overflow(s.parse()?)
+        } else if name.try_match("overflow-wrap") {
+            Style::OverflowWrap(s.parse()?)
         } else if name.try_match("overflow-x") {
             Style::OverflowX(s.parse()?)
         } else if name.try_match("overflow-y") {
@@ -326,6 +406,10 @@ impl Parse for Style {
         // pause
         // pause-after
         // pause-before
+        } else if name.try_match("perspective") {
+            Style::Perspective(s.parse()?)
+        } else if name.try_match("perspective-origin") {
+            Style::PerspectiveOrigin(s.parse()?)
         // pitch
         // pitch-range
         // play-during
@@ -337,6 +421,10 @@ impl Parse for Style {
         // richness
         } else if name.try_match("right") {
             Style::Right(s.parse()?)
+        } else if name.try_match("rotate") {
+            Style::Rotate(s.parse()?)
+        } else if name.try_match("scale") {
+            Style::Scale(s.parse()?)
         // scroll-margin
         // scroll-margin-block
         // scroll-margin-block-end
@@ -371,43 +459,93 @@ impl Parse for Style {
         // speak-punctuation
         // speech-rate
         // stress
+        } else if name.try_match("tab-size") {
+            Style::TabSize(s.parse()?)
         // table-layout
         } else if name.try_match("text-align") {
             Style::TextAlign(s.parse()?)
-        // text-combine-upright
-        // text-decoration
-        // text-decoration-color
-        // text-decoration-line
-        // text-decoration-style
+        } else if name.try_match("text-combine-upright") {
+            Style::TextCombineUpright(s.parse()?)
+        } else if name.try_match("text-decoration") {
+            Style::TextDecoration(s.parse()?)
+        } else if name.try_match("text-decoration-color") {
+            Style::TextDecorationColor(s.parse()?)
+        } else if name.try_match("text-decoration-line") {
+            Style::TextDecorationLine(s.parse()?)
+        } else if name.try_match("text-decoration-style") {
+            Style::TextDecorationStyle(s.parse()?)
+        } else if name.try_match("text-decoration-thickness") {
+            Style::TextDecorationThickness(s.parse()?)
         // text-emphasis
         // text-emphasis-color
         // text-emphasis-position
         // text-emphasis-style
-        // text-indent
-        // text-orientation
+        } else if name.try_match("text-indent") {
+            Style::TextIndent(s.parse()?)
+        } else if name.try_match("text-orientation") {
+            Style::TextOrientation(s.parse()?)
         // text-overflow
-        // text-shadow
-        // text-transform
-        // text-underline-position
+        } else if name.try_match("text-shadow") {
+            Style::TextShadow(s.parse()?)
+        } else if name.try_match("text-transform") {
+            Style::TextTransform(s.parse()?)
+        } else if name.try_match("text-underline-offset") {
+            Style::TextUnderlineOffset(s.parse()?)
+        } else if name.try_match("text-underline-position") {
+            Style::TextUnderlinePosition(s.parse()?)
+        } else if name.try_match("text-wrap") {
+            Style::TextWrap(s.parse()?)
+        } else if name.try_match("text-wrap-mode") {
+            Style::TextWrapMode(s.parse()?)
+        } else if name.try_match("text-wrap-style") {
+            Style::TextWrapStyle(s.parse()?)
         } else if name.try_match("top") {
             Style::Top(s.parse()?)
-        // transform
+        } else if name.try_match("transform") {
+            Style::Transform(s.parse()?)
         // transform-box
-        // transform-origin
-        // unicode-bidi
+        } else if name.try_match("transform-origin") {
+            Style::TransformOrigin(s.parse()?)
+        } else if name.try_match("transform-style") {
+            Style::TransformStyle(s.parse()?)
+        } else if name.try_match("transition") {
+            Style::Transition(transitions(s)?)
+        } else if name.try_match("transition-behavior") {
+            Style::TransitionBehavior(s.parse()?)
+        } else if name.try_match("transition-delay") {
+            Style::TransitionDelay(s.parse()?)
+        } else if name.try_match("transition-duration") {
+            Style::TransitionDuration(durations(s)?)
+        } else if name.try_match("transition-property") {
+            Style::TransitionProperty(s.parse()?)
+        } else if name.try_match("transition-timing-function") {
+            Style::TransitionTimingFunction(s.parse()?)
+        } else if name.try_match("translate") {
+            Style::Translate(s.parse()?)
+        } else if name.try_match("unicode-bidi") {
+            Style::UnicodeBidi(s.parse()?)
         // vertical-align
         // visibility
         // voice-family
         // volume
         } else if name.try_match("white-space") {
             Style::WhiteSpace(s.parse()?)
+        } else if name.try_match("white-space-collapse") {
+            Style::WhiteSpaceCollapse(s.parse()?)
         } else if name.try_match("widows") {
             Style::Widows(integer(s, 1..)?)
         } else if name.try_match("width") {
             Style::Width(s.parse()?)
         // will-change
-        // word-spacing
-        // writing-mode
+        } else if name.try_match("word-break") {
+            Style::WordBreak(s.parse()?)
+        } else if name.try_match("word-wrap") {
+            // legacy name for `overflow-wrap`
+            Style::OverflowWrap(s.parse()?)
+        } else if name.try_match("word-spacing") {
+            Style::WordSpacing(s.parse()?)
+        } else if name.try_match("writing-mode") {
+            Style::WritingMode(s.parse()?)
         // z-index
         } else {
             return Err(name.error());
@@ -417,7 +555,21 @@ impl Parse for Style {
             return Err(s.error("unexpected trailing tokens in style rule"));
         }
 
-        Ok(output)
+        importance(s, output)
+    }
+}
+
+/// Parse the `!important` flag, if present, after the value of a declaration.
+fn importance(s: ParseStream, style: Style) -> syn::Result<Style> {
+    if !s.peek(Token![!]) {
+        return Ok(style);
+    }
+    s.parse::<Token![!]>()?;
+    let word: HyphenWord = s.parse()?;
+    if word.try_match("important") {
+        Ok(style.important())
+    } else {
+        Err(word.error())
     }
 }
 
@@ -634,20 +786,11 @@ impl Parse for BlendMode {
 
 impl Parse for BackgroundImage {
     fn parse(s: ParseStream) -> syn::Result<Self> {
-        let peek = HyphenWord::peek_specific(s);
-        if peek.as_ref().map(|s| s.as_str()) == Some("url") {
-            let url;
-            syn::parenthesized!(url in s);
-            let url = url.parse::<syn::LitStr>()?;
-            Ok(BackgroundImage::Url(url.value()))
+        if HyphenWord::peek_specific(s).as_deref() == Some("none") {
+            s.parse::<HyphenWord>()?;
+            Ok(BackgroundImage::None)
         } else {
-            let word: HyphenWord = s.parse()?;
-            word.add_expected("url");
-            if word.try_match("none") {
-                Ok(BackgroundImage::None)
-            } else {
-                Err(word.error())
-            }
+            s.parse().map(BackgroundImage::Image)
         }
     }
 }
@@ -667,31 +810,15 @@ impl Parse for BackgroundBox {
     }
 }
 
-impl Parse for BackgroundPosition {
-    fn parse(s: ParseStream) -> syn::Result<Self> {
-        let word: HyphenWord = s.parse()?;
-        if word.try_match("top") {
-            Ok(BackgroundPosition::Top)
-        } else if word.try_match("bottom") {
-            Ok(BackgroundPosition::Bottom)
-        } else if word.try_match("left") {
-            Ok(BackgroundPosition::Left)
-        } else if word.try_match("right") {
-            Ok(BackgroundPosition::Right)
-        } else if word.try_match("center") {
-            Ok(BackgroundPosition::Center)
-        } else {
-            Err(word.error())
-        }
-    }
-}
-
 impl Parse for BackgroundRepeat {
     fn parse(s: ParseStream) -> syn::Result<Self> {
-        let word: HyphenWord = s.parse()?;
+        let fork = s.fork();
+        let word: HyphenWord = fork.parse()?;
         if word.try_match("repeat-x") {
+            s.advance_to(&fork);
             Ok(BackgroundRepeat::RepeatX)
         } else if word.try_match("repeat-y") {
+            s.advance_to(&fork);
             Ok(BackgroundRepeat::RepeatY)
         } else if let Ok(v) = s.parse() {
             Ok(BackgroundRepeat::SingleOrDouble(v))
@@ -724,10 +851,13 @@ impl Parse for BgRepeatPart {
 
 impl Parse for BackgroundSize {
     fn parse(s: ParseStream) -> syn::Result<Self> {
-        let word: HyphenWord = s.parse()?;
+        let fork = s.fork();
+        let word: HyphenWord = fork.parse()?;
         if word.try_match("cover") {
+            s.advance_to(&fork);
             Ok(BackgroundSize::Cover)
         } else if word.try_match("contain") {
+            s.advance_to(&fork);
             Ok(BackgroundSize::Contain)
         } else if let Ok(v) = s.parse() {
             Ok(BackgroundSize::SingleOrDouble(v))
@@ -755,40 +885,31 @@ impl Parse for Border {
         while !(border.is_full() || finished_rule(s)) {
             let mut matched_something = false; // prevents an infinite loop when no matches
             let width_fork = s.fork();
-            match width_fork.parse::<LineWidth>() {
-                Ok(line_width) => {
-                    if border.has_line_width() {
-                        return Err(line_width_error(width_fork.cursor().span()));
-                    }
-                    matched_something = true;
-                    border.line_width = Some(line_width);
-                    s.advance_to(&width_fork);
+            if let Ok(line_width) = width_fork.parse::<LineWidth>() {
+                if border.has_line_width() {
+                    return Err(line_width_error(width_fork.cursor().span()));
                 }
-                Err(_) => (),
+                matched_something = true;
+                border.line_width = Some(line_width);
+                s.advance_to(&width_fork);
             }
             let style_fork = s.fork();
-            match style_fork.parse::<LineStyle>() {
-                Ok(line_style) => {
-                    if border.has_line_style() {
-                        return Err(line_style_error(style_fork.cursor().span()));
-                    }
-                    matched_something = true;
-                    border.line_style = Some(line_style);
-                    s.advance_to(&style_fork);
+            if let Ok(line_style) = style_fork.parse::<LineStyle>() {
+                if border.has_line_style() {
+                    return Err(line_style_error(style_fork.cursor().span()));
                 }
-                Err(_) => (),
+                matched_something = true;
+                border.line_style = Some(line_style);
+                s.advance_to(&style_fork);
             }
             let color_fork = s.fork();
-            match color_fork.parse::<Color>() {
-                Ok(color) => {
-                    if border.has_color() {
-                        return Err(color_error(color_fork.cursor().span()));
-                    }
-                    matched_something = true;
-                    border.color = Some(color);
-                    s.advance_to(&color_fork);
+            if let Ok(color) = color_fork.parse::<Color>() {
+                if border.has_color() {
+                    return Err(color_error(color_fork.cursor().span()));
                 }
-                Err(_) => (),
+                matched_something = true;
+                border.color = Some(color);
+                s.advance_to(&color_fork);
             }
             if !(matched_something || finished_rule(s)) {
                 return Err(syn::Error::new(
@@ -1068,8 +1189,12 @@ impl Parse for FlexDirection {
         let word: HyphenWord = s.parse()?;
         if word.try_match("column") {
             Ok(FlexDirection::Column)
+        } else if word.try_match("column-reverse") {
+            Ok(FlexDirection::ColumnReverse)
         } else if word.try_match("row") {
             Ok(FlexDirection::Row)
+        } else if word.try_match("row-reverse") {
+            Ok(FlexDirection::RowReverse)
         } else {
             Err(word.error())
         }
@@ -1083,12 +1208,103 @@ impl Parse for FlexWrap {
             Ok(FlexWrap::Wrap)
         } else if word.try_match("nowrap") {
             Ok(FlexWrap::Nowrap)
+        } else if word.try_match("wrap-reverse") {
+            Ok(FlexWrap::WrapReverse)
         } else {
             Err(word.error())
         }
     }
 }
 
+impl Parse for FlexFlow {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let mut flow = FlexFlow {
+            direction: None,
+            wrap: None,
+        };
+        while !finished_rule(s) {
+            if flow.direction.is_none() {
+                if let Some(direction) = try_parse(s) {
+                    flow.direction = Some(direction);
+                    continue;
+                }
+            }
+            if flow.wrap.is_none() {
+                if let Some(wrap) = try_parse(s) {
+                    flow.wrap = Some(wrap);
+                    continue;
+                }
+            }
+            return Err(s.error("expected flex direction or flex wrap"));
+        }
+        if flow.direction.is_none() && flow.wrap.is_none() {
+            return Err(s.error("expected flex direction or flex wrap"));
+        }
+        Ok(flow)
+    }
+}
+
+impl Parse for Flex {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let word: HyphenWord = fork.parse()?;
+        for (keyword, flex) in [
+            ("none", Flex::None),
+            ("auto", Flex::Auto),
+            ("initial", Flex::Initial),
+        ] {
+            if word.try_match(keyword) && finished_rule(&fork) {
+                s.advance_to(&fork);
+                return Ok(flex);
+            }
+        }
+
+        let mut factors: Option<(f64, Option<f64>)> = None;
+        let mut basis = None;
+        while !finished_rule(s) {
+            // A unitless number is a flex factor, even `0`. The shrink factor must follow the
+            // grow factor directly.
+            if factors.is_none() {
+                if let Some(grow) = flex_factor(s)? {
+                    factors = Some((grow, flex_factor(s)?));
+                    continue;
+                }
+            }
+            if basis.is_none() {
+                basis = Some(s.parse()?);
+                continue;
+            }
+            return Err(s.error("unexpected value in flex"));
+        }
+        if factors.is_none() && basis.is_none() {
+            return Err(s.error("expected flex"));
+        }
+        let (grow, shrink) = factors.unwrap_or((1.0, None));
+        Ok(Flex::Values {
+            grow,
+            shrink: shrink.unwrap_or(1.0),
+            basis: basis.unwrap_or(FlexBasis::Width(Width21::LengthPercentage(Calc::Normal(
+                LengthPercentage::Length(Length::Zero),
+            )))),
+        })
+    }
+}
+
+/// A non-negative unitless number, if there is one.
+fn flex_factor(s: ParseStream) -> syn::Result<Option<f64>> {
+    let fork = s.fork();
+    match fork.parse::<Number>() {
+        Ok(n) if n.suffix.is_empty() => {
+            s.advance_to(&fork);
+            if n.value < 0.0 {
+                return Err(syn::Error::new(n.span, "flex factors can't be negative"));
+            }
+            Ok(Some(n.value))
+        }
+        _ => Ok(None),
+    }
+}
+
 impl Parse for Float {
     fn parse(s: ParseStream) -> syn::Result<Self> {
         let word: HyphenWord = s.parse()?;
@@ -1125,7 +1341,23 @@ impl Parse for Font {
             } else if name.try_match("fantasy") {
                 Ok(Font::Fantasy)
             } else if name.try_match("monospace") {
-                Ok(Font::Fantasy)
+                Ok(Font::Monospace)
+            } else if name.try_match("system-ui") {
+                Ok(Font::SystemUi)
+            } else if name.try_match("emoji") {
+                Ok(Font::Emoji)
+            } else if name.try_match("math") {
+                Ok(Font::Math)
+            } else if name.try_match("fangsong") {
+                Ok(Font::Fangsong)
+            } else if name.try_match("ui-serif") {
+                Ok(Font::UiSerif)
+            } else if name.try_match("ui-sans-serif") {
+                Ok(Font::UiSansSerif)
+            } else if name.try_match("ui-monospace") {
+                Ok(Font::UiMonospace)
+            } else if name.try_match("ui-rounded") {
+                Ok(Font::UiRounded)
             } else {
                 Err(name.error())
             }
@@ -1281,6 +1513,459 @@ fn test_font_weight() {
     }
 }
 
+impl Parse for Grid {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if let Some((dense, auto_rows)) = auto_flow(s)? {
+            s.parse::<Token![/]>()?;
+            return Ok(Grid::AutoRows {
+                dense,
+                auto_rows,
+                columns: s.parse()?,
+            });
+        }
+        if peek_area_row(s)
+            || (HyphenWord::peek_specific(s).as_deref() == Some("none") && {
+                let fork = s.fork();
+                fork.parse::<HyphenWord>()?;
+                finished_rule(&fork)
+            })
+        {
+            return Ok(Grid::Template(s.parse()?));
+        }
+        let rows = s.parse()?;
+        s.parse::<Token![/]>()?;
+        Ok(match auto_flow(s)? {
+            Some((dense, auto_columns)) => Grid::AutoColumns {
+                rows,
+                dense,
+                auto_columns,
+            },
+            None => Grid::Template(GridTemplate::RowsColumns(rows, s.parse()?)),
+        })
+    }
+}
+
+/// Parse `auto-flow && dense?` followed by optional track sizes, if it is next.
+fn auto_flow(s: ParseStream) -> syn::Result<Option<(bool, Option<GridAutoTracks>)>> {
+    let fork = s.fork();
+    let first: HyphenWord = fork.parse()?;
+    let dense = if first.try_match("auto-flow") {
+        s.advance_to(&fork);
+        let fork = s.fork();
+        let dense = fork.parse::<HyphenWord>()?.try_match("dense");
+        if dense {
+            s.advance_to(&fork);
+        }
+        dense
+    } else if first.try_match("dense") {
+        s.advance_to(&fork);
+        if !s.parse::<HyphenWord>()?.try_match("auto-flow") {
+            return Err(s.error("expected `auto-flow`"));
+        }
+        true
+    } else {
+        return Ok(None);
+    };
+    let fork = s.fork();
+    let tracks = match fork.parse::<GridAutoTracks>() {
+        Ok(tracks) => {
+            s.advance_to(&fork);
+            Some(tracks)
+        }
+        Err(_) => None,
+    };
+    Ok(Some((dense, tracks)))
+}
+
+impl Parse for GridArea {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let row_start = s.parse()?;
+        let mut lines = Vec::new();
+        while lines.len() < 3 && s.peek(Token![/]) {
+            s.parse::<Token![/]>()?;
+            lines.push(s.parse()?);
+        }
+        let mut lines = lines.into_iter();
+        Ok(GridArea {
+            row_start,
+            column_start: lines.next(),
+            row_end: lines.next(),
+            column_end: lines.next(),
+        })
+    }
+}
+
+impl Parse for GridAutoFlow {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let mut flow = GridAutoFlow::default();
+        let (mut direction, mut dense) = (false, false);
+        loop {
+            let fork = s.fork();
+            let name: HyphenWord = fork.parse()?;
+            if !direction && name.try_match("row") {
+                direction = true;
+            } else if !direction && name.try_match("column") {
+                direction = true;
+                flow.column = true;
+            } else if !dense && name.try_match("dense") {
+                dense = true;
+                flow.dense = true;
+            } else if direction || dense {
+                return Ok(flow);
+            } else {
+                return Err(name.error());
+            }
+            s.advance_to(&fork);
+        }
+    }
+}
+
+impl Parse for GridAutoTracks {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let mut sizes = vec![s.parse()?];
+        loop {
+            let fork = s.fork();
+            match fork.parse() {
+                Ok(size) => {
+                    s.advance_to(&fork);
+                    sizes.push(size);
+                }
+                Err(_) => return Ok(GridAutoTracks(sizes)),
+            }
+        }
+    }
+}
+
+impl Parse for GridLine {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        if name.try_match("auto") {
+            s.advance_to(&fork);
+            return Ok(GridLine::Auto);
+        }
+        let span = name.try_match("span");
+        if span {
+            s.advance_to(&fork);
+        }
+        let mut number = None;
+        let mut ident = None;
+        for _ in 0..2 {
+            if number.is_none() && (s.peek(syn::LitInt) || s.peek(Token![-])) {
+                number = Some((s.cursor().span(), signed_integer(s)?));
+            } else if ident.is_none() && HyphenWord::peek(s) {
+                ident = Some(grid_line_name(s)?);
+            }
+        }
+        match (span, number, ident) {
+            (_, None, None) => {
+                name.add_expected("integer");
+                name.add_expected("line name");
+                Err(name.error())
+            }
+            (true, Some((span, n)), _) if n < 1 => {
+                Err(syn::Error::new(span, "expected a positive integer"))
+            }
+            (true, number, ident) => Ok(GridLine::Span(
+                number.map(|(_, n)| n as u32).unwrap_or(1),
+                ident,
+            )),
+            (false, Some((span, 0)), _) => Err(syn::Error::new(span, "grid lines can't be 0")),
+            (false, Some((_, n)), ident) => Ok(GridLine::Line(n, ident)),
+            (false, None, Some(ident)) => Ok(GridLine::Name(ident)),
+        }
+    }
+}
+
+/// A custom identifier naming a grid line or area.
+fn grid_line_name(s: ParseStream) -> syn::Result<String> {
+    let name: HyphenWord = s.parse()?;
+    match name.word {
+        Some(word) if !matches!(word.as_str(), "auto" | "span") => Ok(word),
+        _ => Err(syn::Error::new(name.span, "expected line name")),
+    }
+}
+
+/// An integer that may have a leading `-`.
+fn signed_integer(s: ParseStream) -> syn::Result<i32> {
+    if s.peek(Token![-]) {
+        s.parse::<Token![-]>()?;
+        Ok(-integer::<i32, _>(s, 0..)?)
+    } else {
+        integer(s, ..)
+    }
+}
+
+impl Parse for GridPlacement {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let start = s.parse()?;
+        let end = if s.peek(Token![/]) {
+            s.parse::<Token![/]>()?;
+            Some(s.parse()?)
+        } else {
+            None
+        };
+        Ok(GridPlacement { start, end })
+    }
+}
+
+impl Parse for GridTemplate {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if !peek_area_row(s) {
+            let fork = s.fork();
+            if fork.parse::<HyphenWord>()?.try_match("none") && finished_rule(&fork) {
+                s.advance_to(&fork);
+                return Ok(GridTemplate::None);
+            }
+            let rows = s.parse()?;
+            s.parse::<Token![/]>()?;
+            return Ok(GridTemplate::RowsColumns(rows, s.parse()?));
+        }
+
+        let span = s.cursor().span();
+        let mut strings = Vec::new();
+        let mut rows = Vec::new();
+        while peek_area_row(s) {
+            let names_before = optional_line_names(s)?;
+            strings.push(s.parse::<syn::LitStr>()?.value());
+            let fork = s.fork();
+            let size = match fork.parse() {
+                Ok(size) => {
+                    s.advance_to(&fork);
+                    Some(size)
+                }
+                Err(_) => None,
+            };
+            rows.push(GridTemplateRow {
+                names_before,
+                size,
+                names_after: optional_line_names(s)?,
+            });
+        }
+        let areas = GridAreas::new(strings).map_err(|e| syn::Error::new(span, e))?;
+        let columns = if s.peek(Token![/]) {
+            s.parse::<Token![/]>()?;
+            let span = s.cursor().span();
+            let columns: TrackList = s.parse()?;
+            if columns.has_repeat() {
+                return Err(syn::Error::new(
+                    span,
+                    "`repeat()` can't be used with grid areas",
+                ));
+            }
+            Some(columns)
+        } else {
+            None
+        };
+        Ok(GridTemplate::Areas {
+            areas,
+            rows,
+            columns,
+        })
+    }
+}
+
+/// Whether the next tokens start a row of `grid-template` areas (a string, optionally after line
+/// names).
+fn peek_area_row(s: ParseStream) -> bool {
+    s.peek(syn::LitStr) || (s.peek(syn::token::Bracket) && s.peek2(syn::LitStr))
+}
+
+impl Parse for GridTemplateAreas {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if !s.peek(syn::LitStr) {
+            let name: HyphenWord = s.parse()?;
+            name.add_expected("string");
+            return if name.try_match("none") {
+                Ok(GridTemplateAreas::None)
+            } else {
+                Err(name.error())
+            };
+        }
+        let span = s.cursor().span();
+        let mut rows = Vec::new();
+        while s.peek(syn::LitStr) {
+            rows.push(s.parse::<syn::LitStr>()?.value());
+        }
+        GridAreas::new(rows)
+            .map(GridTemplateAreas::Areas)
+            .map_err(|e| syn::Error::new(span, e))
+    }
+}
+
+impl Parse for GridTemplateTracks {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        if name.try_match("none") {
+            s.advance_to(&fork);
+            Ok(GridTemplateTracks::None)
+        } else if name.try_match("subgrid") {
+            s.advance_to(&fork);
+            let mut names = Vec::new();
+            loop {
+                if s.peek(syn::token::Bracket) {
+                    names.push(SubgridLineNames::Names(s.parse()?));
+                } else if HyphenWord::peek_specific(s).as_deref() == Some("repeat") {
+                    s.parse::<HyphenWord>()?;
+                    let content;
+                    syn::parenthesized!(content in s);
+                    let count = repeat_count(&content)?;
+                    if count == RepeatCount::AutoFit {
+                        return Err(content.error("expected integer or `auto-fill`"));
+                    }
+                    content.parse::<Token![,]>()?;
+                    let mut repeated = vec![content.parse()?];
+                    while !content.is_empty() {
+                        repeated.push(content.parse()?);
+                    }
+                    names.push(SubgridLineNames::Repeat(count, repeated));
+                } else {
+                    return Ok(GridTemplateTracks::Subgrid(names));
+                }
+            }
+        } else {
+            s.parse().map(GridTemplateTracks::TrackList)
+        }
+    }
+}
+
+impl Parse for LineNames {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let content;
+        syn::bracketed!(content in s);
+        let mut names = Vec::new();
+        while !content.is_empty() {
+            names.push(grid_line_name(&content)?);
+        }
+        Ok(LineNames(names))
+    }
+}
+
+fn optional_line_names(s: ParseStream) -> syn::Result<Option<LineNames>> {
+    if s.peek(syn::token::Bracket) {
+        s.parse().map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+fn repeat_count(s: ParseStream) -> syn::Result<RepeatCount> {
+    if s.peek(syn::LitInt) {
+        return Ok(RepeatCount::Count(integer(s, 1..)?));
+    }
+    let name: HyphenWord = s.parse()?;
+    name.add_expected("integer");
+    if name.try_match("auto-fill") {
+        Ok(RepeatCount::AutoFill)
+    } else if name.try_match("auto-fit") {
+        Ok(RepeatCount::AutoFit)
+    } else {
+        Err(name.error())
+    }
+}
+
+impl Parse for TrackList {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let span = s.cursor().span();
+        let mut items = Vec::new();
+        loop {
+            if s.peek(syn::token::Bracket) {
+                items.push(TrackListItem::LineNames(s.parse()?));
+            } else if HyphenWord::peek_specific(s).as_deref() == Some("repeat") {
+                s.parse::<HyphenWord>()?;
+                let content;
+                syn::parenthesized!(content in s);
+                let count = repeat_count(&content)?;
+                content.parse::<Token![,]>()?;
+                let tracks = content.parse()?;
+                if !content.is_empty() {
+                    return Err(content.error("unexpected tokens in `repeat()`"));
+                }
+                items.push(TrackListItem::Repeat(TrackRepeat { count, tracks }));
+            } else {
+                let fork = s.fork();
+                match fork.parse() {
+                    Ok(size) => {
+                        s.advance_to(&fork);
+                        items.push(TrackListItem::Size(size));
+                    }
+                    Err(e) if items.is_empty() => return Err(e),
+                    Err(_) => break,
+                }
+            }
+        }
+        let list = TrackList(items);
+        list.validate().map_err(|e| syn::Error::new(span, e))?;
+        Ok(list)
+    }
+}
+
+impl Parse for TrackSize {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        if name.try_match("minmax") {
+            s.advance_to(&fork);
+            let content;
+            syn::parenthesized!(content in s);
+            let min = content.parse()?;
+            if let TrackBreadth::Flex(_) = min {
+                return Err(content.error("the minimum of `minmax()` can't be flexible"));
+            }
+            content.parse::<Token![,]>()?;
+            let max = content.parse()?;
+            if !content.is_empty() {
+                return Err(content.error("trailing tokens"));
+            }
+            Ok(TrackSize::MinMax(min, max))
+        } else if name.try_match("fit-content") {
+            s.advance_to(&fork);
+            let content;
+            syn::parenthesized!(content in s);
+            let lp = content.parse()?;
+            if !content.is_empty() {
+                return Err(content.error("trailing tokens"));
+            }
+            Ok(TrackSize::FitContent(lp))
+        } else {
+            s.parse().map(TrackSize::Breadth)
+        }
+    }
+}
+
+impl Parse for TrackBreadth {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        if name.try_match("auto") {
+            s.advance_to(&fork);
+            return Ok(TrackBreadth::Auto);
+        } else if name.try_match("min-content") {
+            s.advance_to(&fork);
+            return Ok(TrackBreadth::MinContent);
+        } else if name.try_match("max-content") {
+            s.advance_to(&fork);
+            return Ok(TrackBreadth::MaxContent);
+        }
+        if let Ok(n) = fork.parse::<Number>() {
+            if n.suffix == "fr" {
+                if n.value < 0.0 {
+                    return Err(syn::Error::new(n.span, "flex can't be negative"));
+                }
+                s.advance_to(&fork);
+                return Ok(TrackBreadth::Flex(n.value));
+            }
+        }
+        s.parse().map(TrackBreadth::LengthPercentage).map_err(|_| {
+            name.add_expected("length");
+            name.add_expected("percentage");
+            name.add_expected("flex");
+            name.error()
+        })
+    }
+}
+
 impl Parse for JustifyContent {
     fn parse(s: ParseStream) -> syn::Result<Self> {
         let name: HyphenWord = s.parse()?;
@@ -1339,7 +2024,7 @@ impl Length {
             Ok(Length::Pc(n.value * neg))
         } else if n.suffix == "px" {
             Ok(Length::Px(n.value * neg))
-        } else if n.suffix == "" && n.value == 0.0 {
+        } else if n.suffix.is_empty() && n.value == 0.0 {
             Ok(Length::Zero)
         } else {
             // No matches so return error
@@ -1409,12 +2094,6 @@ fn test_parse_line_width() {
     );
 }
 
-impl Parse for LineHeight {
-    fn parse(s: ParseStream) -> syn::Result<Self> {
-        Ok(LineHeight(s.parse::<syn::LitFloat>()?.base10_parse()?))
-    }
-}
-
 impl Parse for ListStyleType {
     fn parse(s: ParseStream) -> syn::Result<Self> {
         let name: HyphenWord = s.parse()?;
@@ -1474,7 +2153,7 @@ impl Parse for MaxWidthHeight {
             Ok(MaxWidthHeight::FitContent(content.parse()?))
         } else {
             s.parse()
-                .map(|lp| MaxWidthHeight::LengthPercentage(lp))
+                .map(MaxWidthHeight::LengthPercentage)
                 .map_err(|_| name.error())
         }
     }
@@ -1537,16 +2216,16 @@ impl Parse for ObjectFit {
         } else if name.try_match("none") {
             Ok(ObjectFit::None)
         } else if name.try_match("contain") {
-            if s.is_empty() {
-                Ok(ObjectFit::Contain { scale_down: false })
-            } else {
+            if HyphenWord::peek(s) {
                 let scale_down_word: HyphenWord = s.parse()?;
                 if scale_down_word.try_match("scale-down") {
                     Ok(ObjectFit::Contain { scale_down: true })
                 } else {
                     Err(scale_down_word.error())
                 }
-            }
+            } else {
+                Ok(ObjectFit::Contain { scale_down: false })
+            }
         } else if name.try_match("cover") {
             if HyphenWord::peek(s) {
                 let scale_down_word: HyphenWord = s.parse()?;
@@ -1639,34 +2318,21 @@ fn test_padding() {
 
 impl Parse for Percentage {
     fn parse(s: ParseStream) -> syn::Result<Self> {
+        let neg = if s.peek(Token![-]) {
+            s.parse::<Token![-]>()?;
+            -1.0
+        } else {
+            1.0
+        };
         let n: Number = s.parse()?;
         if n.suffix == "%" {
-            Ok(Percentage(n.value))
+            Ok(Percentage(n.value * neg))
         } else {
             Err(syn::Error::new(n.span, "expected percentage"))
         }
     }
 }
 
-impl Parse for WhiteSpace {
-    fn parse(s: ParseStream) -> syn::Result<Self> {
-        let name: HyphenWord = s.parse()?;
-        if name.try_match("normal") {
-            Ok(WhiteSpace::Normal)
-        } else if name.try_match("pre") {
-            Ok(WhiteSpace::Pre)
-        } else if name.try_match("nowrap") {
-            Ok(WhiteSpace::Nowrap)
-        } else if name.try_match("pre-wrap") {
-            Ok(WhiteSpace::PreWrap)
-        } else if name.try_match("pre-line") {
-            Ok(WhiteSpace::PreLine)
-        } else {
-            Err(name.error())
-        }
-    }
-}
-
 impl Parse for Width21 {
     fn parse(s: ParseStream) -> syn::Result<Self> {
         syn::custom_keyword!(auto);
@@ -1728,7 +2394,7 @@ fn test_width_height() {
 
 impl Parse for LengthPercentage {
     fn parse(s: ParseStream) -> syn::Result<Self> {
-        if s.peek2(Token![%]) {
+        if s.peek2(Token![%]) || (s.peek(Token![-]) && s.peek3(Token![%])) {
             Ok(LengthPercentage::Percentage(s.parse()?))
         } else {
             Ok(LengthPercentage::Length(s.parse()?))
@@ -1838,50 +2504,2727 @@ impl Parse for ShadowLength {
                 s.advance_to(&fork);
                 blur
             }
-            Err(_) => {
-                return Ok(ShadowLength::Offsets {
-                    horizontal,
-                    vertical,
-                });
+            Err(_) => {
+                return Ok(ShadowLength::Offsets {
+                    horizontal,
+                    vertical,
+                });
+            }
+        };
+
+        // spread
+        let fork = s.fork();
+        match fork.parse::<Length>() {
+            Ok(spread) => {
+                s.advance_to(&fork);
+
+                Ok(ShadowLength::OffsetsBlurSpread {
+                    horizontal,
+                    vertical,
+                    blur,
+                    spread,
+                })
+            }
+            Err(_) => Ok(ShadowLength::OffsetsBlur {
+                horizontal,
+                vertical,
+                blur,
+            }),
+        }
+    }
+}
+
+impl Parse for TextAlign {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let word: HyphenWord = s.parse()?;
+        if word.try_match("left") {
+            Ok(TextAlign::Left)
+        } else if word.try_match("right") {
+            Ok(TextAlign::Right)
+        } else if word.try_match("center") {
+            Ok(TextAlign::Center)
+        } else if word.try_match("justify") {
+            Ok(TextAlign::Justify)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+// border
+// ======
+
+impl Parse for BorderRadius {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let horizontal = s.parse()?;
+        let vertical = if s.peek(Token![/]) {
+            s.parse::<Token![/]>()?;
+            Some(s.parse()?)
+        } else {
+            None
+        };
+        Ok(BorderRadius {
+            horizontal,
+            vertical,
+        })
+    }
+}
+
+impl Parse for BorderImage {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if finished_rule(s) {
+            return Err(s.error("expected a border image"));
+        }
+        let mut image = BorderImage::default();
+        while !finished_rule(s) {
+            if image.source.is_none() {
+                if let Some(v) = try_parse(s) {
+                    image.source = Some(v);
+                    continue;
+                }
+            }
+            if image.slice.is_none() {
+                if let Some(v) = try_parse(s) {
+                    image.slice = Some(v);
+                    // `slice / width`, `slice / / outset` or `slice / width / outset`
+                    if s.peek(Token![/]) {
+                        s.parse::<Token![/]>()?;
+                        if !s.peek(Token![/]) {
+                            image.width = Some(s.parse()?);
+                        }
+                        if s.peek(Token![/]) {
+                            s.parse::<Token![/]>()?;
+                            image.outset = Some(s.parse()?);
+                        }
+                    }
+                    continue;
+                }
+            }
+            if image.repeat.is_none() {
+                if let Some(v) = try_parse(s) {
+                    image.repeat = Some(v);
+                    continue;
+                }
+            }
+            return Err(s.error("unexpected or repeated value in border image"));
+        }
+        Ok(image)
+    }
+}
+
+impl Parse for BorderImageSource {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if HyphenWord::peek_specific(s).as_deref() == Some("none") {
+            s.parse::<HyphenWord>()?;
+            Ok(BorderImageSource::None)
+        } else {
+            s.parse().map(BorderImageSource::Image)
+        }
+    }
+}
+
+impl Parse for BorderImageSlice {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        syn::custom_keyword!(fill);
+        // `fill` can go before or after the offsets
+        let mut has_fill = s.peek(fill);
+        if has_fill {
+            s.parse::<fill>()?;
+        }
+        let offsets = s.parse()?;
+        if !has_fill && s.peek(fill) {
+            s.parse::<fill>()?;
+            has_fill = true;
+        }
+        Ok(BorderImageSlice {
+            offsets,
+            fill: has_fill,
+        })
+    }
+}
+
+impl Parse for NumberOrPercentage {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let n: Number = s.parse()?;
+        if n.suffix.is_empty() {
+            Ok(NumberOrPercentage::Number(n.value))
+        } else if n.suffix == "%" {
+            Ok(NumberOrPercentage::Percentage(Percentage(n.value)))
+        } else {
+            Err(syn::Error::new(n.span, "expected number or percentage"))
+        }
+    }
+}
+
+impl Parse for BorderImageWidth {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        syn::custom_keyword!(auto);
+        if s.peek(auto) {
+            s.parse::<auto>()?;
+            return Ok(BorderImageWidth::Auto);
+        }
+        let n: Number = s.parse()?;
+        if n.suffix.is_empty() {
+            Ok(BorderImageWidth::Number(n.value))
+        } else if n.suffix == "%" {
+            Ok(BorderImageWidth::LengthPercentage(
+                LengthPercentage::Percentage(Percentage(n.value)),
+            ))
+        } else {
+            Ok(BorderImageWidth::LengthPercentage(
+                LengthPercentage::Length(Length::parse_from_number(n, false)?),
+            ))
+        }
+    }
+}
+
+impl Parse for NumberOrLength {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let n: Number = s.parse()?;
+        if n.suffix.is_empty() {
+            Ok(NumberOrLength::Number(n.value))
+        } else {
+            Length::parse_from_number(n, false).map(NumberOrLength::Length)
+        }
+    }
+}
+
+impl Parse for BorderImageRepeat {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        Ok(BorderImageRepeat {
+            horizontal: s.parse()?,
+            vertical: try_parse(s),
+        })
+    }
+}
+
+impl Parse for BorderImageRepeatMode {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let word: HyphenWord = s.parse()?;
+        if word.try_match("stretch") {
+            Ok(BorderImageRepeatMode::Stretch)
+        } else if word.try_match("repeat") {
+            Ok(BorderImageRepeatMode::Repeat)
+        } else if word.try_match("round") {
+            Ok(BorderImageRepeatMode::Round)
+        } else if word.try_match("space") {
+            Ok(BorderImageRepeatMode::Space)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+// text
+// ====
+
+// `Spacing` on its own is the `proc_macro2` type.
+impl Parse for crate::Spacing {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if HyphenWord::peek_specific(s).as_deref() == Some("normal") {
+            s.parse::<HyphenWord>()?;
+            Ok(crate::Spacing::Normal)
+        } else {
+            s.parse().map(crate::Spacing::Length)
+        }
+    }
+}
+
+impl Parse for TextIndent {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let mut length = None;
+        let (mut hanging, mut each_line) = (false, false);
+        while !finished_rule(s) {
+            if length.is_none() {
+                if let Some(v) = try_parse(s) {
+                    length = Some(v);
+                    continue;
+                }
+            }
+            let word: HyphenWord = s.parse()?;
+            if !hanging && word.try_match("hanging") {
+                hanging = true;
+            } else if !each_line && word.try_match("each-line") {
+                each_line = true;
+            } else {
+                if length.is_none() {
+                    word.add_expected("length");
+                    word.add_expected("percentage");
+                }
+                return Err(word.error());
+            }
+        }
+        match length {
+            Some(length) => Ok(TextIndent {
+                length,
+                hanging,
+                each_line,
+            }),
+            None => Err(s.error("expected a length or percentage")),
+        }
+    }
+}
+
+impl Parse for TextTransform {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let mut transform = TextTransform::default();
+        if HyphenWord::peek_specific(s).as_deref() == Some("none") {
+            s.parse::<HyphenWord>()?;
+            return Ok(transform);
+        }
+        while !finished_rule(s) {
+            if transform.case.is_none() {
+                if let Some(v) = try_parse(s) {
+                    transform.case = Some(v);
+                    continue;
+                }
+            }
+            let word: HyphenWord = s.parse()?;
+            if !transform.full_width && word.try_match("full-width") {
+                transform.full_width = true;
+            } else if !transform.full_size_kana && word.try_match("full-size-kana") {
+                transform.full_size_kana = true;
+            } else {
+                return Err(word.error());
+            }
+        }
+        if transform == TextTransform::default() {
+            return Err(s.error("expected text transform"));
+        }
+        Ok(transform)
+    }
+}
+
+impl Parse for TextTransformCase {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let word: HyphenWord = s.parse()?;
+        if word.try_match("capitalize") {
+            Ok(TextTransformCase::Capitalize)
+        } else if word.try_match("uppercase") {
+            Ok(TextTransformCase::Uppercase)
+        } else if word.try_match("lowercase") {
+            Ok(TextTransformCase::Lowercase)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for TextDecoration {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if finished_rule(s) {
+            return Err(s.error("expected a text decoration"));
+        }
+        let mut decoration = TextDecoration::default();
+        while !finished_rule(s) {
+            if decoration.line.is_none() {
+                if let Some(v) = try_parse(s) {
+                    decoration.line = Some(v);
+                    continue;
+                }
+            }
+            if decoration.thickness.is_none() {
+                if let Some(v) = try_parse(s) {
+                    decoration.thickness = Some(v);
+                    continue;
+                }
+            }
+            if decoration.style.is_none() {
+                if let Some(v) = try_parse(s) {
+                    decoration.style = Some(v);
+                    continue;
+                }
+            }
+            if decoration.color.is_none() {
+                if let Some(v) = try_parse(s) {
+                    decoration.color = Some(v);
+                    continue;
+                }
+            }
+            return Err(s.error("unexpected or repeated value in text decoration"));
+        }
+        Ok(decoration)
+    }
+}
+
+impl Parse for TextDecorationLine {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let mut line = TextDecorationLine::default();
+        if HyphenWord::peek_specific(s).as_deref() == Some("none") {
+            s.parse::<HyphenWord>()?;
+            return Ok(line);
+        }
+        loop {
+            match HyphenWord::peek_specific(s).as_deref() {
+                Some("underline") if !line.underline => line.underline = true,
+                Some("overline") if !line.overline => line.overline = true,
+                Some("line-through") if !line.line_through => line.line_through = true,
+                Some("blink") if !line.blink => line.blink = true,
+                _ => break,
+            }
+            s.parse::<HyphenWord>()?;
+        }
+        if line == TextDecorationLine::default() {
+            return Err(s.error(
+                "expected one of `none`, `underline`, `overline`, `line-through`, `blink`",
+            ));
+        }
+        Ok(line)
+    }
+}
+
+impl Parse for TextDecorationStyle {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let word: HyphenWord = s.parse()?;
+        if word.try_match("solid") {
+            Ok(TextDecorationStyle::Solid)
+        } else if word.try_match("double") {
+            Ok(TextDecorationStyle::Double)
+        } else if word.try_match("dotted") {
+            Ok(TextDecorationStyle::Dotted)
+        } else if word.try_match("dashed") {
+            Ok(TextDecorationStyle::Dashed)
+        } else if word.try_match("wavy") {
+            Ok(TextDecorationStyle::Wavy)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for TextDecorationColor {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if HyphenWord::peek_specific(s).as_deref() == Some("currentcolor") {
+            s.parse::<HyphenWord>()?;
+            Ok(TextDecorationColor::CurrentColor)
+        } else {
+            s.parse().map(TextDecorationColor::Color)
+        }
+    }
+}
+
+impl Parse for TextDecorationThickness {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        match HyphenWord::peek_specific(s).as_deref() {
+            Some("auto") => {
+                s.parse::<HyphenWord>()?;
+                Ok(TextDecorationThickness::Auto)
+            }
+            Some("from-font") => {
+                s.parse::<HyphenWord>()?;
+                Ok(TextDecorationThickness::FromFont)
+            }
+            _ => s.parse().map(TextDecorationThickness::LengthPercentage),
+        }
+    }
+}
+
+impl Parse for TextUnderlinePosition {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        match HyphenWord::peek_specific(s).as_deref() {
+            Some("auto") => {
+                s.parse::<HyphenWord>()?;
+                return Ok(TextUnderlinePosition::Auto);
+            }
+            Some("from-font") => {
+                s.parse::<HyphenWord>()?;
+                return Ok(TextUnderlinePosition::FromFont);
+            }
+            _ => (),
+        }
+        let (mut under, mut side) = (false, None);
+        loop {
+            if !under && HyphenWord::peek_specific(s).as_deref() == Some("under") {
+                s.parse::<HyphenWord>()?;
+                under = true;
+            } else if side.is_none() {
+                match try_parse(s) {
+                    Some(v) => side = Some(v),
+                    None => break,
+                }
+            } else {
+                break;
+            }
+        }
+        if !under && side.is_none() {
+            return Err(s.error("expected one of `auto`, `from-font`, `under`, `left`, `right`"));
+        }
+        Ok(TextUnderlinePosition::Position { under, side })
+    }
+}
+
+impl Parse for TextShadow {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if HyphenWord::peek_specific(s).as_deref() == Some("none") {
+            s.parse::<HyphenWord>()?;
+            return Ok(TextShadow::None);
+        }
+        let span = s.cursor().span();
+        let list: NonemptyCommaList<Shadow> = s.parse()?;
+        for shadow in std::iter::once(&list.first).chain(&list.rest) {
+            if shadow.inset {
+                return Err(syn::Error::new(span, "text shadows can't be `inset`"));
+            }
+            if let ShadowLength::OffsetsBlurSpread { .. } = shadow.length {
+                return Err(syn::Error::new(span, "text shadows can't have a spread"));
+            }
+        }
+        Ok(TextShadow::Shadows(list))
+    }
+}
+
+impl Parse for LineHeight {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if HyphenWord::peek_specific(s).as_deref() == Some("normal") {
+            s.parse::<HyphenWord>()?;
+            return Ok(LineHeight::Normal);
+        }
+        // A string after a number is only a unit if it's `"em"` or `"ex"`. Otherwise it could be
+        // the family in the `font` shorthand, like `12px/1.5 "Helvetica"`.
+        let fork = s.fork();
+        let has_unit = match fork.parse::<Number>() {
+            Ok(n) if n.suffix == "%" => true,
+            Ok(n) if !n.suffix.is_empty() => Length::parse_from_number(n, false).is_ok(),
+            _ => false,
+        };
+        if !has_unit {
+            let fork = s.fork();
+            let value = match fork.parse::<syn::Lit>() {
+                Ok(syn::Lit::Float(v)) if v.suffix().is_empty() => Some(v.base10_parse()?),
+                Ok(syn::Lit::Int(v)) if v.suffix().is_empty() => {
+                    Some(v.base10_parse::<u32>()?.into())
+                }
+                _ => None,
+            };
+            if let Some(value) = value {
+                s.advance_to(&fork);
+                return Ok(LineHeight::Number(value));
+            }
+        }
+        let span = s.cursor().span();
+        let value: Calc = s.parse()?;
+        let negative = match &value {
+            Calc::Normal(LengthPercentage::Length(v)) => v.is_negative(),
+            Calc::Normal(LengthPercentage::Percentage(v)) => v.0 < 0.0,
+            Calc::Calculated(_) => false,
+        };
+        if negative {
+            return Err(syn::Error::new(span, "line height can't be negative"));
+        }
+        Ok(LineHeight::LengthPercentage(value))
+    }
+}
+
+impl Parse for WhiteSpace {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        let keyword = if name.try_match("normal") {
+            Some(WhiteSpace::Normal)
+        } else if name.try_match("pre") {
+            Some(WhiteSpace::Pre)
+        } else if name.try_match("pre-wrap") {
+            Some(WhiteSpace::PreWrap)
+        } else if name.try_match("pre-line") {
+            Some(WhiteSpace::PreLine)
+        } else {
+            None
+        };
+        if let Some(keyword) = keyword {
+            s.advance_to(&fork);
+            return Ok(keyword);
+        }
+        let (mut collapse, mut wrap_mode) = (None, None);
+        loop {
+            if collapse.is_none() {
+                if let Some(v) = try_parse(s) {
+                    collapse = Some(v);
+                    continue;
+                }
+            }
+            if wrap_mode.is_none() {
+                if let Some(v) = try_parse(s) {
+                    wrap_mode = Some(v);
+                    continue;
+                }
+            }
+            break;
+        }
+        Ok(match (collapse, wrap_mode) {
+            (None, None) => {
+                name.add_expected("white space collapse");
+                name.add_expected("text wrap mode");
+                return Err(name.error());
+            }
+            (None, Some(TextWrapMode::Nowrap)) => WhiteSpace::Nowrap,
+            (Some(WhiteSpaceCollapse::BreakSpaces), None) => WhiteSpace::BreakSpaces,
+            (collapse, wrap_mode) => WhiteSpace::Longhands {
+                collapse,
+                wrap_mode,
+            },
+        })
+    }
+}
+
+impl Parse for WordBreak {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let word: HyphenWord = s.parse()?;
+        if word.try_match("normal") {
+            Ok(WordBreak::Normal)
+        } else if word.try_match("break-all") {
+            Ok(WordBreak::BreakAll)
+        } else if word.try_match("keep-all") {
+            Ok(WordBreak::KeepAll)
+        } else if word.try_match("break-word") {
+            Ok(WordBreak::BreakWord)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for OverflowWrap {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let word: HyphenWord = s.parse()?;
+        if word.try_match("normal") {
+            Ok(OverflowWrap::Normal)
+        } else if word.try_match("break-word") {
+            Ok(OverflowWrap::BreakWord)
+        } else if word.try_match("anywhere") {
+            Ok(OverflowWrap::Anywhere)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for LineBreak {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let word: HyphenWord = s.parse()?;
+        if word.try_match("auto") {
+            Ok(LineBreak::Auto)
+        } else if word.try_match("loose") {
+            Ok(LineBreak::Loose)
+        } else if word.try_match("normal") {
+            Ok(LineBreak::Normal)
+        } else if word.try_match("strict") {
+            Ok(LineBreak::Strict)
+        } else if word.try_match("anywhere") {
+            Ok(LineBreak::Anywhere)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for Hyphens {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let word: HyphenWord = s.parse()?;
+        if word.try_match("none") {
+            Ok(Hyphens::None)
+        } else if word.try_match("manual") {
+            Ok(Hyphens::Manual)
+        } else if word.try_match("auto") {
+            Ok(Hyphens::Auto)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for HyphenateCharacter {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if s.peek(syn::LitStr) {
+            Ok(HyphenateCharacter::String(
+                s.parse::<syn::LitStr>()?.value(),
+            ))
+        } else {
+            let word: HyphenWord = s.parse()?;
+            if word.try_match("auto") {
+                Ok(HyphenateCharacter::Auto)
+            } else {
+                word.add_expected("string");
+                Err(word.error())
+            }
+        }
+    }
+}
+
+impl Parse for TextWrap {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let mode = try_parse(s);
+        let style = try_parse(s);
+        let mode = match mode {
+            Some(mode) => Some(mode),
+            None if style.is_some() => try_parse(s),
+            None => None,
+        };
+        if mode.is_none() && style.is_none() {
+            return Err(s.error("expected text wrap mode or style"));
+        }
+        Ok(TextWrap { mode, style })
+    }
+}
+
+impl Parse for TextWrapMode {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let word: HyphenWord = s.parse()?;
+        if word.try_match("wrap") {
+            Ok(TextWrapMode::Wrap)
+        } else if word.try_match("nowrap") {
+            Ok(TextWrapMode::Nowrap)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for TextWrapStyle {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let word: HyphenWord = s.parse()?;
+        if word.try_match("auto") {
+            Ok(TextWrapStyle::Auto)
+        } else if word.try_match("balance") {
+            Ok(TextWrapStyle::Balance)
+        } else if word.try_match("stable") {
+            Ok(TextWrapStyle::Stable)
+        } else if word.try_match("pretty") {
+            Ok(TextWrapStyle::Pretty)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for WhiteSpaceCollapse {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let word: HyphenWord = s.parse()?;
+        if word.try_match("collapse") {
+            Ok(WhiteSpaceCollapse::Collapse)
+        } else if word.try_match("discard") {
+            Ok(WhiteSpaceCollapse::Discard)
+        } else if word.try_match("preserve") {
+            Ok(WhiteSpaceCollapse::Preserve)
+        } else if word.try_match("preserve-breaks") {
+            Ok(WhiteSpaceCollapse::PreserveBreaks)
+        } else if word.try_match("preserve-spaces") {
+            Ok(WhiteSpaceCollapse::PreserveSpaces)
+        } else if word.try_match("break-spaces") {
+            Ok(WhiteSpaceCollapse::BreakSpaces)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for HangingPunctuation {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let mut hanging = HangingPunctuation::default();
+        if HyphenWord::peek_specific(s).as_deref() == Some("none") {
+            s.parse::<HyphenWord>()?;
+            return Ok(hanging);
+        }
+        loop {
+            match HyphenWord::peek_specific(s).as_deref() {
+                Some("first") if !hanging.first => hanging.first = true,
+                Some("last") if !hanging.last => hanging.last = true,
+                _ if hanging.end.is_none() => match try_parse(s) {
+                    Some(end) => {
+                        hanging.end = Some(end);
+                        continue;
+                    }
+                    None => break,
+                },
+                _ => break,
+            }
+            s.parse::<HyphenWord>()?;
+        }
+        if hanging == HangingPunctuation::default() {
+            return Err(
+                s.error("expected one of `none`, `first`, `force-end`, `allow-end`, `last`")
+            );
+        }
+        Ok(hanging)
+    }
+}
+
+impl Parse for HangingPunctuationEnd {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let word: HyphenWord = s.parse()?;
+        if word.try_match("force-end") {
+            Ok(HangingPunctuationEnd::ForceEnd)
+        } else if word.try_match("allow-end") {
+            Ok(HangingPunctuationEnd::AllowEnd)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+// writing modes
+
+impl Parse for Direction {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let word: HyphenWord = s.parse()?;
+        if word.try_match("ltr") {
+            Ok(Direction::Ltr)
+        } else if word.try_match("rtl") {
+            Ok(Direction::Rtl)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for UnicodeBidi {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let word: HyphenWord = s.parse()?;
+        if word.try_match("normal") {
+            Ok(UnicodeBidi::Normal)
+        } else if word.try_match("embed") {
+            Ok(UnicodeBidi::Embed)
+        } else if word.try_match("isolate") {
+            Ok(UnicodeBidi::Isolate)
+        } else if word.try_match("bidi-override") {
+            Ok(UnicodeBidi::BidiOverride)
+        } else if word.try_match("isolate-override") {
+            Ok(UnicodeBidi::IsolateOverride)
+        } else if word.try_match("plaintext") {
+            Ok(UnicodeBidi::Plaintext)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for WritingMode {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let word: HyphenWord = s.parse()?;
+        if word.try_match("horizontal-tb") {
+            Ok(WritingMode::HorizontalTb)
+        } else if word.try_match("vertical-rl") {
+            Ok(WritingMode::VerticalRl)
+        } else if word.try_match("vertical-lr") {
+            Ok(WritingMode::VerticalLr)
+        } else if word.try_match("sideways-rl") {
+            Ok(WritingMode::SidewaysRl)
+        } else if word.try_match("sideways-lr") {
+            Ok(WritingMode::SidewaysLr)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for TextOrientation {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let word: HyphenWord = s.parse()?;
+        if word.try_match("mixed") {
+            Ok(TextOrientation::Mixed)
+        } else if word.try_match("upright") {
+            Ok(TextOrientation::Upright)
+        } else if word.try_match("sideways") {
+            Ok(TextOrientation::Sideways)
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+impl Parse for TextCombineUpright {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let word: HyphenWord = s.parse()?;
+        if word.try_match("none") {
+            Ok(TextCombineUpright::None)
+        } else if word.try_match("all") {
+            Ok(TextCombineUpright::All)
+        } else if word.try_match("digits") {
+            let digits = if s.peek(syn::LitInt) {
+                Some(integer(s, 2..=4)?)
+            } else {
+                None
+            };
+            Ok(TextCombineUpright::Digits(digits))
+        } else {
+            Err(word.error())
+        }
+    }
+}
+
+// font
+// ====
+
+impl Parse for FontShorthand {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let word: HyphenWord = fork.parse()?;
+        for (keyword, system) in [
+            ("caption", SystemFont::Caption),
+            ("icon", SystemFont::Icon),
+            ("menu", SystemFont::Menu),
+            ("message-box", SystemFont::MessageBox),
+            ("small-caption", SystemFont::SmallCaption),
+            ("status-bar", SystemFont::StatusBar),
+        ] {
+            if word.try_match(keyword) && finished_rule(&fork) {
+                s.advance_to(&fork);
+                return Ok(FontShorthand::System(system));
+            }
+        }
+
+        let mut style = None;
+        let mut small_caps = false;
+        let mut weight = None;
+        let mut stretch = None;
+        // Up to four values can come before the size, in any order. `normal` leaves one of them
+        // at its initial value.
+        for _ in 0..4 {
+            let fork = s.fork();
+            let word: HyphenWord = fork.parse()?;
+            if word.try_match("normal") {
+                s.advance_to(&fork);
+                continue;
+            }
+            if !small_caps && word.try_match("small-caps") {
+                s.advance_to(&fork);
+                small_caps = true;
+                continue;
+            }
+            if style.is_none() {
+                if let Some(v) = try_parse(s) {
+                    style = Some(v);
+                    continue;
+                }
+            }
+            if weight.is_none() {
+                if let Some(v) = try_parse(s) {
+                    weight = Some(v);
+                    continue;
+                }
+            }
+            if stretch.is_none() {
+                let fork = s.fork();
+                match fork.parse() {
+                    Ok(FontStretch::Percentage(_)) | Err(_) => (),
+                    Ok(v) => {
+                        s.advance_to(&fork);
+                        stretch = Some(v);
+                        continue;
+                    }
+                }
+            }
+            break;
+        }
+        let size = s.parse()?;
+        let line_height = if s.peek(Token![/]) {
+            s.parse::<Token![/]>()?;
+            Some(s.parse()?)
+        } else {
+            None
+        };
+        Ok(FontShorthand::Values {
+            style,
+            small_caps,
+            weight,
+            stretch,
+            size,
+            line_height,
+            family: s.parse()?,
+        })
+    }
+}
+
+impl Parse for FontStretch {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        for (keyword, stretch) in [
+            ("normal", FontStretch::Normal),
+            ("ultra-condensed", FontStretch::UltraCondensed),
+            ("extra-condensed", FontStretch::ExtraCondensed),
+            ("condensed", FontStretch::Condensed),
+            ("semi-condensed", FontStretch::SemiCondensed),
+            ("semi-expanded", FontStretch::SemiExpanded),
+            ("expanded", FontStretch::Expanded),
+            ("extra-expanded", FontStretch::ExtraExpanded),
+            ("ultra-expanded", FontStretch::UltraExpanded),
+        ] {
+            if name.try_match(keyword) {
+                s.advance_to(&fork);
+                return Ok(stretch);
+            }
+        }
+        let span = s.cursor().span();
+        match s.parse() {
+            Ok(Percentage(v)) if v >= 0.0 => Ok(FontStretch::Percentage(Percentage(v))),
+            Ok(_) => Err(syn::Error::new(span, "font-stretch can't be negative")),
+            Err(_) => {
+                name.add_expected("percentage");
+                Err(name.error())
+            }
+        }
+    }
+}
+
+impl Parse for FontKerning {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("auto") {
+            Ok(FontKerning::Auto)
+        } else if name.try_match("normal") {
+            Ok(FontKerning::Normal)
+        } else if name.try_match("none") {
+            Ok(FontKerning::None)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for FontSizeAdjust {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        if name.try_match("none") {
+            s.advance_to(&fork);
+            return Ok(FontSizeAdjust::None);
+        }
+        let metric = try_parse(s).unwrap_or(FontMetric::ExHeight);
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        if name.try_match("from-font") {
+            s.advance_to(&fork);
+            return Ok(FontSizeAdjust::Adjust {
+                metric,
+                value: None,
+            });
+        }
+        let n: Number = s.parse().map_err(|_| {
+            name.add_expected("number");
+            name.error()
+        })?;
+        n.empty_suffix()?;
+        Ok(FontSizeAdjust::Adjust {
+            metric,
+            value: Some(n.value),
+        })
+    }
+}
+
+impl Parse for FontMetric {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("ex-height") {
+            Ok(FontMetric::ExHeight)
+        } else if name.try_match("cap-height") {
+            Ok(FontMetric::CapHeight)
+        } else if name.try_match("ch-width") {
+            Ok(FontMetric::ChWidth)
+        } else if name.try_match("ic-width") {
+            Ok(FontMetric::IcWidth)
+        } else if name.try_match("ic-height") {
+            Ok(FontMetric::IcHeight)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for FontSynthesis {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let mut out = FontSynthesis {
+            weight: false,
+            style: false,
+            small_caps: false,
+            position: false,
+        };
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        if name.try_match("none") {
+            s.advance_to(&fork);
+            return Ok(out);
+        }
+        while add_keyword(s, &mut out, FontSynthesis::add_keyword) {}
+        if !out.weight && !out.style && !out.small_caps && !out.position {
+            return Err(s.error("expected `none`, `weight`, `style`, `small-caps` or `position`"));
+        }
+        Ok(out)
+    }
+}
+
+impl Parse for FontVariant {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let mut out = FontVariant::default();
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        if name.try_match("normal") {
+            s.advance_to(&fork);
+            return Ok(out);
+        } else if name.try_match("none") {
+            s.advance_to(&fork);
+            out.ligatures = FontVariantLigatures::None;
+            return Ok(out);
+        }
+        while !finished_rule(s) {
+            if !(add_keyword(s, &mut out.ligatures, FontVariantLigatures::add_keyword)
+                || set_keyword(s, &mut out.caps)
+                || alternates_part(s, &mut out.alternates)?
+                || add_keyword(s, &mut out.numeric, FontVariantNumeric::add_keyword)
+                || add_keyword(s, &mut out.east_asian, FontVariantEastAsian::add_keyword)
+                || set_keyword(s, &mut out.position)
+                || set_keyword(s, &mut out.emoji))
+            {
+                return Err(s.error("unexpected value in font-variant"));
+            }
+        }
+        if out == FontVariant::default() {
+            return Err(s.error("expected font-variant"));
+        }
+        Ok(out)
+    }
+}
+
+impl Parse for FontVariantLigatures {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        if name.try_match("normal") {
+            s.advance_to(&fork);
+            return Ok(FontVariantLigatures::Normal);
+        } else if name.try_match("none") {
+            s.advance_to(&fork);
+            return Ok(FontVariantLigatures::None);
+        }
+        let mut out = FontVariantLigatures::Normal;
+        while add_keyword(s, &mut out, FontVariantLigatures::add_keyword) {}
+        if out == FontVariantLigatures::Normal {
+            return Err(s.error("expected `normal`, `none` or ligature keyword"));
+        }
+        Ok(out)
+    }
+}
+
+impl Parse for FontVariantCaps {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("normal") {
+            Ok(FontVariantCaps::Normal)
+        } else if name.try_match("small-caps") {
+            Ok(FontVariantCaps::SmallCaps)
+        } else if name.try_match("all-small-caps") {
+            Ok(FontVariantCaps::AllSmallCaps)
+        } else if name.try_match("petite-caps") {
+            Ok(FontVariantCaps::PetiteCaps)
+        } else if name.try_match("all-petite-caps") {
+            Ok(FontVariantCaps::AllPetiteCaps)
+        } else if name.try_match("unicase") {
+            Ok(FontVariantCaps::Unicase)
+        } else if name.try_match("titling-caps") {
+            Ok(FontVariantCaps::TitlingCaps)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for FontVariantAlternates {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let mut out = FontVariantAlternates::default();
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        if name.try_match("normal") && !fork.peek(syn::token::Paren) {
+            s.advance_to(&fork);
+            return Ok(out);
+        }
+        while alternates_part(s, &mut out)? {}
+        if out == FontVariantAlternates::default() {
+            return Err(s.error("expected `normal` or alternates"));
+        }
+        Ok(out)
+    }
+}
+
+/// Parse one keyword or function of `font-variant-alternates` into `alternates`, returning
+/// whether there was one that hadn't already been set.
+fn alternates_part(s: ParseStream, alternates: &mut FontVariantAlternates) -> syn::Result<bool> {
+    let fork = s.fork();
+    let name = match fork.parse::<HyphenWord>()?.word {
+        Some(name) => name,
+        None => return Ok(false),
+    };
+    if name == "historical-forms" {
+        if alternates.historical_forms {
+            return Ok(false);
+        }
+        alternates.historical_forms = true;
+    } else if fork.peek(syn::token::Paren) {
+        let content;
+        match name.as_str() {
+            "stylistic" | "swash" | "ornaments" | "annotation" => {
+                let field = match name.as_str() {
+                    "stylistic" => &mut alternates.stylistic,
+                    "swash" => &mut alternates.swash,
+                    "ornaments" => &mut alternates.ornaments,
+                    _ => &mut alternates.annotation,
+                };
+                if field.is_some() {
+                    return Ok(false);
+                }
+                syn::parenthesized!(content in fork);
+                *field = Some(feature_value_name(&content)?);
+            }
+            "styleset" | "character-variant" => {
+                let field = match name.as_str() {
+                    "styleset" => &mut alternates.styleset,
+                    _ => &mut alternates.character_variant,
+                };
+                if !field.is_empty() {
+                    return Ok(false);
+                }
+                syn::parenthesized!(content in fork);
+                *field = vec![feature_value_name(&content)?];
+                while content.peek(Token![,]) {
+                    content.parse::<Token![,]>()?;
+                    field.push(feature_value_name(&content)?);
+                }
+            }
+            _ => return Ok(false),
+        }
+        if !content.is_empty() {
+            return Err(content.error("trailing tokens"));
+        }
+    } else {
+        return Ok(false);
+    }
+    s.advance_to(&fork);
+    Ok(true)
+}
+
+/// The name of a value defined in a `@font-feature-values` rule.
+fn feature_value_name(s: ParseStream) -> syn::Result<String> {
+    let name: HyphenWord = s.parse()?;
+    name.word
+        .ok_or_else(|| s.error("expected feature value name"))
+}
+
+impl Parse for FontVariantNumeric {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        let mut out = FontVariantNumeric::default();
+        if name.try_match("normal") {
+            s.advance_to(&fork);
+            return Ok(out);
+        }
+        while add_keyword(s, &mut out, FontVariantNumeric::add_keyword) {}
+        if out == FontVariantNumeric::default() {
+            return Err(s.error("expected `normal` or numeric figure, spacing or fraction"));
+        }
+        Ok(out)
+    }
+}
+
+impl Parse for FontVariantEastAsian {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        let mut out = FontVariantEastAsian::default();
+        if name.try_match("normal") {
+            s.advance_to(&fork);
+            return Ok(out);
+        }
+        while add_keyword(s, &mut out, FontVariantEastAsian::add_keyword) {}
+        if out == FontVariantEastAsian::default() {
+            return Err(s.error("expected `normal` or east asian variant, width or `ruby`"));
+        }
+        Ok(out)
+    }
+}
+
+impl Parse for FontVariantPosition {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("normal") {
+            Ok(FontVariantPosition::Normal)
+        } else if name.try_match("sub") {
+            Ok(FontVariantPosition::Sub)
+        } else if name.try_match("super") {
+            Ok(FontVariantPosition::Super)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for FontVariantEmoji {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("normal") {
+            Ok(FontVariantEmoji::Normal)
+        } else if name.try_match("text") {
+            Ok(FontVariantEmoji::Text)
+        } else if name.try_match("emoji") {
+            Ok(FontVariantEmoji::Emoji)
+        } else if name.try_match("unicode") {
+            Ok(FontVariantEmoji::Unicode)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+/// Consume the next word if `add` accepts it as a keyword of `value`.
+fn add_keyword<T>(s: ParseStream, value: &mut T, add: fn(&mut T, &str) -> bool) -> bool {
+    match HyphenWord::peek_specific(s) {
+        Some(word) if add(value, &word) => {
+            s.parse::<HyphenWord>().unwrap();
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Parse a value other than the default into `value`, if it is still the default.
+fn set_keyword<T: Parse + Default + PartialEq>(s: ParseStream, value: &mut T) -> bool {
+    if *value != T::default() {
+        return false;
+    }
+    let fork = s.fork();
+    match fork.parse() {
+        Ok(v) if v != T::default() => {
+            s.advance_to(&fork);
+            *value = v;
+            true
+        }
+        _ => false,
+    }
+}
+
+impl Parse for FontFeatureSettings {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if s.peek(syn::LitStr) {
+            return Ok(FontFeatureSettings::Features(s.parse()?));
+        }
+        let name: HyphenWord = s.parse()?;
+        name.add_expected("feature tag");
+        if name.try_match("normal") {
+            Ok(FontFeatureSettings::Normal)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for FeatureTagValue {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let tag = opentype_tag(s)?;
+        let value = if finished_item(s) {
+            1
+        } else if s.peek(syn::LitInt) {
+            integer(s, 0..)?
+        } else {
+            let name: HyphenWord = s.parse()?;
+            name.add_expected("integer");
+            if name.try_match("on") {
+                1
+            } else if name.try_match("off") {
+                0
+            } else {
+                return Err(name.error());
+            }
+        };
+        Ok(FeatureTagValue { tag, value })
+    }
+}
+
+impl Parse for FontVariationSettings {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if s.peek(syn::LitStr) {
+            return Ok(FontVariationSettings::Axes(s.parse()?));
+        }
+        let name: HyphenWord = s.parse()?;
+        name.add_expected("axis tag");
+        if name.try_match("normal") {
+            Ok(FontVariationSettings::Normal)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for VariationAxis {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        Ok(VariationAxis {
+            tag: opentype_tag(s)?,
+            value: signed_number(s)?,
+        })
+    }
+}
+
+/// A quoted OpenType feature or axis tag.
+fn opentype_tag(s: ParseStream) -> syn::Result<String> {
+    let tag: syn::LitStr = s.parse()?;
+    if is_tag(&tag.value()) {
+        Ok(tag.value())
+    } else {
+        Err(syn::Error::new(
+            tag.span(),
+            "tags must be four ASCII characters",
+        ))
+    }
+}
+
+// image
+// =====
+
+impl Parse for Url {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if !name.try_match("url") {
+            return Err(name.error());
+        }
+        let content;
+        syn::parenthesized!(content in s);
+        let url = content.parse::<syn::LitStr>()?.value();
+        let mut modifiers = Vec::new();
+        while !content.is_empty() {
+            modifiers.push(content.parse()?);
+        }
+        Ok(Url { url, modifiers })
+    }
+}
+
+impl Parse for UrlModifier {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        let content;
+        let modifier = if name.try_match("crossorigin") {
+            syn::parenthesized!(content in s);
+            UrlModifier::CrossOrigin(content.parse()?)
+        } else if name.try_match("integrity") {
+            syn::parenthesized!(content in s);
+            UrlModifier::Integrity(content.parse::<syn::LitStr>()?.value())
+        } else if name.try_match("referrerpolicy") {
+            syn::parenthesized!(content in s);
+            UrlModifier::ReferrerPolicy(content.parse()?)
+        } else {
+            return Err(name.error());
+        };
+        if !content.is_empty() {
+            return Err(content.error("trailing tokens"));
+        }
+        Ok(modifier)
+    }
+}
+
+impl Parse for CrossOrigin {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("anonymous") {
+            Ok(CrossOrigin::Anonymous)
+        } else if name.try_match("use-credentials") {
+            Ok(CrossOrigin::UseCredentials)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for ReferrerPolicy {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("no-referrer") {
+            Ok(ReferrerPolicy::NoReferrer)
+        } else if name.try_match("no-referrer-when-downgrade") {
+            Ok(ReferrerPolicy::NoReferrerWhenDowngrade)
+        } else if name.try_match("same-origin") {
+            Ok(ReferrerPolicy::SameOrigin)
+        } else if name.try_match("origin") {
+            Ok(ReferrerPolicy::Origin)
+        } else if name.try_match("strict-origin") {
+            Ok(ReferrerPolicy::StrictOrigin)
+        } else if name.try_match("origin-when-cross-origin") {
+            Ok(ReferrerPolicy::OriginWhenCrossOrigin)
+        } else if name.try_match("strict-origin-when-cross-origin") {
+            Ok(ReferrerPolicy::StrictOriginWhenCrossOrigin)
+        } else if name.try_match("unsafe-url") {
+            Ok(ReferrerPolicy::UnsafeUrl)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for Image {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        match HyphenWord::peek_specific(s).as_deref() {
+            Some("url") => s.parse().map(Image::Url),
+            Some("image-set") => s.parse().map(Image::ImageSet),
+            _ => s.parse().map(|v| Image::Gradient(Box::new(v))),
+        }
+    }
+}
+
+impl Parse for Gradient {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        let repeating = name
+            .word
+            .as_deref()
+            .is_some_and(|word| word.starts_with("repeating-"));
+        let content;
+        let gradient = if name.try_match("linear-gradient")
+            || name.try_match("repeating-linear-gradient")
+        {
+            syn::parenthesized!(content in s);
+            let direction = try_parse(&content);
+            if direction.is_some() {
+                content.parse::<Token![,]>()?;
+            }
+            Gradient::Linear(LinearGradient {
+                repeating,
+                direction,
+                stops: content.parse()?,
+            })
+        } else if name.try_match("radial-gradient") || name.try_match("repeating-radial-gradient") {
+            syn::parenthesized!(content in s);
+            let (shape, size) = radial_shape(&content)?;
+            let position = gradient_position(&content)?;
+            if shape.is_some() || size.is_some() || position.is_some() {
+                content.parse::<Token![,]>()?;
+            }
+            Gradient::Radial(RadialGradient {
+                repeating,
+                shape,
+                size,
+                position,
+                stops: content.parse()?,
+            })
+        } else if name.try_match("conic-gradient") || name.try_match("repeating-conic-gradient") {
+            syn::parenthesized!(content in s);
+            let from = if HyphenWord::peek_specific(&content).as_deref() == Some("from") {
+                content.parse::<HyphenWord>()?;
+                Some(content.parse()?)
+            } else {
+                None
+            };
+            let position = gradient_position(&content)?;
+            if from.is_some() || position.is_some() {
+                content.parse::<Token![,]>()?;
+            }
+            Gradient::Conic(ConicGradient {
+                repeating,
+                from,
+                position,
+                stops: content.parse()?,
+            })
+        } else {
+            name.add_expected("url");
+            name.add_expected("image-set");
+            return Err(name.error());
+        };
+        if !content.is_empty() {
+            return Err(content.error("trailing tokens"));
+        }
+        Ok(gradient)
+    }
+}
+
+impl Parse for LineDirection {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if HyphenWord::peek_specific(s).as_deref() != Some("to") {
+            return s.parse().map(LineDirection::Angle);
+        }
+        let to: HyphenWord = s.parse()?;
+        let mut horizontal = try_parse(s);
+        let vertical = try_parse(s);
+        if horizontal.is_none() {
+            horizontal = try_parse(s);
+        }
+        if horizontal.is_none() && vertical.is_none() {
+            return Err(syn::Error::new(
+                to.span,
+                "expected a side or corner after `to`",
+            ));
+        }
+        Ok(LineDirection::To {
+            horizontal,
+            vertical,
+        })
+    }
+}
+
+impl Parse for HorizontalSide {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("left") {
+            Ok(HorizontalSide::Left)
+        } else if name.try_match("right") {
+            Ok(HorizontalSide::Right)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for VerticalSide {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("top") {
+            Ok(VerticalSide::Top)
+        } else if name.try_match("bottom") {
+            Ok(VerticalSide::Bottom)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for EndingShape {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("circle") {
+            Ok(EndingShape::Circle)
+        } else if name.try_match("ellipse") {
+            Ok(EndingShape::Ellipse)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+/// The shape and size of a radial gradient, in either order.
+fn radial_shape(s: ParseStream) -> syn::Result<(Option<EndingShape>, Option<RadialSize>)> {
+    let span = s.cursor().span();
+    let mut shape = None;
+    let mut size = None;
+    let mut lengths = None;
+    loop {
+        if shape.is_none() {
+            if let Some(v) = try_parse(s) {
+                shape = Some(v);
+                continue;
+            }
+        }
+        if size.is_none() && lengths.is_none() {
+            if let Some(v) = radial_extent(s) {
+                size = Some(v);
+                continue;
+            }
+            if let Some(first) = try_parse::<Calc>(s) {
+                lengths = Some((first, try_parse(s)));
+                continue;
+            }
+        }
+        break;
+    }
+    if let Some((first, second)) = lengths {
+        size = Some(
+            RadialSize::from_lengths(shape, first, second).map_err(|e| syn::Error::new(span, e))?,
+        );
+    }
+    Ok((shape, size))
+}
+
+/// A keyword for the size of a radial gradient.
+fn radial_extent(s: ParseStream) -> Option<RadialSize> {
+    let extent = match HyphenWord::peek_specific(s)?.as_str() {
+        "closest-side" => RadialSize::ClosestSide,
+        "closest-corner" => RadialSize::ClosestCorner,
+        "farthest-side" => RadialSize::FarthestSide,
+        "farthest-corner" => RadialSize::FarthestCorner,
+        _ => return None,
+    };
+    s.parse::<HyphenWord>().unwrap();
+    Some(extent)
+}
+
+/// The `at <position>` of a radial or conic gradient, if there is one.
+fn gradient_position(s: ParseStream) -> syn::Result<Option<PositionValue>> {
+    if HyphenWord::peek_specific(s).as_deref() != Some("at") {
+        return Ok(None);
+    }
+    s.parse::<HyphenWord>()?;
+    s.parse().map(Some)
+}
+
+impl Parse for AnglePercentage {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        match try_parse(s) {
+            Some(v) => Ok(AnglePercentage::Percentage(v)),
+            None => s.parse().map(AnglePercentage::Angle),
+        }
+    }
+}
+
+impl<P: Parse> Parse for ColorStopList<P> {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let span = s.cursor().span();
+        let items = Punctuated::<GradientItem<P>, Token![,]>::parse_separated_nonempty(s)?;
+        let items: Vec<_> = items.into_iter().collect();
+        if !ColorStopList::is_valid(&items) {
+            return Err(syn::Error::new(
+                span,
+                "expected at least two color stops, with at most one hint between each pair",
+            ));
+        }
+        Ok(ColorStopList(items))
+    }
+}
+
+impl<P: Parse> Parse for GradientItem<P> {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let color = match try_parse(s) {
+            Some(color) => color,
+            None => return s.parse().map(GradientItem::Hint),
+        };
+        let start = if finished_item(s) {
+            None
+        } else {
+            Some(s.parse()?)
+        };
+        let end = if start.is_none() || finished_item(s) {
+            None
+        } else {
+            Some(s.parse()?)
+        };
+        Ok(GradientItem::Stop { color, start, end })
+    }
+}
+
+impl Parse for ImageSet {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if !name.try_match("image-set") {
+            return Err(name.error());
+        }
+        let content;
+        syn::parenthesized!(content in s);
+        let options = content.parse()?;
+        if !content.is_empty() {
+            return Err(content.error("trailing tokens"));
+        }
+        Ok(ImageSet(options))
+    }
+}
+
+impl Parse for ImageSetOption {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let span = s.cursor().span();
+        let image = if s.peek(syn::LitStr) {
+            Image::Url(Url {
+                url: s.parse::<syn::LitStr>()?.value(),
+                modifiers: Vec::new(),
+            })
+        } else {
+            s.parse()?
+        };
+        if let Image::ImageSet(_) = image {
+            return Err(syn::Error::new(span, "`image-set()` can't be nested"));
+        }
+        let mut resolution = None;
+        let mut mime_type = None;
+        while !finished_item(s) {
+            if resolution.is_none() {
+                if let Some(v) = try_parse(s) {
+                    resolution = Some(v);
+                    continue;
+                }
+            }
+            let name: HyphenWord = s.parse()?;
+            if mime_type.is_none() && name.try_match("type") {
+                let content;
+                syn::parenthesized!(content in s);
+                mime_type = Some(content.parse::<syn::LitStr>()?.value());
+                if !content.is_empty() {
+                    return Err(content.error("trailing tokens"));
+                }
+            } else {
+                name.add_expected("resolution");
+                return Err(name.error());
+            }
+        }
+        Ok(ImageSetOption {
+            image: Box::new(image),
+            resolution,
+            mime_type,
+        })
+    }
+}
+
+impl Parse for Resolution {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let n: Number = s.parse()?;
+        if n.suffix == "x" {
+            Ok(Resolution::X(n.value))
+        } else if n.suffix == "dppx" {
+            Ok(Resolution::Dppx(n.value))
+        } else if n.suffix == "dpi" {
+            Ok(Resolution::Dpi(n.value))
+        } else if n.suffix == "dpcm" {
+            Ok(Resolution::Dpcm(n.value))
+        } else {
+            Err(syn::Error::new(
+                n.span,
+                "expected one of `x`, `dppx`, `dpi`, `dpcm` after number",
+            ))
+        }
+    }
+}
+
+impl Parse for Background {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let (first, mut color) = background_layer(s)?;
+        let mut rest = Vec::new();
+        while s.peek(Token![,]) {
+            if color.is_some() {
+                return Err(s.error("the background color can only be given in the last layer"));
+            }
+            s.parse::<Token![,]>()?;
+            let (layer, layer_color) = background_layer(s)?;
+            rest.push(layer);
+            color = layer_color;
+        }
+        Ok(Background {
+            layers: NonemptyCommaList { first, rest },
+            color,
+        })
+    }
+}
+
+/// A layer of the `background` shorthand, and the color if there is one.
+fn background_layer(s: ParseStream) -> syn::Result<(BackgroundLayer, Option<Color>)> {
+    if finished_item(s) {
+        return Err(s.error("expected a background layer"));
+    }
+    let mut layer = BackgroundLayer::default();
+    let mut color = None;
+    while !finished_item(s) {
+        if layer.image.is_none() {
+            if let Some(v) = try_parse(s) {
+                layer.image = Some(v);
+                continue;
+            }
+        }
+        if layer.position.is_none() {
+            if let Some(v) = try_parse(s) {
+                layer.position = Some(v);
+                if s.peek(Token![/]) {
+                    s.parse::<Token![/]>()?;
+                    layer.size = Some(s.parse()?);
+                }
+                continue;
+            }
+        }
+        if layer.repeat.is_none() {
+            if let Some(v) = try_parse(s) {
+                layer.repeat = Some(v);
+                continue;
+            }
+        }
+        if layer.attachment.is_none() {
+            if let Some(v) = try_parse(s) {
+                layer.attachment = Some(v);
+                continue;
+            }
+        }
+        if layer.clip.is_none() {
+            if let Some(v) = try_parse(s) {
+                if layer.origin.is_none() {
+                    layer.origin = Some(v);
+                } else {
+                    layer.clip = Some(v);
+                }
+                continue;
+            }
+        }
+        if color.is_none() {
+            if let Some(v) = try_parse(s) {
+                color = Some(v);
+                continue;
+            }
+        }
+        return Err(s.error("unexpected or repeated value in background layer"));
+    }
+    Ok((layer, color))
+}
+
+// position
+// ========
+
+impl Parse for PositionValue {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let span = s.cursor().span();
+        let mut values = vec![s.parse()?];
+        while values.len() < 4 {
+            match try_parse(s) {
+                Some(value) => values.push(value),
+                None => break,
+            }
+        }
+        PositionValue::from_values(values).map_err(|e| syn::Error::new(span, e))
+    }
+}
+
+impl<S: Parse> Parse for PositionComponent<S> {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if let Some(side) = try_parse(s) {
+            return Ok(PositionComponent::Side(side, try_parse(s)));
+        }
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        if name.try_match("center") {
+            s.advance_to(&fork);
+            return Ok(PositionComponent::Center);
+        }
+        s.parse().map(PositionComponent::Offset).map_err(|_| {
+            name.add_expected("side");
+            name.add_expected("length");
+            name.add_expected("percentage");
+            name.error()
+        })
+    }
+}
+
+// transform
+// =========
+
+impl Parse for Angle {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let neg = if s.peek(Token![-]) {
+            s.parse::<Token![-]>()?;
+            -1.0
+        } else {
+            1.0
+        };
+        let n: Number = s.parse()?;
+        if n.suffix == "deg" {
+            Ok(Angle::Deg(n.value * neg))
+        } else if n.suffix == "grad" {
+            Ok(Angle::Grad(n.value * neg))
+        } else if n.suffix == "rad" {
+            Ok(Angle::Rad(n.value * neg))
+        } else if n.suffix == "turn" {
+            Ok(Angle::Turn(n.value * neg))
+        } else if n.suffix.is_empty() && n.value == 0.0 {
+            Ok(Angle::Zero)
+        } else {
+            Err(syn::Error::new(
+                n.span,
+                "expected one of `deg`, `grad`, `rad`, `turn` after number, or 0",
+            ))
+        }
+    }
+}
+
+impl Parse for TransformList {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        if name.try_match("none") {
+            s.advance_to(&fork);
+            return Ok(TransformList::none());
+        }
+        let mut functions = vec![s.parse()?];
+        while !finished_rule(s) {
+            functions.push(s.parse()?);
+        }
+        Ok(TransformList(functions))
+    }
+}
+
+impl Parse for TransformFunction {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        let content;
+        let function = if name.try_match("matrix") {
+            syn::parenthesized!(content in s);
+            TransformFunction::Matrix(numbers(&content)?)
+        } else if name.try_match("matrix3d") {
+            syn::parenthesized!(content in s);
+            TransformFunction::Matrix3d(numbers(&content)?)
+        } else if name.try_match("translate") {
+            syn::parenthesized!(content in s);
+            TransformFunction::Translate(content.parse()?, optional_arg(&content, Calc::parse)?)
+        } else if name.try_match("translateX") {
+            syn::parenthesized!(content in s);
+            TransformFunction::TranslateX(content.parse()?)
+        } else if name.try_match("translateY") {
+            syn::parenthesized!(content in s);
+            TransformFunction::TranslateY(content.parse()?)
+        } else if name.try_match("translateZ") {
+            syn::parenthesized!(content in s);
+            TransformFunction::TranslateZ(content.parse()?)
+        } else if name.try_match("translate3d") {
+            syn::parenthesized!(content in s);
+            let x = content.parse()?;
+            content.parse::<Token![,]>()?;
+            let y = content.parse()?;
+            content.parse::<Token![,]>()?;
+            TransformFunction::Translate3d(x, y, content.parse()?)
+        } else if name.try_match("scale") {
+            syn::parenthesized!(content in s);
+            TransformFunction::Scale(
+                number_or_percentage(&content)?,
+                optional_arg(&content, number_or_percentage)?,
+            )
+        } else if name.try_match("scaleX") {
+            syn::parenthesized!(content in s);
+            TransformFunction::ScaleX(number_or_percentage(&content)?)
+        } else if name.try_match("scaleY") {
+            syn::parenthesized!(content in s);
+            TransformFunction::ScaleY(number_or_percentage(&content)?)
+        } else if name.try_match("scaleZ") {
+            syn::parenthesized!(content in s);
+            TransformFunction::ScaleZ(number_or_percentage(&content)?)
+        } else if name.try_match("scale3d") {
+            syn::parenthesized!(content in s);
+            let x = number_or_percentage(&content)?;
+            content.parse::<Token![,]>()?;
+            let y = number_or_percentage(&content)?;
+            content.parse::<Token![,]>()?;
+            TransformFunction::Scale3d(x, y, number_or_percentage(&content)?)
+        } else if name.try_match("rotate") {
+            syn::parenthesized!(content in s);
+            TransformFunction::Rotate(content.parse()?)
+        } else if name.try_match("rotateX") {
+            syn::parenthesized!(content in s);
+            TransformFunction::RotateX(content.parse()?)
+        } else if name.try_match("rotateY") {
+            syn::parenthesized!(content in s);
+            TransformFunction::RotateY(content.parse()?)
+        } else if name.try_match("rotateZ") {
+            syn::parenthesized!(content in s);
+            TransformFunction::RotateZ(content.parse()?)
+        } else if name.try_match("rotate3d") {
+            syn::parenthesized!(content in s);
+            let [x, y, z] = numbers(&content)?;
+            content.parse::<Token![,]>()?;
+            TransformFunction::Rotate3d(x, y, z, content.parse()?)
+        } else if name.try_match("skew") {
+            syn::parenthesized!(content in s);
+            TransformFunction::Skew(content.parse()?, optional_arg(&content, Angle::parse)?)
+        } else if name.try_match("skewX") {
+            syn::parenthesized!(content in s);
+            TransformFunction::SkewX(content.parse()?)
+        } else if name.try_match("skewY") {
+            syn::parenthesized!(content in s);
+            TransformFunction::SkewY(content.parse()?)
+        } else if name.try_match("perspective") {
+            syn::parenthesized!(content in s);
+            let fork = content.fork();
+            let word: HyphenWord = fork.parse()?;
+            if word.try_match("none") {
+                content.advance_to(&fork);
+                TransformFunction::Perspective(None)
+            } else {
+                TransformFunction::Perspective(Some(non_negative_length(&content)?))
+            }
+        } else {
+            return Err(name.error());
+        };
+        if !content.is_empty() {
+            return Err(content.error("trailing tokens"));
+        }
+        Ok(function)
+    }
+}
+
+/// A number that may have a leading `-`.
+fn signed_number(s: ParseStream) -> syn::Result<f64> {
+    let neg = if s.peek(Token![-]) {
+        s.parse::<Token![-]>()?;
+        -1.0
+    } else {
+        1.0
+    };
+    let n: Number = s.parse()?;
+    n.empty_suffix()?;
+    Ok(n.value * neg)
+}
+
+/// A number or a percentage, with percentages converted to numbers (so `50%` is `0.5`).
+fn number_or_percentage(s: ParseStream) -> syn::Result<f64> {
+    let neg = if s.peek(Token![-]) {
+        s.parse::<Token![-]>()?;
+        -1.0
+    } else {
+        1.0
+    };
+    let n: Number = s.parse()?;
+    if n.suffix == "%" {
+        Ok(n.value * neg / 100.0)
+    } else {
+        n.empty_suffix()?;
+        Ok(n.value * neg)
+    }
+}
+
+/// `N` comma-separated numbers.
+fn numbers<const N: usize>(s: ParseStream) -> syn::Result<[f64; N]> {
+    let mut out = [0.0; N];
+    for (idx, value) in out.iter_mut().enumerate() {
+        if idx > 0 {
+            s.parse::<Token![,]>()?;
+        }
+        *value = signed_number(s)?;
+    }
+    Ok(out)
+}
+
+/// An optional argument, after a comma.
+fn optional_arg<T>(
+    s: ParseStream,
+    parse: fn(ParseStream) -> syn::Result<T>,
+) -> syn::Result<Option<T>> {
+    if s.peek(Token![,]) {
+        s.parse::<Token![,]>()?;
+        parse(s).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+fn non_negative_length(s: ParseStream) -> syn::Result<Length> {
+    let span = s.cursor().span();
+    let length: Length = s.parse()?;
+    if length.is_negative() {
+        return Err(syn::Error::new(span, "length can't be negative"));
+    }
+    Ok(length)
+}
+
+impl Parse for TransformOrigin {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let span = s.cursor().span();
+        let first = s.parse()?;
+        let second = if finished_rule(s) {
+            None
+        } else {
+            Some(s.parse()?)
+        };
+        let (x, y) =
+            TransformOrigin::from_values(first, second).map_err(|e| syn::Error::new(span, e))?;
+        let z = if finished_rule(s) {
+            None
+        } else {
+            Some(s.parse()?)
+        };
+        Ok(TransformOrigin { x, y, z })
+    }
+}
+
+impl Parse for OriginValue {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        let value = if name.try_match("left") {
+            OriginValue::Left
+        } else if name.try_match("center") {
+            OriginValue::Center
+        } else if name.try_match("right") {
+            OriginValue::Right
+        } else if name.try_match("top") {
+            OriginValue::Top
+        } else if name.try_match("bottom") {
+            OriginValue::Bottom
+        } else {
+            return s.parse().map(OriginValue::Offset).map_err(|_| {
+                name.add_expected("length");
+                name.add_expected("percentage");
+                name.error()
+            });
+        };
+        s.advance_to(&fork);
+        Ok(value)
+    }
+}
+
+impl Parse for TransformStyle {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        // `preserve-3d` isn't a `HyphenWord`, because `3d` is a number literal in rust.
+        let name = s.call(Ident::parse_any)?;
+        if name == "flat" {
+            return Ok(TransformStyle::Flat);
+        } else if name == "preserve" && s.peek(Token![-]) && s.peek2(syn::LitInt) {
+            s.parse::<Token![-]>()?;
+            let lit: syn::LitInt = s.parse()?;
+            if lit.to_string() == "3d" {
+                return Ok(TransformStyle::Preserve3d);
+            }
+        }
+        Err(syn::Error::new(
+            name.span(),
+            "expected one of `flat`, `preserve-3d`",
+        ))
+    }
+}
+
+impl Parse for Perspective {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        if name.try_match("none") {
+            s.advance_to(&fork);
+            Ok(Perspective::None)
+        } else {
+            non_negative_length(s).map(Perspective::Length)
+        }
+    }
+}
+
+impl Parse for BackfaceVisibility {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("visible") {
+            Ok(BackfaceVisibility::Visible)
+        } else if name.try_match("hidden") {
+            Ok(BackfaceVisibility::Hidden)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for Translate {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        if name.try_match("none") {
+            s.advance_to(&fork);
+            return Ok(Translate::None);
+        }
+        let x = s.parse()?;
+        let y = if finished_rule(s) {
+            None
+        } else {
+            Some(s.parse()?)
+        };
+        let z = if y.is_none() || finished_rule(s) {
+            None
+        } else {
+            Some(s.parse()?)
+        };
+        Ok(Translate::Offset { x, y, z })
+    }
+}
+
+impl Parse for Rotate {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        if name.try_match("none") {
+            s.advance_to(&fork);
+            return Ok(Rotate::None);
+        }
+        // A unitless number starts an axis, not an angle.
+        let fork = s.fork();
+        if name.word.is_some() || signed_number(&fork).is_ok() {
+            let axis = s.parse()?;
+            return Ok(Rotate::Axis(axis, s.parse()?));
+        }
+        let angle = s.parse()?;
+        if finished_rule(s) {
+            Ok(Rotate::Angle(angle))
+        } else {
+            Ok(Rotate::Axis(s.parse()?, angle))
+        }
+    }
+}
+
+impl Parse for RotateAxis {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        let axis = if name.try_match("x") {
+            RotateAxis::X
+        } else if name.try_match("y") {
+            RotateAxis::Y
+        } else if name.try_match("z") {
+            RotateAxis::Z
+        } else {
+            name.add_expected("number");
+            if name.word.is_some() {
+                return Err(name.error());
+            }
+            let x = signed_number(s)?;
+            let y = signed_number(s)?;
+            return Ok(RotateAxis::Vector(x, y, signed_number(s)?));
+        };
+        s.advance_to(&fork);
+        Ok(axis)
+    }
+}
+
+impl Parse for Scale {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let fork = s.fork();
+        let name: HyphenWord = fork.parse()?;
+        if name.try_match("none") {
+            s.advance_to(&fork);
+            return Ok(Scale::None);
+        }
+        let x = number_or_percentage(s)?;
+        let y = if finished_rule(s) {
+            None
+        } else {
+            Some(number_or_percentage(s)?)
+        };
+        let z = if y.is_none() || finished_rule(s) {
+            None
+        } else {
+            Some(number_or_percentage(s)?)
+        };
+        Ok(Scale::Factors { x, y, z })
+    }
+}
+
+// animation
+// =========
+
+/// Parse an optional `-`, returning `-1.0` if it is present and `1.0` if it isn't.
+fn sign(s: ParseStream) -> syn::Result<f64> {
+    if s.peek(Token![-]) {
+        s.parse::<Token![-]>()?;
+        Ok(-1.0)
+    } else {
+        Ok(1.0)
+    }
+}
+
+/// Parse a `T` if possible, otherwise leave the stream where it was.
+fn try_parse<T: Parse>(s: ParseStream) -> Option<T> {
+    let fork = s.fork();
+    match fork.parse() {
+        Ok(v) => {
+            s.advance_to(&fork);
+            Some(v)
+        }
+        Err(_) => None,
+    }
+}
+
+/// Whether we are at the end of an item in a comma-separated list.
+fn finished_item(s: ParseStream) -> bool {
+    finished_rule(s) || s.peek(Token![,])
+}
+
+impl Parse for Time {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let sign = sign(s)?;
+        let n: Number = s.parse()?;
+        if n.suffix == "s" {
+            Ok(Time::S(n.value * sign))
+        } else if n.suffix == "ms" {
+            Ok(Time::Ms(n.value * sign))
+        } else {
+            Err(syn::Error::new(n.span, "expected `s` or `ms` after number"))
+        }
+    }
+}
+
+/// Check that a `<time>` used as a duration isn't negative.
+fn non_negative_time(time: Time, span: Span) -> syn::Result<Time> {
+    if time.to_seconds() < 0.0 {
+        return Err(syn::Error::new(span, "duration can't be negative"));
+    }
+    Ok(time)
+}
+
+/// Parse the value of `animation-duration` or `transition-duration`.
+fn durations(s: ParseStream) -> syn::Result<NonemptyCommaList<Time>> {
+    let punctuated = Punctuated::<Time, Token![,]>::parse_separated_nonempty_with(s, |s| {
+        let span = s.cursor().span();
+        non_negative_time(s.parse()?, span)
+    })?;
+    let mut iter = punctuated.into_iter();
+    Ok(NonemptyCommaList {
+        first: iter.next().unwrap(),
+        rest: iter.collect(),
+    })
+}
+
+/// Parse the value of `transition`, where `none` can only be used if there is one transition.
+fn transitions(s: ParseStream) -> syn::Result<NonemptyCommaList<SingleTransition>> {
+    let span = s.cursor().span();
+    let list: NonemptyCommaList<SingleTransition> = s.parse()?;
+    let is_none = |t: &SingleTransition| t.property == Some(TransitionProperty::None);
+    if !list.rest.is_empty() && (is_none(&list.first) || list.rest.iter().any(is_none)) {
+        return Err(syn::Error::new(
+            span,
+            "`none` can only be used when there is a single transition",
+        ));
+    }
+    Ok(list)
+}
+
+impl Parse for EasingFunction {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("linear") {
+            if !s.peek(syn::token::Paren) {
+                return Ok(EasingFunction::Linear);
+            }
+            let content;
+            syn::parenthesized!(content in s);
+            let span = content.cursor().span();
+            let stops = Punctuated::<LinearStop, Token![,]>::parse_separated_nonempty(&content)?;
+            if !content.is_empty() {
+                return Err(content.error("trailing tokens"));
+            }
+            if stops.len() < 2 {
+                return Err(syn::Error::new(span, "`linear()` needs at least two stops"));
+            }
+            Ok(EasingFunction::LinearStops(stops.into_iter().collect()))
+        } else if name.try_match("ease") {
+            Ok(EasingFunction::Ease)
+        } else if name.try_match("ease-in") {
+            Ok(EasingFunction::EaseIn)
+        } else if name.try_match("ease-out") {
+            Ok(EasingFunction::EaseOut)
+        } else if name.try_match("ease-in-out") {
+            Ok(EasingFunction::EaseInOut)
+        } else if name.try_match("cubic-bezier") {
+            let content;
+            syn::parenthesized!(content in s);
+            let span = content.cursor().span();
+            let [x1, y1, x2, y2] = numbers(&content)?;
+            if !content.is_empty() {
+                return Err(content.error("trailing tokens"));
+            }
+            if !((0.0..=1.0).contains(&x1) && (0.0..=1.0).contains(&x2)) {
+                return Err(syn::Error::new(
+                    span,
+                    "the x values of `cubic-bezier()` must be between 0 and 1",
+                ));
+            }
+            Ok(EasingFunction::CubicBezier(x1, y1, x2, y2))
+        } else if name.try_match("step-start") {
+            Ok(EasingFunction::StepStart)
+        } else if name.try_match("step-end") {
+            Ok(EasingFunction::StepEnd)
+        } else if name.try_match("steps") {
+            let content;
+            syn::parenthesized!(content in s);
+            let span = content.cursor().span();
+            let count = integer(&content, 1..)?;
+            let position = if content.peek(Token![,]) {
+                content.parse::<Token![,]>()?;
+                content.parse()?
+            } else {
+                StepPosition::JumpEnd
+            };
+            if !content.is_empty() {
+                return Err(content.error("trailing tokens"));
+            }
+            if position == StepPosition::JumpNone && count < 2 {
+                return Err(syn::Error::new(
+                    span,
+                    "`steps()` with `jump-none` needs at least 2 steps",
+                ));
+            }
+            Ok(EasingFunction::Steps(count, position))
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for StepPosition {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("jump-start") || name.try_match("start") {
+            Ok(StepPosition::JumpStart)
+        } else if name.try_match("jump-end") || name.try_match("end") {
+            Ok(StepPosition::JumpEnd)
+        } else if name.try_match("jump-none") {
+            Ok(StepPosition::JumpNone)
+        } else if name.try_match("jump-both") {
+            Ok(StepPosition::JumpBoth)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for LinearStop {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let span = s.cursor().span();
+        let mut output = None;
+        let mut inputs = Vec::new();
+        while !(s.is_empty() || s.peek(Token![,])) {
+            let sign = sign(s)?;
+            let n: Number = s.parse()?;
+            if n.suffix == "%" {
+                inputs.push(Percentage(n.value * sign));
+            } else if output.is_none() {
+                n.empty_suffix()?;
+                output = Some(n.value * sign);
+            } else {
+                return Err(syn::Error::new(n.span, "expected percentage"));
+            }
+        }
+        match (output, inputs.len()) {
+            (Some(output), 0..=2) => Ok(LinearStop {
+                output,
+                start: inputs.first().copied(),
+                end: inputs.get(1).copied(),
+            }),
+            (Some(_), _) => Err(syn::Error::new(
+                span,
+                "a `linear()` stop can have at most two percentages",
+            )),
+            (None, _) => Err(syn::Error::new(span, "expected number")),
+        }
+    }
+}
+
+impl Parse for TransitionProperty {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("none") {
+            Ok(TransitionProperty::None)
+        } else if name.try_match("all") {
+            Ok(TransitionProperty::All)
+        } else if let Some(word) = name.word {
+            Ok(TransitionProperty::Property(word))
+        } else {
+            name.add_expected("property name");
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for TransitionBehavior {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("normal") {
+            Ok(TransitionBehavior::Normal)
+        } else if name.try_match("allow-discrete") {
+            Ok(TransitionBehavior::AllowDiscrete)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for SingleTransition {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let mut out = SingleTransition::default();
+        let span = s.cursor().span();
+        while !finished_item(s) {
+            let word = HyphenWord::peek_specific(s);
+            if out.delay.is_none() {
+                let time_span = s.cursor().span();
+                if let Some(time) = try_parse(s) {
+                    if out.duration.is_none() {
+                        out.duration = Some(non_negative_time(time, time_span)?);
+                    } else {
+                        out.delay = Some(time);
+                    }
+                    continue;
+                }
+            }
+            if out.timing_function.is_none() && word.as_deref().is_some_and(EasingFunction::is_name)
+            {
+                out.timing_function = Some(s.parse()?);
+            } else if out.behavior.is_none()
+                && matches!(word.as_deref(), Some("normal") | Some("allow-discrete"))
+            {
+                out.behavior = Some(s.parse()?);
+            } else if out.property.is_none() && word.is_some() {
+                out.property = Some(s.parse()?);
+            } else {
+                return Err(s.error("unexpected value in transition"));
+            }
+        }
+        if out == SingleTransition::default() {
+            return Err(syn::Error::new(span, "expected transition"));
+        }
+        Ok(out)
+    }
+}
+
+impl Parse for AnimationName {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if s.peek(syn::LitStr) {
+            return Ok(AnimationName::Name(s.parse::<syn::LitStr>()?.value()));
+        }
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("none") {
+            Ok(AnimationName::None)
+        } else if let Some(word) = name.word {
+            Ok(AnimationName::Name(word))
+        } else {
+            name.add_expected("animation name");
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for AnimationIterationCount {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if HyphenWord::peek(s) {
+            let name: HyphenWord = s.parse()?;
+            if name.try_match("infinite") {
+                return Ok(AnimationIterationCount::Infinite);
+            }
+            name.add_expected("number");
+            return Err(name.error());
+        }
+        let n: Number = s.parse()?;
+        n.empty_suffix()?;
+        if n.value < 0.0 {
+            return Err(syn::Error::new(n.span, "iteration count can't be negative"));
+        }
+        Ok(AnimationIterationCount::Count(n.value))
+    }
+}
+
+impl Parse for AnimationDirection {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("normal") {
+            Ok(AnimationDirection::Normal)
+        } else if name.try_match("reverse") {
+            Ok(AnimationDirection::Reverse)
+        } else if name.try_match("alternate") {
+            Ok(AnimationDirection::Alternate)
+        } else if name.try_match("alternate-reverse") {
+            Ok(AnimationDirection::AlternateReverse)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for AnimationFillMode {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("none") {
+            Ok(AnimationFillMode::None)
+        } else if name.try_match("forwards") {
+            Ok(AnimationFillMode::Forwards)
+        } else if name.try_match("backwards") {
+            Ok(AnimationFillMode::Backwards)
+        } else if name.try_match("both") {
+            Ok(AnimationFillMode::Both)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for AnimationPlayState {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let name: HyphenWord = s.parse()?;
+        if name.try_match("running") {
+            Ok(AnimationPlayState::Running)
+        } else if name.try_match("paused") {
+            Ok(AnimationPlayState::Paused)
+        } else {
+            Err(name.error())
+        }
+    }
+}
+
+impl Parse for SingleAnimation {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let mut out = SingleAnimation::default();
+        let span = s.cursor().span();
+        while !finished_item(s) {
+            if out.delay.is_none() {
+                let time_span = s.cursor().span();
+                if let Some(time) = try_parse(s) {
+                    if out.duration.is_none() {
+                        out.duration = Some(non_negative_time(time, time_span)?);
+                    } else {
+                        out.delay = Some(time);
+                    }
+                    continue;
+                }
+            }
+            let word = HyphenWord::peek_specific(s);
+            if out.timing_function.is_none() && word.as_deref().is_some_and(EasingFunction::is_name)
+            {
+                out.timing_function = Some(s.parse()?);
+                continue;
             }
-        };
-
-        // spread
-        let fork = s.fork();
-        match fork.parse::<Length>() {
-            Ok(spread) => {
-                s.advance_to(&fork);
-
-                Ok(ShadowLength::OffsetsBlurSpread {
-                    horizontal,
-                    vertical,
-                    blur,
-                    spread,
-                })
+            // A keyword is only the name if it isn't valid for anything else.
+            if out.iteration_count.is_none() {
+                if let Some(v) = try_parse(s) {
+                    out.iteration_count = Some(v);
+                    continue;
+                }
             }
-            Err(_) => Ok(ShadowLength::OffsetsBlur {
-                horizontal,
-                vertical,
-                blur,
-            }),
+            if out.direction.is_none() {
+                if let Some(v) = try_parse(s) {
+                    out.direction = Some(v);
+                    continue;
+                }
+            }
+            if out.fill_mode.is_none() {
+                if let Some(v) = try_parse(s) {
+                    out.fill_mode = Some(v);
+                    continue;
+                }
+            }
+            if out.play_state.is_none() {
+                if let Some(v) = try_parse(s) {
+                    out.play_state = Some(v);
+                    continue;
+                }
+            }
+            if out.name.is_none() {
+                out.name = Some(s.parse()?);
+                continue;
+            }
+            return Err(s.error("unexpected value in animation"));
+        }
+        if out == SingleAnimation::default() {
+            return Err(syn::Error::new(span, "expected animation"));
         }
+        Ok(out)
     }
 }
 
-impl Parse for TextAlign {
+impl Parse for Keyframe {
     fn parse(s: ParseStream) -> syn::Result<Self> {
-        let word: HyphenWord = s.parse()?;
-        if word.try_match("left") {
-            Ok(TextAlign::Left)
-        } else if word.try_match("right") {
-            Ok(TextAlign::Right)
-        } else if word.try_match("center") {
-            Ok(TextAlign::Center)
-        } else if word.try_match("justify") {
-            Ok(TextAlign::Justify)
-        } else {
-            Err(word.error())
+        let mut selectors = Vec::new();
+        loop {
+            let fork = s.fork();
+            let name: HyphenWord = fork.parse()?;
+            if name.try_match("from") {
+                s.advance_to(&fork);
+                selectors.push(Percentage(0.0));
+            } else if name.try_match("to") {
+                s.advance_to(&fork);
+                selectors.push(Percentage(100.0));
+            } else {
+                let span = s.cursor().span();
+                let selector: Percentage = s.parse().map_err(|_| {
+                    name.add_expected("percentage");
+                    name.error()
+                })?;
+                if !(0.0..=100.0).contains(&selector.0) {
+                    return Err(syn::Error::new(
+                        span,
+                        "keyframe selectors must be between 0% and 100%",
+                    ));
+                }
+                selectors.push(selector);
+            }
+            if !s.peek(Token![,]) {
+                break;
+            }
+            s.parse::<Token![,]>()?;
         }
+        let content;
+        syn::braced!(content in s);
+        Ok(Keyframe {
+            selectors,
+            styles: content.parse()?,
+        })
     }
 }
 
@@ -1921,7 +5264,7 @@ impl Parse for Color {
 }
 
 fn parse_hex_color(s: ParseStream) -> syn::Result<Color> {
-    const ERR_MSG: &'static str = "to avoid confusing rust, please enclose hex colors in `\"`";
+    const ERR_MSG: &str = "to avoid confusing rust, please enclose hex colors in `\"`";
     s.parse::<Token![#]>()?;
     if !(s.peek(syn::LitStr) || s.peek(Ident)) {
         return Err(s.error(ERR_MSG));
@@ -1941,7 +5284,7 @@ fn parse_hsl_color(s: ParseStream, with_alpha: bool) -> syn::Result<Color> {
     let n: Number = content.parse()?;
     n.empty_suffix()?;
     let hue = n.value;
-    if hue < 0.0 || hue >= 360.0 {
+    if !(0.0..360.0).contains(&hue) {
         return Err(syn::Error::new(
             n.span,
             "hue should be in the range `0 <= hue < 360`",
@@ -1956,7 +5299,7 @@ fn parse_hsl_color(s: ParseStream, with_alpha: bool) -> syn::Result<Color> {
         ));
     }
     let sat = n.value;
-    if sat < 0.0 || sat > 100.0 {
+    if !(0.0..=100.0).contains(&sat) {
         return Err(syn::Error::new(
             n.span,
             "saturation should be in the range `0 <= sat < 100`",
@@ -1971,7 +5314,7 @@ fn parse_hsl_color(s: ParseStream, with_alpha: bool) -> syn::Result<Color> {
         ));
     }
     let light = n.value;
-    if light < 0.0 || light > 100.0 {
+    if !(0.0..=100.0).contains(&light) {
         return Err(syn::Error::new(
             n.span,
             "lightness should be in the range `0 <= light < 100`",
@@ -1990,7 +5333,7 @@ fn parse_hsl_color(s: ParseStream, with_alpha: bool) -> syn::Result<Color> {
     let n: Number = content.parse()?;
     n.empty_suffix()?;
     let alpha = n.value;
-    if alpha < 0.0 || alpha > 1.0 {
+    if !(0.0..=1.0).contains(&alpha) {
         return Err(syn::Error::new(
             n.span,
             "alpha should be in the range `0 <= alpha < 1`",
@@ -2022,6 +5365,209 @@ fn test_color() {
     }
 }
 
+// Stylesheets
+// ===========
+
+impl Parse for Stylesheet {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let mut rules = Vec::new();
+        while !s.is_empty() {
+            rules.push(s.parse()?);
+        }
+        Ok(Stylesheet { rules })
+    }
+}
+
+impl Parse for Rule {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        if s.peek(Token![@]) {
+            Ok(Rule::At(s.parse()?))
+        } else {
+            Ok(Rule::Qualified(s.parse()?))
+        }
+    }
+}
+
+impl Parse for QualifiedRule {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let selectors = s.parse()?;
+        let content;
+        syn::braced!(content in s);
+        Ok(QualifiedRule {
+            selectors,
+            styles: content.parse()?,
+        })
+    }
+}
+
+impl Parse for AtRule {
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        s.parse::<Token![@]>()?;
+        let vendor = if s.peek(Token![-]) {
+            s.parse::<Token![-]>()?;
+            "-"
+        } else {
+            ""
+        };
+        let name = match s.parse::<HyphenWord>()?.word {
+            Some(word) => format!("{}{}", vendor, word),
+            None => return Err(s.error("expected at-rule name")),
+        };
+        let prelude = prelude(s)?;
+        let block = if s.peek(Token![;]) {
+            s.parse::<Token![;]>()?;
+            None
+        } else if s.is_empty() {
+            None
+        } else {
+            let content;
+            syn::braced!(content in s);
+            Some(if AtRule::contains_rules(&name) {
+                AtRuleBlock::Rules(content.parse::<Stylesheet>()?.rules)
+            } else if AtRule::contains_keyframes(&name) {
+                let mut keyframes = Vec::new();
+                while !content.is_empty() {
+                    keyframes.push(content.parse()?);
+                }
+                AtRuleBlock::Keyframes(keyframes)
+            } else {
+                AtRuleBlock::Raw(css_from_tokens(content.parse()?))
+            })
+        };
+        Ok(AtRule {
+            name,
+            prelude,
+            block,
+        })
+    }
+}
+
+impl Parse for SelectorList {
+    /// The selectors are written out as css text (see `css_from_tokens`), and then parsed with the
+    /// runtime parser.
+    fn parse(s: ParseStream) -> syn::Result<Self> {
+        let span = s.cursor().span();
+        // A string can be used for selectors that can't be written with rust tokens.
+        let fork = s.fork();
+        let text = match fork.parse::<syn::LitStr>() {
+            Ok(lit) if fork.is_empty() || fork.peek(syn::token::Brace) => {
+                s.advance_to(&fork);
+                lit.value()
+            }
+            _ => prelude(s)?,
+        };
+        text.parse().map_err(|e: crate::ParseError| {
+            syn::Error::new(span, format!("{} in selector `{}`", e.message(), text))
+        })
+    }
+}
+
+/// Take the tokens up to the next `{}` block or `;`, and write them out as css.
+fn prelude(s: ParseStream) -> syn::Result<String> {
+    let mut tokens = TokenStream::new();
+    while !(s.is_empty() || s.peek(syn::token::Brace) || s.peek(Token![;])) {
+        tokens.extend(Some(s.parse::<TokenTree>()?));
+    }
+    Ok(css_from_tokens(tokens))
+}
+
+/// Write out rust tokens as css text, for the parts of a stylesheet that are kept as text.
+///
+/// Rust's tokenizer throws away whitespace, so it has to be guessed. Tokens are separated by a
+/// space, except around punctuation that is usually part of a word or compound selector (`-`,
+/// `.`, `#`, `:`, ...) and before the `(` of a function. This means `div .class` is written out
+/// as `div.class`: use `div *.class` or a string literal if you need the descendant combinator.
+fn css_from_tokens(tokens: TokenStream) -> String {
+    let mut out = String::new();
+    let mut prev: Option<TokenTree> = None;
+    // Whether the previous token was an identifier directly after a `:`, like `:not`.
+    let mut pseudo = false;
+    for tok in tokens {
+        if let Some(prev) = &prev {
+            if space_between(prev, &tok, pseudo) {
+                out.push(' ');
+            }
+        }
+        match &tok {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::None => ("", ""),
+                };
+                let inner = if group.delimiter() == Delimiter::Bracket {
+                    attribute_from_tokens(group.stream())
+                } else {
+                    css_from_tokens(group.stream())
+                };
+                write!(out, "{}{}{}", open, inner, close).unwrap();
+            }
+            TokenTree::Literal(lit) => match syn::Lit::new(lit.clone()) {
+                // A unit that rust can't tokenize, like `1.5"em"`.
+                syn::Lit::Str(unit) if prev.as_ref().is_some_and(is_number) => {
+                    out.push_str(&unit.value())
+                }
+                _ => write!(out, "{}", lit).unwrap(),
+            },
+            tok => write!(out, "{}", tok).unwrap(),
+        }
+        pseudo = matches!(
+            (&prev, &tok),
+            (Some(TokenTree::Punct(colon)), TokenTree::Ident(_)) if colon.as_char() == ':'
+        );
+        prev = Some(tok);
+    }
+    out
+}
+
+fn space_between(prev: &TokenTree, next: &TokenTree, pseudo: bool) -> bool {
+    match (prev, next) {
+        (TokenTree::Punct(p), _) if p.as_char() == ',' || p.as_char() == ';' => true,
+        (TokenTree::Punct(p), _) if p.spacing() == Spacing::Joint => false,
+        (TokenTree::Punct(p), _) if "-.#:@|=!".contains(p.as_char()) => false,
+        (_, TokenTree::Punct(p)) if "-.#:|=%,;".contains(p.as_char()) => false,
+        (_, TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => false,
+        (TokenTree::Ident(ident), TokenTree::Group(group))
+            if group.delimiter() == Delimiter::Parenthesis =>
+        {
+            // In media queries these keywords can be followed by a condition in brackets.
+            !pseudo && matches!(ident.to_string().as_str(), "and" | "or" | "not" | "only")
+        }
+        (TokenTree::Literal(number), TokenTree::Literal(unit)) => {
+            !(is_number_literal(number) && unit.to_string().starts_with('"'))
+        }
+        _ => true,
+    }
+}
+
+/// Like `css_from_tokens`, but for the inside of an attribute selector like `[href~="a" i]`, where
+/// only words are separated by spaces.
+fn attribute_from_tokens(tokens: TokenStream) -> String {
+    let mut out = String::new();
+    let mut prev_word = false;
+    for tok in tokens {
+        let word = !matches!(tok, TokenTree::Punct(_));
+        if prev_word && word {
+            out.push(' ');
+        }
+        write!(out, "{}", tok).unwrap();
+        prev_word = word;
+    }
+    out
+}
+
+fn is_number(tok: &TokenTree) -> bool {
+    matches!(tok, TokenTree::Literal(lit) if is_number_literal(lit))
+}
+
+fn is_number_literal(lit: &Literal) -> bool {
+    matches!(
+        syn::Lit::new(lit.clone()),
+        syn::Lit::Int(_) | syn::Lit::Float(_)
+    )
+}
+
 // Util
 // ====
 
@@ -2051,8 +5597,8 @@ where
             Ok(second) => {
                 s.advance_to(&fork);
                 SingleOrDouble::Double {
-                    vert: first,
-                    horiz: second,
+                    horiz: first,
+                    vert: second,
                 }
             }
             Err(_) => SingleOrDouble::Single(first),
@@ -2076,7 +5622,7 @@ struct Number {
 
 impl Number {
     fn empty_suffix(&self) -> syn::Result<()> {
-        if self.suffix != "" {
+        if !self.suffix.is_empty() {
             Err(syn::Error::new(
                 self.span,
                 "unexpected characters after number",
@@ -2176,7 +5722,7 @@ impl HyphenWord {
     }
 
     pub fn try_match(&self, other: &str) -> bool {
-        if Some(other) == self.word.as_ref().map(|s| s.as_str()) {
+        if Some(other) == self.word.as_deref() {
             true
         } else {
             self.tried.add_literal(other);
@@ -2202,7 +5748,7 @@ impl HyphenWord {
     pub fn peek_specific(s: ParseStream) -> Option<String> {
         let fork = s.fork();
         match HyphenWord::parse(&fork) {
-            Ok(hw) => Some(hw.word.unwrap()),
+            Ok(hw) => hw.word,
             Err(_) => None,
         }
     }
@@ -2276,9 +5822,9 @@ impl TryList {
 }
 
 /// Whether we are at the end of a rule. Either the stream will be empty, or there will be a
-/// semi-colon.
+/// semi-colon or the `!` of `!important`.
 fn finished_rule(s: ParseStream) -> bool {
-    s.is_empty() || s.peek(Token![;])
+    s.is_empty() || s.peek(Token![;]) || s.peek(Token![!])
 }
 
 // Parsing integers
@@ -2386,6 +5932,715 @@ mod tests {
         assert_eq!(&parse(input).to_string(), input);
     }
 
+    #[test]
+    fn stylesheet() {
+        let sheet: Stylesheet = syn::parse_str(
+            r#"
+            a:hover > *.b, ul li:nth-child(2n+1), a[href ~= "x" i] { display: flex; }
+            "div .c" { display: block }
+            @import url("x.css");
+            @-webkit-keyframes spin { from { height: 0 } 50% { height: 1.5"em" } }
+            @media not screen and (min-width: 600px) { p::first-line { color: red } }
+            @font-face { font-family: "X"; src: local("X"), url(x.woff); }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            sheet.to_string(),
+            "a:hover > .b, ul li:nth-child(2n+1), a[href~=\"x\" i]{display:flex;}\
+             div .c{display:block;}\
+             @import url(\"x.css\");\
+             @-webkit-keyframes spin{0%{height:0;}50%{height:1.5em;}}\
+             @media not screen and (min-width:600px){p::first-line{color:red;}}\
+             @font-face{font-family:\"X\"; src:local(\"X\"), url(x.woff);}"
+        );
+        assert!(syn::parse_str::<Stylesheet>("{ display: flex }").is_err());
+        assert!(syn::parse_str::<Stylesheet>("a { display: flx }").is_err());
+    }
+
+    #[test]
+    fn selector_list() {
+        for (rust, css) in [
+            (
+                "a:not(.b) > li:nth-child(2n+1)",
+                "a:not(.b) > li:nth-child(2n+1)",
+            ),
+            ("nav *.item, #main::before", "nav .item, #main::before"),
+            (
+                "a[href ^= \"https\" i]:has(> img)",
+                "a[href^=\"https\" i]:has(> img)",
+            ),
+            ("\"nav .item\"", "nav .item"),
+        ] {
+            let selectors = syn::parse_str::<SelectorList>(rust).unwrap();
+            assert_eq!(selectors, css.parse().unwrap());
+            assert_eq!(selectors.to_string(), css);
+        }
+        let err = syn::parse_str::<SelectorList>("a >").unwrap_err();
+        assert_eq!(err.to_string(), "expected selector in selector `a >`");
+    }
+
+    #[test]
+    fn grid() {
+        for input in [
+            "grid-template-columns:[full-start] minmax(1em, 1fr) [main-start] repeat(3, [col] 10px) [main-end]",
+            "grid-template-columns:repeat(auto-fill, minmax(10px, 1fr))",
+            "grid-template-rows:fit-content(40%) min-content 2.5fr",
+            "grid-template-rows:subgrid [a] repeat(auto-fill, [b] [c d])",
+            "grid-template-rows:none",
+            "grid-template-areas:\"head head\" \"nav main\" \". foot\"",
+            "grid-template:[top] \"a a\" 40px [middle] \"b c\" / 1fr 2fr",
+            "grid-template:auto 1fr / repeat(2, 1fr)",
+            "grid-auto-columns:1fr min-content",
+            "grid-auto-flow:column dense",
+            "grid-row:1 / -1",
+            "grid-column:span 2 col / auto",
+            "grid-row-start:span a",
+            "grid-column-end:2 b",
+            "grid-area:main",
+            "grid-area:1 / 2 / span 3 / 4",
+            "grid:auto-flow dense 40px / 1fr 1fr",
+            "grid:100px / auto-flow",
+            "grid:\"a\" 1fr / 10px",
+            "grid:none",
+        ] {
+            round_trip_style(input);
+        }
+        assert_eq!(
+            parse("grid-auto-flow: dense row").to_string(),
+            "grid-auto-flow:dense"
+        );
+        assert_eq!(parse("grid-row-end: a 3").to_string(), "grid-row-end:3 a");
+        for input in [
+            "grid-template-columns: repeat(auto-fill, 1fr)",
+            "grid-template-columns: minmax(1fr, 10px)",
+            "grid-template-columns: [a] [b] 10px",
+            "grid-template-areas: \"a b a\"",
+            "grid-template: \"a\" / repeat(2, 1fr)",
+            "grid-row-start: 0",
+            "grid-row-start: span 0",
+            "grid-row-start: span",
+            "grid-area: 1 / 2 / 3 / 4 / 5",
+            "grid-template-columns: -1fr",
+            "grid-auto-rows: 10px -2fr",
+        ] {
+            assert!(syn::parse_str::<Style>(input).is_err(), "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn transform() {
+        for input in [
+            "transform:translate(-50%, -50%) rotate(45deg) scale(1.5)",
+            "transform:matrix(1, 0, 0, 1, 10, -2.5) skew(0, 0.5turn)",
+            "transform:translate3d(10px, 0, -1px) rotate3d(1, 1, 0, 1rad) perspective(none)",
+            "transform:none",
+            "transform-origin:left 10% 2px",
+            "transform-style:preserve-3d",
+            "perspective:100px",
+            "perspective-origin:right bottom",
+            "backface-visibility:hidden",
+            "translate:10px 20% 1px",
+            "rotate:x 90deg",
+            "rotate:1 1 0 -45deg",
+            "scale:2 0.5",
+            "scale:none",
+        ] {
+            round_trip_style(input);
+        }
+        assert_eq!(
+            parse("transform: scaleX(50%) translateY(1\"em\")").to_string(),
+            "transform:scaleX(0.5) translateY(1em)"
+        );
+        assert_eq!(
+            parse("transform-origin: top").to_string(),
+            "transform-origin:center top"
+        );
+        assert_eq!(
+            parse("transform-origin: bottom right").to_string(),
+            "transform-origin:right bottom"
+        );
+        assert_eq!(parse("rotate: 45deg z").to_string(), "rotate:z 45deg");
+        for input in [
+            "transform: translate(10px) none",
+            "transform: rotate(10px)",
+            "transform: spin(1turn)",
+            "transform: translate(1px, 2px, 3px)",
+            "transform-origin: top 10px",
+            "transform-origin: left right",
+            "transform-style: preserve-2d",
+            "perspective: -10px",
+            "rotate: 45",
+        ] {
+            assert!(syn::parse_str::<Style>(input).is_err(), "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn animation() {
+        for input in [
+            "transition:opacity 200ms ease-in-out 1s,transform 0.5s cubic-bezier(0.1, 0.7, 1, 0.1)",
+            "transition:all 1s steps(4, jump-start) allow-discrete",
+            "transition-property:none",
+            "transition-timing-function:linear,linear(0, 0.25 75%, 1)",
+            "transition-delay:-1s,0s",
+            "transition:opacity 1s -1s",
+            "transition:none 2s",
+            "animation:2s ease 1s infinite alternate both paused spin",
+            "animation:1s forwards none",
+            "animation-name:none,spin",
+            "animation-iteration-count:2.5",
+            "animation-timing-function:steps(2, jump-none)",
+        ] {
+            round_trip_style(input);
+        }
+        assert_eq!(
+            parse("transition: color 1s steps(3, end)").to_string(),
+            "transition:color 1s steps(3)"
+        );
+        assert_eq!(
+            parse("animation-name: \"my animation\"").to_string(),
+            "animation-name:my animation"
+        );
+        for input in [
+            "transition: opacity 1",
+            "transition: opacity 1s cubic-bezier(1.5, 0, 0, 1)",
+            "transition-timing-function: cubic-bezier(0.1, 0.2, 0.3)",
+            "transition-timing-function: steps(0)",
+            "transition-timing-function: steps(1, jump-none)",
+            "transition-timing-function: linear(0)",
+            "animation: 1s 2s 3s spin",
+            "animation-iteration-count: -1",
+            "transition-duration: -1s",
+            "animation-duration: 1s, -2s",
+            "transition: opacity -1s",
+            "animation: -1ms spin",
+            "transition: opacity 1s, none 2s",
+            "transition: none, opacity 1s",
+        ] {
+            assert!(syn::parse_str::<Style>(input).is_err(), "input: {}", input);
+        }
+        assert_eq!(
+            syn::parse_str::<Style>("transition: opacity 1s cubic-bezier(2, 0, 0, 1)")
+                .unwrap_err()
+                .to_string(),
+            "the x values of `cubic-bezier()` must be between 0 and 1"
+        );
+        let sheet: Stylesheet =
+            syn::parse_str("@keyframes fade { from, 50% { height: 0 } to { height: 1px } }")
+                .unwrap();
+        assert_eq!(
+            sheet.to_string(),
+            "@keyframes fade{0%, 50%{height:0;}100%{height:1px;}}"
+        );
+        assert!(syn::parse_str::<Stylesheet>("@keyframes fade { 150% { height: 0 } }").is_err());
+    }
+
+    #[test]
+    fn border_radius() {
+        for input in [
+            "border-radius:4px",
+            "border-radius:4px 8px",
+            "border-radius:4px 50% 2px",
+            "border-radius:1px 2px 3px 4px / 5px 6px",
+            "border-radius:10px / 20px",
+            "border-radius:calc(50% - 2px)",
+            "border-top-left-radius:10px 20%",
+        ] {
+            round_trip_style(input);
+        }
+        for input in [
+            "border-radius: 1px 2px 3px 4px 5px",
+            "border-radius: 1px /",
+            "border-radius: 1px / 2px / 3px",
+            "border-top-left-radius: 1px 2px 3px",
+        ] {
+            assert!(syn::parse_str::<Style>(input).is_err(), "input: {}", input);
+        }
+        assert_eq!(
+            parse("border-bottom-right-radius: 10px 20%"),
+            Style::BorderBottomRightRadius(SingleOrDouble::Double {
+                horiz: Calc::Normal(LengthPercentage::Length(Length::Px(10.0))),
+                vert: Calc::Normal(LengthPercentage::Percentage(Percentage(20.0))),
+            })
+        );
+
+        let styles: Styles = syn::parse_str("border-radius: 1px 2px 3px / 4px").unwrap();
+        let expanded: Styles = syn::parse_str(
+            "border-top-left-radius: 1px 4px; border-top-right-radius: 2px 4px;
+            border-bottom-right-radius: 3px 4px; border-bottom-left-radius: 2px 4px",
+        )
+        .unwrap();
+        assert_eq!(styles.expand_shorthands(), expanded);
+        let styles: Styles = syn::parse_str("border-radius: 1px 2px").unwrap();
+        let expanded: Styles = syn::parse_str(
+            "border-top-left-radius: 1px; border-top-right-radius: 2px;
+            border-bottom-right-radius: 1px; border-bottom-left-radius: 2px",
+        )
+        .unwrap();
+        assert_eq!(styles.expand_shorthands(), expanded);
+    }
+
+    #[test]
+    fn border_image() {
+        for input in [
+            "border-image:none",
+            "border-image:url(\"frame.png\") 30 fill / 10px / 2 round",
+            "border-image:url(\"frame.png\") 10% 20 / auto 1 25% 3px",
+            "border-image:linear-gradient(red, blue) 100% / / 4px space repeat",
+            "border-image:stretch",
+            "border-image-source:none",
+            "border-image-source:url(\"frame.png\")",
+            "border-image-slice:10 20% 30 fill",
+            "border-image-width:2 auto",
+            "border-image-width:10px 5%",
+            "border-image-outset:1 2px 0",
+            "border-image-repeat:round space",
+        ] {
+            round_trip_style(input);
+        }
+        for (input, output) in [
+            ("border-image-slice: fill 30", "border-image-slice:30 fill"),
+            (
+                "border-image: repeat url(\"frame.png\") 100% / 2",
+                "border-image:url(\"frame.png\") 100% / 2 repeat",
+            ),
+            (
+                "border-image: 5 fill url(\"frame.png\")",
+                "border-image:url(\"frame.png\") 5 fill",
+            ),
+        ] {
+            assert_eq!(parse(input).to_string(), output);
+        }
+        for input in [
+            "border-image: round round round",
+            "border-image: none none",
+            "border-image: 30 / ",
+            "border-image: 30 / / ",
+            "border-image: 30 fill fill",
+            "border-image-slice: 10px",
+            "border-image-width: 10px 10px 10px 10px 10px",
+            "border-image-outset: 10%",
+            "border-image-repeat: fill",
+        ] {
+            assert!(syn::parse_str::<Style>(input).is_err(), "input: {}", input);
+        }
+
+        let styles: Styles =
+            syn::parse_str("border-image: url(\"frame.png\") 30 / / 2 round").unwrap();
+        let expanded: Styles = syn::parse_str(
+            "border-image-source: url(\"frame.png\"); border-image-slice: 30;
+            border-image-width: 1; border-image-outset: 2; border-image-repeat: round",
+        )
+        .unwrap();
+        assert_eq!(styles.expand_shorthands(), expanded);
+    }
+
+    #[test]
+    fn flex() {
+        for input in [
+            "flex:1 1 0",
+            "flex:none",
+            "flex:auto",
+            "flex:2 0 content",
+            "flex-flow:column wrap",
+            "flex-flow:wrap-reverse",
+            "flex-flow:row-reverse",
+        ] {
+            round_trip_style(input);
+        }
+        for (input, output) in [
+            ("flex: 1", "flex:1 1 0"),
+            ("flex: 10px 2", "flex:2 1 10px"),
+            ("flex: 0 auto", "flex:0 1 auto"),
+            ("flex: 2 3", "flex:2 3 0"),
+            ("flex-flow: wrap column", "flex-flow:column wrap"),
+        ] {
+            assert_eq!(parse(input).to_string(), output);
+        }
+        for input in [
+            "flex: 1 10px 2",
+            "flex: -1",
+            "flex: none 1",
+            "flex-flow: row column",
+            "flex-flow: wrap wrap",
+        ] {
+            assert!(syn::parse_str::<Style>(input).is_err(), "input: {}", input);
+        }
+
+        let styles: Styles =
+            syn::parse_str("flex: none; flex-flow: wrap !important; height: 0").unwrap();
+        let expanded: Styles = syn::parse_str(
+            "flex-grow: 0; flex-shrink: 0; flex-basis: auto;
+            flex-direction: row !important; flex-wrap: wrap !important; height: 0",
+        )
+        .unwrap();
+        assert_eq!(styles.expand_shorthands(), expanded);
+    }
+
+    #[test]
+    fn font() {
+        for input in [
+            "font:italic small-caps bold condensed 12px/1.5 \"Helvetica\",sans-serif",
+            "font:12px system-ui",
+            "font:larger ui-monospace,math",
+            "font:message-box",
+            "font-stretch:semi-expanded",
+            "font-stretch:80%",
+            "font-kerning:none",
+            "font-size-adjust:0.5",
+            "font-size-adjust:cap-height from-font",
+            "font-synthesis:none",
+            "font-synthesis:weight small-caps",
+            "font-variant:none",
+            "font-variant:no-common-ligatures small-caps stylistic(fancy) oldstyle-nums ruby sub",
+            "font-variant-ligatures:discretionary-ligatures no-contextual",
+            "font-variant-caps:all-petite-caps",
+            "font-variant-alternates:historical-forms styleset(a, b) swash(flowing)",
+            "font-variant-numeric:lining-nums diagonal-fractions ordinal",
+            "font-variant-east-asian:jis78 full-width",
+            "font-variant-position:super",
+            "font-variant-emoji:emoji",
+            "font-feature-settings:\"liga\" 0,\"smcp\"",
+            "font-variation-settings:\"wght\" 400,\"slnt\" -10",
+        ] {
+            round_trip_style(input);
+        }
+        for (input, output) in [
+            ("font: normal normal 400 1em serif", "font:400 1em serif"),
+            ("font: normal 12px \"caption\"", "font:12px \"caption\""),
+            ("font-size-adjust: ex-height 0.5", "font-size-adjust:0.5"),
+            (
+                "font-variant: tabular-nums common-ligatures",
+                "font-variant:common-ligatures tabular-nums",
+            ),
+            (
+                "font-feature-settings: \"liga\" on, \"kern\" 1",
+                "font-feature-settings:\"liga\",\"kern\"",
+            ),
+        ] {
+            assert_eq!(parse(input).to_string(), output);
+        }
+        for input in [
+            "font: bold",
+            "font: italic italic 12px serif",
+            "font: 150% 12px serif",
+            "font: caption 12px serif",
+            "font-stretch: -10%",
+            "font-synthesis: weight weight",
+            "font-variant: none small-caps",
+            "font-variant: small-caps all-small-caps",
+            "font-variant: lining-nums oldstyle-nums",
+            "font-variant-ligatures: common-ligatures no-common-ligatures",
+            "font-variant-alternates: swash(a) swash(b)",
+            "font-feature-settings: \"liga\" -1",
+            "font-feature-settings: \"ligature\"",
+            "font-variation-settings: \"wght\"",
+        ] {
+            assert!(syn::parse_str::<Style>(input).is_err(), "input: {}", input);
+        }
+
+        let styles: Styles = syn::parse_str("font-variant: small-caps slashed-zero").unwrap();
+        let expanded: Styles = syn::parse_str(
+            "font-variant-ligatures: normal; font-variant-caps: small-caps;
+            font-variant-alternates: normal; font-variant-numeric: slashed-zero;
+            font-variant-east-asian: normal; font-variant-position: normal;
+            font-variant-emoji: normal",
+        )
+        .unwrap();
+        assert_eq!(styles.expand_shorthands(), expanded);
+    }
+
+    #[test]
+    fn background() {
+        for input in [
+            "background:none",
+            "background:red",
+            "background:url(\"a.png\"),linear-gradient(to left top, red, blue)",
+            "background-image:linear-gradient(45deg, red 0 50%, 10%, blue)",
+            "background-image:repeating-linear-gradient(red, blue 20px)",
+            "background-image:radial-gradient(circle 10px at left top, red, blue)",
+            "background-image:radial-gradient(farthest-side, red, blue)",
+            "background-image:repeating-radial-gradient(ellipse 20% 10px, red, blue)",
+            "background-image:conic-gradient(from 90deg at 25% 75%, red, blue 50%, green 0.5turn)",
+            "background-image:image-set(url(\"a.png\") 1x, url(\"b.png\") 192dpi type(\"image/png\"))",
+            "background-image:url(\"a.png\" crossorigin(use-credentials) referrerpolicy(no-referrer))",
+        ] {
+            round_trip_style(input);
+        }
+        for (input, output) in [
+            (
+                "background: url(\"a.png\") center / cover no-repeat fixed padding-box content-box #\"fff\"",
+                "background:url(\"a.png\") center center/cover no-repeat fixed padding-box content-box #ffffff",
+            ),
+            (
+                "background: radial-gradient(10px 20px, red, blue)",
+                "background:radial-gradient(10px 20px, red, blue)",
+            ),
+            (
+                "background-image: image-set(\"a.png\", \"b.png\" 2x)",
+                "background-image:image-set(url(\"a.png\"), url(\"b.png\") 2x)",
+            ),
+            (
+                "background-image: linear-gradient(to bottom right, red, blue)",
+                "background-image:linear-gradient(to right bottom, red, blue)",
+            ),
+        ] {
+            assert_eq!(parse(input).to_string(), output);
+        }
+        for input in [
+            "background: red, url(\"a.png\")",
+            "background: fixed fixed",
+            "background: url(\"a.png\") url(\"b.png\")",
+            "background-image: linear-gradient(red)",
+            "background-image: linear-gradient(red, 10%, 20%, blue)",
+            "background-image: linear-gradient(to, red, blue)",
+            "background-image: radial-gradient(circle 10px 20px, red, blue)",
+            "background-image: radial-gradient(circle 10%, red, blue)",
+            "background-image: radial-gradient(ellipse 10px, red, blue)",
+            "background-image: radial-gradient(-10px, red, blue)",
+            "background-image: conic-gradient(red 10px, blue)",
+            "background-image: image-set(image-set(\"a.png\"))",
+            "background-image: image-set(\"a.png\" 2)",
+            "background-image: url(\"a.png\" crossorigin(none))",
+        ] {
+            assert!(syn::parse_str::<Style>(input).is_err(), "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn keywords() {
+        assert_eq!(
+            syn::parse_str::<Font>("monospace").unwrap(),
+            Font::Monospace
+        );
+        for input in [
+            "font-family:monospace",
+            "background-repeat:repeat-x,space round,no-repeat",
+            "background-size:auto 10px",
+            "background-size:cover",
+            "object-fit:contain",
+            "object-fit:contain scale-down",
+        ] {
+            round_trip_style(input);
+        }
+        let styles: Styles = syn::parse_str("object-fit: contain; height: 0").unwrap();
+        assert_eq!(styles.to_string(), "object-fit:contain;height:0;");
+        for input in ["background-size: cover 10px", "object-fit: contain fill"] {
+            assert!(syn::parse_str::<Style>(input).is_err(), "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn position() {
+        for input in [
+            "background-position:left top",
+            "background-position:10px 50%",
+            "background-position:right 10px bottom 20%",
+            "background-position:center bottom 5px",
+            "background-position-x:right 5px",
+            "background-position-y:center",
+            "object-position:left 25%",
+            "mask-position:center top",
+            "perspective-origin:100% center",
+            "background-image:radial-gradient(at right 10px top 10px, red, blue)",
+        ] {
+            round_trip_style(input);
+        }
+        for (input, output) in [
+            ("background-position: top", "background-position:center top"),
+            ("object-position: top right", "object-position:right top"),
+            (
+                "mask-position: bottom 10px right",
+                "mask-position:right bottom 10px",
+            ),
+        ] {
+            assert_eq!(parse(input).to_string(), output);
+        }
+        for input in [
+            "background-position: left right",
+            "background-position: 10px left",
+            "background-position: left 10px center 5px",
+            "background-position: left 10px 20px",
+            "background-position: 10% bottom 20px",
+            "background-position: left top center",
+            "background-position-x: top",
+        ] {
+            assert!(syn::parse_str::<Style>(input).is_err(), "input: {}", input);
+        }
+    }
+    #[test]
+    fn text() {
+        for input in [
+            "letter-spacing:normal",
+            "letter-spacing:-1px",
+            "word-spacing:4px",
+            "text-indent:10%",
+            "text-indent:2px hanging each-line",
+            "text-transform:none",
+            "text-transform:uppercase full-width full-size-kana",
+            "text-decoration:none",
+            "text-decoration:underline overline 2px wavy red",
+            "text-decoration:line-through",
+            "text-decoration-line:underline blink",
+            "text-decoration-style:dotted",
+            "text-decoration-color:currentcolor",
+            "text-decoration-thickness:from-font",
+            "text-decoration-thickness:10%",
+            "text-underline-offset:auto",
+            "text-underline-offset:3px",
+            "text-underline-position:under right",
+            "text-underline-position:left",
+            "text-underline-position:from-font",
+            "text-shadow:none",
+            "text-shadow:1px 1px 2px red,0 0 5px",
+        ] {
+            round_trip_style(input);
+        }
+        for (input, output) in [
+            ("text-indent: each-line 5px", "text-indent:5px each-line"),
+            (
+                "text-transform: full-width capitalize",
+                "text-transform:capitalize full-width",
+            ),
+            (
+                "text-decoration: red dashed underline",
+                "text-decoration:underline dashed red",
+            ),
+            (
+                "text-underline-position: right under",
+                "text-underline-position:under right",
+            ),
+        ] {
+            assert_eq!(parse(input).to_string(), output);
+        }
+        for input in [
+            "letter-spacing: 10%",
+            "text-indent: hanging",
+            "text-indent: 1px 2px",
+            "text-indent: 1px hanging hanging",
+            "text-transform: uppercase lowercase",
+            "text-transform: none full-width",
+            "text-decoration: underline underline",
+            "text-decoration: solid dotted",
+            "text-decoration-line: none underline",
+            "text-underline-position: left right",
+            "text-shadow: 1px 1px red inset",
+            "text-shadow: 1px 1px 2px 3px",
+        ] {
+            assert!(syn::parse_str::<Style>(input).is_err(), "input: {}", input);
+        }
+
+        let styles: Styles = syn::parse_str("text-decoration: underline red").unwrap();
+        let expanded: Styles = syn::parse_str(
+            "text-decoration-line: underline; text-decoration-thickness: auto;
+            text-decoration-style: solid; text-decoration-color: red",
+        )
+        .unwrap();
+        assert_eq!(styles.expand_shorthands(), expanded);
+    }
+
+    #[test]
+    fn line_breaking() {
+        for input in [
+            "word-break:break-all",
+            "overflow-wrap:anywhere",
+            "line-break:strict",
+            "hyphens:manual",
+            "hyphenate-character:auto",
+            "hyphenate-character:\"-\"",
+            "tab-size:4",
+            "tab-size:2px",
+            "text-wrap:balance",
+            "text-wrap:nowrap pretty",
+            "text-wrap-mode:wrap",
+            "text-wrap-style:stable",
+            "white-space-collapse:preserve-breaks",
+            "hanging-punctuation:none",
+            "hanging-punctuation:first allow-end last",
+            "white-space:pre-wrap",
+            "white-space:nowrap",
+            "white-space:break-spaces",
+            "white-space:preserve-spaces nowrap",
+            "white-space:wrap",
+            "line-height:normal",
+            "line-height:1.5",
+            "line-height:2",
+            "line-height:20px",
+            "line-height:150%",
+            "font:12px/20px serif",
+        ] {
+            round_trip_style(input);
+        }
+        for (input, output) in [
+            ("word-wrap: break-word", "overflow-wrap:break-word"),
+            ("text-wrap: balance wrap", "text-wrap:wrap balance"),
+            (
+                "hanging-punctuation: last force-end",
+                "hanging-punctuation:force-end last",
+            ),
+            (
+                "white-space: nowrap break-spaces",
+                "white-space:break-spaces nowrap",
+            ),
+        ] {
+            assert_eq!(parse(input).to_string(), output);
+        }
+        for input in [
+            "word-break: anywhere",
+            "hyphens: all",
+            "text-wrap: wrap nowrap",
+            "white-space: pre nowrap",
+            "white-space: collapse preserve",
+            "hanging-punctuation: first first",
+            "hanging-punctuation: force-end allow-end",
+            "line-height: -1px",
+        ] {
+            assert!(syn::parse_str::<Style>(input).is_err(), "input: {}", input);
+        }
+
+        let styles: Styles = syn::parse_str("white-space: pre; text-wrap: pretty").unwrap();
+        let expanded: Styles = syn::parse_str(
+            "white-space-collapse: preserve; text-wrap-mode: nowrap;
+            text-wrap-mode: wrap; text-wrap-style: pretty",
+        )
+        .unwrap();
+        assert_eq!(styles.expand_shorthands(), expanded);
+    }
+
+    #[test]
+    fn writing_modes() {
+        for input in [
+            "direction:rtl",
+            "unicode-bidi:bidi-override",
+            "unicode-bidi:plaintext",
+            "writing-mode:horizontal-tb",
+            "writing-mode:sideways-lr",
+            "text-orientation:mixed",
+            "text-combine-upright:none",
+            "text-combine-upright:all",
+            "text-combine-upright:digits",
+            "text-combine-upright:digits 4",
+        ] {
+            round_trip_style(input);
+        }
+        for input in [
+            "direction: auto",
+            "writing-mode: vertical",
+            "text-combine-upright: digits 1",
+            "text-combine-upright: all 2",
+        ] {
+            assert!(syn::parse_str::<Style>(input).is_err(), "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn important() {
+        round_trip_style("border:1px solid !important");
+        round_trip_style("height:10px !important");
+        assert!(syn::parse_str::<Style>("height: 10px !importnt").is_err());
+    }
+
     #[test]
     fn border_bottom_left_radius() {
         round_trip_style("border-bottom-left-radius:30% 3px");
@@ -0,0 +1,523 @@
+//! Values of the text properties, from https://www.w3.org/TR/css-text-3/ and
+//! https://www.w3.org/TR/css-text-decor-3/.
+use crate::{Calc, Color, HorizontalSide, Length, NonemptyCommaList, Shadow};
+use std::fmt;
+
+/// The value of `letter-spacing` and `word-spacing`: extra space added on top of the normal
+/// spacing. Can be negative.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Spacing {
+    Normal,
+    Length(Length),
+}
+
+impl fmt::Display for Spacing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Spacing::Normal => f.write_str("normal"),
+            Spacing::Length(v) => v.fmt(f),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-text-3/#text-indent-property
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextIndent {
+    /// Percentages are of the width of the containing block.
+    pub length: Calc,
+    /// Indent every line except the first, rather than just the first.
+    pub hanging: bool,
+    /// Also indent the first line after each forced line break.
+    pub each_line: bool,
+}
+
+impl fmt::Display for TextIndent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.length)?;
+        if self.hanging {
+            f.write_str(" hanging")?;
+        }
+        if self.each_line {
+            f.write_str(" each-line")?;
+        }
+        Ok(())
+    }
+}
+
+/// https://www.w3.org/TR/css-text-3/#text-transform-property
+///
+/// `none` is when no part is set.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TextTransform {
+    pub case: Option<TextTransformCase>,
+    pub full_width: bool,
+    pub full_size_kana: bool,
+}
+
+impl fmt::Display for TextTransform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(case) = self.case {
+            parts.push(case.to_string());
+        }
+        if self.full_width {
+            parts.push("full-width".to_string());
+        }
+        if self.full_size_kana {
+            parts.push("full-size-kana".to_string());
+        }
+        if parts.is_empty() {
+            f.write_str("none")
+        } else {
+            f.write_str(&parts.join(" "))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextTransformCase {
+    Capitalize,
+    Uppercase,
+    Lowercase,
+}
+
+impl fmt::Display for TextTransformCase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TextTransformCase::Capitalize => "capitalize",
+            TextTransformCase::Uppercase => "uppercase",
+            TextTransformCase::Lowercase => "lowercase",
+        })
+    }
+}
+
+/// The value of the `text-decoration` shorthand. Parts that are `None` are reset to their initial
+/// values.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TextDecoration {
+    pub line: Option<TextDecorationLine>,
+    pub thickness: Option<TextDecorationThickness>,
+    pub style: Option<TextDecorationStyle>,
+    pub color: Option<TextDecorationColor>,
+}
+
+impl TextDecoration {
+    pub fn line(&self) -> TextDecorationLine {
+        self.line.unwrap_or_default()
+    }
+
+    pub fn thickness(&self) -> TextDecorationThickness {
+        self.thickness
+            .clone()
+            .unwrap_or(TextDecorationThickness::Auto)
+    }
+
+    pub fn style(&self) -> TextDecorationStyle {
+        self.style.unwrap_or(TextDecorationStyle::Solid)
+    }
+
+    pub fn color(&self) -> TextDecorationColor {
+        self.color.unwrap_or(TextDecorationColor::CurrentColor)
+    }
+}
+
+impl fmt::Display for TextDecoration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(line) = &self.line {
+            parts.push(line.to_string());
+        }
+        if let Some(thickness) = &self.thickness {
+            parts.push(thickness.to_string());
+        }
+        if let Some(style) = &self.style {
+            parts.push(style.to_string());
+        }
+        if let Some(color) = &self.color {
+            parts.push(color.to_string());
+        }
+        if parts.is_empty() {
+            f.write_str("none")
+        } else {
+            f.write_str(&parts.join(" "))
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-text-decor-3/#text-decoration-line-property
+///
+/// `none` is when no line is set.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TextDecorationLine {
+    pub underline: bool,
+    pub overline: bool,
+    pub line_through: bool,
+    pub blink: bool,
+}
+
+impl fmt::Display for TextDecorationLine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let parts: Vec<&str> = [
+            (self.underline, "underline"),
+            (self.overline, "overline"),
+            (self.line_through, "line-through"),
+            (self.blink, "blink"),
+        ]
+        .iter()
+        .filter(|(set, _)| *set)
+        .map(|(_, name)| *name)
+        .collect();
+        if parts.is_empty() {
+            f.write_str("none")
+        } else {
+            f.write_str(&parts.join(" "))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextDecorationStyle {
+    Solid,
+    Double,
+    Dotted,
+    Dashed,
+    Wavy,
+}
+
+impl fmt::Display for TextDecorationStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TextDecorationStyle::Solid => "solid",
+            TextDecorationStyle::Double => "double",
+            TextDecorationStyle::Dotted => "dotted",
+            TextDecorationStyle::Dashed => "dashed",
+            TextDecorationStyle::Wavy => "wavy",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextDecorationColor {
+    /// The value of the `color` property.
+    CurrentColor,
+    Color(Color),
+}
+
+impl fmt::Display for TextDecorationColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TextDecorationColor::CurrentColor => f.write_str("currentcolor"),
+            TextDecorationColor::Color(v) => v.fmt(f),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-text-decor-4/#text-decoration-width-property
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextDecorationThickness {
+    Auto,
+    /// Use the thickness recommended by the font, if it has one.
+    FromFont,
+    /// Percentages are of `1em`.
+    LengthPercentage(Calc),
+}
+
+impl fmt::Display for TextDecorationThickness {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TextDecorationThickness::Auto => f.write_str("auto"),
+            TextDecorationThickness::FromFont => f.write_str("from-font"),
+            TextDecorationThickness::LengthPercentage(v) => v.fmt(f),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-text-decor-4/#underline-position-property
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextUnderlinePosition {
+    Auto,
+    FromFont,
+    /// At least one of `under` and `side` is set. `side` is for vertical text.
+    Position {
+        under: bool,
+        side: Option<HorizontalSide>,
+    },
+}
+
+impl fmt::Display for TextUnderlinePosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TextUnderlinePosition::Auto => f.write_str("auto"),
+            TextUnderlinePosition::FromFont => f.write_str("from-font"),
+            TextUnderlinePosition::Position { under, side } => match (under, side) {
+                (true, Some(side)) => write!(f, "under {}", side),
+                (true, None) => f.write_str("under"),
+                (false, Some(side)) => side.fmt(f),
+                (false, None) => f.write_str("auto"),
+            },
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-text-decor-3/#text-shadow-property
+///
+/// The shadows are never `inset` and don't have a spread.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextShadow {
+    None,
+    Shadows(NonemptyCommaList<Shadow>),
+}
+
+impl fmt::Display for TextShadow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TextShadow::None => f.write_str("none"),
+            TextShadow::Shadows(list) => list.fmt(f),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-text-3/#word-break-property
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WordBreak {
+    Normal,
+    BreakAll,
+    KeepAll,
+    /// Deprecated, the same as `overflow-wrap: anywhere` with `word-break: normal`.
+    BreakWord,
+}
+
+impl fmt::Display for WordBreak {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            WordBreak::Normal => "normal",
+            WordBreak::BreakAll => "break-all",
+            WordBreak::KeepAll => "keep-all",
+            WordBreak::BreakWord => "break-word",
+        })
+    }
+}
+
+/// The value of `overflow-wrap`, and its legacy name `word-wrap`.
+///
+/// https://www.w3.org/TR/css-text-3/#overflow-wrap-property
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OverflowWrap {
+    Normal,
+    /// Break anywhere if there is nowhere else to break, but don't count the breaks when
+    /// working out the min-content size.
+    BreakWord,
+    Anywhere,
+}
+
+impl fmt::Display for OverflowWrap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            OverflowWrap::Normal => "normal",
+            OverflowWrap::BreakWord => "break-word",
+            OverflowWrap::Anywhere => "anywhere",
+        })
+    }
+}
+
+/// How strict the rules for breaking lines in CJK text are.
+///
+/// https://www.w3.org/TR/css-text-3/#line-break-property
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineBreak {
+    Auto,
+    Loose,
+    Normal,
+    Strict,
+    Anywhere,
+}
+
+impl fmt::Display for LineBreak {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            LineBreak::Auto => "auto",
+            LineBreak::Loose => "loose",
+            LineBreak::Normal => "normal",
+            LineBreak::Strict => "strict",
+            LineBreak::Anywhere => "anywhere",
+        })
+    }
+}
+
+/// https://www.w3.org/TR/css-text-3/#hyphens-property
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Hyphens {
+    None,
+    /// Only hyphenate at soft hyphens (`&shy;`).
+    Manual,
+    Auto,
+}
+
+impl fmt::Display for Hyphens {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Hyphens::None => "none",
+            Hyphens::Manual => "manual",
+            Hyphens::Auto => "auto",
+        })
+    }
+}
+
+/// https://www.w3.org/TR/css-text-4/#hyphenate-character
+#[derive(Debug, Clone, PartialEq)]
+pub enum HyphenateCharacter {
+    Auto,
+    // todo escape when `Display`ing
+    String(String),
+}
+
+impl fmt::Display for HyphenateCharacter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HyphenateCharacter::Auto => f.write_str("auto"),
+            HyphenateCharacter::String(v) => write!(f, "\"{}\"", v),
+        }
+    }
+}
+
+/// The value of the `text-wrap` shorthand. Parts that are `None` are reset to their initial
+/// values.
+///
+/// https://www.w3.org/TR/css-text-4/#text-wrap
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextWrap {
+    pub mode: Option<TextWrapMode>,
+    pub style: Option<TextWrapStyle>,
+}
+
+impl TextWrap {
+    pub fn mode(&self) -> TextWrapMode {
+        self.mode.unwrap_or(TextWrapMode::Wrap)
+    }
+
+    pub fn style(&self) -> TextWrapStyle {
+        self.style.unwrap_or(TextWrapStyle::Auto)
+    }
+}
+
+impl fmt::Display for TextWrap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.mode, self.style) {
+            (Some(mode), Some(style)) => write!(f, "{} {}", mode, style),
+            (Some(mode), None) => mode.fmt(f),
+            (None, Some(style)) => style.fmt(f),
+            (None, None) => self.mode().fmt(f),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextWrapMode {
+    Wrap,
+    Nowrap,
+}
+
+impl fmt::Display for TextWrapMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TextWrapMode::Wrap => "wrap",
+            TextWrapMode::Nowrap => "nowrap",
+        })
+    }
+}
+
+/// How to choose where to break lines, when they wrap.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextWrapStyle {
+    Auto,
+    /// Make the lines about the same length.
+    Balance,
+    /// Don't move earlier line breaks when the text after them is edited.
+    Stable,
+    /// Choose line breaks for the best layout rather than the fastest.
+    Pretty,
+}
+
+impl fmt::Display for TextWrapStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TextWrapStyle::Auto => "auto",
+            TextWrapStyle::Balance => "balance",
+            TextWrapStyle::Stable => "stable",
+            TextWrapStyle::Pretty => "pretty",
+        })
+    }
+}
+
+/// https://www.w3.org/TR/css-text-4/#white-space-collapsing
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WhiteSpaceCollapse {
+    Collapse,
+    Discard,
+    Preserve,
+    PreserveBreaks,
+    PreserveSpaces,
+    BreakSpaces,
+}
+
+impl fmt::Display for WhiteSpaceCollapse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            WhiteSpaceCollapse::Collapse => "collapse",
+            WhiteSpaceCollapse::Discard => "discard",
+            WhiteSpaceCollapse::Preserve => "preserve",
+            WhiteSpaceCollapse::PreserveBreaks => "preserve-breaks",
+            WhiteSpaceCollapse::PreserveSpaces => "preserve-spaces",
+            WhiteSpaceCollapse::BreakSpaces => "break-spaces",
+        })
+    }
+}
+
+/// Which punctuation can hang outside the line box.
+///
+/// https://www.w3.org/TR/css-text-3/#hanging-punctuation-property
+///
+/// `none` is when no part is set.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HangingPunctuation {
+    pub first: bool,
+    pub end: Option<HangingPunctuationEnd>,
+    pub last: bool,
+}
+
+impl fmt::Display for HangingPunctuation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = Vec::new();
+        if self.first {
+            parts.push("first".to_string());
+        }
+        if let Some(end) = self.end {
+            parts.push(end.to_string());
+        }
+        if self.last {
+            parts.push("last".to_string());
+        }
+        if parts.is_empty() {
+            f.write_str("none")
+        } else {
+            f.write_str(&parts.join(" "))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HangingPunctuationEnd {
+    /// Stops and commas at the end of a line always hang.
+    ForceEnd,
+    /// Stops and commas at the end of a line hang if they wouldn't otherwise fit.
+    AllowEnd,
+}
+
+impl fmt::Display for HangingPunctuationEnd {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            HangingPunctuationEnd::ForceEnd => "force-end",
+            HangingPunctuationEnd::AllowEnd => "allow-end",
+        })
+    }
+}
@@ -0,0 +1,869 @@
+//! Values of the transform properties, from https://www.w3.org/TR/css-transforms-1/ and
+//! https://www.w3.org/TR/css-transforms-2/.
+use crate::{Angle, Animate, Calc, Length, LengthPercentage, TransformMatrix};
+use std::fmt;
+
+/// The value of `transform`: a list of transform functions, applied from left to right.
+///
+/// An empty list is `none`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TransformList(pub Vec<TransformFunction>);
+
+impl TransformList {
+    pub fn none() -> Self {
+        TransformList(Vec::new())
+    }
+
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<Vec<TransformFunction>> for TransformList {
+    fn from(functions: Vec<TransformFunction>) -> Self {
+        TransformList(functions)
+    }
+}
+
+impl fmt::Display for TransformList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_none() {
+            return write!(f, "none");
+        }
+        for (idx, function) in self.0.iter().enumerate() {
+            if idx > 0 {
+                write!(f, " ")?;
+            }
+            function.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// https://www.w3.org/TR/css-transforms-2/#transform-functions
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransformFunction {
+    /// `matrix(a, b, c, d, e, f)`
+    Matrix([f64; 6]),
+    /// A 4x4 matrix in column-major order.
+    Matrix3d([f64; 16]),
+    Translate(Calc, Option<Calc>),
+    TranslateX(Calc),
+    TranslateY(Calc),
+    TranslateZ(Length),
+    Translate3d(Calc, Calc, Length),
+    /// Percentages are converted to numbers, so `scale(50%)` is `Scale(0.5, None)`.
+    Scale(f64, Option<f64>),
+    ScaleX(f64),
+    ScaleY(f64),
+    ScaleZ(f64),
+    Scale3d(f64, f64, f64),
+    Rotate(Angle),
+    RotateX(Angle),
+    RotateY(Angle),
+    RotateZ(Angle),
+    /// Rotate around the axis `[x, y, z]`.
+    Rotate3d(f64, f64, f64, Angle),
+    Skew(Angle, Option<Angle>),
+    SkewX(Angle),
+    SkewY(Angle),
+    /// `None` is `perspective(none)`.
+    Perspective(Option<Length>),
+}
+
+impl fmt::Display for TransformFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TransformFunction::Matrix(v) => write_function(f, "matrix", v),
+            TransformFunction::Matrix3d(v) => write_function(f, "matrix3d", v),
+            TransformFunction::Translate(x, None) => write!(f, "translate({})", x),
+            TransformFunction::Translate(x, Some(y)) => write!(f, "translate({}, {})", x, y),
+            TransformFunction::TranslateX(v) => write!(f, "translateX({})", v),
+            TransformFunction::TranslateY(v) => write!(f, "translateY({})", v),
+            TransformFunction::TranslateZ(v) => write!(f, "translateZ({})", v),
+            TransformFunction::Translate3d(x, y, z) => {
+                write!(f, "translate3d({}, {}, {})", x, y, z)
+            }
+            TransformFunction::Scale(x, None) => write!(f, "scale({})", x),
+            TransformFunction::Scale(x, Some(y)) => write!(f, "scale({}, {})", x, y),
+            TransformFunction::ScaleX(v) => write!(f, "scaleX({})", v),
+            TransformFunction::ScaleY(v) => write!(f, "scaleY({})", v),
+            TransformFunction::ScaleZ(v) => write!(f, "scaleZ({})", v),
+            TransformFunction::Scale3d(x, y, z) => write_function(f, "scale3d", &[x, y, z]),
+            TransformFunction::Rotate(v) => write!(f, "rotate({})", v),
+            TransformFunction::RotateX(v) => write!(f, "rotateX({})", v),
+            TransformFunction::RotateY(v) => write!(f, "rotateY({})", v),
+            TransformFunction::RotateZ(v) => write!(f, "rotateZ({})", v),
+            TransformFunction::Rotate3d(x, y, z, angle) => {
+                write!(f, "rotate3d({}, {}, {}, {})", x, y, z, angle)
+            }
+            TransformFunction::Skew(x, None) => write!(f, "skew({})", x),
+            TransformFunction::Skew(x, Some(y)) => write!(f, "skew({}, {})", x, y),
+            TransformFunction::SkewX(v) => write!(f, "skewX({})", v),
+            TransformFunction::SkewY(v) => write!(f, "skewY({})", v),
+            TransformFunction::Perspective(None) => write!(f, "perspective(none)"),
+            TransformFunction::Perspective(Some(v)) => write!(f, "perspective({})", v),
+        }
+    }
+}
+
+fn write_function<T: fmt::Display>(f: &mut fmt::Formatter, name: &str, args: &[T]) -> fmt::Result {
+    write!(f, "{}(", name)?;
+    for (idx, arg) in args.iter().enumerate() {
+        if idx > 0 {
+            write!(f, ", ")?;
+        }
+        arg.fmt(f)?;
+    }
+    write!(f, ")")
+}
+
+/// The value of `transform-origin`: where the origin of the transform is, relative to the element.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransformOrigin {
+    pub x: OriginX,
+    pub y: OriginY,
+    pub z: Option<Length>,
+}
+
+impl fmt::Display for TransformOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.x, self.y)?;
+        if let Some(z) = &self.z {
+            write!(f, " {}", z)?;
+        }
+        Ok(())
+    }
+}
+
+impl TransformOrigin {
+    /// Resolve the `x` and `y` of the origin from one or two values, which may be in either
+    /// order if they are both keywords.
+    pub(crate) fn from_values(
+        first: OriginValue,
+        second: Option<OriginValue>,
+    ) -> Result<(OriginX, OriginY), &'static str> {
+        use OriginValue::*;
+        let second = match second {
+            Some(second) => second,
+            None => {
+                return Ok(match first {
+                    Top => (OriginX::Center, OriginY::Top),
+                    Bottom => (OriginX::Center, OriginY::Bottom),
+                    first => (first.x().unwrap(), OriginY::Center),
+                })
+            }
+        };
+        // keywords can be in either order, but offsets can't be swapped
+        let swapped = matches!(first, Top | Bottom) || matches!(second, Left | Right);
+        let (x, y) = match (swapped, &first, &second) {
+            (false, _, _) => (first.x(), second.y()),
+            (true, Offset(_), _) | (true, _, Offset(_)) => (None, None),
+            (true, _, _) => (second.x(), first.y()),
+        };
+        match (x, y) {
+            (Some(x), Some(y)) => Ok((x, y)),
+            _ => Err("expected a horizontal position followed by a vertical position"),
+        }
+    }
+}
+
+/// A keyword or offset in a `transform-origin` or `<position>`, before it is known which axis it
+/// is for.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum OriginValue {
+    Left,
+    Center,
+    Right,
+    Top,
+    Bottom,
+    Offset(Calc),
+}
+
+impl OriginValue {
+    fn x(self) -> Option<OriginX> {
+        match self {
+            OriginValue::Left => Some(OriginX::Left),
+            OriginValue::Center => Some(OriginX::Center),
+            OriginValue::Right => Some(OriginX::Right),
+            OriginValue::Offset(v) => Some(OriginX::Offset(v)),
+            OriginValue::Top | OriginValue::Bottom => None,
+        }
+    }
+
+    fn y(self) -> Option<OriginY> {
+        match self {
+            OriginValue::Top => Some(OriginY::Top),
+            OriginValue::Center => Some(OriginY::Center),
+            OriginValue::Bottom => Some(OriginY::Bottom),
+            OriginValue::Offset(v) => Some(OriginY::Offset(v)),
+            OriginValue::Left | OriginValue::Right => None,
+        }
+    }
+}
+
+/// The horizontal part of a `transform-origin`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OriginX {
+    Left,
+    Center,
+    Right,
+    /// An offset from the left edge.
+    Offset(Calc),
+}
+
+impl fmt::Display for OriginX {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OriginX::Left => write!(f, "left"),
+            OriginX::Center => write!(f, "center"),
+            OriginX::Right => write!(f, "right"),
+            OriginX::Offset(v) => v.fmt(f),
+        }
+    }
+}
+
+/// The vertical part of a `transform-origin`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OriginY {
+    Top,
+    Center,
+    Bottom,
+    /// An offset from the top edge.
+    Offset(Calc),
+}
+
+impl fmt::Display for OriginY {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OriginY::Top => write!(f, "top"),
+            OriginY::Center => write!(f, "center"),
+            OriginY::Bottom => write!(f, "bottom"),
+            OriginY::Offset(v) => v.fmt(f),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-transforms-2/#transform-style-property
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransformStyle {
+    Flat,
+    Preserve3d,
+}
+
+impl fmt::Display for TransformStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TransformStyle::Flat => write!(f, "flat"),
+            TransformStyle::Preserve3d => write!(f, "preserve-3d"),
+        }
+    }
+}
+
+/// The value of `perspective`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Perspective {
+    None,
+    /// The distance to the `z = 0` plane. It can't be negative.
+    Length(Length),
+}
+
+impl fmt::Display for Perspective {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Perspective::None => write!(f, "none"),
+            Perspective::Length(v) => v.fmt(f),
+        }
+    }
+}
+
+/// https://www.w3.org/TR/css-transforms-2/#backface-visibility-property
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BackfaceVisibility {
+    Visible,
+    Hidden,
+}
+
+impl fmt::Display for BackfaceVisibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BackfaceVisibility::Visible => write!(f, "visible"),
+            BackfaceVisibility::Hidden => write!(f, "hidden"),
+        }
+    }
+}
+
+/// The value of the `translate` property.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Translate {
+    None,
+    /// The `y` offset is `0` if missing. The `z` offset can only be given with a `y` offset.
+    Offset {
+        x: Calc,
+        y: Option<Calc>,
+        z: Option<Length>,
+    },
+}
+
+impl fmt::Display for Translate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Translate::None => write!(f, "none"),
+            Translate::Offset { x, y, z } => {
+                write!(f, "{}", x)?;
+                if let Some(y) = y {
+                    write!(f, " {}", y)?;
+                }
+                if let Some(z) = z {
+                    write!(f, " {}", z)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The value of the `rotate` property.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rotate {
+    None,
+    /// Rotate around the z axis.
+    Angle(Angle),
+    Axis(RotateAxis, Angle),
+}
+
+impl fmt::Display for Rotate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rotate::None => write!(f, "none"),
+            Rotate::Angle(v) => v.fmt(f),
+            Rotate::Axis(axis, angle) => write!(f, "{} {}", axis, angle),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RotateAxis {
+    X,
+    Y,
+    Z,
+    /// The axis `[x, y, z]`.
+    Vector(f64, f64, f64),
+}
+
+impl fmt::Display for RotateAxis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RotateAxis::X => write!(f, "x"),
+            RotateAxis::Y => write!(f, "y"),
+            RotateAxis::Z => write!(f, "z"),
+            RotateAxis::Vector(x, y, z) => write!(f, "{} {} {}", x, y, z),
+        }
+    }
+}
+
+/// The value of the `scale` property.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scale {
+    None,
+    /// If `y` is missing it is the same as `x`, and if `z` is missing it is 1. Percentages are
+    /// converted to numbers.
+    Factors {
+        x: f64,
+        y: Option<f64>,
+        z: Option<f64>,
+    },
+}
+
+impl fmt::Display for Scale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Scale::None => write!(f, "none"),
+            Scale::Factors { x, y, z } => {
+                write!(f, "{}", x)?;
+                if let Some(y) = y {
+                    write!(f, " {}", y)?;
+                }
+                if let Some(z) = z {
+                    write!(f, " {}", z)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+// Matrices and interpolation
+
+/// The box that a transform is resolved against, in css pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReferenceBox {
+    pub width: f64,
+    pub height: f64,
+    /// The computed `font-size`, for `em` and `ex` lengths.
+    pub font_size: f64,
+}
+
+impl TransformList {
+    /// The matrix of the whole list, with percentages resolved against `reference`.
+    ///
+    /// This doesn't include the `transform-origin`: see `TransformOrigin::resolve`.
+    pub fn to_matrix(&self, reference: &ReferenceBox) -> TransformMatrix {
+        self.0
+            .iter()
+            .fold(TransformMatrix::IDENTITY, |matrix, function| {
+                matrix.multiply(&function.to_matrix(reference))
+            })
+    }
+
+    /// Interpolate between two transform lists, where `t` is the progress from `self` (at 0) to
+    /// `other` (at 1).
+    ///
+    /// Functions are interpolated in pairs while they have a common primitive (so `translateX`
+    /// and `translate` are interpolated as `translate`), and the rest of the lists are
+    /// interpolated as matrices. The shorter list is padded with identity functions first.
+    /// Lengths are resolved against `reference`, so the result only contains pixel lengths.
+    ///
+    /// https://www.w3.org/TR/css-transforms-2/#interpolation-of-transforms
+    pub fn interpolate(
+        &self,
+        other: &TransformList,
+        t: f64,
+        reference: &ReferenceBox,
+    ) -> TransformList {
+        let mut from: Vec<_> = self
+            .0
+            .iter()
+            .map(|f| Primitive::new(f, reference))
+            .collect();
+        let mut to: Vec<_> = other
+            .0
+            .iter()
+            .map(|f| Primitive::new(f, reference))
+            .collect();
+        let pad = |short: &mut Vec<Primitive>, long: &[Primitive]| {
+            let identities: Vec<_> = long[short.len()..].iter().map(|p| p.identity()).collect();
+            short.extend(identities);
+        };
+        if from.len() < to.len() {
+            pad(&mut from, &to);
+        } else {
+            pad(&mut to, &from);
+        }
+
+        let mut out = Vec::with_capacity(from.len());
+        for idx in 0..from.len() {
+            match from[idx].interpolate(&to[idx], t) {
+                Some(function) => out.push(function),
+                None => {
+                    let matrix = |list: &[Primitive]| {
+                        list.iter().fold(TransformMatrix::IDENTITY, |matrix, p| {
+                            matrix.multiply(&p.to_matrix())
+                        })
+                    };
+                    out.push(interpolate_matrix(
+                        &matrix(&from[idx..]),
+                        &matrix(&to[idx..]),
+                        t,
+                    ));
+                    break;
+                }
+            }
+        }
+        TransformList(out)
+    }
+
+    /// Like `interpolate`, but without a reference box to resolve percentages and font-relative
+    /// lengths against. Gives `None` if the lists can't be interpolated without one.
+    ///
+    /// Lists that don't need the reference box are interpolated as in `interpolate`. Otherwise
+    /// each pair of functions must have a common primitive, and translations keep their
+    /// percentages and font-relative lengths by using `calc()`.
+    pub fn interpolate_unresolved(&self, other: &TransformList, t: f64) -> Option<TransformList> {
+        // Nothing depends on the reference box, so any will do.
+        let reference = ReferenceBox {
+            width: 0.0,
+            height: 0.0,
+            font_size: 0.0,
+        };
+        if self
+            .0
+            .iter()
+            .chain(&other.0)
+            .all(TransformFunction::is_absolute)
+        {
+            return Some(self.interpolate(other, t, &reference));
+        }
+        let unresolved = |list: &TransformList| {
+            list.0
+                .iter()
+                .map(|f| Unresolved::new(f, &reference))
+                .collect::<Option<Vec<_>>>()
+        };
+        let (mut from, mut to) = (unresolved(self)?, unresolved(other)?);
+        let pad = |short: &mut Vec<Unresolved>, long: &[Unresolved]| {
+            let identities: Vec<_> = long[short.len()..].iter().map(|u| u.identity()).collect();
+            short.extend(identities);
+        };
+        if from.len() < to.len() {
+            pad(&mut from, &to);
+        } else {
+            pad(&mut to, &from);
+        }
+        from.iter()
+            .zip(&to)
+            .map(|(from, to)| from.interpolate(to, t))
+            .collect::<Option<Vec<_>>>()
+            .map(TransformList)
+    }
+}
+
+/// A transform function for interpolating without a reference box: translations keep their
+/// lengths as they are, and anything else must not depend on the reference box.
+#[derive(Debug, Clone, PartialEq)]
+enum Unresolved {
+    /// The x and y offsets, the z offset, and whether it is 3d.
+    Translate([Calc; 2], Length, bool),
+    Resolved(Primitive),
+}
+
+impl Unresolved {
+    fn new(function: &TransformFunction, reference: &ReferenceBox) -> Option<Self> {
+        let zero = || Calc::Normal(LengthPercentage::Length(Length::Zero));
+        Some(match function {
+            TransformFunction::Translate(x, y) => Unresolved::Translate(
+                [x.clone(), y.clone().unwrap_or_else(zero)],
+                Length::Zero,
+                false,
+            ),
+            TransformFunction::TranslateX(x) => {
+                Unresolved::Translate([x.clone(), zero()], Length::Zero, false)
+            }
+            TransformFunction::TranslateY(y) => {
+                Unresolved::Translate([zero(), y.clone()], Length::Zero, false)
+            }
+            TransformFunction::TranslateZ(z) => Unresolved::Translate([zero(), zero()], *z, true),
+            TransformFunction::Translate3d(x, y, z) => {
+                Unresolved::Translate([x.clone(), y.clone()], *z, true)
+            }
+            f if f.is_absolute() => Unresolved::Resolved(Primitive::new(f, reference)),
+            _ => return None,
+        })
+    }
+
+    fn identity(&self) -> Self {
+        let zero = || Calc::Normal(LengthPercentage::Length(Length::Zero));
+        match self {
+            Unresolved::Translate(_, _, is_3d) => {
+                Unresolved::Translate([zero(), zero()], Length::Zero, *is_3d)
+            }
+            Unresolved::Resolved(p) => Unresolved::Resolved(p.identity()),
+        }
+    }
+
+    fn interpolate(&self, other: &Unresolved, t: f64) -> Option<TransformFunction> {
+        match (self, other) {
+            (Unresolved::Translate(a, a_z, a_3d), Unresolved::Translate(b, b_z, b_3d)) => {
+                let x = a[0].animate(&b[0], t)?;
+                let y = a[1].animate(&b[1], t)?;
+                Some(if *a_3d || *b_3d {
+                    TransformFunction::Translate3d(x, y, a_z.animate(b_z, t)?)
+                } else {
+                    TransformFunction::Translate(x, Some(y))
+                })
+            }
+            (Unresolved::Resolved(a), Unresolved::Resolved(b)) => a.interpolate(b, t),
+            _ => None,
+        }
+    }
+}
+
+impl TransformFunction {
+    /// The matrix of this function, with percentages resolved against `reference`.
+    pub fn to_matrix(&self, reference: &ReferenceBox) -> TransformMatrix {
+        Primitive::new(self, reference).to_matrix()
+    }
+
+    /// Whether this doesn't depend on the reference box or the font size.
+    pub fn is_absolute(&self) -> bool {
+        match self {
+            TransformFunction::Translate(x, y) => {
+                x.is_absolute() && y.as_ref().is_none_or(Calc::is_absolute)
+            }
+            TransformFunction::TranslateX(v) | TransformFunction::TranslateY(v) => v.is_absolute(),
+            TransformFunction::TranslateZ(z) => !z.is_font_relative(),
+            TransformFunction::Translate3d(x, y, z) => {
+                x.is_absolute() && y.is_absolute() && !z.is_font_relative()
+            }
+            TransformFunction::Perspective(v) => v.is_none_or(|v| !v.is_font_relative()),
+            _ => true,
+        }
+    }
+}
+
+impl TransformOrigin {
+    /// The origin in css pixels, relative to the top left of `reference`.
+    ///
+    /// The transform of an element is `translate(origin) * transform * translate(-origin)`.
+    pub fn resolve(&self, reference: &ReferenceBox) -> [f64; 3] {
+        let x = match &self.x {
+            OriginX::Left => 0.0,
+            OriginX::Center => reference.width / 2.0,
+            OriginX::Right => reference.width,
+            OriginX::Offset(v) => v.to_px(reference.width, reference.font_size),
+        };
+        let y = match &self.y {
+            OriginY::Top => 0.0,
+            OriginY::Center => reference.height / 2.0,
+            OriginY::Bottom => reference.height,
+            OriginY::Offset(v) => v.to_px(reference.height, reference.font_size),
+        };
+        let z = self.z.map(|z| z.to_px(reference.font_size)).unwrap_or(0.0);
+        [x, y, z]
+    }
+}
+
+/// A transform function with its lengths resolved, reduced to the primitive that it is
+/// interpolated as.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Primitive {
+    Translate([f64; 3], bool),
+    Scale([f64; 3], bool),
+    /// The axis and the angle in degrees.
+    Rotate([f64; 3], f64, bool),
+    /// Angles in degrees.
+    Skew(f64, f64),
+    Perspective(Option<f64>),
+    Matrix(TransformMatrix),
+}
+
+impl Primitive {
+    fn new(function: &TransformFunction, reference: &ReferenceBox) -> Self {
+        let x = |v: &Calc| v.to_px(reference.width, reference.font_size);
+        let y = |v: &Calc| v.to_px(reference.height, reference.font_size);
+        let z = |v: &Length| v.to_px(reference.font_size);
+        match function {
+            TransformFunction::Matrix(v) => Primitive::Matrix(TransformMatrix::from_2d(*v)),
+            TransformFunction::Matrix3d(v) => Primitive::Matrix(TransformMatrix(*v)),
+            TransformFunction::Translate(tx, ty) => {
+                Primitive::Translate([x(tx), ty.as_ref().map(y).unwrap_or(0.0), 0.0], false)
+            }
+            TransformFunction::TranslateX(v) => Primitive::Translate([x(v), 0.0, 0.0], false),
+            TransformFunction::TranslateY(v) => Primitive::Translate([0.0, y(v), 0.0], false),
+            TransformFunction::TranslateZ(v) => Primitive::Translate([0.0, 0.0, z(v)], true),
+            TransformFunction::Translate3d(tx, ty, tz) => {
+                Primitive::Translate([x(tx), y(ty), z(tz)], true)
+            }
+            TransformFunction::Scale(sx, sy) => {
+                Primitive::Scale([*sx, sy.unwrap_or(*sx), 1.0], false)
+            }
+            TransformFunction::ScaleX(v) => Primitive::Scale([*v, 1.0, 1.0], false),
+            TransformFunction::ScaleY(v) => Primitive::Scale([1.0, *v, 1.0], false),
+            TransformFunction::ScaleZ(v) => Primitive::Scale([1.0, 1.0, *v], true),
+            TransformFunction::Scale3d(sx, sy, sz) => Primitive::Scale([*sx, *sy, *sz], true),
+            TransformFunction::Rotate(a) | TransformFunction::RotateZ(a) => {
+                Primitive::Rotate([0.0, 0.0, 1.0], a.to_degrees(), false)
+            }
+            TransformFunction::RotateX(a) => {
+                Primitive::Rotate([1.0, 0.0, 0.0], a.to_degrees(), true)
+            }
+            TransformFunction::RotateY(a) => {
+                Primitive::Rotate([0.0, 1.0, 0.0], a.to_degrees(), true)
+            }
+            TransformFunction::Rotate3d(ax, ay, az, a) => {
+                Primitive::Rotate([*ax, *ay, *az], a.to_degrees(), true)
+            }
+            TransformFunction::Skew(ax, ay) => {
+                Primitive::Skew(ax.to_degrees(), ay.map(|a| a.to_degrees()).unwrap_or(0.0))
+            }
+            TransformFunction::SkewX(a) => Primitive::Skew(a.to_degrees(), 0.0),
+            TransformFunction::SkewY(a) => Primitive::Skew(0.0, a.to_degrees()),
+            TransformFunction::Perspective(v) => Primitive::Perspective(v.as_ref().map(z)),
+        }
+    }
+
+    /// The identity function with the same primitive.
+    fn identity(&self) -> Self {
+        match *self {
+            Primitive::Translate(_, is_3d) => Primitive::Translate([0.0; 3], is_3d),
+            Primitive::Scale(_, is_3d) => Primitive::Scale([1.0; 3], is_3d),
+            Primitive::Rotate(axis, _, is_3d) => Primitive::Rotate(axis, 0.0, is_3d),
+            Primitive::Skew(_, _) => Primitive::Skew(0.0, 0.0),
+            Primitive::Perspective(_) => Primitive::Perspective(None),
+            Primitive::Matrix(_) => Primitive::Matrix(TransformMatrix::IDENTITY),
+        }
+    }
+
+    fn to_matrix(self) -> TransformMatrix {
+        match self {
+            Primitive::Translate([x, y, z], _) => TransformMatrix::translate(x, y, z),
+            Primitive::Scale([x, y, z], _) => TransformMatrix::scale(x, y, z),
+            Primitive::Rotate([x, y, z], angle, _) => {
+                TransformMatrix::rotate(x, y, z, angle.to_radians())
+            }
+            Primitive::Skew(x, y) => TransformMatrix::skew(x.to_radians(), y.to_radians()),
+            Primitive::Perspective(Some(d)) => TransformMatrix::perspective(d),
+            Primitive::Perspective(None) => TransformMatrix::IDENTITY,
+            Primitive::Matrix(m) => m,
+        }
+    }
+
+    /// Interpolate with `other`, or `None` if they don't have a common primitive.
+    fn interpolate(&self, other: &Primitive, t: f64) -> Option<TransformFunction> {
+        let lerp = |a: f64, b: f64| a + (b - a) * t;
+        let lerp3 =
+            |a: [f64; 3], b: [f64; 3]| [lerp(a[0], b[0]), lerp(a[1], b[1]), lerp(a[2], b[2])];
+        Some(match (*self, *other) {
+            (Primitive::Translate(a, a_3d), Primitive::Translate(b, b_3d)) => {
+                let [x, y, z] = lerp3(a, b);
+                if a_3d || b_3d {
+                    TransformFunction::Translate3d(px(x), px(y), Length::Px(z))
+                } else {
+                    TransformFunction::Translate(px(x), Some(px(y)))
+                }
+            }
+            (Primitive::Scale(a, a_3d), Primitive::Scale(b, b_3d)) => {
+                let [x, y, z] = lerp3(a, b);
+                if a_3d || b_3d {
+                    TransformFunction::Scale3d(x, y, z)
+                } else {
+                    TransformFunction::Scale(x, Some(y))
+                }
+            }
+            (Primitive::Rotate(_, a, false), Primitive::Rotate(_, b, false)) => {
+                TransformFunction::Rotate(Angle::Deg(lerp(a, b)))
+            }
+            (Primitive::Rotate(a_axis, a, _), Primitive::Rotate(b_axis, b, _)) => {
+                // The axes only need to match if both angles are non-zero.
+                let axis = if b == 0.0 {
+                    a_axis
+                } else if a == 0.0 || normalize(a_axis) == normalize(b_axis) {
+                    b_axis
+                } else {
+                    return Some(interpolate_matrix(&self.to_matrix(), &other.to_matrix(), t));
+                };
+                TransformFunction::Rotate3d(axis[0], axis[1], axis[2], Angle::Deg(lerp(a, b)))
+            }
+            (Primitive::Skew(ax, ay), Primitive::Skew(bx, by)) => {
+                TransformFunction::Skew(Angle::Deg(lerp(ax, bx)), Some(Angle::Deg(lerp(ay, by))))
+            }
+            (Primitive::Perspective(a), Primitive::Perspective(b)) => {
+                // Perspective is interpolated as the inverse of the distance, with `none` as 0.
+                let inverse = |d: Option<f64>| d.map(|d| 1.0 / d.max(1.0)).unwrap_or(0.0);
+                let v = lerp(inverse(a), inverse(b));
+                TransformFunction::Perspective(if v > 0.0 {
+                    Some(Length::Px(1.0 / v))
+                } else {
+                    None
+                })
+            }
+            (Primitive::Matrix(a), Primitive::Matrix(b)) => interpolate_matrix(&a, &b, t),
+            _ => return None,
+        })
+    }
+}
+
+/// Interpolate two matrices, switching from one to the other half way if they can't be
+/// decomposed.
+fn interpolate_matrix(from: &TransformMatrix, to: &TransformMatrix, t: f64) -> TransformFunction {
+    let matrix = from
+        .interpolate(to, t)
+        .unwrap_or(if t < 0.5 { *from } else { *to });
+    match matrix.to_2d() {
+        Some(v) => TransformFunction::Matrix(v),
+        None => TransformFunction::Matrix3d(matrix.0),
+    }
+}
+
+fn normalize([x, y, z]: [f64; 3]) -> [f64; 3] {
+    let len = (x * x + y * y + z * z).sqrt();
+    [x / len, y / len, z / len]
+}
+
+fn px(v: f64) -> Calc {
+    Calc::Normal(LengthPercentage::Length(Length::Px(v)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let px = |v| Calc::Normal(LengthPercentage::Length(Length::Px(v)));
+        let list = TransformList(vec![
+            TransformFunction::Translate(px(10.0), Some(px(-5.0))),
+            TransformFunction::Rotate(Angle::Turn(0.25)),
+            TransformFunction::Matrix([1.0, 0.0, 0.0, 1.0, 0.0, 2.5]),
+            TransformFunction::Scale3d(1.0, 2.0, 1.0),
+            TransformFunction::Perspective(None),
+        ]);
+        assert_eq!(
+            list.to_string(),
+            "translate(10px, -5px) rotate(0.25turn) matrix(1, 0, 0, 1, 0, 2.5) \
+                scale3d(1, 2, 1) perspective(none)"
+        );
+        assert_eq!(TransformList::none().to_string(), "none");
+        assert_eq!(
+            Rotate::Axis(RotateAxis::Vector(1.0, 1.0, 0.0), Angle::Deg(45.0)).to_string(),
+            "1 1 0 45deg"
+        );
+        assert_eq!(
+            TransformOrigin {
+                x: OriginX::Left,
+                y: OriginY::Offset(px(2.0)),
+                z: Some(Length::Px(3.0)),
+            }
+            .to_string(),
+            "left 2px 3px"
+        );
+    }
+
+    const BOX: ReferenceBox = ReferenceBox {
+        width: 200.0,
+        height: 100.0,
+        font_size: 16.0,
+    };
+
+    #[test]
+    fn to_matrix() {
+        let list: TransformList = "translate(50%, 1em) scale(2) rotate(90deg)"
+            .parse()
+            .unwrap();
+        let m = list.to_matrix(&BOX);
+        let p = m.transform_point([1.0, 0.0, 0.0]);
+        assert!((p[0] - 100.0).abs() < 1e-9 && (p[1] - 18.0).abs() < 1e-9);
+        let back = m.invert().unwrap().transform_point(p);
+        assert!((back[0] - 1.0).abs() < 1e-9 && back[1].abs() < 1e-9);
+
+        let origin: TransformOrigin = "right 25% 2px".parse().unwrap();
+        assert_eq!(origin.resolve(&BOX), [200.0, 25.0, 2.0]);
+    }
+
+    #[test]
+    fn interpolate() {
+        let list = |s: &str| s.parse::<TransformList>().unwrap();
+        let from = list("translateX(10px) rotate(0deg)");
+        let to = list("translate(50%, 20px) rotate(1turn) scale(3)");
+        assert_eq!(
+            from.interpolate(&to, 0.5, &BOX).to_string(),
+            "translate(55px, 10px) rotate(180deg) scale(2, 2)"
+        );
+        assert_eq!(
+            TransformList::none()
+                .interpolate(&list("perspective(100px)"), 0.5, &BOX)
+                .to_string(),
+            "perspective(200px)"
+        );
+        assert!(TransformList::none()
+            .interpolate(&TransformList::none(), 0.5, &BOX)
+            .is_none());
+        // the lists don't match after the first function, so the rest are matrices
+        assert_eq!(
+            list("scale(1) translateX(10px)")
+                .interpolate(&list("scale(3) rotate(0)"), 0.5, &BOX)
+                .to_string(),
+            "scale(2, 2) matrix(1, 0, 0, 1, 5, 0)"
+        );
+    }
+}
@@ -0,0 +1,267 @@
+//! Values of the writing mode and bidi properties, from
+//! https://www.w3.org/TR/css-writing-modes-4/.
+use std::fmt;
+
+/// The inline base direction of text.
+///
+/// https://www.w3.org/TR/css-writing-modes-4/#direction
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Ltr,
+    Rtl,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Direction::Ltr => "ltr",
+            Direction::Rtl => "rtl",
+        })
+    }
+}
+
+/// https://www.w3.org/TR/css-writing-modes-4/#unicode-bidi
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnicodeBidi {
+    Normal,
+    Embed,
+    Isolate,
+    BidiOverride,
+    IsolateOverride,
+    Plaintext,
+}
+
+impl fmt::Display for UnicodeBidi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            UnicodeBidi::Normal => "normal",
+            UnicodeBidi::Embed => "embed",
+            UnicodeBidi::Isolate => "isolate",
+            UnicodeBidi::BidiOverride => "bidi-override",
+            UnicodeBidi::IsolateOverride => "isolate-override",
+            UnicodeBidi::Plaintext => "plaintext",
+        })
+    }
+}
+
+/// Whether lines are laid out horizontally or vertically, and which way blocks progress.
+///
+/// https://www.w3.org/TR/css-writing-modes-4/#block-flow
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WritingMode {
+    HorizontalTb,
+    VerticalRl,
+    VerticalLr,
+    /// Like `vertical-rl`, but all text is set sideways as if it were horizontal text turned
+    /// 90° clockwise.
+    SidewaysRl,
+    /// Like `vertical-lr`, but all text is set sideways as if it were horizontal text turned
+    /// 90° counter-clockwise.
+    SidewaysLr,
+}
+
+impl WritingMode {
+    /// Whether lines are laid out vertically.
+    pub fn is_vertical(self) -> bool {
+        !matches!(self, WritingMode::HorizontalTb)
+    }
+}
+
+impl fmt::Display for WritingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            WritingMode::HorizontalTb => "horizontal-tb",
+            WritingMode::VerticalRl => "vertical-rl",
+            WritingMode::VerticalLr => "vertical-lr",
+            WritingMode::SidewaysRl => "sideways-rl",
+            WritingMode::SidewaysLr => "sideways-lr",
+        })
+    }
+}
+
+/// How characters are set in a line in `vertical-rl` and `vertical-lr`. It has no effect in
+/// the other writing modes.
+///
+/// https://www.w3.org/TR/css-writing-modes-4/#text-orientation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextOrientation {
+    /// Set characters from horizontal scripts sideways, and from vertical scripts upright.
+    Mixed,
+    /// Set all characters upright. This also makes the used `direction` `ltr`.
+    Upright,
+    /// Set all characters sideways.
+    Sideways,
+}
+
+impl fmt::Display for TextOrientation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TextOrientation::Mixed => "mixed",
+            TextOrientation::Upright => "upright",
+            TextOrientation::Sideways => "sideways",
+        })
+    }
+}
+
+/// Which characters to squeeze into the space of a single character in vertical text.
+///
+/// https://www.w3.org/TR/css-writing-modes-4/#text-combine-upright
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextCombineUpright {
+    None,
+    All,
+    /// Runs of up to this many ascii digits, between 2 and 4. `None` is 2.
+    Digits(Option<u8>),
+}
+
+impl fmt::Display for TextCombineUpright {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TextCombineUpright::None => f.write_str("none"),
+            TextCombineUpright::All => f.write_str("all"),
+            TextCombineUpright::Digits(None) => f.write_str("digits"),
+            TextCombineUpright::Digits(Some(v)) => write!(f, "digits {}", v),
+        }
+    }
+}
+
+/// A side of a box on the screen, rather than relative to the flow of text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PhysicalSide {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl fmt::Display for PhysicalSide {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            PhysicalSide::Top => "top",
+            PhysicalSide::Right => "right",
+            PhysicalSide::Bottom => "bottom",
+            PhysicalSide::Left => "left",
+        })
+    }
+}
+
+/// The physical side that each of the logical sides of a box maps to.
+///
+/// https://www.w3.org/TR/css-writing-modes-4/#logical-to-physical
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogicalSides {
+    pub block_start: PhysicalSide,
+    pub block_end: PhysicalSide,
+    pub inline_start: PhysicalSide,
+    pub inline_end: PhysicalSide,
+}
+
+impl LogicalSides {
+    /// The mapping for a box with the given `writing-mode`, `direction` and `text-orientation`.
+    pub fn new(
+        writing_mode: WritingMode,
+        direction: Direction,
+        text_orientation: TextOrientation,
+    ) -> Self {
+        use PhysicalSide::*;
+        let direction = match (writing_mode, text_orientation) {
+            (WritingMode::VerticalRl | WritingMode::VerticalLr, TextOrientation::Upright) => {
+                Direction::Ltr
+            }
+            _ => direction,
+        };
+        let (block_start, block_end) = match writing_mode {
+            WritingMode::HorizontalTb => (Top, Bottom),
+            WritingMode::VerticalRl | WritingMode::SidewaysRl => (Right, Left),
+            WritingMode::VerticalLr | WritingMode::SidewaysLr => (Left, Right),
+        };
+        // the start and end of a line when `direction` is `ltr`
+        let (line_left, line_right) = match writing_mode {
+            WritingMode::HorizontalTb => (Left, Right),
+            WritingMode::VerticalRl | WritingMode::VerticalLr | WritingMode::SidewaysRl => {
+                (Top, Bottom)
+            }
+            WritingMode::SidewaysLr => (Bottom, Top),
+        };
+        let (inline_start, inline_end) = match direction {
+            Direction::Ltr => (line_left, line_right),
+            Direction::Rtl => (line_right, line_left),
+        };
+        LogicalSides {
+            block_start,
+            block_end,
+            inline_start,
+            inline_end,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn logical_sides() {
+        use PhysicalSide::*;
+        for (writing_mode, direction, text_orientation, sides) in [
+            (
+                WritingMode::HorizontalTb,
+                Direction::Ltr,
+                TextOrientation::Mixed,
+                [Top, Bottom, Left, Right],
+            ),
+            (
+                WritingMode::HorizontalTb,
+                Direction::Rtl,
+                TextOrientation::Upright,
+                [Top, Bottom, Right, Left],
+            ),
+            (
+                WritingMode::VerticalRl,
+                Direction::Ltr,
+                TextOrientation::Mixed,
+                [Right, Left, Top, Bottom],
+            ),
+            (
+                WritingMode::VerticalRl,
+                Direction::Rtl,
+                TextOrientation::Mixed,
+                [Right, Left, Bottom, Top],
+            ),
+            // upright text is always laid out left to right
+            (
+                WritingMode::VerticalLr,
+                Direction::Rtl,
+                TextOrientation::Upright,
+                [Left, Right, Top, Bottom],
+            ),
+            (
+                WritingMode::SidewaysRl,
+                Direction::Rtl,
+                TextOrientation::Upright,
+                [Right, Left, Bottom, Top],
+            ),
+            (
+                WritingMode::SidewaysLr,
+                Direction::Ltr,
+                TextOrientation::Mixed,
+                [Left, Right, Bottom, Top],
+            ),
+        ] {
+            let [block_start, block_end, inline_start, inline_end] = sides;
+            assert_eq!(
+                LogicalSides::new(writing_mode, direction, text_orientation),
+                LogicalSides {
+                    block_start,
+                    block_end,
+                    inline_start,
+                    inline_end,
+                },
+                "{} {} {}",
+                writing_mode,
+                direction,
+                text_orientation
+            );
+        }
+    }
+}
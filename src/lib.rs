@@ -1,11 +1,42 @@
 use proc_macro_hack::proc_macro_hack;
 
 pub use style_shared::{
-    AlignItems, AutoLengthPercentage, Border, BoxShadow, BoxSizing, Calc, Color, ColumnCount,
-    Display, DynamicColor, FlexBasis, FlexDirection, FlexWrap, Font, FontFamily, FontStyle,
-    FontWeight, JustifyContent, Length, LengthPercentage, LineStyle, LineWidth, ListStyleType,
-    Margin, MarginWidth, MaxWidthHeight, NonemptyCommaList, Padding, PaddingWidth, Percentage,
-    Rect, Resize, Shadow, ShadowLength, Style, Styles, TextAlign, Width21, WidthHeight,
+    discrete, sample_keyframes, AlignItems, Angle, AnglePercentage, Animate, AnimationDirection,
+    AnimationFillMode, AnimationIterationCount, AnimationName, AnimationPlayState, AtRule,
+    AtRuleBlock, AttributeMatcher, AttributeOperator, AttributeSelector, AutoLengthPercentage,
+    BackfaceVisibility, Background, BackgroundAttachment, BackgroundBox, BackgroundImage,
+    BackgroundLayer, BackgroundRepeat, BackgroundSize, BgRepeatPart, BlendMode, Border,
+    BorderImage, BorderImageRepeat, BorderImageRepeatMode, BorderImageSlice, BorderImageSource,
+    BorderImageWidth, BorderRadius, BoxShadow, BoxSizing, Calc, Cascade, CaseSensitivity, Color,
+    ColorSpace, ColorStopList, ColumnCount, Combinator, CompoundSelector, ConicGradient,
+    CrossOrigin, Declaration, DecomposedMatrix, Direction, Display, DynamicColor, EasingFunction,
+    EastAsianVariant, EastAsianWidth, Element, EndingShape, FeatureTagValue, Flex, FlexBasis,
+    FlexDirection, FlexFlow, FlexWrap, Font, FontFamily, FontFeatureSettings, FontKerning,
+    FontMetric, FontShorthand, FontSize, FontSizeAdjust, FontStretch, FontStyle, FontSynthesis,
+    FontVariant, FontVariantAlternates, FontVariantCaps, FontVariantEastAsian, FontVariantEmoji,
+    FontVariantLigatures, FontVariantNumeric, FontVariantPosition, FontVariationSettings,
+    FontWeight, Gradient, GradientItem, Grid, GridArea, GridAreaLines, GridAreas, GridAutoFlow,
+    GridAutoTracks, GridLine, GridPlacement, GridTemplate, GridTemplateAreas, GridTemplateRow,
+    GridTemplateTracks, HangingPunctuation, HangingPunctuationEnd, HorizontalSide,
+    HyphenateCharacter, Hyphens, Image, ImageSet, ImageSetOption, JustifyContent, Keyframe, Length,
+    LengthPercentage, LineBreak, LineDirection, LineHeight, LineNames, LineStyle, LineWidth,
+    LinearGradient, LinearStop, ListStyleType, LogicalSides, Margin, MarginWidth, MaxWidthHeight,
+    NamespacePrefix, Namespaces, NonemptyCommaList, Nth, NumberOrLength, NumberOrPercentage,
+    NumericFigure, NumericFraction, NumericSpacing, Origin, OriginX, OriginY, OverflowWrap,
+    Padding, PaddingWidth, ParseError, Percentage, Perspective, PhysicalSide, PositionComponent,
+    PositionValue, PseudoClass, PseudoElement, PseudoElementSelector, QualifiedRule,
+    RadialGradient, RadialSize, Rect, ReferenceBox, ReferrerPolicy, RelativeSelector, RepeatCount,
+    Resize, Resolution, Rotate, RotateAxis, Rule, Scale, Selector, SelectorList, Shadow,
+    ShadowLength, SingleAnimation, SingleOrDouble, SingleTransition, Spacing, Specificity,
+    StepPosition, Style, Styles, Stylesheet, SubclassSelector, SubgridLineNames, SystemFont,
+    TextAlign, TextCombineUpright, TextDecoration, TextDecorationColor, TextDecorationLine,
+    TextDecorationStyle, TextDecorationThickness, TextIndent, TextOrientation, TextShadow,
+    TextTransform, TextTransformCase, TextUnderlinePosition, TextWrap, TextWrapMode, TextWrapStyle,
+    Time, TrackBreadth, TrackList, TrackListItem, TrackRepeat, TrackSize, TransformFunction,
+    TransformList, TransformMatrix, TransformOrigin, TransformStyle, TransitionBehavior,
+    TransitionProperty, Translate, TypeName, TypeSelector, UnicodeBidi, Url, UrlModifier,
+    VariationAxis, VerticalSide, WhiteSpace, WhiteSpaceCollapse, Width21, WidthHeight, WordBreak,
+    WritingMode,
 };
 
 /// Parse a list of css properties.
@@ -80,6 +111,39 @@ pub use style_proc::styles;
 #[proc_macro_hack]
 pub use style_proc::static_styles;
 
+/// Parse a css stylesheet: a list of rules, each with selectors and a block of properties, and
+/// at-rules like `@media`.
+///
+/// Selectors are parsed into a `SelectorList`, and at-rule preludes are kept as text. Rust
+/// doesn't keep whitespace, so a space before `.`, `#` or `:` is lost (`div .a` becomes
+/// `div.a`). Write the selectors as a string literal (the whole prelude) if you need it.
+///
+/// # Examples
+///
+/// ```
+/// # use style::*;
+/// let sheet = stylesheet! {
+///     a:hover, li > *.item {
+///         color: red;
+///     }
+///     "nav .item" {
+///         color: red;
+///     }
+///     @media print and (min-width: 600px) {
+///         div > p { padding: 0 1"em"; }
+///     }
+/// };
+///
+/// assert_eq!(sheet.rules.len(), 3);
+/// assert_eq!(
+///     sheet.to_string(),
+///     "a:hover, li > .item{color:red;}nav .item{color:red;}\
+///         @media print and (min-width:600px){div > p{padding:0 1em;}}"
+/// );
+/// ```
+#[proc_macro_hack]
+pub use style_proc::stylesheet;
+
 /// Parse a css property.
 ///
 /// # Examples